@@ -11,6 +11,8 @@ import { AuthClient } from "../utils/auth";
 let api = new AdminSDK({
 	accessToken: () => AuthClient.getToken(),
 	refreshToken: () => AuthClient.forceToken(),
+	authHeaders: (token, method, url) =>
+		AuthClient.authHeaders(token, method, url),
 	baseURL: "/api",
 	projectId: window.VULPO_ADMIN_ID,
 });
@@ -58,7 +60,15 @@ let adminApiQuery: BaseQueryFn = (_args) => {
 export let adminApi = createApi({
 	reducerPath: "admin_api",
 	baseQuery: adminApiQuery,
-	tagTypes: ["User", "Users"],
+	tagTypes: [
+		"User",
+		"Users",
+		"UserRoles",
+		"UserSessions",
+		"ServiceAccounts",
+		"OAuthClients",
+		"UserInvitations",
+	],
 	endpoints: (builder) => ({
 		getProjects: builder.query({
 			queryFn: toQueryFn<typeof api.getProjects>(api.getProjects),
@@ -79,6 +89,11 @@ export let adminApi = createApi({
 				}
 			},
 		}),
+		revokeProjectTokens: builder.mutation({
+			queryFn: toQueryFn<typeof api.revokeProjectTokens>(
+				api.revokeProjectTokens,
+			),
+		}),
 
 		setProject: builder.mutation({
 			queryFn: toQueryFn<typeof api.setProject>(api.setProject),
@@ -290,6 +305,12 @@ export let adminApi = createApi({
 				res ? [{ type: "User", id: res.userId }, "User"] : [],
 		}),
 
+		restoreUser: builder.mutation({
+			queryFn: toQueryFn<typeof api.restoreUser>(api.restoreUser),
+			invalidatesTags: (res) =>
+				res ? [{ type: "User", id: res.userId }, "Users"] : [],
+		}),
+
 		disableUser: builder.mutation({
 			queryFn: toQueryFn<typeof api.disableUser>(api.disableUser),
 			async onQueryStarted(
@@ -395,9 +416,387 @@ export let adminApi = createApi({
 			},
 		}),
 
+		getPasswordlessSettings: builder.query({
+			queryFn: toQueryFn<typeof api.getPasswordlessSettings>(
+				api.getPasswordlessSettings,
+			),
+		}),
+
+		setPasswordlessSettings: builder.mutation({
+			queryFn: toQueryFn<typeof api.setPasswordlessSettings>(
+				api.setPasswordlessSettings,
+			),
+			async onQueryStarted([projectId, settings], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getPasswordlessSettings",
+							[projectId],
+							() => settings,
+						),
+					);
+				} catch (err) {
+					console.error("setPasswordlessSettings: ", err);
+				}
+			},
+		}),
+
+		getEmailVerificationSettings: builder.query({
+			queryFn: toQueryFn<typeof api.getEmailVerificationSettings>(
+				api.getEmailVerificationSettings,
+			),
+		}),
+
+		setEmailVerificationSettings: builder.mutation({
+			queryFn: toQueryFn<typeof api.setEmailVerificationSettings>(
+				api.setEmailVerificationSettings,
+			),
+			async onQueryStarted([projectId, settings], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getEmailVerificationSettings",
+							[projectId],
+							() => settings,
+						),
+					);
+				} catch (err) {
+					console.error("setEmailVerificationSettings: ", err);
+				}
+			},
+		}),
+
+		getAnonymousSettings: builder.query({
+			queryFn: toQueryFn<typeof api.getAnonymousSettings>(
+				api.getAnonymousSettings,
+			),
+		}),
+
+		setAnonymousSettings: builder.mutation({
+			queryFn: toQueryFn<typeof api.setAnonymousSettings>(
+				api.setAnonymousSettings,
+			),
+			async onQueryStarted([projectId, settings], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getAnonymousSettings",
+							[projectId],
+							() => settings,
+						),
+					);
+				} catch (err) {
+					console.error("setAnonymousSettings: ", err);
+				}
+			},
+		}),
+
+		getSessionSettings: builder.query({
+			queryFn: toQueryFn<typeof api.getSessionSettings>(api.getSessionSettings),
+		}),
+
+		setSessionSettings: builder.mutation({
+			queryFn: toQueryFn<typeof api.setSessionSettings>(api.setSessionSettings),
+			async onQueryStarted([projectId, settings], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getSessionSettings",
+							[projectId],
+							() => settings,
+						),
+					);
+				} catch (err) {
+					console.error("setSessionSettings: ", err);
+				}
+			},
+		}),
+
+		getReauthenticationSettings: builder.query({
+			queryFn: toQueryFn<typeof api.getReauthenticationSettings>(
+				api.getReauthenticationSettings,
+			),
+		}),
+
+		setReauthenticationSettings: builder.mutation({
+			queryFn: toQueryFn<typeof api.setReauthenticationSettings>(
+				api.setReauthenticationSettings,
+			),
+			async onQueryStarted([projectId, settings], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getReauthenticationSettings",
+							[projectId],
+							() => settings,
+						),
+					);
+				} catch (err) {
+					console.error("setReauthenticationSettings: ", err);
+				}
+			},
+		}),
+
+		getAccountDeletionSettings: builder.query({
+			queryFn: toQueryFn<typeof api.getAccountDeletionSettings>(
+				api.getAccountDeletionSettings,
+			),
+		}),
+
+		setAccountDeletionSettings: builder.mutation({
+			queryFn: toQueryFn<typeof api.setAccountDeletionSettings>(
+				api.setAccountDeletionSettings,
+			),
+			async onQueryStarted([projectId, settings], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getAccountDeletionSettings",
+							[projectId],
+							() => settings,
+						),
+					);
+				} catch (err) {
+					console.error("setAccountDeletionSettings: ", err);
+				}
+			},
+		}),
+
+		getCorsSettings: builder.query({
+			queryFn: toQueryFn<typeof api.getCorsSettings>(api.getCorsSettings),
+		}),
+
+		setCorsSettings: builder.mutation({
+			queryFn: toQueryFn<typeof api.setCorsSettings>(api.setCorsSettings),
+			async onQueryStarted([projectId, settings], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getCorsSettings",
+							[projectId],
+							() => settings,
+						),
+					);
+				} catch (err) {
+					console.error("setCorsSettings: ", err);
+				}
+			},
+		}),
+
+		getRedirectUrls: builder.query({
+			queryFn: toQueryFn<typeof api.getRedirectUrls>(api.getRedirectUrls),
+		}),
+
+		setRedirectUrls: builder.mutation({
+			queryFn: toQueryFn<typeof api.setRedirectUrls>(api.setRedirectUrls),
+			async onQueryStarted([projectId, redirectUrls], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getRedirectUrls",
+							[projectId],
+							() => redirectUrls,
+						),
+					);
+				} catch (err) {
+					console.error("setRedirectUrls: ", err);
+				}
+			},
+		}),
+
+		getCaptchaSettings: builder.query({
+			queryFn: toQueryFn<typeof api.getCaptchaSettings>(api.getCaptchaSettings),
+		}),
+
+		setCaptchaSettings: builder.mutation({
+			queryFn: toQueryFn<typeof api.setCaptchaSettings>(api.setCaptchaSettings),
+			async onQueryStarted([projectId, settings], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getCaptchaSettings",
+							[projectId],
+							() => settings,
+						),
+					);
+				} catch (err) {
+					console.error("setCaptchaSettings: ", err);
+				}
+			},
+		}),
+
+		getEmailDomains: builder.query({
+			queryFn: toQueryFn<typeof api.getEmailDomains>(api.getEmailDomains),
+		}),
+
+		setEmailDomains: builder.mutation({
+			queryFn: toQueryFn<typeof api.setEmailDomains>(api.setEmailDomains),
+			async onQueryStarted([projectId, emailDomains], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getEmailDomains",
+							[projectId],
+							() => emailDomains,
+						),
+					);
+				} catch (err) {
+					console.error("setEmailDomains: ", err);
+				}
+			},
+		}),
+
+		getHostedPages: builder.query({
+			queryFn: toQueryFn<typeof api.getHostedPages>(api.getHostedPages),
+		}),
+
+		setHostedPages: builder.mutation({
+			queryFn: toQueryFn<typeof api.setHostedPages>(api.setHostedPages),
+			async onQueryStarted([projectId, hostedPages], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getHostedPages",
+							[projectId],
+							() => hostedPages,
+						),
+					);
+				} catch (err) {
+					console.error("setHostedPages: ", err);
+				}
+			},
+		}),
+
+		getLinkingSettings: builder.query({
+			queryFn: toQueryFn<typeof api.getLinkingSettings>(api.getLinkingSettings),
+		}),
+
+		setLinkingSettings: builder.mutation({
+			queryFn: toQueryFn<typeof api.setLinkingSettings>(api.setLinkingSettings),
+			async onQueryStarted([projectId, settings], { dispatch, queryFulfilled }) {
+				try {
+					await queryFulfilled;
+					dispatch(
+						adminApi.util.updateQueryData(
+							"getLinkingSettings",
+							[projectId],
+							() => settings,
+						),
+					);
+				} catch (err) {
+					console.error("setLinkingSettings: ", err);
+				}
+			},
+		}),
+
+		getRoles: builder.query({
+			queryFn: toQueryFn<typeof api.getRoles>(api.getRoles),
+		}),
+
+		getUserRoles: builder.query({
+			queryFn: toQueryFn<typeof api.getUserRoles>(api.getUserRoles),
+			providesTags: (_result, _error, [userId]) => [
+				{ type: "UserRoles" as const, id: userId },
+			],
+		}),
+
+		setUserRoles: builder.mutation({
+			queryFn: toQueryFn<typeof api.setUserRoles>(api.setUserRoles),
+			invalidatesTags: (_res, _error, [userId]) => [
+				{ type: "UserRoles", id: userId },
+			],
+		}),
+
+		getUserSessions: builder.query({
+			queryFn: toQueryFn<typeof api.getUserSessions>(api.getUserSessions),
+			providesTags: (_result, _error, [userId]) => [
+				{ type: "UserSessions" as const, id: userId },
+			],
+		}),
+
+		revokeUserSession: builder.mutation({
+			queryFn: toQueryFn<typeof api.revokeUserSession>(api.revokeUserSession),
+			invalidatesTags: (_res, _error, [_sessionId, userId]) => [
+				{ type: "UserSessions", id: userId },
+			],
+		}),
+
 		getPublicKeys: builder.query({
 			queryFn: toQueryFn<typeof api.getPublicKeys>(api.getPublicKeys),
 		}),
+
+		inviteAdmin: builder.mutation({
+			queryFn: toQueryFn<typeof api.inviteAdmin>(api.inviteAdmin),
+		}),
+
+		getUserInvitations: builder.query({
+			queryFn: toQueryFn<typeof api.listUserInvitations>(
+				api.listUserInvitations,
+			),
+			providesTags: ["UserInvitations"],
+		}),
+		inviteUser: builder.mutation({
+			queryFn: toQueryFn<typeof api.inviteUser>(api.inviteUser),
+			invalidatesTags: ["UserInvitations", "Users"],
+		}),
+		revokeUserInvitation: builder.mutation({
+			queryFn: toQueryFn<typeof api.revokeUserInvitation>(
+				api.revokeUserInvitation,
+			),
+			invalidatesTags: ["UserInvitations", "Users"],
+		}),
+
+		getServiceAccounts: builder.query({
+			queryFn: toQueryFn<typeof api.getServiceAccounts>(
+				api.getServiceAccounts,
+			),
+			providesTags: ["ServiceAccounts"],
+		}),
+		createServiceAccount: builder.mutation({
+			queryFn: toQueryFn<typeof api.createServiceAccount>(
+				api.createServiceAccount,
+			),
+			invalidatesTags: ["ServiceAccounts"],
+		}),
+		rotateServiceAccountSecret: builder.mutation({
+			queryFn: toQueryFn<typeof api.rotateServiceAccountSecret>(
+				api.rotateServiceAccountSecret,
+			),
+		}),
+		deleteServiceAccount: builder.mutation({
+			queryFn: toQueryFn<typeof api.deleteServiceAccount>(
+				api.deleteServiceAccount,
+			),
+			invalidatesTags: ["ServiceAccounts"],
+		}),
+
+		getOAuthClients: builder.query({
+			queryFn: toQueryFn<typeof api.getOAuthClients>(api.getOAuthClients),
+			providesTags: ["OAuthClients"],
+		}),
+		createOAuthClient: builder.mutation({
+			queryFn: toQueryFn<typeof api.createOAuthClient>(api.createOAuthClient),
+			invalidatesTags: ["OAuthClients"],
+		}),
+		updateOAuthClient: builder.mutation({
+			queryFn: toQueryFn<typeof api.updateOAuthClient>(api.updateOAuthClient),
+			invalidatesTags: ["OAuthClients"],
+		}),
+		deleteOAuthClient: builder.mutation({
+			queryFn: toQueryFn<typeof api.deleteOAuthClient>(api.deleteOAuthClient),
+			invalidatesTags: ["OAuthClients"],
+		}),
 	}),
 });
 
@@ -406,6 +805,7 @@ export let {
 	useSetProjectMutation,
 	useDeleteProjectMutation,
 	useCreateProjectMutation,
+	useRevokeProjectTokensMutation,
 
 	useReloadUsersMutation,
 	useGetUsersQuery,
@@ -413,6 +813,7 @@ export let {
 	useUpdateUserMutation,
 	useCreateUserMutation,
 	useDeleteUserMutation,
+	useRestoreUserMutation,
 	useDisableUserMutation,
 	useVerifyUserEmailMutation,
 	useRequestPasswordResetMutation,
@@ -424,7 +825,48 @@ export let {
 	useSetFlagsMutation,
 	useGetGoogleSettingsQuery,
 	useSaveGoogleSettingsMutation,
+	useGetPasswordlessSettingsQuery,
+	useSetPasswordlessSettingsMutation,
+	useGetEmailVerificationSettingsQuery,
+	useSetEmailVerificationSettingsMutation,
+	useGetAnonymousSettingsQuery,
+	useSetAnonymousSettingsMutation,
+	useGetSessionSettingsQuery,
+	useSetSessionSettingsMutation,
+	useGetReauthenticationSettingsQuery,
+	useSetReauthenticationSettingsMutation,
+	useGetAccountDeletionSettingsQuery,
+	useSetAccountDeletionSettingsMutation,
+	useGetCorsSettingsQuery,
+	useSetCorsSettingsMutation,
+	useGetRedirectUrlsQuery,
+	useSetRedirectUrlsMutation,
+	useGetEmailDomainsQuery,
+	useSetEmailDomainsMutation,
+	useGetCaptchaSettingsQuery,
+	useSetCaptchaSettingsMutation,
+	useGetHostedPagesQuery,
+	useSetHostedPagesMutation,
+	useGetLinkingSettingsQuery,
+	useSetLinkingSettingsMutation,
+	useGetRolesQuery,
+	useGetUserRolesQuery,
+	useSetUserRolesMutation,
+	useGetUserSessionsQuery,
+	useRevokeUserSessionMutation,
 	useGetPublicKeysQuery,
+	useInviteAdminMutation,
+	useGetUserInvitationsQuery,
+	useInviteUserMutation,
+	useRevokeUserInvitationMutation,
+	useGetServiceAccountsQuery,
+	useCreateServiceAccountMutation,
+	useRotateServiceAccountSecretMutation,
+	useDeleteServiceAccountMutation,
+	useGetOAuthClientsQuery,
+	useCreateOAuthClientMutation,
+	useUpdateOAuthClientMutation,
+	useDeleteOAuthClientMutation,
 } = adminApi;
 
 export default adminApi;
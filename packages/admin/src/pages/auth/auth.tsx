@@ -12,6 +12,8 @@ import { Flag } from "@vulpo-dev/auth-sdk";
 import { Navigate, Route, Routes, useNavigate } from "react-router-dom";
 import "@vulpo-dev/auth-ui/styles.css";
 
+import { AcceptInvitation } from "./invitation";
+
 export let AuthPage = () => {
 	let navigate = useNavigate();
 
@@ -23,6 +25,7 @@ export let AuthPage = () => {
 						<AuthWrapper className="vulpo-auth-box-shadow">
 							<Routes>
 								<Route path='set_password' element={<SetPassword />} />
+								<Route path='invitation' element={<AcceptInvitation />} />
 								<Route
 									path='signin'
 									element={
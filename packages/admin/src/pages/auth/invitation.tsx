@@ -0,0 +1,53 @@
+import { FormEvent, useState } from "react";
+import { useNavigate, useSearchParams } from "react-router-dom";
+import { acceptAdminInvitation } from "@vulpo-dev/auth-sdk-admin";
+
+import { Input, Label, Section } from "werkbank/component/form";
+import { Button } from "werkbank/component/button";
+
+export let AcceptInvitation = () => {
+	let navigate = useNavigate();
+	let [search] = useSearchParams();
+	let [loading, setLoading] = useState(false);
+	let [error, setError] = useState(false);
+
+	async function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let data = new FormData(e.target as HTMLFormElement);
+		let password = data.get("password")?.toString() || null;
+
+		setLoading(true);
+		setError(false);
+
+		try {
+			await acceptAdminInvitation(window.VULPO_ADMIN_BASE_URL ?? "/api", {
+				id: search.get("id") ?? "",
+				token: search.get("token") ?? "",
+				password,
+			});
+
+			navigate("/auth/signin");
+		} catch (_err) {
+			setError(true);
+		} finally {
+			setLoading(false);
+		}
+	}
+
+	return (
+		<form onSubmit={handleSubmit}>
+			<h2>Accept Invitation</h2>
+
+			<Section>
+				<Label htmlFor="password">Password:</Label>
+				<Input id="password" name="password" type="password" minLength={8} />
+				<p>Leave empty if you already have a dashboard account.</p>
+			</Section>
+
+			{error && <p>The invitation is invalid or has expired.</p>}
+
+			<Button loading={loading}>Accept</Button>
+		</form>
+	);
+};
@@ -3,15 +3,29 @@ import styled from "@emotion/styled";
 import { Button } from "werkbank/component/button";
 import { Input, Label, Section } from "werkbank/component/form";
 import { Flow } from "werkbank/component/loading";
-import { Flags, GoogleConfig, ProjectFlags } from "@vulpo-dev/auth-sdk-admin";
+import {
+	EmailVerificationPolicy,
+	Flags,
+	GoogleConfig,
+	PasswordlessMode,
+	ProjectFlags,
+} from "@vulpo-dev/auth-sdk-admin";
 import { Key } from "@phosphor-icons/react";
 
 import adminApi, {
+	useGetAnonymousSettingsQuery,
 	useGetEmailSettingsQuery,
+	useGetEmailVerificationSettingsQuery,
 	useGetFlagsQuery,
 	useGetGoogleSettingsQuery,
+	useGetLinkingSettingsQuery,
+	useGetPasswordlessSettingsQuery,
 	useSaveGoogleSettingsMutation,
+	useSetAnonymousSettingsMutation,
+	useSetEmailVerificationSettingsMutation,
 	useSetFlagsMutation,
+	useSetLinkingSettingsMutation,
+	useSetPasswordlessSettingsMutation,
 } from "../../data/admin_api";
 import { useActiveProject } from "../../data/project";
 import {
@@ -27,12 +41,24 @@ let GOOGLE = "google-form";
 export function usePrefetchAuthMethods() {
 	let prefetchEmailSettings = adminApi.usePrefetch("getEmailSettings");
 	let prefetchGoogleSettings = adminApi.usePrefetch("getGoogleSettings");
+	let prefetchPasswordlessSettings = adminApi.usePrefetch(
+		"getPasswordlessSettings",
+	);
+	let prefetchEmailVerificationSettings = adminApi.usePrefetch(
+		"getEmailVerificationSettings",
+	);
+	let prefetchAnonymousSettings = adminApi.usePrefetch("getAnonymousSettings");
+	let prefetchLinkingSettings = adminApi.usePrefetch("getLinkingSettings");
 	let prefetchFlags = adminApi.usePrefetch("getFlags");
 
 	return useCallback((project: string) => {
 		prefetchFlags([project]);
 		prefetchEmailSettings([project]);
 		prefetchGoogleSettings([project]);
+		prefetchPasswordlessSettings([project]);
+		prefetchEmailVerificationSettings([project]);
+		prefetchAnonymousSettings([project]);
+		prefetchLinkingSettings([project]);
 	}, []);
 }
 
@@ -44,8 +70,29 @@ export let AuthMethods = () => {
 	let googleConfig = useGetGoogleSettingsQuery([project]);
 	let [saveGoogleConfig, saveGoogleConfigResult] =
 		useSaveGoogleSettingsMutation();
-
-	if (flags.isLoading || googleConfig.data === undefined) {
+	let passwordlessSettings = useGetPasswordlessSettingsQuery([project]);
+	let [savePasswordlessSettings, savePasswordlessSettingsResult] =
+		useSetPasswordlessSettingsMutation();
+	let emailVerificationSettings = useGetEmailVerificationSettingsQuery([
+		project,
+	]);
+	let [saveEmailVerificationSettings, saveEmailVerificationSettingsResult] =
+		useSetEmailVerificationSettingsMutation();
+	let anonymousSettings = useGetAnonymousSettingsQuery([project]);
+	let [saveAnonymousSettings, saveAnonymousSettingsResult] =
+		useSetAnonymousSettingsMutation();
+	let linkingSettings = useGetLinkingSettingsQuery([project]);
+	let [saveLinkingSettings, saveLinkingSettingsResult] =
+		useSetLinkingSettingsMutation();
+
+	if (
+		flags.isLoading ||
+		googleConfig.data === undefined ||
+		passwordlessSettings.data === undefined ||
+		emailVerificationSettings.data === undefined ||
+		anonymousSettings.data === undefined ||
+		linkingSettings.data === undefined
+	) {
 		return (
 			<PageWrapper>
 				<PageHeader>
@@ -85,15 +132,38 @@ export let AuthMethods = () => {
 	let handleMainForm = (e: FormEvent) => {
 		e.preventDefault();
 
-		let data = Array.from(new FormData(e.target as HTMLFormElement).entries())
+		let form = new FormData(e.target as HTMLFormElement);
+		let mode = form.get("passwordless_mode")?.toString() as
+			| PasswordlessMode
+			| undefined;
+		let policy = form.get("email_verification")?.toString() as
+			| EmailVerificationPolicy
+			| undefined;
+		let ttlDays = form.get("anonymous_ttl")?.toString();
+		let ttl = ttlDays ? parseInt(ttlDays, 10) : null;
+		let autoLink = form.get("auto_link") !== null;
+
+		let data = Array.from(form.entries())
 			.map((entry) => {
 				switch (entry[0]) {
 					case "signin":
 						return Flags.SignIn;
 					case "signup":
 						return Flags.SignUp;
+					case "anonymous":
+						return Flags.Anonymous;
+					case "organizations":
+						return Flags.Organizations;
 					case "auth_link":
 						return Flags.AuthenticationLink;
+					case "sms":
+						return Flags.Sms;
+					case "dpop":
+						return Flags.DPoP;
+					case "cookie_session":
+						return Flags.CookieSession;
+					case "hosted_pages":
+						return Flags.HostedPages;
 					case "email_password":
 						return Flags.EmailAndPassword;
 					case "verify_email":
@@ -107,6 +177,25 @@ export let AuthMethods = () => {
 			.filter((entry): entry is Flags => entry !== undefined);
 
 		updateFlags([project, data]);
+
+		if (mode !== undefined && mode !== passwordlessSettings.data?.mode) {
+			savePasswordlessSettings([project, { mode }]);
+		}
+
+		if (
+			policy !== undefined &&
+			policy !== emailVerificationSettings.data?.policy
+		) {
+			saveEmailVerificationSettings([project, { policy }]);
+		}
+
+		if (ttl !== anonymousSettings.data?.ttl_days) {
+			saveAnonymousSettings([project, { ttl_days: ttl }]);
+		}
+
+		if (autoLink !== linkingSettings.data?.auto_link_verified_email) {
+			saveLinkingSettings([project, { auto_link_verified_email: autoLink }]);
+		}
 		// TODO: Success Toast
 	};
 
@@ -151,6 +240,51 @@ export let AuthMethods = () => {
 							</FlagHeader>
 						</Flag>
 
+						<Flag>
+							<FlagHeader>
+								<FlagTitle htmlFor='anonymous'>Anonymous Users</FlagTitle>
+								<input
+									form={MAIN}
+									id='anonymous'
+									name='anonymous'
+									type='checkbox'
+									defaultChecked={items.includes(Flags.Anonymous)}
+								/>
+							</FlagHeader>
+							<NestedSection>
+								<Label htmlFor='anonymous_ttl'>
+									Remove inactive guests after (days)
+								</Label>
+								<Input
+									form={MAIN}
+									id='anonymous_ttl'
+									name='anonymous_ttl'
+									type='number'
+									min={1}
+									placeholder='Never'
+									defaultValue={anonymousSettings.data?.ttl_days ?? ""}
+								/>
+							</NestedSection>
+						</Flag>
+
+						<Flag disabled={!hasEmail}>
+							<FlagHeader
+								title={
+									hasEmail ? "Organizations" : "Disabled: Add email settings"
+								}
+							>
+								<FlagTitle htmlFor='organizations'>Organizations</FlagTitle>
+								<input
+									form={MAIN}
+									id='organizations'
+									name='organizations'
+									type='checkbox'
+									disabled={!hasEmail}
+									defaultChecked={items.includes(Flags.Organizations)}
+								/>
+							</FlagHeader>
+						</Flag>
+
 						<Flag disabled={!hasEmail}>
 							<FlagHeader
 								title={
@@ -169,17 +303,96 @@ export let AuthMethods = () => {
 									defaultChecked={items.includes(Flags.AuthenticationLink)}
 								/>
 							</FlagHeader>
+							<NestedSection>
+								<Label htmlFor='passwordless_mode'>Send</Label>
+								<select
+									form={MAIN}
+									id='passwordless_mode'
+									name='passwordless_mode'
+									disabled={!hasEmail}
+									defaultValue={passwordlessSettings.data?.mode ?? "link"}
+								>
+									<option value='link'>Link</option>
+									<option value='code'>One-time code</option>
+								</select>
+							</NestedSection>
+						</Flag>
+
+						<Flag>
+							<FlagHeader>
+								<FlagTitle htmlFor='sms'>SMS Code</FlagTitle>
+								<input
+									form={MAIN}
+									id='sms'
+									name='sms'
+									type='checkbox'
+									defaultChecked={items.includes(Flags.Sms)}
+								/>
+							</FlagHeader>
+						</Flag>
+
+						<Flag>
+							<FlagHeader title='Access tokens only work with a proof signed by the device (DPoP), can not be combined with cookie sessions'>
+								<FlagTitle htmlFor='dpop'>Bind Tokens to Device</FlagTitle>
+								<input
+									form={MAIN}
+									id='dpop'
+									name='dpop'
+									type='checkbox'
+									defaultChecked={items.includes(Flags.DPoP)}
+								/>
+							</FlagHeader>
+						</Flag>
+
+						<Flag>
+							<FlagHeader title='Sign ins set HttpOnly cookies, sessions are refreshed with the cookie. Can not be combined with binding tokens to the device'>
+								<FlagTitle htmlFor='cookie_session'>Cookie Sessions</FlagTitle>
+								<input
+									form={MAIN}
+									id='cookie_session'
+									name='cookie_session'
+									type='checkbox'
+									defaultChecked={items.includes(Flags.CookieSession)}
+								/>
+							</FlagHeader>
+						</Flag>
+
+						<Flag>
+							<FlagHeader title='Sign in, sign up, password reset and email verification pages served by the server, needs cookie sessions'>
+								<FlagTitle htmlFor='hosted_pages'>Hosted Pages</FlagTitle>
+								<input
+									form={MAIN}
+									id='hosted_pages'
+									name='hosted_pages'
+									type='checkbox'
+									defaultChecked={items.includes(Flags.HostedPages)}
+								/>
+							</FlagHeader>
 						</Flag>
 
-						<EmailAndPassword items={items} hasEmail={hasEmail} />
+						<EmailAndPassword
+							items={items}
+							hasEmail={hasEmail}
+							policy={emailVerificationSettings.data.policy}
+						/>
 						<GoogleForm
 							items={items}
 							config={googleConfig.data}
+							autoLink={linkingSettings.data.auto_link_verified_email}
 							loading={saveGoogleConfigResult.isLoading}
 						/>
 					</FlagList>
 					<ButtonWrapper>
-						<Button form={MAIN} loading={updateFlagsResult.isLoading}>
+						<Button
+							form={MAIN}
+							loading={
+								updateFlagsResult.isLoading ||
+								savePasswordlessSettingsResult.isLoading ||
+								saveEmailVerificationSettingsResult.isLoading ||
+								saveAnonymousSettingsResult.isLoading ||
+								saveLinkingSettingsResult.isLoading
+							}
+						>
 							Save
 						</Button>
 					</ButtonWrapper>
@@ -194,9 +407,10 @@ export let AuthMethods = () => {
 type EmailAndPasswordProps = {
 	items: ProjectFlags;
 	hasEmail: boolean;
+	policy: EmailVerificationPolicy;
 };
 
-let EmailAndPassword = ({ items, hasEmail }: EmailAndPasswordProps) => {
+let EmailAndPassword = ({ items, hasEmail, policy }: EmailAndPasswordProps) => {
 	let [showPasswordOptions, setShowPasswordOptions] = useState<boolean>(() => {
 		return items.includes(Flags.EmailAndPassword);
 	});
@@ -242,6 +456,20 @@ let EmailAndPassword = ({ items, hasEmail }: EmailAndPasswordProps) => {
 								defaultChecked={items.includes(Flags.VerifyEmail)}
 							/>
 						</FlagHeader>
+						<NestedSection>
+							<Label htmlFor='email_verification'>Unverified users</Label>
+							<select
+								form={MAIN}
+								id='email_verification'
+								name='email_verification'
+								disabled={!hasEmail}
+								defaultValue={policy}
+							>
+								<option value='off'>Can sign in</option>
+								<option value='mark'>Get unverified tokens</option>
+								<option value='block'>Can't sign in</option>
+							</select>
+						</NestedSection>
 					</Flag>
 				</FlagList>
 			)}
@@ -252,10 +480,11 @@ let EmailAndPassword = ({ items, hasEmail }: EmailAndPasswordProps) => {
 type GoogleFormProps = {
 	items: ProjectFlags;
 	config: GoogleConfig;
+	autoLink: boolean;
 	loading: boolean;
 };
 
-let GoogleForm = ({ items, config, loading }: GoogleFormProps) => {
+let GoogleForm = ({ items, config, autoLink, loading }: GoogleFormProps) => {
 	let [showGoogleForm, setShowGoogleForm] = useState<boolean>(() => {
 		return items.includes(Flags.OAuthGoogle);
 	});
@@ -273,6 +502,18 @@ let GoogleForm = ({ items, config, loading }: GoogleFormProps) => {
 					onChange={(e) => setShowGoogleForm(e.target.checked)}
 				/>
 			</FlagHeader>
+			<NestedSection>
+				<input
+					form={MAIN}
+					id='auto_link'
+					name='auto_link'
+					type='checkbox'
+					defaultChecked={autoLink}
+				/>
+				<Label htmlFor='auto_link'>
+					Link to existing users with the same verified email
+				</Label>
+			</NestedSection>
 			{showGoogleForm && (
 				<NestedContainer>
 					<Header>
@@ -373,6 +614,13 @@ let NestedContainer = styled.form`
 	margin-top: 1rem;
 `;
 
+let NestedSection = styled.div`
+	display: flex;
+	align-items: center;
+	gap: var(--size-2);
+	margin-top: var(--size-2);
+`;
+
 let Header = styled.div`
 	display: flex;
 	justify-content: flex-end;
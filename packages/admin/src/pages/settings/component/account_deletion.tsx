@@ -0,0 +1,78 @@
+import { FormEvent } from "react";
+import { Button } from "werkbank/component/button";
+import { Input, Section as FormSection, Label } from "werkbank/component/form";
+
+import { Header, Section } from "./layout";
+import {
+	useGetAccountDeletionSettingsQuery,
+	useSetAccountDeletionSettingsMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let AccountDeletionSettings = ({ project }: Props) => {
+	let settings = useGetAccountDeletionSettingsQuery([project]);
+	let [save, saveResult] = useSetAccountDeletionSettingsMutation();
+
+	function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let data = new FormData(e.target as HTMLFormElement);
+		let graceDays = data.get("grace_days")?.toString();
+		let days = graceDays ? parseInt(graceDays, 10) : null;
+
+		save([
+			project,
+			{
+				grace_days: days !== null && days > 0 ? days : null,
+				send_email: data.get("send_email") === "on",
+			},
+		]);
+	}
+
+	if (settings.data === undefined) {
+		return null;
+	}
+
+	return (
+		<Section>
+			<form key={project} onSubmit={handleSubmit}>
+				<Header>
+					<h2>Account Deletion</h2>
+
+					<Button loading={saveResult.isLoading}>Save</Button>
+				</Header>
+
+				<FormSection>
+					<Label htmlFor='account_deletion_grace_days'>
+						Deleted users can be restored for (days, empty to remove users
+						right away):
+					</Label>
+					<Input
+						id='account_deletion_grace_days'
+						name='grace_days'
+						type='number'
+						min={1}
+						defaultValue={settings.data.grace_days ?? ""}
+					/>
+				</FormSection>
+
+				<FormSection>
+					<Label htmlFor='account_deletion_send_email'>
+						<input
+							id='account_deletion_send_email'
+							name='send_email'
+							type='checkbox'
+							defaultChecked={settings.data.send_email}
+						/>{" "}
+						Send users an email when their account is deleted
+					</Label>
+				</FormSection>
+			</form>
+		</Section>
+	);
+};
+
+export default AccountDeletionSettings;
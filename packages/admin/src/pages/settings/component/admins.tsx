@@ -0,0 +1,79 @@
+import { FormEvent } from "react";
+import { AdminRole, InviteAdmin } from "@vulpo-dev/auth-sdk-admin";
+
+import {
+	Input,
+	Label,
+	Option,
+	Section as InputSection,
+	Select,
+} from "werkbank/component/form";
+import { Button } from "werkbank/component/button";
+
+import { Header, Section } from "./layout";
+import { useInviteAdminMutation } from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let InviteAdmins = ({ project }: Props) => {
+	let [invite, inviteResult] = useInviteAdminMutation();
+
+	async function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let form = e.target as HTMLFormElement;
+		let data = new FormData(form);
+		let role = data.get("role") as AdminRole;
+
+		let payload: InviteAdmin = {
+			email: `${data.get("email") ?? ""}`,
+			project: role === AdminRole.SuperAdmin ? null : project,
+			role,
+		};
+
+		let result = await invite([payload]);
+
+		if ("data" in result) {
+			form.reset();
+		}
+	}
+
+	return (
+		<Section>
+			<form onSubmit={handleSubmit}>
+				<Header>
+					<h2>Invite Admin</h2>
+
+					<Button loading={inviteResult.isLoading}>Invite</Button>
+				</Header>
+
+				<InputSection>
+					<Label htmlFor="admin-email">Email:</Label>
+					<Input id="admin-email" name="email" type="email" required />
+				</InputSection>
+
+				<InputSection>
+					<Label htmlFor="admin-role">Role:</Label>
+					<Select id="admin-role" name="role" defaultValue={AdminRole.Viewer}>
+						<Option value={AdminRole.Viewer}>Viewer (read-only)</Option>
+						<Option value={AdminRole.Editor}>Editor</Option>
+						<Option value={AdminRole.Owner}>Owner</Option>
+						<Option value={AdminRole.SuperAdmin}>
+							Super Admin (all projects)
+						</Option>
+					</Select>
+				</InputSection>
+
+				{inviteResult.isError && (
+					<InputSection>
+						<p>The invitation could not be sent.</p>
+					</InputSection>
+				)}
+			</form>
+		</Section>
+	);
+};
+
+export default InviteAdmins;
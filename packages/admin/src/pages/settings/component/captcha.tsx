@@ -0,0 +1,108 @@
+import { FormEvent } from "react";
+import { Button } from "werkbank/component/button";
+import {
+	Input,
+	Option,
+	Section as FormSection,
+	Label,
+	Select,
+} from "werkbank/component/form";
+import { CaptchaSettings as Settings } from "@vulpo-dev/auth-sdk-admin";
+
+import { Header, Section } from "./layout";
+import {
+	useGetCaptchaSettingsQuery,
+	useSetCaptchaSettingsMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let CaptchaSettings = ({ project }: Props) => {
+	let captchaSettings = useGetCaptchaSettingsQuery([project]);
+	let [save, saveResult] = useSetCaptchaSettingsMutation();
+
+	function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let data = new FormData(e.target as HTMLFormElement);
+
+		save([
+			project,
+			{
+				enabled: data.get("enabled") === "on",
+				provider: data.get("provider") as Settings["provider"],
+				site_key: data.get("site_key")?.toString().trim() ?? "",
+				secret: data.get("secret")?.toString().trim() ?? "",
+			},
+		]);
+	}
+
+	if (captchaSettings.data === undefined) {
+		return null;
+	}
+
+	let settings = captchaSettings.data;
+
+	return (
+		<Section>
+			<form key={project} onSubmit={handleSubmit}>
+				<Header>
+					<h2>CAPTCHA</h2>
+
+					<Button loading={saveResult.isLoading}>Save</Button>
+				</Header>
+
+				<FormSection>
+					<Label htmlFor='captcha-enabled'>
+						<input
+							id='captcha-enabled'
+							name='enabled'
+							type='checkbox'
+							defaultChecked={settings?.enabled ?? false}
+						/>{" "}
+						Require a CAPTCHA for sign ups, passwordless and password reset
+						requests
+					</Label>
+				</FormSection>
+
+				<FormSection>
+					<Label htmlFor='captcha-provider'>Provider:</Label>
+					<Select
+						id='captcha-provider'
+						name='provider'
+						defaultValue={settings?.provider ?? "turnstile"}
+					>
+						<Option value='turnstile'>Cloudflare Turnstile</Option>
+						<Option value='hcaptcha'>hCaptcha</Option>
+						<Option value='recaptcha'>reCAPTCHA</Option>
+					</Select>
+				</FormSection>
+
+				<FormSection>
+					<Label htmlFor='captcha-site-key'>Site Key:</Label>
+					<Input
+						id='captcha-site-key'
+						name='site_key'
+						defaultValue={settings?.site_key ?? ""}
+						required
+					/>
+				</FormSection>
+
+				<FormSection>
+					<Label htmlFor='captcha-secret'>Secret:</Label>
+					<Input
+						id='captcha-secret'
+						name='secret'
+						type='password'
+						placeholder={settings ? "Leave empty to keep the current secret" : ""}
+						required={!settings}
+					/>
+				</FormSection>
+			</form>
+		</Section>
+	);
+};
+
+export default CaptchaSettings;
@@ -0,0 +1,104 @@
+import { FormEvent } from "react";
+import { Button } from "werkbank/component/button";
+import { Input, Section as FormSection, Label } from "werkbank/component/form";
+
+import { Header, Section } from "./layout";
+import {
+	useGetCorsSettingsQuery,
+	useSetCorsSettingsMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+function toList(value: FormDataEntryValue | null, separator: RegExp): Array<string> {
+	return (value?.toString() ?? "")
+		.split(separator)
+		.map((item) => item.trim())
+		.filter((item) => item !== "");
+}
+
+let CorsSettings = ({ project }: Props) => {
+	let corsSettings = useGetCorsSettingsQuery([project]);
+	let [save, saveResult] = useSetCorsSettingsMutation();
+
+	function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let data = new FormData(e.target as HTMLFormElement);
+		let maxAge = data.get("max_age")?.toString();
+
+		save([
+			project,
+			{
+				origins: toList(data.get("origins"), /\n/),
+				methods: toList(data.get("methods"), /,/).map((method) =>
+					method.toUpperCase(),
+				),
+				headers: toList(data.get("headers"), /,/),
+				max_age: maxAge ? parseInt(maxAge, 10) : null,
+			},
+		]);
+	}
+
+	if (corsSettings.data === undefined) {
+		return null;
+	}
+
+	return (
+		<Section>
+			<form key={project} onSubmit={handleSubmit}>
+				<Header>
+					<h2>CORS</h2>
+
+					<Button loading={saveResult.isLoading}>Save</Button>
+				</Header>
+
+				<FormSection>
+					<Label htmlFor='origins'>
+						Allowed origins besides the project domain, one per line (e.g.
+						https://*.example.com, capacitor://localhost):
+					</Label>
+					<textarea
+						id='origins'
+						name='origins'
+						rows={4}
+						defaultValue={corsSettings.data.origins.join("\n")}
+					/>
+				</FormSection>
+				<FormSection>
+					<Label htmlFor='methods'>Allowed methods:</Label>
+					<Input
+						id='methods'
+						name='methods'
+						defaultValue={corsSettings.data.methods.join(", ")}
+					/>
+				</FormSection>
+				<FormSection>
+					<Label htmlFor='headers'>Allowed headers:</Label>
+					<Input
+						id='headers'
+						name='headers'
+						defaultValue={corsSettings.data.headers.join(", ")}
+					/>
+				</FormSection>
+				<FormSection>
+					<Label htmlFor='max_age'>
+						Cache preflight requests for seconds (empty for the browser
+						default):
+					</Label>
+					<Input
+						id='max_age'
+						name='max_age'
+						type='number'
+						min={0}
+						defaultValue={corsSettings.data.max_age ?? ""}
+					/>
+				</FormSection>
+			</form>
+		</Section>
+	);
+};
+
+export default CorsSettings;
@@ -0,0 +1,95 @@
+import { FormEvent } from "react";
+import { Button } from "werkbank/component/button";
+import { Section as FormSection, Label } from "werkbank/component/form";
+
+import { Header, Section } from "./layout";
+import {
+	useGetEmailDomainsQuery,
+	useSetEmailDomainsMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+function toList(value: FormDataEntryValue | null): Array<string> {
+	return (value?.toString() ?? "")
+		.split(/\n/)
+		.map((domain) => domain.trim())
+		.filter((domain) => domain !== "");
+}
+
+let EmailDomains = ({ project }: Props) => {
+	let emailDomains = useGetEmailDomainsQuery([project]);
+	let [save, saveResult] = useSetEmailDomainsMutation();
+
+	function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let data = new FormData(e.target as HTMLFormElement);
+
+		save([
+			project,
+			{
+				allow: toList(data.get("allow")),
+				deny: toList(data.get("deny")),
+				block_disposable: data.get("block_disposable") === "on",
+			},
+		]);
+	}
+
+	if (emailDomains.data === undefined) {
+		return null;
+	}
+
+	return (
+		<Section>
+			<form key={project} onSubmit={handleSubmit}>
+				<Header>
+					<h2>Email Domains</h2>
+
+					<Button loading={saveResult.isLoading}>Save</Button>
+				</Header>
+
+				<FormSection>
+					<Label htmlFor='email-domains-allow'>
+						Only allow sign ups with these domains, one per line, every domain
+						is allowed when empty (e.g. example.com, *.example.com):
+					</Label>
+					<textarea
+						id='email-domains-allow'
+						name='allow'
+						rows={4}
+						defaultValue={emailDomains.data.allow.join("\n")}
+					/>
+				</FormSection>
+
+				<FormSection>
+					<Label htmlFor='email-domains-deny'>
+						Block sign ups with these domains, one per line:
+					</Label>
+					<textarea
+						id='email-domains-deny'
+						name='deny'
+						rows={4}
+						defaultValue={emailDomains.data.deny.join("\n")}
+					/>
+				</FormSection>
+
+				<FormSection>
+					<Label htmlFor='email-domains-block-disposable'>
+						<input
+							id='email-domains-block-disposable'
+							name='block_disposable'
+							type='checkbox'
+							defaultChecked={emailDomains.data.block_disposable}
+						/>{" "}
+						Block disposable email addresses
+					</Label>
+				</FormSection>
+			</form>
+		</Section>
+	);
+};
+
+export default EmailDomains;
@@ -0,0 +1,86 @@
+import { FormEvent } from "react";
+import { Button } from "werkbank/component/button";
+import { Input, Section as FormSection, Label } from "werkbank/component/form";
+
+import { Header, Section } from "./layout";
+import {
+	useGetHostedPagesQuery,
+	useSetHostedPagesMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let HostedPages = ({ project }: Props) => {
+	let hostedPages = useGetHostedPagesQuery([project]);
+	let [save, saveResult] = useSetHostedPagesMutation();
+
+	function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let data = new FormData(e.target as HTMLFormElement);
+		let value = (name: string) => {
+			let entry = data.get(name)?.toString().trim() ?? "";
+			return entry === "" ? null : entry;
+		};
+
+		save([
+			project,
+			{
+				logo_url: value("logo_url"),
+				primary_color: value("primary_color"),
+				background_color: value("background_color"),
+			},
+		]);
+	}
+
+	if (hostedPages.data === undefined) {
+		return null;
+	}
+
+	return (
+		<Section>
+			<form key={project} onSubmit={handleSubmit}>
+				<Header>
+					<h2>Hosted Pages</h2>
+
+					<Button loading={saveResult.isLoading}>Save</Button>
+				</Header>
+
+				<FormSection>
+					<Label htmlFor='logo_url'>Logo URL:</Label>
+					<Input
+						id='logo_url'
+						name='logo_url'
+						type='url'
+						placeholder='https://example.com/logo.png'
+						defaultValue={hostedPages.data.logo_url ?? ""}
+					/>
+				</FormSection>
+				<FormSection>
+					<Label htmlFor='primary_color'>Primary color (e.g. #1a73e8):</Label>
+					<Input
+						id='primary_color'
+						name='primary_color'
+						pattern='#([0-9a-fA-F]{3}|[0-9a-fA-F]{6})'
+						defaultValue={hostedPages.data.primary_color ?? ""}
+					/>
+				</FormSection>
+				<FormSection>
+					<Label htmlFor='background_color'>
+						Background color (e.g. #f4f5f7):
+					</Label>
+					<Input
+						id='background_color'
+						name='background_color'
+						pattern='#([0-9a-fA-F]{3}|[0-9a-fA-F]{6})'
+						defaultValue={hostedPages.data.background_color ?? ""}
+					/>
+				</FormSection>
+			</form>
+		</Section>
+	);
+};
+
+export default HostedPages;
@@ -0,0 +1,121 @@
+import { FormEvent, useState } from "react";
+import { OAuthClientCredentials } from "@vulpo-dev/auth-sdk-admin";
+
+import { Input, Label, Section as InputSection } from "werkbank/component/form";
+import { Button } from "werkbank/component/button";
+
+import { Header, Section } from "./layout";
+import {
+	useCreateOAuthClientMutation,
+	useDeleteOAuthClientMutation,
+	useGetOAuthClientsQuery,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let OAuthClients = ({ project }: Props) => {
+	let clients = useGetOAuthClientsQuery([project]);
+	let [create, createResult] = useCreateOAuthClientMutation();
+	let [remove] = useDeleteOAuthClientMutation();
+	let [credentials, setCredentials] = useState<OAuthClientCredentials | null>(
+		null,
+	);
+
+	async function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let form = e.target as HTMLFormElement;
+		let data = new FormData(form);
+		let redirect_uris = `${data.get("redirect_uris") ?? ""}`
+			.split(/\s+/)
+			.filter((uri) => uri !== "");
+
+		let result = await create([
+			project,
+			{
+				name: `${data.get("name") ?? ""}`,
+				redirect_uris,
+				confidential: data.get("confidential") === "on",
+			},
+		]);
+
+		if ("data" in result) {
+			setCredentials(result.data);
+			form.reset();
+		}
+	}
+
+	return (
+		<Section>
+			<form onSubmit={handleSubmit}>
+				<Header>
+					<h2>OAuth Clients</h2>
+
+					<Button loading={createResult.isLoading}>Create</Button>
+				</Header>
+
+				<InputSection>
+					<Label htmlFor="oauth-client-name">Name:</Label>
+					<Input id="oauth-client-name" name="name" required />
+				</InputSection>
+
+				<InputSection>
+					<Label htmlFor="oauth-client-redirect-uris">Redirect URIs:</Label>
+					<Input
+						id="oauth-client-redirect-uris"
+						name="redirect_uris"
+						placeholder="https://app.example.com/callback"
+						required
+					/>
+				</InputSection>
+
+				<InputSection>
+					<Label htmlFor="oauth-client-confidential">
+						<input
+							id="oauth-client-confidential"
+							name="confidential"
+							type="checkbox"
+							defaultChecked
+						/>{" "}
+						Confidential (the client can keep a secret)
+					</Label>
+				</InputSection>
+
+				{credentials && (
+					<InputSection>
+						<Label>Client ID</Label>
+						<Input value={credentials.client_id} readOnly />
+						{credentials.client_secret && (
+							<>
+								<p>Copy the secret now, it won't be shown again.</p>
+								<Label>Client Secret</Label>
+								<Input value={credentials.client_secret} readOnly />
+							</>
+						)}
+					</InputSection>
+				)}
+
+				{createResult.isError && (
+					<InputSection>
+						<p>The client could not be created.</p>
+					</InputSection>
+				)}
+			</form>
+
+			{clients.data?.items.map((client) => (
+				<InputSection key={client.id}>
+					<Label>{client.name}</Label>
+					<Input value={client.id} readOnly />
+					<p>{client.redirect_uris.join(" ")}</p>
+					<Button type="button" onClick={() => remove([client.id, project])}>
+						Delete
+					</Button>
+				</InputSection>
+			))}
+		</Section>
+	);
+};
+
+export default OAuthClients;
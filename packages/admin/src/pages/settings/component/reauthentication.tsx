@@ -0,0 +1,68 @@
+import { FormEvent } from "react";
+import { Button } from "werkbank/component/button";
+import { Input, Section as FormSection, Label } from "werkbank/component/form";
+
+import { Header, Section } from "./layout";
+import {
+	useGetReauthenticationSettingsQuery,
+	useSetReauthenticationSettingsMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let ReauthenticationSettings = ({ project }: Props) => {
+	let settings = useGetReauthenticationSettingsQuery([project]);
+	let [save, saveResult] = useSetReauthenticationSettingsMutation();
+
+	function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let data = new FormData(e.target as HTMLFormElement);
+		let maxAge = data.get("max_age")?.toString();
+		let minutes = maxAge ? parseInt(maxAge, 10) : null;
+
+		save([
+			project,
+			{
+				max_age: minutes !== null && minutes > 0 ? minutes * 60 : null,
+			},
+		]);
+	}
+
+	if (settings.data === undefined) {
+		return null;
+	}
+
+	let maxAge = settings.data.max_age;
+
+	return (
+		<Section>
+			<form key={project} onSubmit={handleSubmit}>
+				<Header>
+					<h2>Re-authentication</h2>
+
+					<Button loading={saveResult.isLoading}>Save</Button>
+				</Header>
+
+				<FormSection>
+					<Label htmlFor='reauthentication_max_age'>
+						Changing the email, setting the password, generating API keys
+						and deleting the account require a sign in within the last
+						minutes (empty to disable):
+					</Label>
+					<Input
+						id='reauthentication_max_age'
+						name='max_age'
+						type='number'
+						min={1}
+						defaultValue={maxAge !== null ? Math.ceil(maxAge / 60) : ""}
+					/>
+				</FormSection>
+			</form>
+		</Section>
+	);
+};
+
+export default ReauthenticationSettings;
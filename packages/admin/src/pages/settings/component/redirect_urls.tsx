@@ -0,0 +1,62 @@
+import { FormEvent } from "react";
+import { Button } from "werkbank/component/button";
+import { Section as FormSection, Label } from "werkbank/component/form";
+
+import { Header, Section } from "./layout";
+import {
+	useGetRedirectUrlsQuery,
+	useSetRedirectUrlsMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let RedirectUrls = ({ project }: Props) => {
+	let redirectUrls = useGetRedirectUrlsQuery([project]);
+	let [save, saveResult] = useSetRedirectUrlsMutation();
+
+	function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let data = new FormData(e.target as HTMLFormElement);
+		let urls = (data.get("urls")?.toString() ?? "")
+			.split(/\n/)
+			.map((url) => url.trim())
+			.filter((url) => url !== "");
+
+		save([project, { urls }]);
+	}
+
+	if (redirectUrls.data === undefined) {
+		return null;
+	}
+
+	return (
+		<Section>
+			<form key={project} onSubmit={handleSubmit}>
+				<Header>
+					<h2>Redirect URLs</h2>
+
+					<Button loading={saveResult.isLoading}>Save</Button>
+				</Header>
+
+				<FormSection>
+					<Label htmlFor='urls'>
+						Allowed redirect URLs for email links and OAuth besides the project
+						domain, one per line (e.g. https://*.example.com/auth/*,
+						myapp://auth):
+					</Label>
+					<textarea
+						id='urls'
+						name='urls'
+						rows={4}
+						defaultValue={redirectUrls.data.urls.join("\n")}
+					/>
+				</FormSection>
+			</form>
+		</Section>
+	);
+};
+
+export default RedirectUrls;
@@ -0,0 +1,46 @@
+import { FormEvent } from "react";
+
+import { Section as FormSection } from "werkbank/component/form";
+import { Header, Section } from "./layout";
+import { WarnButton } from "../../../component/button";
+import { useRevokeProjectTokensMutation } from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let RevokeTokens = ({ project }: Props) => {
+	let [revoke, revokeResult] = useRevokeProjectTokensMutation();
+
+	async function submit(e: FormEvent) {
+		e.preventDefault();
+		await revoke([project]);
+	}
+
+	return (
+		<Section>
+			<form onSubmit={submit}>
+				<Header>
+					<h2>Sign Out All Users</h2>
+
+					<WarnButton loading={revokeResult.isLoading}>Sign Out</WarnButton>
+				</Header>
+
+				<FormSection>
+					<p>
+						Removes all sessions of the project and rejects access tokens
+						that were issued until now.
+					</p>
+				</FormSection>
+
+				{revokeResult.isSuccess && (
+					<FormSection>
+						<p>All users have been signed out.</p>
+					</FormSection>
+				)}
+			</form>
+		</Section>
+	);
+};
+
+export default RevokeTokens;
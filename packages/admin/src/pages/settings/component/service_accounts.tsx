@@ -0,0 +1,110 @@
+import { FormEvent, useState } from "react";
+import { ClientCredentials } from "@vulpo-dev/auth-sdk-admin";
+
+import { Input, Label, Section as InputSection } from "werkbank/component/form";
+import { Button } from "werkbank/component/button";
+
+import { Header, Section } from "./layout";
+import {
+	useCreateServiceAccountMutation,
+	useDeleteServiceAccountMutation,
+	useGetServiceAccountsQuery,
+	useRotateServiceAccountSecretMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let ServiceAccounts = ({ project }: Props) => {
+	let accounts = useGetServiceAccountsQuery([project]);
+	let [create, createResult] = useCreateServiceAccountMutation();
+	let [rotate] = useRotateServiceAccountSecretMutation();
+	let [remove] = useDeleteServiceAccountMutation();
+	let [credentials, setCredentials] = useState<ClientCredentials | null>(null);
+
+	async function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let form = e.target as HTMLFormElement;
+		let data = new FormData(form);
+		let scopes = `${data.get("scopes") ?? ""}`
+			.split(/[\s,]+/)
+			.filter((scope) => scope !== "");
+
+		let result = await create([
+			project,
+			{ name: `${data.get("name") ?? ""}`, scopes },
+		]);
+
+		if ("data" in result) {
+			setCredentials(result.data);
+			form.reset();
+		}
+	}
+
+	async function handleRotate(id: string) {
+		let result = await rotate([id, project]);
+		if ("data" in result) {
+			setCredentials(result.data);
+		}
+	}
+
+	return (
+		<Section>
+			<form onSubmit={handleSubmit}>
+				<Header>
+					<h2>Service Accounts</h2>
+
+					<Button loading={createResult.isLoading}>Create</Button>
+				</Header>
+
+				<InputSection>
+					<Label htmlFor="service-account-name">Name:</Label>
+					<Input id="service-account-name" name="name" required />
+				</InputSection>
+
+				<InputSection>
+					<Label htmlFor="service-account-scopes">Scopes:</Label>
+					<Input
+						id="service-account-scopes"
+						name="scopes"
+						placeholder="invoices:read invoices:write"
+					/>
+				</InputSection>
+
+				{credentials && (
+					<InputSection>
+						<p>Copy the secret now, it won't be shown again.</p>
+						<Label>Client ID</Label>
+						<Input value={credentials.client_id} readOnly />
+						<Label>Client Secret</Label>
+						<Input value={credentials.client_secret} readOnly />
+					</InputSection>
+				)}
+
+				{createResult.isError && (
+					<InputSection>
+						<p>The service account could not be created.</p>
+					</InputSection>
+				)}
+			</form>
+
+			{accounts.data?.items.map((account) => (
+				<InputSection key={account.id}>
+					<Label>{account.name}</Label>
+					<Input value={account.id} readOnly />
+					<p>{account.scopes.join(" ")}</p>
+					<Button type="button" onClick={() => handleRotate(account.id)}>
+						Rotate Secret
+					</Button>
+					<Button type="button" onClick={() => remove([account.id, project])}>
+						Delete
+					</Button>
+				</InputSection>
+			))}
+		</Section>
+	);
+};
+
+export default ServiceAccounts;
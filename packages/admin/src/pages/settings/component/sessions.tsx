@@ -0,0 +1,78 @@
+import { FormEvent } from "react";
+import { SessionLimitMode } from "@vulpo-dev/auth-sdk-admin";
+import { Button } from "werkbank/component/button";
+import { Input, Section as FormSection, Label } from "werkbank/component/form";
+
+import { Header, Section } from "./layout";
+import {
+	useGetSessionSettingsQuery,
+	useSetSessionSettingsMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let SessionSettings = ({ project }: Props) => {
+	let sessionSettings = useGetSessionSettingsQuery([project]);
+	let [save, saveResult] = useSetSessionSettingsMutation();
+
+	function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let data = new FormData(e.target as HTMLFormElement);
+		let maxSessions = data.get("max_sessions")?.toString();
+		let max = maxSessions ? parseInt(maxSessions, 10) : null;
+
+		save([
+			project,
+			{
+				max_sessions: max !== null && max > 0 ? max : null,
+				limit_mode: (data.get("limit_mode")?.toString() ??
+					"evict") as SessionLimitMode,
+			},
+		]);
+	}
+
+	if (sessionSettings.data === undefined) {
+		return null;
+	}
+
+	return (
+		<Section>
+			<form key={project} onSubmit={handleSubmit}>
+				<Header>
+					<h2>Sessions</h2>
+
+					<Button loading={saveResult.isLoading}>Save</Button>
+				</Header>
+
+				<FormSection>
+					<Label htmlFor='max_sessions'>
+						Maximum devices per user (empty for no limit):
+					</Label>
+					<Input
+						id='max_sessions'
+						name='max_sessions'
+						type='number'
+						min={1}
+						defaultValue={sessionSettings.data.max_sessions ?? ""}
+					/>
+				</FormSection>
+				<FormSection>
+					<Label htmlFor='limit_mode'>When the limit is reached:</Label>
+					<select
+						id='limit_mode'
+						name='limit_mode'
+						defaultValue={sessionSettings.data.limit_mode}
+					>
+						<option value='evict'>Sign out the least recently used device</option>
+						<option value='reject'>Reject the sign in</option>
+					</select>
+				</FormSection>
+			</form>
+		</Section>
+	);
+};
+
+export default SessionSettings;
@@ -0,0 +1,95 @@
+import { FormEvent } from "react";
+
+import { Input, Label, Section as InputSection } from "werkbank/component/form";
+import { Button } from "werkbank/component/button";
+
+import { Header, Section } from "./layout";
+import {
+	useGetUserInvitationsQuery,
+	useInviteUserMutation,
+	useRevokeUserInvitationMutation,
+} from "../../../data/admin_api";
+
+type Props = {
+	project: string;
+};
+
+let UserInvitations = ({ project }: Props) => {
+	let invitations = useGetUserInvitationsQuery([project]);
+	let [invite, inviteResult] = useInviteUserMutation();
+	let [revoke] = useRevokeUserInvitationMutation();
+
+	async function handleSubmit(e: FormEvent) {
+		e.preventDefault();
+
+		let form = e.target as HTMLFormElement;
+		let data = new FormData(form);
+		let traits = `${data.get("traits") ?? ""}`
+			.split(/[\s,]+/)
+			.filter((trait) => trait !== "");
+
+		let result = await invite([
+			{
+				project_id: project,
+				email: `${data.get("email") ?? ""}`,
+				traits,
+			},
+		]);
+
+		if ("data" in result) {
+			form.reset();
+		}
+	}
+
+	return (
+		<Section>
+			<form onSubmit={handleSubmit} onChange={() => inviteResult.reset()}>
+				<Header>
+					<h2>User Invitations</h2>
+
+					<Button loading={inviteResult.isLoading}>Invite</Button>
+				</Header>
+
+				<InputSection>
+					<Label htmlFor="user-invitation-email">Email:</Label>
+					<Input
+						id="user-invitation-email"
+						name="email"
+						type="email"
+						required
+					/>
+				</InputSection>
+
+				<InputSection>
+					<Label htmlFor="user-invitation-traits">Traits:</Label>
+					<Input
+						id="user-invitation-traits"
+						name="traits"
+						placeholder="beta"
+					/>
+				</InputSection>
+
+				{inviteResult.isError && (
+					<InputSection>
+						<p>
+							The invitation could not be sent:{" "}
+							{(inviteResult.error as { type: string }).type}
+						</p>
+					</InputSection>
+				)}
+			</form>
+
+			{invitations.data?.map((invitation) => (
+				<InputSection key={invitation.id}>
+					<Label>{invitation.email}</Label>
+					<p>Expires {new Date(invitation.expire_at).toLocaleString()}</p>
+					<Button type="button" onClick={() => revoke([invitation.id, project])}>
+						Revoke
+					</Button>
+				</InputSection>
+			))}
+		</Section>
+	);
+};
+
+export default UserInvitations;
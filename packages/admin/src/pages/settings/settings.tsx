@@ -12,8 +12,21 @@ import { useActiveProject } from "../../data/project";
 
 import ProjectSettings from "./component/project";
 import EmailSettings from "./component/email";
+import SessionSettings from "./component/sessions";
+import ReauthenticationSettings from "./component/reauthentication";
+import AccountDeletionSettings from "./component/account_deletion";
+import CorsSettings from "./component/cors";
+import RedirectUrls from "./component/redirect_urls";
+import EmailDomains from "./component/email_domains";
+import CaptchaSettings from "./component/captcha";
+import HostedPages from "./component/hosted_pages";
 import PublicKeysSettings from "./component/public_keys";
 import DeleteProject from "./component/delete";
+import RevokeTokens from "./component/revoke_tokens";
+import InviteAdmins from "./component/admins";
+import ServiceAccounts from "./component/service_accounts";
+import UserInvitations from "./component/user_invitations";
+import OAuthClients from "./component/oauth_clients";
 import { Gear } from "@phosphor-icons/react";
 import { useCallback, useEffect } from "react";
 
@@ -72,7 +85,21 @@ export let SettingsPage = () => {
 			<StyledPageContent>
 				<ProjectSettings project={project} />
 				<EmailSettings project={project.id} />
+				<SessionSettings project={project.id} />
+				<ReauthenticationSettings project={project.id} />
+				<AccountDeletionSettings project={project.id} />
+				<CorsSettings project={project.id} />
+				<RedirectUrls project={project.id} />
+				<EmailDomains project={project.id} />
+				<CaptchaSettings project={project.id} />
+				<HostedPages project={project.id} />
 				<PublicKeysSettings project={project.id} />
+				<InviteAdmins project={project.id} />
+				<UserInvitations project={project.id} />
+				<ServiceAccounts project={project.id} />
+				<OAuthClients project={project.id} />
+
+				<RevokeTokens project={project.id} />
 
 				{project.is_admin === false && <DeleteProject project={project} />}
 			</StyledPageContent>
@@ -3,7 +3,13 @@ import { HTMLAttributes } from "react";
 import { X } from "@phosphor-icons/react";
 
 import { IconButton } from "werkbank/component/button/button";
-import { Section, Input, Label, ChipsInput } from "werkbank/component/form";
+import {
+	Section,
+	Input,
+	Label,
+	ChipsInput,
+	Textarea,
+} from "werkbank/component/form";
 import { User } from "@vulpo-dev/auth-sdk-admin";
 
 let StyledLabel = styled(Label)`
@@ -54,6 +60,13 @@ export let UserForm = ({ defaultValue, ...props }: Props) => {
 				/>
 			</Section>
 
+			{defaultValue?.phone && (
+				<Section>
+					<Label>Phone number</Label>
+					<Input defaultValue={defaultValue.phone} readOnly />
+				</Section>
+			)}
+
 			<Section>
 				<Label>User Id</Label>
 				<Input defaultValue={defaultValue?.id ?? ""} readOnly />
@@ -83,6 +96,32 @@ export let UserForm = ({ defaultValue, ...props }: Props) => {
 					)}
 				/>
 			</Section>
+			<Section>
+				<Label htmlFor="user_metadata">User Metadata:</Label>
+				<Textarea
+					id="user_metadata"
+					name="user_metadata"
+					minRows={3}
+					defaultValue={JSON.stringify(
+						defaultValue?.user_metadata ?? {},
+						null,
+						2,
+					)}
+				/>
+			</Section>
+			<Section>
+				<Label htmlFor="app_metadata">App Metadata:</Label>
+				<Textarea
+					id="app_metadata"
+					name="app_metadata"
+					minRows={3}
+					defaultValue={JSON.stringify(
+						defaultValue?.app_metadata ?? {},
+						null,
+						2,
+					)}
+				/>
+			</Section>
 		</form>
 	);
 };
@@ -2,6 +2,7 @@ import styled from "@emotion/styled";
 import { useNavigate } from "react-router-dom";
 import {
 	ArchiveBox,
+	ArrowCounterClockwise,
 	ClockClockwise,
 	IdentificationCard,
 	Trash,
@@ -17,8 +18,14 @@ import {
 	useDeleteUserMutation,
 	useDisableUserMutation,
 	useGetEmailSettingsQuery,
+	useGetRolesQuery,
 	useGetUserQuery,
+	useGetUserRolesQuery,
+	useGetUserSessionsQuery,
 	useRequestPasswordResetMutation,
+	useRestoreUserMutation,
+	useRevokeUserSessionMutation,
+	useSetUserRolesMutation,
 	useUpdateUserMutation,
 	useVerifyUserEmailMutation,
 } from "../../data/admin_api";
@@ -34,6 +41,7 @@ type UserDetailsProps = {
 };
 
 let FORM_ID = "user-form";
+let ROLES_FORM_ID = "user-roles-form";
 
 let UserDetails = ({ userId }: UserDetailsProps) => {
 	let project = useActiveProject();
@@ -51,6 +59,8 @@ let UserDetails = ({ userId }: UserDetailsProps) => {
 		<>
 			<UserActions userId={userId} />
 			<UserProfile userId={userId} />
+			<UserRoles userId={userId} />
+			<UserSessions userId={userId} />
 		</>
 	);
 };
@@ -70,10 +80,15 @@ let UserProfile = ({ userId }: UserDetailsProps) => {
 
 		let data = new FormData(e.target as HTMLFormElement);
 		let updatedUser: UpdateUser = {
-			data: JSON.parse(data.get("data")?.toString() ?? "{}"),
 			display_name: data.get("display_name")?.toString(),
-			email: data.get("email")?.toString() ?? "",
+			email: data.get("email")?.toString() || null,
 			traits: data.get("traits")?.toString().split(",") ?? [],
+			user_metadata: JSON.parse(
+				data.get("user_metadata")?.toString() || "{}",
+			),
+			app_metadata: JSON.parse(
+				data.get("app_metadata")?.toString() || "{}",
+			),
 		};
 
 		updateUser([userId, project, updatedUser]);
@@ -117,6 +132,10 @@ let UserProfile = ({ userId }: UserDetailsProps) => {
 					touched.current = false;
 				}}
 			/>
+			<Section>
+				<Label>Linked Providers:</Label>
+				<p>{user.providers.join(", ")}</p>
+			</Section>
 			<Section>
 				<Label>Updated At:</Label>
 				<p>
@@ -133,6 +152,106 @@ let UserProfile = ({ userId }: UserDetailsProps) => {
 	);
 };
 
+let UserRoles = ({ userId }: UserDetailsProps) => {
+	let project = useActiveProject();
+	let roles = useGetRolesQuery([project]);
+	let userRoles = useGetUserRolesQuery([userId, project]);
+	let [setUserRoles, setUserRolesResult] = useSetUserRolesMutation();
+
+	let handleSubmit = (e: FormEvent<HTMLFormElement>) => {
+		e.preventDefault();
+		let data = new FormData(e.target as HTMLFormElement);
+		let roleIds = data.getAll("roles").map((id) => id.toString());
+		setUserRoles([userId, project, roleIds]);
+	};
+
+	if (roles.data === undefined || userRoles.data === undefined) {
+		return null;
+	}
+
+	let assigned = userRoles.data.items.map((role) => role.id);
+
+	return (
+		<Container>
+			<Header>
+				<h2>Roles</h2>
+
+				<Actions>
+					<Button
+						form={ROLES_FORM_ID}
+						type="submit"
+						loading={setUserRolesResult.isLoading}
+						disabled={roles.data.items.length === 0}
+					>
+						Save
+					</Button>
+				</Actions>
+			</Header>
+			<form
+				id={ROLES_FORM_ID}
+				key={assigned.join(",")}
+				onSubmit={handleSubmit}
+			>
+				{roles.data.items.length === 0 && (
+					<p>No roles defined for this project</p>
+				)}
+				{roles.data.items.map((role) => (
+					<Section key={role.id}>
+						<Label title={role.permissions.join(", ")}>
+							<input
+								type="checkbox"
+								name="roles"
+								value={role.id}
+								defaultChecked={assigned.includes(role.id)}
+							/>{" "}
+							{role.name}
+						</Label>
+					</Section>
+				))}
+			</form>
+		</Container>
+	);
+};
+
+let UserSessions = ({ userId }: UserDetailsProps) => {
+	let project = useActiveProject();
+	let sessions = useGetUserSessionsQuery([userId, project]);
+	let [revokeSession, revokeSessionResult] = useRevokeUserSessionMutation();
+
+	if (sessions.data === undefined) {
+		return null;
+	}
+
+	return (
+		<Container>
+			<Header>
+				<h2>Sessions</h2>
+			</Header>
+			{sessions.data.length === 0 && <p>The user is not signed in</p>}
+			{sessions.data.map((session) => (
+				<Section key={session.id}>
+					<Label title={session.user_agent ?? undefined}>
+						{session.device_name ?? "Unknown Device"}
+						{session.ip && ` (${session.ip})`}
+					</Label>
+					<p>
+						Last active:{" "}
+						<DateTime
+							value={session.last_refreshed_at ?? session.created_at}
+						/>
+					</p>
+					<LinkButton
+						onClick={() => revokeSession([session.id, userId, project])}
+						disabled={revokeSessionResult.isLoading}
+					>
+						Sign Out
+					</LinkButton>
+				</Section>
+			))}
+		</Container>
+	);
+};
+
 let ActionsContainer = styled(Container)`
 	display: flex;
 	justify-content: space-evenly;
@@ -162,6 +281,7 @@ let UserActions = ({ userId }: UserDetailsProps) => {
 
 	let [deleteUser] = useDeleteUserMutation();
 	let [disableUser] = useDisableUserMutation();
+	let [restoreUser] = useRestoreUserMutation();
 	let [verifyEmail] = useVerifyUserEmailMutation();
 	let [requestPasswordReset] = useRequestPasswordResetMutation();
 
@@ -176,6 +296,12 @@ let UserActions = ({ userId }: UserDetailsProps) => {
 		}
 	};
 
+	let handleRestore = () => {
+		if (user) {
+			restoreUser([user.id, project]);
+		}
+	};
+
 	let handleVerify = () => {
 		if (user) {
 			verifyEmail([user.id, project]);
@@ -192,7 +318,7 @@ let UserActions = ({ userId }: UserDetailsProps) => {
 	};
 
 	let handleReset = () => {
-		if (user) {
+		if (user?.email) {
 			requestPasswordReset([user.email, project]);
 		}
 	};
@@ -202,16 +328,27 @@ let UserActions = ({ userId }: UserDetailsProps) => {
 	}
 
 	let disableLabel = user?.state === UserState.Disabled ? "Enable" : "Disable";
+	let isDeleted = user.state === UserState.Deleted;
 
 	return (
 		<ActionsContainer>
-			<ActionItem
-				onClick={handleDisable}
-				title={`${disableLabel} ${user.email}`}
-			>
-				<ArchiveBox weight='bold' size={24} />
-				<ActionLabel>{disableLabel} Account</ActionLabel>
-			</ActionItem>
+			{isDeleted ? (
+				<ActionItem
+					onClick={handleRestore}
+					title={`Restore ${user.email ?? user.phone}`}
+				>
+					<ArrowCounterClockwise weight='bold' size={24} />
+					<ActionLabel>Restore Account</ActionLabel>
+				</ActionItem>
+			) : (
+				<ActionItem
+					onClick={handleDisable}
+					title={`${disableLabel} ${user.email ?? user.phone}`}
+				>
+					<ArchiveBox weight='bold' size={24} />
+					<ActionLabel>{disableLabel} Account</ActionLabel>
+				</ActionItem>
+			)}
 			<ActionItem
 				onClick={handleReset}
 				disabled={!hasEmail}
@@ -236,9 +373,12 @@ let UserActions = ({ userId }: UserDetailsProps) => {
 				<IdentificationCard weight='bold' size={24} />
 				<ActionLabel>Send Email Verification</ActionLabel>
 			</ActionItem>
-			<ActionItem onClick={handleDelete} title={`Delete ${user.email}`}>
+			<ActionItem
+				onClick={handleDelete}
+				title={`Delete ${user.email ?? user.phone}`}
+			>
 				<Trash weight='bold' size={24} />
-				<ActionLabel>Delete</ActionLabel>
+				<ActionLabel>{isDeleted ? "Delete Permanently" : "Delete"}</ActionLabel>
 			</ActionItem>
 		</ActionsContainer>
 	);
@@ -283,7 +283,9 @@ let UserItem = ({ user }: UserItemProps) => {
 			to={user.id}
 			state={user}
 		>
-			<Email title={user.email}>{user.email}</Email>
+			<Email title={user.email ?? user.phone ?? "Anonymous"}>
+				{user.email ?? user.phone ?? "Anonymous"}
+			</Email>
 			<UserId title={user.id}>{user.id}</UserId>
 			<CreatedAt title={user.created_at}>
 				<DateTime value={user.created_at} />
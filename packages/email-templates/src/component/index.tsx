@@ -64,4 +64,12 @@ export let Link = ({ href }: LinkProps) => (
 
 export let Text = styled.p`
 	text-align: center;
+`
+
+export let Code = styled.p`
+	font-family: monospace;
+	font-size: 32px;
+	font-weight: bold;
+	letter-spacing: 8px;
+	text-align: center;
 `
\ No newline at end of file
@@ -0,0 +1,14 @@
+import { Previews, Item } from "postler";
+import { faker } from "@faker-js/faker"
+import { TemplateProps } from "./types";
+
+export let Data: Previews<TemplateProps> = [
+	Item("name", {
+		project: "email-templates",
+		grace_days: 30,
+	}),
+	Item("long text", {
+		project: faker.company.name() + " " + faker.company.name(),
+		grace_days: 7,
+	}),
+];
@@ -0,0 +1,25 @@
+import { createTranslations, Document, html } from "postler";
+import { Translation } from "./translations";
+import { Typography } from "@vulpo-dev/brief";
+import { Body, Container, Title, Text } from "../../component";
+
+let t = createTranslations<Translation>();
+
+export let Plaintext = () => {
+	return <>{t.headline}: {t.text}</>
+}
+
+export let Template = () => {
+	return (
+		<Document>
+			<Typography />
+			<Body>
+				<Container>
+					<Title>{t.headline}</Title>
+					<Text>{t.text}</Text>
+					<Text>{html(t.restore)}</Text>
+				</Container>
+			</Body>
+		</Document>
+	);
+};
@@ -0,0 +1,26 @@
+import { GetTranslation, ToTranslations } from "postler";
+import { props } from "./types";
+
+export let DefaultTranslation = {
+	lang: "en",
+	translation: {
+		subject: "Account Deleted",
+		headline: "Your account has been deleted",
+		text: `Your ${props.project} account has been deleted. You can ignore this email if you just deleted your account.`,
+		restore: `If you didn't delete your account, contact us within <span class="bold">${props.grace_days} days</span> to restore it. After that, your data is removed for good.`
+	},
+};
+
+export type Translation = GetTranslation<typeof DefaultTranslation>;
+
+export let Translations: ToTranslations<typeof DefaultTranslation> = [
+	{
+		lang: "de",
+		translation: {
+			subject: "Konto gelöscht",
+			headline: "Ihr Konto wurde gelöscht",
+			text: `Ihr ${props.project} Konto wurde gelöscht. Sie können diese E-Mail ignorieren, wenn Sie Ihr Konto gerade gelöscht haben.`,
+			restore: `Wenn Sie Ihr Konto nicht gelöscht haben, kontaktieren Sie uns innerhalb von <span class="bold">${props.grace_days} Tagen</span>, um es wiederherzustellen. Danach werden Ihre Daten endgültig gelöscht.`
+		},
+	},
+];
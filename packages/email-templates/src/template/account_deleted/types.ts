@@ -0,0 +1,8 @@
+import { createProps } from "postler";
+
+export type TemplateProps = {
+	project: string;
+	grace_days: number;
+};
+
+export let props = createProps<TemplateProps>();
@@ -0,0 +1,7 @@
+export { Template, Plaintext } from "./template";
+export { type TemplateProps } from "./types";
+export {
+	DefaultTranslation,
+	Translations,
+	type Translation,
+} from "./translations";
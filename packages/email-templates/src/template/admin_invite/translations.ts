@@ -0,0 +1,28 @@
+import { GetTranslation, ToTranslations } from "postler";
+import { props } from "./types";
+
+export let DefaultTranslation = {
+	lang: "en",
+	translation: {
+		subject: `Join the ${props.project} dashboard`,
+		headline: "You have been invited",
+		label: "Accept Invitation",
+		text: `You have been invited to join the ${props.project} admin dashboard. Click on the link below to accept the invitation.`,
+		expire: `The invitation is valid for <span class="bold">${props.expire_in} days</span> and can only be used once`
+	},
+};
+
+export type Translation = GetTranslation<typeof DefaultTranslation>;
+
+export let Translations: ToTranslations<typeof DefaultTranslation> = [
+	{
+		lang: "de",
+		translation: {
+			subject: `Treten Sie dem ${props.project} Dashboard bei`,
+			headline: "Sie wurden eingeladen",
+			label: "Einladung annehmen",
+			text: `Sie wurden eingeladen, dem ${props.project} Admin Dashboard beizutreten. Klicken Sie auf den untenstehenden Link, um die Einladung anzunehmen`,
+			expire: `Die Einladung ist für <span class="bold">${props.expire_in} Tage</span> gültig und kann nur einmal verwendet werden`
+		},
+	},
+];
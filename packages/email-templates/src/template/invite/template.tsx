@@ -0,0 +1,38 @@
+import { createTranslations, Document, html } from "postler";
+import { props } from "./types";
+import { Translation } from "./translations";
+import { Button } from "../../component/button";
+import { Typography } from "@vulpo-dev/brief";
+import { Body, Container, Title, Link, Text } from "../../component";
+
+let t = createTranslations<Translation>();
+
+export let Plaintext = () => {
+	return <>{t.headline}: {t.text}</>
+}
+
+export let Template = () => {
+	return (
+		<Document>
+			<Typography />
+			<Body>
+				<Container>
+					<Title>{t.headline}</Title>
+					<Text>{t.text}</Text>
+
+					<Button align="center" primary href={props.href}>
+						{t.label}
+					</Button>
+
+					<Link href={props.href} />
+					
+					<footer>
+						<Text>
+							{html(t.expire)}
+						</Text>				
+					</footer>
+				</Container>
+			</Body>
+		</Document>
+	);
+};
@@ -0,0 +1,28 @@
+import { GetTranslation, ToTranslations } from "postler";
+import { props } from "./types";
+
+export let DefaultTranslation = {
+	lang: "en",
+	translation: {
+		subject: `You have been invited to ${props.project}`,
+		headline: "You have been invited",
+		label: "Accept Invitation",
+		text: `You have been invited to ${props.project}. Click on the link below to accept the invitation and set up your account.`,
+		expire: `The invitation is valid for <span class="bold">${props.expire_in} days</span> and can only be used once`
+	},
+};
+
+export type Translation = GetTranslation<typeof DefaultTranslation>;
+
+export let Translations: ToTranslations<typeof DefaultTranslation> = [
+	{
+		lang: "de",
+		translation: {
+			subject: `Sie wurden zu ${props.project} eingeladen`,
+			headline: "Sie wurden eingeladen",
+			label: "Einladung annehmen",
+			text: `Sie wurden zu ${props.project} eingeladen. Klicken Sie auf den untenstehenden Link, um die Einladung anzunehmen und Ihr Konto einzurichten`,
+			expire: `Die Einladung ist für <span class="bold">${props.expire_in} Tage</span> gültig und kann nur einmal verwendet werden`
+		},
+	},
+];
@@ -0,0 +1,18 @@
+import { Previews, Item } from "postler";
+import { faker } from "@faker-js/faker"
+import { TemplateProps } from "./types";
+
+export let Data: Previews<TemplateProps> = [
+	Item("name", {
+		project: "email-templates",
+		organization: faker.company.name(),
+		href: faker.internet.url(),
+		expire_in: 7,
+	}),
+	Item("long text", {
+		project: faker.company.name(),
+		organization: faker.company.name() + " " + faker.company.name(),
+		href: faker.internet.url(),
+		expire_in: 30,
+	}),
+];
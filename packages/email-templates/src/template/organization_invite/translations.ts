@@ -0,0 +1,28 @@
+import { GetTranslation, ToTranslations } from "postler";
+import { props } from "./types";
+
+export let DefaultTranslation = {
+	lang: "en",
+	translation: {
+		subject: `Join ${props.organization}`,
+		headline: "You have been invited",
+		label: "Accept Invitation",
+		text: `You have been invited to join ${props.organization} on ${props.project}. Click on the link below to accept the invitation.`,
+		expire: `The invitation is valid for <span class="bold">${props.expire_in} days</span> and can only be used once`
+	},
+};
+
+export type Translation = GetTranslation<typeof DefaultTranslation>;
+
+export let Translations: ToTranslations<typeof DefaultTranslation> = [
+	{
+		lang: "de",
+		translation: {
+			subject: `Treten Sie ${props.organization} bei`,
+			headline: "Sie wurden eingeladen",
+			label: "Einladung annehmen",
+			text: `Sie wurden eingeladen, ${props.organization} auf ${props.project} beizutreten. Klicken Sie auf den untenstehenden Link, um die Einladung anzunehmen`,
+			expire: `Die Einladung ist für <span class="bold">${props.expire_in} Tage</span> gültig und kann nur einmal verwendet werden`
+		},
+	},
+];
@@ -0,0 +1,10 @@
+import { createProps } from "postler";
+
+export type TemplateProps = {
+	project: string;
+	organization: string;
+	href: string;
+	expire_in: number;
+};
+
+export let props = createProps<TemplateProps>();
@@ -0,0 +1,16 @@
+import { Previews, Item } from "postler";
+import { faker } from "@faker-js/faker"
+import { TemplateProps } from "./types";
+
+export let Data: Previews<TemplateProps> = [
+	Item("name", {
+		project: "email-templates",
+		code: faker.random.numeric(8),
+		expire_in: 15,
+	}),
+	Item("long text", {
+		project: faker.company.name(),
+		code: faker.random.numeric(8),
+		expire_in: 120,
+	}),
+];
@@ -0,0 +1,33 @@
+import { createTranslations, Document, html } from "postler";
+import { props } from "./types";
+import { Translation } from "./translations";
+import { Typography } from "@vulpo-dev/brief";
+import { Body, Container, Title, Code, Text } from "../../component";
+
+let t = createTranslations<Translation>();
+
+export let Plaintext = () => {
+	return <>{t.headline}: {t.text} {props.code}</>
+}
+
+export let Template = () => {
+	return (
+		<Document>
+			<Typography />
+			<Body>
+				<Container>
+					<Title>{t.headline}</Title>
+					<Text>{t.text}</Text>
+
+					<Code>{props.code}</Code>
+
+					<footer>
+						<Text>
+							{html(t.expire)}
+						</Text>				
+					</footer>
+				</Container>
+			</Body>
+		</Document>
+	);
+};
@@ -0,0 +1,26 @@
+import { GetTranslation, ToTranslations } from "postler";
+import { props } from "./types";
+
+export let DefaultTranslation = {
+	lang: "en",
+	translation: {
+		subject: "Your sign in code",
+		headline: "Sign In",
+		text: `Enter the code below on the device you started from to sign in to your ${props.project} account.`,
+		expire: `The code is valid for <span class="bold">${props.expire_in} minutes</span> and can only be used once`
+	},
+};
+
+export type Translation = GetTranslation<typeof DefaultTranslation>;
+
+export let Translations: ToTranslations<typeof DefaultTranslation> = [
+	{
+		lang: "de",
+		translation: {
+			subject: "Ihr Anmeldecode",
+			headline: "Anmelden",
+			text: `Geben Sie den untenstehenden Code auf dem Gerät ein, auf dem Sie die Anmeldung begonnen haben, um sich bei Ihrem ${props.project} Konto anzumelden`,
+			expire: `Der Code ist <span class="bold">${props.expire_in} Minuten</span> gültig und kann nur einmal verwendet werden`
+		},
+	},
+];
@@ -10,7 +10,7 @@ async fn main() {
     );
 
     match auth.sign_in("michael@riezler.co", "password").await {
-        Ok(user) => println!("Sign In success: {}", user.email),
+        Ok(user) => println!("Sign In success: {}", user.id),
         Err(err) => panic!("Failed to sign in: {:#?}", err),
     };
 
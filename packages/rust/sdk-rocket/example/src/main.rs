@@ -4,7 +4,7 @@ extern crate rocket;
 use rocket::http::Status;
 use serde::Deserialize;
 use vulpo::{AccessToken, Authorize};
-use vulpo_rocket::{Auth, AuthClient, Claims};
+use vulpo_rocket::{permission, Auth, AuthClient, Claims, RequirePermission};
 
 type User = Auth<AccessToken>;
 
@@ -29,6 +29,13 @@ fn admin(_auth: AdminUser) -> Status {
     Status::Ok
 }
 
+permission!(WritePosts, "posts:write");
+
+#[post("/posts")]
+fn create_post(_auth: Auth<RequirePermission<WritePosts>>) -> Status {
+    Status::Created
+}
+
 #[derive(Deserialize)]
 struct InternalError;
 
@@ -46,6 +53,6 @@ fn internal_error(_auth: Auth<InternalError>) -> Status {
 #[launch]
 fn rocket() -> _ {
     rocket::build()
-        .attach(AuthClient::fairing("http://127.0.0.1:7000".to_string()))
-        .mount("/", routes![test, admin, internal_error])
+        .attach(AuthClient::fairing("http://127.0.0.1:7000".to_string(), None))
+        .mount("/", routes![test, admin, create_post, internal_error])
 }
@@ -1,11 +1,18 @@
 use rocket::fairing::{AdHoc, Fairing};
-use rocket::http::Status;
+use rocket::http::{Method, Status};
 use rocket::request::Outcome;
 use rocket::request::{FromRequest, Request};
 use std::marker::PhantomData;
 use vulpo::{AuthKeys, Authorize, Error, Token};
 
-pub use vulpo::Claims;
+pub use vulpo::{
+    permission, scope, AccessToken, Claims, OAuthClient, OrgAdmin, OrgMember, OrgOwner, Permission,
+    RequirePermission, RequireScope, Scope, ServiceAccount, ServiceCredentials, VerifiedEmail,
+};
+
+const ACCESS_TOKEN_COOKIE: &str = "vulpo_access_token";
+const CSRF_COOKIE: &str = "vulpo_csrf";
+const CSRF_HEADER: &str = "X-CSRF-Token";
 
 pub struct Auth<C: Authorize>(Claims, PhantomData<C>);
 
@@ -23,14 +30,15 @@ where
     type Error = ();
 
     async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
-        let token_string = match req.headers().get_one("Authorization") {
-            None => return Outcome::Failure((Status::BadRequest, ())),
-            Some(token) => token,
-        };
-
-        let token = match AuthKeys::get_token(&token_string) {
-            Err(_) => return Outcome::Failure((Status::BadRequest, ())),
-            Ok(token) => token,
+        let token = match req.headers().get_one("Authorization") {
+            Some(token_string) => match AuthKeys::get_token(token_string) {
+                Err(_) => return Outcome::Failure((Status::BadRequest, ())),
+                Ok(token) => token,
+            },
+            None => match cookie_token(req) {
+                Err(status) => return Outcome::Failure((status, ())),
+                Ok(token) => token,
+            },
         };
 
         let auth = match req.rocket().state::<AuthKeys>() {
@@ -41,15 +49,23 @@ where
         let get_claims = match token {
             Token::JWT(token) => auth.verify_jwt(&token).await,
             Token::ApiKey(token) => auth.verify_api_key(&token).await,
+            Token::DPoP(token) => {
+                let proof = req.headers().get_one("DPoP");
+                let url = request_url(req);
+                auth.verify_dpop(&token, proof, req.method().as_str(), &url)
+                    .await
+            }
         };
 
         let claims = match get_claims {
             Err(err) => {
                 let status = match err {
                     Error::Expired
+                    | Error::Revoked
                     | Error::Unauthorized
                     | Error::InvalidKey
-                    | Error::InvalidClaims => Status::Unauthorized,
+                    | Error::InvalidClaims
+                    | Error::InvalidProof => Status::Unauthorized,
                     Error::KeyMissing | Error::GetApiKeyRequest | Error::InvalidPayload => {
                         Status::InternalServerError
                     }
@@ -73,12 +89,49 @@ where
     }
 }
 
+/// Access token of a cookie session. Requests that change something have to
+/// send the `vulpo_csrf` cookie in the `X-CSRF-Token` header.
+fn cookie_token(req: &Request<'_>) -> Result<Token, Status> {
+    let cookies = req.cookies();
+    let token = cookies
+        .get(ACCESS_TOKEN_COOKIE)
+        .ok_or(Status::BadRequest)?
+        .value()
+        .to_string();
+
+    if matches!(req.method(), Method::Get | Method::Head | Method::Options) {
+        return Ok(Token::JWT(token));
+    }
+
+    let csrf = cookies.get(CSRF_COOKIE).map(|cookie| cookie.value());
+    match (csrf, req.headers().get_one(CSRF_HEADER)) {
+        (Some(cookie), Some(header)) if !cookie.is_empty() && cookie == header => {
+            Ok(Token::JWT(token))
+        }
+        _ => Err(Status::Forbidden),
+    }
+}
+
+/// The URL the client sent the request to, proxies have to forward
+/// `X-Forwarded-Host`
+fn request_url(req: &Request<'_>) -> String {
+    let headers = req.headers();
+    let host = headers
+        .get_one("X-Forwarded-Host")
+        .or_else(|| headers.get_one("Host"))
+        .unwrap_or("localhost");
+
+    format!("{}{}", host, req.uri().path())
+}
+
 pub struct AuthClient;
 
 impl AuthClient {
-    pub fn fairing(host: String) -> impl Fairing {
+    /// Revoked tokens are only rejected with the credentials of a service
+    /// account, see [`ServiceCredentials`]
+    pub fn fairing(host: String, credentials: Option<ServiceCredentials>) -> impl Fairing {
         AdHoc::on_ignite("Get PublicKeys", move |rocket| async move {
-            let auth = AuthKeys::init(&host)
+            let auth = AuthKeys::init(&host, credentials)
                 .await
                 .expect("Failed to load public keys");
 
@@ -0,0 +1,144 @@
+//! Proof of possession for access tokens, following
+//! [RFC 9449](https://www.rfc-editor.org/rfc/rfc9449). Bound tokens carry the
+//! thumbprint of the session's public key in `cnf.jkt`, every request has to
+//! send a proof that is signed with the session's private key.
+
+use crate::Error;
+use jsonwebtoken as jwt;
+use jsonwebtoken::jwk::{AlgorithmParameters, EllipticCurve, Jwk};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+
+/// Proofs are accepted for this many seconds after `iat`, the same leeway
+/// applies to clients whose clock runs ahead
+pub const PROOF_MAX_AGE: i64 = 60;
+
+/// The `cnf` claim of an access token
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Confirmation {
+    /// JWK SHA-256 thumbprint of the session's public key
+    pub jkt: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProofClaims {
+    pub jti: String,
+    pub htm: String,
+    pub htu: String,
+    pub iat: i64,
+
+    /// Hash of the access token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ath: Option<String>,
+}
+
+/// [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638) thumbprint of an
+/// elliptic curve key, `x` and `y` are base64url encoded
+pub fn ec_thumbprint(crv: &str, x: &str, y: &str) -> String {
+    let jwk = format!(r#"{{"crv":"{}","kty":"EC","x":"{}","y":"{}"}}"#, crv, x, y);
+    hash(&jwk)
+}
+
+/// [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638) thumbprint of an RSA
+/// key, `n` and `e` are base64url encoded
+pub fn rsa_thumbprint(n: &str, e: &str) -> String {
+    let jwk = format!(r#"{{"e":"{}","kty":"RSA","n":"{}"}}"#, e, n);
+    hash(&jwk)
+}
+
+pub fn thumbprint(jwk: &Jwk) -> Option<String> {
+    match &jwk.algorithm {
+        AlgorithmParameters::EllipticCurve(params) => {
+            let crv = match params.curve {
+                EllipticCurve::P256 => "P-256",
+                EllipticCurve::P384 => "P-384",
+                _ => return None,
+            };
+            Some(ec_thumbprint(crv, &params.x, &params.y))
+        }
+        AlgorithmParameters::RSA(params) => Some(rsa_thumbprint(&params.n, &params.e)),
+        _ => None,
+    }
+}
+
+/// Value of the `ath` claim
+pub fn access_token_hash(access_token: &str) -> String {
+    hash(access_token)
+}
+
+fn hash(value: &str) -> String {
+    let digest = Sha256::digest(value.as_bytes());
+    base64::encode_config(digest, base64::URL_SAFE_NO_PAD)
+}
+
+/// Verifies the `DPoP` header of a request made with `access_token`. `htu` is
+/// compared without scheme, query and fragment because TLS is usually
+/// terminated in front of the server. Replays have to be checked by the
+/// caller with the returned `jti`.
+pub fn verify(
+    proof: &str,
+    access_token: &str,
+    cnf: &Confirmation,
+    method: &str,
+    url: &str,
+    now: i64,
+) -> Result<ProofClaims, Error> {
+    let header = jwt::decode_header(proof).map_err(|_| Error::InvalidProof)?;
+
+    if header.typ.as_deref() != Some("dpop+jwt") {
+        return Err(Error::InvalidProof);
+    }
+
+    match header.alg {
+        Algorithm::ES256 | Algorithm::ES384 | Algorithm::RS256 => {}
+        _ => return Err(Error::InvalidProof),
+    };
+
+    let jwk = header.jwk.ok_or(Error::InvalidProof)?;
+
+    if thumbprint(&jwk).as_ref() != Some(&cnf.jkt) {
+        return Err(Error::InvalidProof);
+    }
+
+    let key = DecodingKey::from_jwk(&jwk).map_err(|_| Error::InvalidProof)?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.required_spec_claims = HashSet::new();
+    validation.validate_exp = false;
+
+    let claims = jwt::decode::<ProofClaims>(proof, &key, &validation)
+        .map_err(|_| Error::InvalidProof)?
+        .claims;
+
+    if claims.htm != method.to_uppercase() {
+        return Err(Error::InvalidProof);
+    }
+
+    if normalize_url(&claims.htu) != normalize_url(url) {
+        return Err(Error::InvalidProof);
+    }
+
+    if (now - claims.iat).abs() > PROOF_MAX_AGE {
+        return Err(Error::InvalidProof);
+    }
+
+    if claims.ath.as_deref() != Some(access_token_hash(access_token).as_str()) {
+        return Err(Error::InvalidProof);
+    }
+
+    Ok(claims)
+}
+
+fn normalize_url(url: &str) -> &str {
+    let url = match url.find("://") {
+        None => url,
+        Some(index) => &url[index + 3..],
+    };
+
+    match url.find(['?', '#']) {
+        None => url,
+        Some(index) => &url[..index],
+    }
+}
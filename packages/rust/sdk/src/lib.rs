@@ -5,6 +5,7 @@ use reqwest;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::marker::PhantomData;
 use std::mem;
 use std::str::FromStr;
 use std::sync::Mutex;
@@ -18,6 +19,7 @@ pub struct ApiKeyPayload {
 pub enum Token {
     ApiKey(String),
     JWT(String),
+    DPoP(String),
 }
 
 pub enum TokenError {
@@ -30,14 +32,135 @@ pub trait Authorize {
     fn authorize(claims: &Claims) -> Result<bool, u16>;
 }
 
+/// Access tokens of users, tokens issued to OAuth clients and service
+/// accounts are rejected, see [`OAuthClient`] and [`ServiceAccount`]
 pub struct AccessToken;
 
 impl Authorize for AccessToken {
-    fn authorize(_: &Claims) -> Result<bool, u16> {
-        Ok(true)
+    fn authorize(claims: &Claims) -> Result<bool, u16> {
+        Ok(claims.client_id.is_none())
     }
 }
 
+/// Requires a token a third-party OAuth client got on behalf of a user
+pub struct OAuthClient;
+
+impl Authorize for OAuthClient {
+    fn authorize(claims: &Claims) -> Result<bool, u16> {
+        Ok(claims.client_id.is_some() && !claims.is_service_account())
+    }
+}
+
+/// Requires an active organization, any role
+pub struct OrgMember;
+
+impl Authorize for OrgMember {
+    fn authorize(claims: &Claims) -> Result<bool, u16> {
+        Ok(claims.has_org_role(OrgRole::Member))
+    }
+}
+
+/// Requires the `admin` or `owner` role in the active organization
+pub struct OrgAdmin;
+
+impl Authorize for OrgAdmin {
+    fn authorize(claims: &Claims) -> Result<bool, u16> {
+        Ok(claims.has_org_role(OrgRole::Admin))
+    }
+}
+
+/// Requires the `owner` role in the active organization
+pub struct OrgOwner;
+
+impl Authorize for OrgOwner {
+    fn authorize(claims: &Claims) -> Result<bool, u16> {
+        Ok(claims.has_org_role(OrgRole::Owner))
+    }
+}
+
+/// Requires a token issued to a service account through the client credentials grant
+pub struct ServiceAccount;
+
+impl Authorize for ServiceAccount {
+    fn authorize(claims: &Claims) -> Result<bool, u16> {
+        Ok(claims.is_service_account())
+    }
+}
+
+/// Rejects tokens of users that didn't verify their email address yet
+pub struct VerifiedEmail;
+
+impl Authorize for VerifiedEmail {
+    fn authorize(claims: &Claims) -> Result<bool, u16> {
+        Ok(claims.email_verified != Some(false))
+    }
+}
+
+/// A named permission from the project's permission catalog, usually
+/// declared with the [`permission!`] macro
+pub trait Permission {
+    const NAME: &'static str;
+}
+
+/// Requires the permission `P`, e.g. `Auth<RequirePermission<WritePosts>>`
+pub struct RequirePermission<P: Permission>(PhantomData<P>);
+
+impl<P: Permission> Authorize for RequirePermission<P> {
+    fn authorize(claims: &Claims) -> Result<bool, u16> {
+        Ok(claims.has_permission(P::NAME))
+    }
+}
+
+/// A scope that can be granted to OAuth clients and service accounts,
+/// usually declared with the [`scope!`] macro
+pub trait Scope {
+    const NAME: &'static str;
+}
+
+/// Requires a client token with the scope `S`, e.g. `Auth<RequireScope<ReadInvoices>>`
+pub struct RequireScope<S: Scope>(PhantomData<S>);
+
+impl<S: Scope> Authorize for RequireScope<S> {
+    fn authorize(claims: &Claims) -> Result<bool, u16> {
+        Ok(claims.client_id.is_some() && claims.has_scope(S::NAME))
+    }
+}
+
+/// Declares a [`Permission`]
+///
+/// ```
+/// vulpo::permission!(WritePosts, "posts:write");
+/// ```
+#[macro_export]
+macro_rules! permission {
+    ($name:ident, $permission:literal) => {
+        pub struct $name;
+
+        impl $crate::Permission for $name {
+            const NAME: &'static str = $permission;
+        }
+    };
+}
+
+/// Declares a [`Scope`]
+///
+/// ```
+/// vulpo::scope!(ReadInvoices, "invoices:read");
+/// ```
+#[macro_export]
+macro_rules! scope {
+    ($name:ident, $scope:literal) => {
+        pub struct $name;
+
+        impl $crate::Scope for $name {
+            const NAME: &'static str = $scope;
+        }
+    };
+}
+
+pub mod dpop;
+pub use dpop::Confirmation;
+
 #[cfg(test)]
 mod test;
 
@@ -46,6 +169,86 @@ pub struct Claims {
     pub sub: Uuid,
     pub exp: i64,
     pub traits: Vec<String>,
+
+    /// The organization that is active for the session
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<Uuid>,
+
+    /// The role of the user inside of `org_id`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_role: Option<OrgRole>,
+
+    /// Permissions granted through the user's roles
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<String>,
+
+    /// The OAuth client the token was issued to, for service accounts
+    /// it is the same as `sub`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<Uuid>,
+
+    /// Space separated scopes granted to the client
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
+    /// When the token was issued, used to check the token against
+    /// the [`Revocations`] feed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+
+    /// Binds the token to the session's key, requests have to send a
+    /// DPoP proof, see [`AuthKeys::verify_dpop`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnf: Option<Confirmation>,
+
+    /// Whether the user verified their email address, only set when the
+    /// project marks the tokens of unverified users
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+
+    /// When the user last authenticated the session, sensitive operations
+    /// require a recent `auth_time`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_time: Option<i64>,
+
+    /// How the user last authenticated the session, e.g. `pwd` or `otp`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub amr: Vec<String>,
+}
+
+impl Claims {
+    /// `true` when the user has at least the given role in the active organization,
+    /// owners are admins and admins are members
+    pub fn has_org_role(&self, role: OrgRole) -> bool {
+        match self.org_role {
+            None => false,
+            Some(current) => self.org_id.is_some() && current >= role,
+        }
+    }
+
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.permissions.iter().any(|p| p == permission)
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        match &self.scope {
+            None => false,
+            Some(scopes) => scopes.split(' ').any(|s| s == scope),
+        }
+    }
+
+    /// `true` for tokens issued through the client credentials grant
+    pub fn is_service_account(&self) -> bool {
+        self.client_id == Some(self.sub)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, PartialOrd, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum OrgRole {
+    Member,
+    Admin,
+    Owner,
 }
 
 type Key = Vec<u8>;
@@ -61,6 +264,8 @@ pub enum Error {
     GetKeysRequest,
     InvalidTimestamp,
     Expired,
+    Revoked,
+    InvalidProof,
 
     GetApiKeyRequest,
 }
@@ -71,24 +276,105 @@ impl fmt::Display for Error {
     }
 }
 
+/// Credentials of a service account, the revocation feed is only
+/// available to the project's service accounts
+#[derive(Debug, Clone)]
+pub struct ServiceCredentials {
+    pub project: Uuid,
+    pub client_id: String,
+    pub client_secret: String,
+}
+
 #[derive(Debug)]
 pub struct AuthKeys {
     pub keys: Mutex<HashMap<Uuid, Key>>,
     pub expire_at: Mutex<DateTime<Utc>>,
+    pub revocations: Mutex<Revocations>,
+    pub proofs: Mutex<HashMap<String, i64>>,
     pub host: String,
+    /// Without credentials, revoked tokens are accepted until they expire
+    pub credentials: Option<ServiceCredentials>,
 }
 
 impl AuthKeys {
-    pub async fn init(host: &str) -> Result<AuthKeys, Error> {
+    pub async fn init(
+        host: &str,
+        credentials: Option<ServiceCredentials>,
+    ) -> Result<AuthKeys, Error> {
         let keys_url = format!("{}/keys", host);
         let keys = AuthKeys::get_keys(&keys_url).await?;
+
+        let revocations = match &credentials {
+            None => Revocations::default(),
+            Some(credentials) => {
+                let url = format!("{}/keys/revocations", host);
+                AuthKeys::get_revocations(&url, credentials).await?
+            }
+        };
+
         Ok(AuthKeys {
             keys: keys.keys,
             expire_at: keys.expire_at,
+            revocations: Mutex::new(revocations),
+            proofs: Mutex::new(HashMap::new()),
             host: String::from(host),
+            credentials,
         })
     }
 
+    pub async fn get_revocations(
+        url: &str,
+        credentials: &ServiceCredentials,
+    ) -> Result<Revocations, Error> {
+        let res = reqwest::Client::new()
+            .get(url)
+            .header("Vulpo-Project", credentials.project.to_string())
+            .basic_auth(&credentials.client_id, Some(&credentials.client_secret))
+            .send()
+            .await
+            .map_err(|_| Error::GetKeysRequest)?;
+
+        if res.status().as_u16() == 401 {
+            return Err(Error::Unauthorized);
+        }
+
+        if res.status().as_u16() >= 300 {
+            return Err(Error::GetKeysRequest);
+        }
+
+        res.json::<Revocations>()
+            .await
+            .map_err(|_| Error::InvalidPayload)
+    }
+
+    /// Polls the revocation feed once the current one expired, the last
+    /// feed is kept when the server can't be reached
+    pub async fn is_revoked(&self, project: &Uuid, claims: &Claims) -> bool {
+        let credentials = match &self.credentials {
+            None => return false,
+            Some(credentials) => credentials,
+        };
+
+        let expired = match self.revocations.lock() {
+            Err(_) => false,
+            Ok(revocations) => revocations.expire_at <= Utc::now(),
+        };
+
+        if expired {
+            let url = format!("{}/keys/revocations", self.host);
+            if let Ok(feed) = AuthKeys::get_revocations(&url, credentials).await {
+                if let Ok(mut revocations) = self.revocations.lock() {
+                    *revocations = feed;
+                }
+            }
+        }
+
+        match self.revocations.lock() {
+            Err(_) => false,
+            Ok(revocations) => revocations.is_revoked(project, claims),
+        }
+    }
+
     pub async fn get_keys(url: &str) -> Result<Keys, Error> {
         let res = reqwest::get(url).await.map_err(|_| Error::GetKeysRequest)?;
 
@@ -184,6 +470,7 @@ impl AuthKeys {
         match token_type.to_lowercase().as_str() {
             "bearer" => Ok(Token::JWT(token)),
             "apikey" => Ok(Token::ApiKey(token)),
+            "dpop" => Ok(Token::DPoP(token)),
             _ => Err(TokenError::InvalidTokenType),
         }
     }
@@ -195,7 +482,55 @@ impl AuthKeys {
         })
     }
 
+    /// Verifies a bearer token, tokens that are bound to a session key are
+    /// rejected, they have to be verified with [`AuthKeys::verify_dpop`]
     pub async fn verify_jwt(&self, token: &String) -> Result<Claims, Error> {
+        let claims = self.decode_jwt(token).await?;
+
+        match claims.cnf {
+            Some(_) => Err(Error::InvalidProof),
+            None => Ok(claims),
+        }
+    }
+
+    /// Verifies a token that is bound to a session key, `proof` is the `DPoP`
+    /// header and `url` the URL of the request
+    pub async fn verify_dpop(
+        &self,
+        token: &String,
+        proof: Option<&str>,
+        method: &str,
+        url: &str,
+    ) -> Result<Claims, Error> {
+        let claims = self.decode_jwt(token).await?;
+        let cnf = claims.cnf.as_ref().ok_or(Error::InvalidProof)?;
+        let proof = proof.ok_or(Error::InvalidProof)?;
+
+        let now = Utc::now().timestamp();
+        let proof = dpop::verify(proof, token, cnf, method, url, now)?;
+
+        if self.is_replay(&cnf.jkt, &proof, now) {
+            return Err(Error::InvalidProof);
+        }
+
+        Ok(claims)
+    }
+
+    /// Proofs are remembered until they expire, a proof can only be used once
+    fn is_replay(&self, jkt: &str, proof: &dpop::ProofClaims, now: i64) -> bool {
+        let mut proofs = match self.proofs.lock() {
+            Err(_) => return true,
+            Ok(proofs) => proofs,
+        };
+
+        proofs.retain(|_, expire_at| *expire_at >= now);
+
+        let id = format!("{}.{}", jkt, proof.jti);
+        let expire_at = proof.iat + dpop::PROOF_MAX_AGE;
+        proofs.insert(id, expire_at).is_some()
+    }
+
+    async fn decode_jwt(&self, token: &String) -> Result<Claims, Error> {
         let header = AuthKeys::decode_header(&token)?;
 
         let kid = header
@@ -218,6 +553,7 @@ impl AuthKeys {
                 ErrorKind::ExpiredSignature => Err(Error::Expired),
                 _ => Err(Error::InvalidClaims),
             },
+            Ok(td) if self.is_revoked(&kid, &td.claims).await => Err(Error::Revoked),
             Ok(td) => Ok(td.claims),
         }
     }
@@ -264,6 +600,32 @@ pub struct PublicKey {
     pub key: Vec<u8>,
 }
 
+/// Users and projects whose tokens were revoked during the lifetime of
+/// an access token, tokens issued before the timestamp are rejected
+#[derive(Debug, Default, Deserialize)]
+pub struct Revocations {
+    pub expire_at: DateTime<Utc>,
+    pub users: HashMap<Uuid, i64>,
+    pub projects: HashMap<Uuid, i64>,
+}
+
+impl Revocations {
+    pub fn is_revoked(&self, project: &Uuid, claims: &Claims) -> bool {
+        let valid_after = match (self.users.get(&claims.sub), self.projects.get(project)) {
+            (None, None) => return false,
+            (Some(user), Some(project)) => *user.max(project),
+            (Some(valid_after), None) | (None, Some(valid_after)) => *valid_after,
+        };
+
+        // tokens without `iat` were issued before the feed existed
+        match claims.iat {
+            None => true,
+            // `iat` has seconds, tokens of the second of the revocation are revoked too
+            Some(iat) => iat <= valid_after,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Keys {
     pub keys: Mutex<HashMap<Uuid, Key>>,
@@ -96,10 +96,26 @@ pub enum ApiError {
     #[serde(rename = "user/duplicate")]
     UserDuplicate,
 
+    #[error("user/provider_linked")]
+    #[serde(rename = "user/provider_linked")]
+    UserProviderLinked,
+
+    #[error("user/last_provider")]
+    #[serde(rename = "user/last_provider")]
+    UserLastProvider,
+
     #[error("user/disabled")]
     #[serde(rename = "user/disabled")]
     UserDisabled,
 
+    #[error("user/email_not_verified")]
+    #[serde(rename = "user/email_not_verified")]
+    UserEmailNotVerified,
+
+    #[error("user/email_domain_not_allowed")]
+    #[serde(rename = "user/email_domain_not_allowed")]
+    UserEmailDomainNotAllowed,
+
     #[error("token/not_found")]
     #[serde(rename = "token/not_found")]
     TokenNotFound,
@@ -112,6 +128,10 @@ pub enum ApiError {
     #[serde(rename = "token/expired")]
     TokenExpired,
 
+    #[error("token/invalid_proof")]
+    #[serde(rename = "token/invalid_proof")]
+    TokenInvalidProof,
+
     #[error("reset/invalid_token")]
     #[serde(rename = "reset/invalid_token")]
     ResetInvalidToken,
@@ -140,6 +160,54 @@ pub enum ApiError {
     #[serde(rename = "passwordless/await_confirm")]
     PasswordlessAwaitConfirm,
 
+    #[error("passwordless/too_many_attempts")]
+    #[serde(rename = "passwordless/too_many_attempts")]
+    PasswordlessTooManyAttempts,
+
+    #[error("sms/invalid_phone")]
+    #[serde(rename = "sms/invalid_phone")]
+    SmsInvalidPhone,
+
+    #[error("sms/invalid_code")]
+    #[serde(rename = "sms/invalid_code")]
+    SmsInvalidCode,
+
+    #[error("sms/code_expired")]
+    #[serde(rename = "sms/code_expired")]
+    SmsCodeExpired,
+
+    #[error("sms/too_many_attempts")]
+    #[serde(rename = "sms/too_many_attempts")]
+    SmsTooManyAttempts,
+
+    #[error("sms/too_many_requests")]
+    #[serde(rename = "sms/too_many_requests")]
+    SmsTooManyRequests,
+
+    #[error("sms/not_configured")]
+    #[serde(rename = "sms/not_configured")]
+    SmsNotConfigured,
+
+    #[error("organization/last_owner")]
+    #[serde(rename = "organization/last_owner")]
+    OrganizationLastOwner,
+
+    #[error("organization/member_exists")]
+    #[serde(rename = "organization/member_exists")]
+    OrganizationMemberExists,
+
+    #[error("role/exists")]
+    #[serde(rename = "role/exists")]
+    RoleExists,
+
+    #[error("permission/exists")]
+    #[serde(rename = "permission/exists")]
+    PermissionExists,
+
+    #[error("permission/not_found")]
+    #[serde(rename = "permission/not_found")]
+    PermissionNotFound,
+
     #[error("template/render")]
     #[serde(rename = "template/render")]
     TemplateRender,
@@ -147,6 +215,66 @@ pub enum ApiError {
     #[error("session/expired")]
     #[serde(rename = "session/expired")]
     SessionExpired,
+
+    #[error("session/limit_reached")]
+    #[serde(rename = "session/limit_reached")]
+    SessionLimitReached,
+
+    #[error("session/invalid_csrf_token")]
+    #[serde(rename = "session/invalid_csrf_token")]
+    SessionInvalidCsrfToken,
+
+    #[error("session/reauthentication_required")]
+    #[serde(rename = "session/reauthentication_required")]
+    SessionReauthenticationRequired,
+
+    #[error("session/too_many_attempts")]
+    #[serde(rename = "session/too_many_attempts")]
+    SessionTooManyAttempts,
+
+    #[error("captcha/required")]
+    #[serde(rename = "captcha/required")]
+    CaptchaRequired,
+
+    #[error("captcha/invalid")]
+    #[serde(rename = "captcha/invalid")]
+    CaptchaInvalid,
+
+    #[error("redirect_url/not_allowed")]
+    #[serde(rename = "redirect_url/not_allowed")]
+    RedirectUrlNotAllowed,
+
+    #[error("oauth/unsupported_grant_type")]
+    #[serde(rename = "oauth/unsupported_grant_type")]
+    OAuthUnsupportedGrantType,
+
+    #[error("oauth/invalid_client")]
+    #[serde(rename = "oauth/invalid_client")]
+    OAuthInvalidClient,
+
+    #[error("oauth/invalid_scope")]
+    #[serde(rename = "oauth/invalid_scope")]
+    OAuthInvalidScope,
+
+    #[error("oauth/invalid_grant")]
+    #[serde(rename = "oauth/invalid_grant")]
+    OAuthInvalidGrant,
+
+    #[error("oauth/invalid_request")]
+    #[serde(rename = "oauth/invalid_request")]
+    OAuthInvalidRequest,
+
+    #[error("oauth/invalid_redirect_uri")]
+    #[serde(rename = "oauth/invalid_redirect_uri")]
+    OAuthInvalidRedirectUri,
+
+    #[error("oauth/invalid_token")]
+    #[serde(rename = "oauth/invalid_token")]
+    OAuthInvalidToken,
+
+    #[error("oauth/unsupported_token_type")]
+    #[serde(rename = "oauth/unsupported_token_type")]
+    OAuthUnsupportedTokenType,
 }
 
 impl From<sqlx::Error> for ApiError {
@@ -157,7 +285,10 @@ impl From<sqlx::Error> for ApiError {
                 match err.constraint() {
                     Some("project_settings_name_key") => ApiError::ProjectNameExists,
                     Some("users_project_id_email_key") => ApiError::UserExists,
+                    Some("users_project_id_phone_key") => ApiError::UserExists,
                     Some("users_project_id_fkey") => ApiError::UserInvalidProject,
+                    Some("roles_project_id_name_key") => ApiError::RoleExists,
+                    Some("permissions_project_id_name_key") => ApiError::PermissionExists,
                     _ => ApiError::InternalServerError,
                 }
             }
@@ -177,16 +308,25 @@ impl ApiError {
         match self {
             ApiError::InternalServerError | ApiError::TemplateRender => Status::InternalServerError,
             ApiError::NotFound => Status::NotFound,
-            ApiError::Forbidden => Status::Forbidden,
+            ApiError::Forbidden | ApiError::AdminAuth => Status::Forbidden,
+            ApiError::SessionInvalidCsrfToken => Status::Forbidden,
+            ApiError::SessionReauthenticationRequired => Status::Forbidden,
+            ApiError::UserEmailNotVerified => Status::Forbidden,
+            ApiError::UserEmailDomainNotAllowed => Status::Forbidden,
+            ApiError::SmsTooManyRequests => Status::TooManyRequests,
             ApiError::TokenInvalid => Status::Forbidden,
+            ApiError::CaptchaInvalid => Status::Forbidden,
             ApiError::ProjectNameExists | ApiError::UserExists => Status::BadRequest,
             ApiError::ProjectNotFound => Status::NotFound,
             ApiError::PasswordlessAwaitConfirm
             | ApiError::TokenExpired
             | ApiError::TokenNotFound
+            | ApiError::TokenInvalidProof
             | ApiError::AuthTokenMissing
             | ApiError::AuthRefreshTokenMissing
-            | ApiError::AuthRefreshTokenNotFound => Status::Unauthorized,
+            | ApiError::AuthRefreshTokenNotFound
+            | ApiError::OAuthInvalidClient
+            | ApiError::OAuthInvalidToken => Status::Unauthorized,
             _ => Status::BadRequest,
         }
     }
@@ -23,18 +23,23 @@ pub enum UserState {
     Active,
     Disabled,
     SetPassword,
+    Deleted,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct User {
     pub id: Uuid,
     pub display_name: Option<String>,
-    pub email: String,
+    pub email: Option<String>,
     pub email_verified: bool,
+    pub phone: Option<String>,
+    pub phone_verified: bool,
     pub photo_url: Option<String>,
     pub traits: Vec<String>,
-    pub data: serde_json::value::Value,
+    pub user_metadata: serde_json::value::Value,
+    pub app_metadata: serde_json::value::Value,
     pub provider_id: String,
+    pub providers: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub state: UserState,
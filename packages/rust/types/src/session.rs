@@ -43,6 +43,8 @@ pub struct RefreshAccessTokenClaims {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SessionResponse {
+    /// Left out for cookie sessions, the access token is only set as cookie
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub access_token: String,
     pub created: bool,
     pub user_id: Uuid,
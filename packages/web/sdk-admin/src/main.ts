@@ -13,6 +13,7 @@ enum UserState {
 	Active = "active",
 	Disabled = "disabled",
 	SetPassword = "set_password",
+	Deleted = "deleted",
 }
 
 type Uuid = string;
@@ -22,12 +23,16 @@ type DateTime = string;
 export type User = {
 	id: Uuid;
 	display_name: Option<string>;
-	email: string;
+	email: Option<string>;
 	email_verified: boolean;
+	phone: Option<string>;
+	phone_verified: boolean;
 	photo_url: Option<string>;
 	traits: Array<string>;
-	data: Record<string, unknown>;
+	user_metadata: Record<string, unknown>;
+	app_metadata: Record<string, unknown>;
 	provider_id: string;
+	providers: Array<string>;
 	created_at: DateTime;
 	updated_at: DateTime;
 	state: UserState;
@@ -36,9 +41,10 @@ export type User = {
 
 export type UpdateUser = {
 	display_name: Option<string>;
-	email: string;
+	email: Option<string>;
 	traits: Array<string>;
-	data: Record<string, unknown>;
+	user_metadata: Record<string, unknown>;
+	app_metadata: Record<string, unknown>;
 };
 
 export type NewUser = {
@@ -46,10 +52,40 @@ export type NewUser = {
 	project_id: Uuid;
 	password?: Option<string>;
 	display_name?: Option<string>;
-	data?: Option<{ [key: string]: unknown }>;
+	user_metadata?: Option<{ [key: string]: unknown }>;
+	app_metadata?: Option<{ [key: string]: unknown }>;
 	provider_id: string;
 };
 
+export type InviteUser = {
+	email: string;
+	project_id: Uuid;
+	display_name?: Option<string>;
+	traits?: Array<string>;
+	user_metadata?: Option<{ [key: string]: unknown }>;
+	app_metadata?: Option<{ [key: string]: unknown }>;
+	// has to be on the project's redirect allow-list
+	redirect_url?: Option<string>;
+};
+
+export type UserInvitation = {
+	id: Uuid;
+	user_id: Uuid;
+	email: Option<string>;
+	expire_at: DateTime;
+	created_at: DateTime;
+};
+
+export type UserSession = {
+	id: Uuid;
+	device_name: Option<string>;
+	user_agent: Option<string>;
+	ip: Option<string>;
+	created_at: DateTime;
+	last_refreshed_at: Option<DateTime>;
+	expire_at: DateTime;
+};
+
 /* EMAIL */
 export type EmailSettings = {
 	host: string;
@@ -71,6 +107,179 @@ export let DefaultEmailSettings: EmailSettings = {
 	port: 465,
 };
 
+/* SMS */
+export type SmsSettings = {
+	provider: "log" | "http";
+	url: Option<string>;
+	auth_header: Option<string>;
+	sender: Option<string>;
+};
+
+/* CAPTCHA */
+export type CaptchaSettings = {
+	enabled: boolean;
+	provider: "turnstile" | "hcaptcha" | "recaptcha";
+	site_key: string;
+	// never returned, an empty secret keeps the current one
+	secret?: string;
+};
+
+/* PASSWORDLESS */
+export type PasswordlessMode = "link" | "code";
+
+export type PasswordlessSettings = {
+	mode: PasswordlessMode;
+};
+
+/* EMAIL VERIFICATION */
+export type EmailVerificationPolicy = "off" | "block" | "mark";
+
+export type EmailVerificationSettings = {
+	policy: EmailVerificationPolicy;
+};
+
+/* ANONYMOUS */
+export type AnonymousSettings = {
+	ttl_days: number | null;
+};
+
+/* SESSIONS */
+export type SessionLimitMode = "evict" | "reject";
+
+export type SessionSettings = {
+	max_sessions: number | null;
+	limit_mode: SessionLimitMode;
+};
+
+export type ReauthenticationSettings = {
+	/** seconds since the last sign in, `null` disables re-authentication */
+	max_age: number | null;
+};
+
+/* ACCOUNT DELETION */
+export type AccountDeletionSettings = {
+	/** days a deleted user can be restored, `null` removes users right away */
+	grace_days: number | null;
+	send_email: boolean;
+};
+
+/* CORS */
+export type CorsSettings = {
+	origins: Array<string>;
+	methods: Array<string>;
+	headers: Array<string>;
+	max_age: number | null;
+};
+
+/* REDIRECT URLS */
+export type RedirectUrls = {
+	urls: Array<string>;
+};
+
+/* EMAIL DOMAINS */
+export type EmailDomains = {
+	allow: Array<string>;
+	deny: Array<string>;
+	block_disposable: boolean;
+};
+
+/* HOSTED PAGES */
+export type HostedPages = {
+	logo_url: string | null;
+	primary_color: string | null;
+	background_color: string | null;
+};
+
+/* LINKING */
+export type LinkingSettings = {
+	auto_link_verified_email: boolean;
+};
+
+/* ROLES */
+export type Permission = {
+	name: string;
+	description: Option<string>;
+};
+
+export type Role = {
+	id: Uuid;
+	name: string;
+	description: Option<string>;
+	permissions: Array<string>;
+	created_at: DateTime;
+};
+
+export type RoleData = {
+	name: string;
+	description: Option<string>;
+	permissions: Array<string>;
+};
+
+/* ADMINS */
+export enum AdminRole {
+	Viewer = "viewer",
+	Editor = "editor",
+	Owner = "owner",
+	SuperAdmin = "super_admin",
+}
+
+export type InviteAdmin = {
+	email: string;
+	// required for all roles but super_admin
+	project?: Option<Uuid>;
+	role: AdminRole;
+};
+
+export type AcceptAdminInvitation = {
+	id: Uuid;
+	token: string;
+	// creates the dashboard account if the email doesn't have one yet
+	password?: Option<string>;
+};
+
+/* SERVICE ACCOUNTS */
+export type ServiceAccount = {
+	id: Uuid;
+	name: string;
+	scopes: Array<string>;
+	created_at: DateTime;
+};
+
+export type NewServiceAccount = {
+	name: string;
+	scopes: Array<string>;
+};
+
+// the secret is only returned when it is created or rotated
+export type ClientCredentials = {
+	client_id: Uuid;
+	client_secret: string;
+};
+
+/* OAUTH CLIENTS */
+export type OAuthClient = {
+	id: Uuid;
+	name: string;
+	redirect_uris: Array<string>;
+	confidential: boolean;
+	created_at: DateTime;
+};
+
+export type OAuthClientData = {
+	name: string;
+	redirect_uris: Array<string>;
+};
+
+export type NewOAuthClient = OAuthClientData & {
+	// public clients, e.g. single page apps, don't get a secret
+	confidential: boolean;
+};
+
+export type OAuthClientCredentials = {
+	client_id: Uuid;
+	client_secret: Option<string>;
+};
+
 /* GOOGLE */
 export type GoogleConfig = {
 	client_id: string;
@@ -88,11 +297,19 @@ let defaultGoolgeConfig: GoogleConfig = {
 export enum Flags {
 	SignIn = "auth::signin",
 	SignUp = "auth::signup",
+	Anonymous = "auth::anonymous",
+	Organizations = "auth::organizations",
 	PasswordReset = "action::password_reset",
 	VerifyEmail = "action::verify_email",
 
 	EmailAndPassword = "method::email_password",
 	AuthenticationLink = "method::authentication_link",
+	Sms = "method::sms",
+
+	DPoP = "token::dpop",
+	CookieSession = "session::cookie",
+
+	HostedPages = "hosted::pages",
 
 	OAuthGoogle = "oauth::google",
 }
@@ -129,6 +346,12 @@ type PublicKey = {
 type Deps = {
 	accessToken: () => Promise<string>;
 	refreshToken: () => Promise<string>;
+	// headers for tokens that are bound to the session key (DPoP)
+	authHeaders?: (
+		token: string,
+		method: string,
+		url: string,
+	) => Promise<Record<string, string>>;
 	baseURL: string;
 	projectId: string;
 };
@@ -151,11 +374,23 @@ type Projects = Array<Project>;
 
 export type PartialUser = {
 	id: string;
-	email: string;
+	email: Option<string>;
+	phone: Option<string>;
+	providers: Array<string>;
 	created_at: string;
 };
 
 
+async function setAuthorization(deps: Deps, request: Request, token: string) {
+	let headers = (await deps.authHeaders?.(token, request.method, request.url)) ?? {
+		Authorization: `bearer ${token}`,
+	};
+
+	Object.entries(headers).forEach(([name, value]) => {
+		request.headers.set(name, value);
+	});
+}
+
 export default class AdminSDK {
 	private http: typeof Ky;
 
@@ -171,7 +406,7 @@ export default class AdminSDK {
 				beforeRequest: [
 					async (request) => {
 						let at = await deps.accessToken();
-						request.headers.set("Authorization", `bearer ${at}`);
+						await setAuthorization(deps, request, at);
 						request.headers.set("Vulpo-Project", deps.projectId);
 					},
 				],
@@ -179,7 +414,7 @@ export default class AdminSDK {
 					async ({ request, error }) => {
 						if (error instanceof HTTPError && error.response.status === 401) {
 							const token = await deps.refreshToken();
-							request.headers.set("Authorization", `bearer ${token}`);
+							await setAuthorization(deps, request, token);
 						}
 					},
 				],
@@ -202,6 +437,12 @@ export default class AdminSDK {
 		return this.http.post(url, { json: { project } });
 	};
 
+	// signs out all users and revokes their access tokens
+	revokeProjectTokens = (project: Uuid) => {
+		let url = "project/revoke_tokens";
+		return this.http.post(url, { json: { project } });
+	};
+
 	createProject = (project: NewProject) => {
 		let url = "admin/project/create";
 		return this.http.post(url, { json: project }).json<[string]>();
@@ -257,12 +498,36 @@ export default class AdminSDK {
 		return this.http.post("admin/create_user", { json: user }).json<User>();
 	};
 
+	inviteUser = (invite: InviteUser) => {
+		return this.http
+			.post("user/invite", { json: invite })
+			.json<{ id: Uuid; user_id: Uuid }>();
+	};
+
+	listUserInvitations = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project", projectId]]);
+		let url = `user/invitations?${params}`;
+		return this.http.get(url).json<Array<UserInvitation>>();
+	};
+
+	revokeUserInvitation = async (id: Uuid, projectId: Uuid) => {
+		let json = { id, project_id: projectId };
+		await this.http.post("user/invite/revoke", { json });
+		return { id, projectId };
+	};
+
 	deleteUser = async (userId: Uuid, projectId: Uuid) => {
 		let url = `user/admin/delete_account/${userId}`;
 		await this.http.post(url);
 		return { userId, projectId };
 	};
 
+	restoreUser = async (userId: Uuid, projectId: Uuid) => {
+		let url = `user/admin/restore_account/${userId}`;
+		await this.http.post(url);
+		return { userId, projectId };
+	};
+
 	disableUser = (userId: Uuid, projectId: Uuid, disabled: boolean) => {
 		let url = "user/disable";
 		let json = {
@@ -304,6 +569,171 @@ export default class AdminSDK {
 		return this.http.post(url, { json: settings });
 	};
 
+	getSmsSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/sms?${params}`;
+		return this.http.get(url).json<SmsSettings | null>();
+	};
+
+	setSmsSettings = (projectId: Uuid, settings: SmsSettings) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/sms?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
+	getCaptchaSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/captcha?${params}`;
+		return this.http.get(url).json<CaptchaSettings | null>();
+	};
+
+	setCaptchaSettings = (projectId: Uuid, settings: CaptchaSettings) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/captcha?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
+	getPasswordlessSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/passwordless?${params}`;
+		return this.http.get(url).json<PasswordlessSettings>();
+	};
+
+	setPasswordlessSettings = (projectId: Uuid, settings: PasswordlessSettings) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/passwordless?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
+	getEmailVerificationSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/email_verification?${params}`;
+		return this.http.get(url).json<EmailVerificationSettings>();
+	};
+
+	setEmailVerificationSettings = (
+		projectId: Uuid,
+		settings: EmailVerificationSettings,
+	) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/email_verification?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
+	getAnonymousSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/anonymous?${params}`;
+		return this.http.get(url).json<AnonymousSettings>();
+	};
+
+	setAnonymousSettings = (projectId: Uuid, settings: AnonymousSettings) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/anonymous?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
+	getSessionSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/sessions?${params}`;
+		return this.http.get(url).json<SessionSettings>();
+	};
+
+	setSessionSettings = (projectId: Uuid, settings: SessionSettings) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/sessions?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
+	getReauthenticationSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/reauthentication?${params}`;
+		return this.http.get(url).json<ReauthenticationSettings>();
+	};
+
+	setReauthenticationSettings = (
+		projectId: Uuid,
+		settings: ReauthenticationSettings,
+	) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/reauthentication?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
+	getAccountDeletionSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/account_deletion?${params}`;
+		return this.http.get(url).json<AccountDeletionSettings>();
+	};
+
+	setAccountDeletionSettings = (
+		projectId: Uuid,
+		settings: AccountDeletionSettings,
+	) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/account_deletion?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
+	getCorsSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/cors?${params}`;
+		return this.http.get(url).json<CorsSettings>();
+	};
+
+	setCorsSettings = (projectId: Uuid, settings: CorsSettings) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/cors?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
+	getRedirectUrls = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/redirect_urls?${params}`;
+		return this.http.get(url).json<RedirectUrls>();
+	};
+
+	setRedirectUrls = (projectId: Uuid, redirectUrls: RedirectUrls) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/redirect_urls?${params}`;
+		return this.http.post(url, { json: redirectUrls });
+	};
+
+	getEmailDomains = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/email_domains?${params}`;
+		return this.http.get(url).json<EmailDomains>();
+	};
+
+	setEmailDomains = (projectId: Uuid, emailDomains: EmailDomains) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/email_domains?${params}`;
+		return this.http.post(url, { json: emailDomains });
+	};
+
+	getHostedPages = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/hosted_pages?${params}`;
+		return this.http.get(url).json<HostedPages>();
+	};
+
+	setHostedPages = (projectId: Uuid, hostedPages: HostedPages) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/hosted_pages?${params}`;
+		return this.http.post(url, { json: hostedPages });
+	};
+
+	getLinkingSettings = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/linking?${params}`;
+		return this.http.get(url).json<LinkingSettings>();
+	};
+
+	setLinkingSettings = (projectId: Uuid, settings: LinkingSettings) => {
+		let params = new URLSearchParams([["project_id", projectId]]);
+		let url = `settings/linking?${params}`;
+		return this.http.post(url, { json: settings });
+	};
+
 	getFlags = (projectId: Uuid) => {
 		let params = new URLSearchParams([["project", projectId]]);
 		let url = `project/flags?${params}`;
@@ -343,5 +773,139 @@ export default class AdminSDK {
 		let url = `keys/public?${params}`;
 		return this.http.get(url).json<Array<PublicKey>>();
 	};
+
+	getPermissions = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project", projectId]]);
+		let url = `role/permission/list?${params}`;
+		return this.http.get(url).json<{ items: Array<Permission> }>();
+	};
+
+	createPermission = (projectId: Uuid, permission: Permission) => {
+		let json = { ...permission, project: projectId };
+		return this.http.post("role/permission/create", { json });
+	};
+
+	deletePermission = (projectId: Uuid, name: string) => {
+		let json = { name, project: projectId };
+		return this.http.post("role/permission/delete", { json });
+	};
+
+	getRoles = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project", projectId]]);
+		let url = `role/list?${params}`;
+		return this.http.get(url).json<{ items: Array<Role> }>();
+	};
+
+	createRole = (projectId: Uuid, role: RoleData) => {
+		let json = { ...role, project: projectId };
+		return this.http.post("role/create", { json }).json<{ id: Uuid }>();
+	};
+
+	updateRole = (roleId: Uuid, projectId: Uuid, role: RoleData) => {
+		let json = { ...role, id: roleId, project: projectId };
+		return this.http.post("role/update", { json });
+	};
+
+	deleteRole = (roleId: Uuid, projectId: Uuid) => {
+		let json = { id: roleId, project: projectId };
+		return this.http.post("role/delete", { json });
+	};
+
+	getUserRoles = (userId: Uuid, projectId: Uuid) => {
+		let params = new URLSearchParams([
+			["user", userId],
+			["project", projectId],
+		]);
+
+		let url = `role/user?${params}`;
+		return this.http.get(url).json<{ items: Array<Role> }>();
+	};
+
+	getUserSessions = (userId: Uuid, projectId: Uuid) => {
+		let params = new URLSearchParams([
+			["user", userId],
+			["project", projectId],
+		]);
+
+		let url = `user/admin/sessions?${params}`;
+		return this.http.get(url).json<Array<UserSession>>();
+	};
+
+	revokeUserSession = (sessionId: Uuid, userId: Uuid, projectId: Uuid) => {
+		let json = { id: sessionId, user: userId, project: projectId };
+		return this.http.post("user/admin/sessions/revoke", { json });
+	};
+
+	setUserRoles = (userId: Uuid, projectId: Uuid, roles: Array<Uuid>) => {
+		let json = { user: userId, project: projectId, roles };
+		return this.http.post("role/user/set", { json });
+	};
+
+	inviteAdmin = (invite: InviteAdmin) => {
+		return this.http.post("admin/invite", { json: invite }).json<{ id: Uuid }>();
+	};
+
+	getServiceAccounts = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project", projectId]]);
+		let url = `service_account/list?${params}`;
+		return this.http.get(url).json<{ items: Array<ServiceAccount> }>();
+	};
+
+	createServiceAccount = (projectId: Uuid, account: NewServiceAccount) => {
+		let json = { ...account, project: projectId };
+		return this.http
+			.post("service_account/create", { json })
+			.json<ClientCredentials>();
+	};
+
+	rotateServiceAccountSecret = (id: Uuid, projectId: Uuid) => {
+		let json = { id, project: projectId };
+		return this.http
+			.post("service_account/rotate_secret", { json })
+			.json<ClientCredentials>();
+	};
+
+	deleteServiceAccount = (id: Uuid, projectId: Uuid) => {
+		let json = { id, project: projectId };
+		return this.http.post("service_account/delete", { json });
+	};
+
+	getOAuthClients = (projectId: Uuid) => {
+		let params = new URLSearchParams([["project", projectId]]);
+		let url = `oauth/client/list?${params}`;
+		return this.http.get(url).json<{ items: Array<OAuthClient> }>();
+	};
+
+	createOAuthClient = (projectId: Uuid, client: NewOAuthClient) => {
+		let json = { ...client, project: projectId };
+		return this.http
+			.post("oauth/client/create", { json })
+			.json<OAuthClientCredentials>();
+	};
+
+	updateOAuthClient = (
+		id: Uuid,
+		projectId: Uuid,
+		client: OAuthClientData,
+	) => {
+		let json = { ...client, id, project: projectId };
+		return this.http.post("oauth/client/update", { json });
+	};
+
+	deleteOAuthClient = (id: Uuid, projectId: Uuid) => {
+		let json = { id, project: projectId };
+		return this.http.post("oauth/client/delete", { json });
+	};
+}
+
+// invitations are accepted before the admin is signed in
+export function acceptAdminInvitation(
+	baseURL: string,
+	invitation: AcceptAdminInvitation,
+) {
+	return Ky.post("admin/invite/accept", {
+		prefixUrl: baseURL,
+		json: invitation,
+	});
 }
 
@@ -6,6 +6,7 @@ import {
 	SetPasswordPayload,
 	Url,
 	Flag,
+	Captcha,
 	SessionInfo,
 	EmailPasswordPayload,
 	PasswordResetPayload,
@@ -14,7 +15,12 @@ import {
     PasswordlessResponse,
     ConfirmPasswordlessPayload,
     VerifyPasswordlessPayload,
+    VerifyPasswordlessCodePayload,
+    PasswordlessMode,
+    SignInAnonymouslyPayload,
+    LinkPasswordPayload,
     VerifyEmailPayload,
+    ResendEmailVerificationPayload,
     OAuthAuthorizeUrlPayload,
     OAuthAuthorizeUrlResponse,
     GenerateApiKey,
@@ -25,6 +31,20 @@ import {
     RejectUpdateEmailPayload,
     UserSetPasswordPayload,
     DeleteApiKeyPayload,
+    Organization,
+    Organizations,
+    OrganizationRole,
+    InviteMemberPayload,
+    AcceptInvitationPayload,
+    AcceptUserInvitationPayload,
+    SwitchOrganizationPayload,
+    ReauthenticatePayload,
+    ReauthenticationCodePayload,
+    AuthorizationRequest,
+    ConfirmAuthorizationPayload,
+    AuthorizationRedirect,
+    UserSession,
+    RevokeSessionPayload,
 } from './types'
 
 import { SessionService } from './session'
@@ -51,36 +71,76 @@ export type ClientDep = {
 
 export type RequestConfig = Partial<Request>
 
+/**
+ * `redirectUrl` replaces the link that is sent by email or the OAuth
+ * redirect, the URL has to be on the project's allow-list. `captchaToken` is
+ * the response of the project's CAPTCHA widget, see {@link AuthClient.captcha}
+*/
+export type RedirectConfig = RequestConfig & { redirectUrl?: string, captchaToken?: string }
+
+function splitRedirect(config?: RedirectConfig): [string | undefined, RequestConfig | undefined, string | undefined] {
+	if (config === undefined) {
+		return [undefined, undefined, undefined]
+	}
+
+	let { redirectUrl, captchaToken, ...requestConfig } = config
+	return [redirectUrl, requestConfig, captchaToken]
+}
+
 export interface IAuthClient {
 	setProject(id: string): void;
 	signIn(email: string, password: string, config?: RequestConfig): Promise<User>;
-	signUp(email: string, password: string, config?: RequestConfig): Promise<User>;
+	signUp(email: string, password: string, config?: RedirectConfig): Promise<User>;
+	signInAnonymously(config?: RequestConfig): Promise<User>;
+	linkPassword(email: string, password: string, config?: RedirectConfig): Promise<void>;
+	linkPasswordless(email: string, config?: RedirectConfig): Promise<{ id: string; session: string; mode: PasswordlessMode }>;
+	oAuthLinkUrl(provider: 'google', config?: RedirectConfig): Promise<string>;
+	addPassword(password: string, config?: RequestConfig): Promise<void>;
+	unlinkProvider(provider: 'password' | 'google', config?: RequestConfig): Promise<void>;
 	signOut(sessionId?: string, config?: RequestConfig): Promise<unknown>;
 	signOutAll(sessionId?: string, config?: RequestConfig): Promise<unknown>;
 	getToken(sessionId?: string): Promise<string>;
 	forceToken(sessionId?: string): Promise<string>;
-	resetPassword(email: string, config?: RequestConfig): Promise<void>;
+	reauthenticate(password: string, config?: RequestConfig): Promise<void>;
+	requestReauthenticationCode(config?: RequestConfig): Promise<string>;
+	reauthenticateWithCode(id: string, code: string, config?: RequestConfig): Promise<void>;
+	resetPassword(email: string, config?: RedirectConfig): Promise<void>;
 	setResetPassword(body: SetPasswordPayload, config?: RequestConfig): Promise<void>;
 	setPassword(password: string, config?: RequestConfig): Promise<void>;
 	verifyToken(id: string, token: string, config?: RequestConfig): Promise<void>;
-	passwordless(email: string, config?: RequestConfig): Promise<{ id: string; session: string }>;
+	passwordless(email: string, config?: RedirectConfig): Promise<{ id: string; session: string; mode: PasswordlessMode }>;
 	confirmPasswordless(id: string, token: string, config?: RequestConfig): Promise<void>;
 	verifyEmail(id: string, token: string, config?: RequestConfig): Promise<void>;
+	acceptUserInvitation(id: string, token: string, password?: string, config?: RequestConfig): Promise<User>;
 	verifyPasswordless(id: string, session: string, config?: RequestConfig): Promise<User | null>;
+	verifyPasswordlessCode(id: string, session: string, code: string, config?: RequestConfig): Promise<User | null>;
 	authStateChange(cb: AuthCallback): Unsubscribe;
 	activate(userId: string): void;
 	readonly active: SessionInfo | null;
 	withToken(fn: (token: string) => Promise<Response>, session?: string): Promise<Response>;
+	authHeaders(token: string, method: string, url: string, sessionId?: string): Promise<Record<string, string>>;
 	flags(config?: RequestConfig): Promise<Array<Flag>>;
+	captcha(config?: RequestConfig): Promise<Captcha | null>;
 	getUser(): User | null;
-	oAuthGetAuthorizeUrl(provider: 'google', config?: RequestConfig): Promise<string>;
+	oAuthGetAuthorizeUrl(provider: 'google', config?: RedirectConfig): Promise<string>;
 	oAuthConfirm(csrf_token: string, code: string, config?: RequestConfig): Promise<[User | null, string]>;
-	updateEmail(email: string, config?: RequestConfig): Promise<void>;
+	updateEmail(email: string, config?: RedirectConfig): Promise<void>;
 	confirmUpdateEmail(id: string, token: string, config?: RequestConfig): Promise<void>;
 	rejectUpdateEmail(id: string, token: string, config?: RequestConfig): Promise<void>;
 	generateApiKey(payload: GenerateApiKey, config?: RequestConfig): Promise<GenerateApiKeyResponse>;
 	listApiKeys(config?: Partial<Request>): Promise<ApiKeys>;
 	deleteApiKey(id: string, config?: Partial<Request>): Promise<void>
+	listSessions(config?: RequestConfig): Promise<Array<UserSession>>;
+	revokeSession(id: string, config?: RequestConfig): Promise<void>;
+	createOrganization(name: string, config?: RequestConfig): Promise<Organization>;
+	listOrganizations(config?: RequestConfig): Promise<Organizations>;
+	inviteToOrganization(organizationId: string, email: string, role?: OrganizationRole, config?: RequestConfig): Promise<string>;
+	acceptInvitation(id: string, token: string, config?: RequestConfig): Promise<void>;
+	switchOrganization(organizationId: string | null, config?: RequestConfig): Promise<string>;
+	leaveOrganization(organizationId: string, config?: RequestConfig): Promise<void>;
+	getAuthorizationRequest(id: string, config?: RequestConfig): Promise<AuthorizationRequest>;
+	confirmAuthorization(id: string, config?: RequestConfig): Promise<string>;
+	denyAuthorization(id: string, config?: RequestConfig): Promise<string>;
 }
 
 export class AuthClient implements IAuthClient {
@@ -106,8 +166,9 @@ export class AuthClient implements IAuthClient {
 		url: Url.SignIn | Url.SignUp,
 		email: string,
 		password: string,
-		config?: RequestConfig,
+		redirectConfig?: RedirectConfig,
 	) {
+		let [redirect_url, config, captcha_token] = splitRedirect(redirectConfig)
 		let session = await this.sessionService.create()
 		let public_key = await getPublicKey(session)
 
@@ -117,6 +178,8 @@ export class AuthClient implements IAuthClient {
 			public_key, 
 			session: session.id,
 			device_languages: getLanguages([...navigator.languages]),
+			redirect_url,
+			captcha_token,
 		}
 
 		let onError = async (err: ApiError) => {
@@ -221,7 +284,7 @@ export class AuthClient implements IAuthClient {
 	 * @param email - The user email address
 	 * @param password - The user password
 	*/
-	async signUp(email: string, password: string, config?: RequestConfig): Promise<User> {
+	async signUp(email: string, password: string, config?: RedirectConfig): Promise<User> {
 		return await this.emailPasswordAuth(
 			Url.SignUp,
 			email,
@@ -230,6 +293,136 @@ export class AuthClient implements IAuthClient {
 		)
 	}
 
+	/**
+	 * Create a new session for an anonymous (guest) user, the access token contains
+	 * the `anonymous` trait. The guest can later be linked to an email and password
+	 * ({@link AuthClient.linkPassword}), passwordless ({@link AuthClient.linkPasswordless})
+	 * or Google ({@link AuthClient.oAuthLinkUrl}) while keeping the user id and data
+	*/
+	async signInAnonymously(config?: RequestConfig): Promise<User> {
+		let session = await this.sessionService.create()
+		let public_key = await getPublicKey(session)
+
+		let payload: SignInAnonymouslyPayload = {
+			public_key,
+			session: session.id,
+			device_languages: getLanguages([...navigator.languages]),
+		}
+
+		let onError = async (err: ApiError) => {
+			await this.sessionService.remove(session.id)
+			return Promise.reject(err)
+		}
+
+		let { data } = await this.httpService
+			.post<SessionResponse>(Url.Anonymous, payload, config)
+			.catch(onError)
+
+		let { user } = await this.sessionService
+			.fromResponse(data)
+			.catch(onError)
+
+		this.sessionService.activate(data.session)
+		this.tokens.fromResponse(data)
+		return user!
+	}
+
+	/**
+	 * Link the current anonymous user to an email and password
+	*/
+	async linkPassword(email: string, password: string, redirectConfig?: RedirectConfig): Promise<void> {
+		let [redirect_url, config] = splitRedirect(redirectConfig)
+		let currentSession = this.sessionService.current()
+
+		if (!currentSession) {
+			return
+		}
+
+		await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.AnonymousLinkPassword, currentSession!.id)
+			let payload: LinkPasswordPayload = { email, password, redirect_url }
+			return this.httpService.post(Url.AnonymousLinkPassword, payload, { ...config, headers })
+		}, currentSession.id)
+
+		// the new token no longer contains the anonymous trait
+		let accessToken = await this.forceToken(currentSession.id)
+
+		await this.sessionService.fromResponse({
+			session: currentSession.id,
+			expire_at: currentSession.expire_at!,
+			access_token: accessToken
+		})
+
+		this.sessionService.activate(currentSession.id)
+	}
+
+	/**
+	 * Add a password to the current user, e.g. a user that signed up with
+	 * an authentication link or Google
+	*/
+	async addPassword(password: string, config?: RequestConfig): Promise<void> {
+		let currentSession = this.sessionService.current()
+
+		if (!currentSession) {
+			return
+		}
+
+		await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.UserLinkPassword, currentSession!.id)
+			return this.httpService.post(Url.UserLinkPassword, { password }, { ...config, headers })
+		}, currentSession.id)
+
+		await this.reloadUser(currentSession)
+	}
+
+	/**
+	 * Remove a linked provider from the current user, the last provider
+	 * can not be removed
+	*/
+	async unlinkProvider(provider: 'password' | 'google', config?: RequestConfig): Promise<void> {
+		let currentSession = this.sessionService.current()
+
+		if (!currentSession) {
+			return
+		}
+
+		await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.UserUnlink, currentSession!.id)
+			return this.httpService.post(Url.UserUnlink, { provider }, { ...config, headers })
+		}, currentSession.id)
+
+		await this.reloadUser(currentSession)
+	}
+
+	private async reloadUser(currentSession: SessionInfo) {
+		let accessToken = await this.getToken(currentSession.id)
+
+		await this.sessionService.fromResponse({
+			session: currentSession.id,
+			expire_at: currentSession.expire_at!,
+			access_token: accessToken
+		})
+	}
+
+	/**
+	 * Link the current anonymous user to an email address, the sign in is completed
+	 * with {@link AuthClient.verifyPasswordless} or {@link AuthClient.verifyPasswordlessCode}
+	*/
+	async linkPasswordless(email: string, config?: RedirectConfig): Promise<{ id: string; session: string; mode: PasswordlessMode }> {
+		let accessToken = await this.getToken()
+		let headers = new Headers(config?.headers)
+		await this.authorize(headers, accessToken, 'POST', Url.AnonymousLinkPasswordless)
+
+		return this.requestPasswordless(
+			Url.AnonymousLinkPasswordless,
+			email,
+			{ ...config, headers },
+		)
+	}
+
 	private async removeSession(
 		url: Url.SignOut | Url.SignOutAll,
 		sessionId?: string,
@@ -329,6 +522,56 @@ export class AuthClient implements IAuthClient {
 		return await this.getAccessToken(this.tokens.forceToken, sessionId)
 	}
 
+	private async stepUp(credentials: ReauthenticatePayload, config?: RequestConfig): Promise<void> {
+		let session = this.sessionService.active?.id
+
+		if (!session) {
+			throw new SessionNotFoundError()
+		}
+
+		let token = await this.sessionService.generateAccessToken(session, ratPayload())
+		let payload: ReauthenticatePayload = { ...credentials, token: token ?? undefined }
+		let url = Url.Reauthenticate.replace(':session', session)
+		let { data } = await this.httpService.post<SessionResponse>(url, payload, config)
+		this.tokens.fromResponse(data)
+	}
+
+	/**
+	 * Re-authenticate the current session with the user's password. Changing the
+	 * email address, setting the password, generating API keys and deleting the
+	 * account fail with `session/reauthentication_required` when the user didn't
+	 * authenticate recently.
+	*/
+	async reauthenticate(password: string, config?: RequestConfig): Promise<void> {
+		await this.stepUp({ method: 'password', password }, config)
+	}
+
+	/**
+	 * Send a code to re-authenticate the current session to the user's email
+	 * address, returns the id for {@link AuthClient.reauthenticateWithCode}
+	*/
+	async requestReauthenticationCode(config?: RequestConfig): Promise<string> {
+		let session = this.sessionService.active?.id
+
+		if (!session) {
+			throw new SessionNotFoundError()
+		}
+
+		let token = await this.sessionService.generateAccessToken(session, ratPayload())
+		let payload: ReauthenticationCodePayload = { token: token ?? undefined }
+		let url = Url.ReauthenticationCode.replace(':session', session)
+		let { data } = await this.httpService.post<{ id: string }>(url, payload, config)
+		return data.id
+	}
+
+	/**
+	 * Re-authenticate the current session with the code of
+	 * {@link AuthClient.requestReauthenticationCode}
+	*/
+	async reauthenticateWithCode(id: string, code: string, config?: RequestConfig): Promise<void> {
+		await this.stepUp({ method: 'code', id, code }, config)
+	}
+
 	/**
 	 * Request to reset the current user password, if the user exists a rest email
 	 * will be send to the given email address, no email will be send if the user
@@ -336,8 +579,9 @@ export class AuthClient implements IAuthClient {
 	 * 
 	 * @param email - the user email address
 	*/
-	async resetPassword(email: string, config?: RequestConfig): Promise<void> {
-		let payload: PasswordResetPayload = { email }
+	async resetPassword(email: string, redirectConfig?: RedirectConfig): Promise<void> {
+		let [redirect_url, config, captcha_token] = splitRedirect(redirectConfig)
+		let payload: PasswordResetPayload = { email, redirect_url, captcha_token }
 		await this.httpService
 			.post(Url.RequestPasswordReset, payload, config)
 	}
@@ -376,7 +620,7 @@ export class AuthClient implements IAuthClient {
 		let accessToken = await this.getToken(currentSession.id)
 
 		let headers = config?.headers ?? new Headers()
-		headers.set('Authorization', `Bearer ${accessToken}`)
+		await this.authorize(headers, accessToken, 'POST', Url.UserSetPassword, currentSession.id)
 
 		let payload: UserSetPasswordPayload = { password }
 
@@ -417,9 +661,19 @@ export class AuthClient implements IAuthClient {
 
 	/**
 	 * Create new session using an authentication link, if the user does not exits, a new
-	 * user will be created
+	 * user will be created. Depending on the project settings, the email contains either a
+	 * link or a one-time code, see {@link PasswordlessMode}
 	*/
-	async passwordless(email: string, config?: RequestConfig): Promise<{ id: string; session: string }> {
+	async passwordless(email: string, config?: RedirectConfig): Promise<{ id: string; session: string; mode: PasswordlessMode }> {
+		return this.requestPasswordless(Url.Passwordless, email, config)
+	}
+
+	private async requestPasswordless(
+		url: Url.Passwordless | Url.AnonymousLinkPasswordless,
+		email: string,
+		redirectConfig?: RedirectConfig,
+	): Promise<{ id: string; session: string; mode: PasswordlessMode }> {
+		let [redirect_url, config, captcha_token] = splitRedirect(redirectConfig)
 		let session = await this.sessionService.create()
 		let public_key = await getPublicKey(session)
 
@@ -428,16 +682,18 @@ export class AuthClient implements IAuthClient {
 			public_key, 
 			session: session.id,
 			device_languages: getLanguages([...navigator.languages]),
+			redirect_url,
+			captcha_token,
 		}
 
 		let { data } = await this.httpService
-			.post<PasswordlessResponse>(Url.Passwordless, payload, config)
+			.post<PasswordlessResponse>(url, payload, config)
 			.catch(async err => {
 				await this.sessionService.remove(session.id)
 				return Promise.reject(err)
 			})
 
-		return { id: data.id, session: session.id }
+		return { id: data.id, session: session.id, mode: data.mode }
 	}
 
 	/**
@@ -479,6 +735,66 @@ export class AuthClient implements IAuthClient {
 			.post(Url.UserVerifyEmail, payload, config)
 	}
 
+	/**
+	 * Send a new verification email, nothing is sent when the user does
+	 * not exist or already verified the email address. Requests are throttled,
+	 * no email is sent when the last one was sent less than a minute ago
+	 * 
+	 * @param email - the user email address
+	*/
+	async resendEmailVerification(email: string, redirectConfig?: RedirectConfig): Promise<void> {
+		let [redirect_url, config] = splitRedirect(redirectConfig)
+		let payload: ResendEmailVerificationPayload = { email, redirect_url }
+		await this.httpService
+			.post(Url.UserResendEmailVerification, payload, config)
+	}
+
+	/**
+	 * Accept the invitation an admin sent to the user and create a new session,
+	 * the id and token are part of the link in the invitation email. Without a
+	 * password the user signs in with authentication links
+	 * 
+	 * ```js
+	 *   let params = new URLSearchParams(window.location.search)
+	 *   
+	 *   let user = await auth.acceptUserInvitation(
+	 *       params.get("id"),
+	 *       params.get("token"),
+	 *       password,
+	 *   )
+	 * ```
+	*/
+	async acceptUserInvitation(id: string, token: string, password?: string, config?: RequestConfig): Promise<User> {
+		let session = await this.sessionService.create()
+		let public_key = await getPublicKey(session)
+
+		let payload: AcceptUserInvitationPayload = {
+			id,
+			token,
+			password,
+			public_key,
+			session: session.id,
+			device_languages: getLanguages([...navigator.languages]),
+		}
+
+		let onError = async (err: ApiError) => {
+			await this.sessionService.remove(session.id)
+			return Promise.reject(err)
+		}
+
+		let { data } = await this.httpService
+			.post<SessionResponse>(Url.UserAcceptInvitation, payload, config)
+			.catch(onError)
+
+		let { user } = await this.sessionService
+			.fromResponse(data)
+			.catch(onError)
+
+		this.sessionService.activate(data.session)
+		this.tokens.fromResponse(data)
+		return user!
+	}
+
 	/**
 	 * This method is used in combination witht the {@link AuthClient.passwordless} method, it polls the
 	 * server for update and resolves the promise once the authentication link has been confirmed
@@ -534,6 +850,41 @@ export class AuthClient implements IAuthClient {
 		})
 	}
 
+	/**
+	 * This method is used in combination with the {@link AuthClient.passwordless} method when the
+	 * project uses {@link PasswordlessMode.Code}, the code from the email is entered on the device
+	 * that requested it
+	 * 
+	 * ```js
+	 *   let { id, session } = await auth.passwordless("email@vulpo.dev")
+	 *   let user = await auth.verifyPasswordlessCode(id, session, "12345678")
+	 * ```
+	*/
+	async verifyPasswordlessCode(id: string, session: string, code: string, config?: RequestConfig): Promise<User | null> {
+		let token = await this.sessionService.generateAccessToken(session, ratPayload())
+
+		if (!token) {
+			return null
+		}
+
+		let payload: VerifyPasswordlessCodePayload = {
+			id,
+			code,
+			token,
+			session,
+			device_languages: getLanguages([...navigator.languages]),
+		}
+
+		let { data } = await this.httpService
+			.post<SessionResponse>(Url.PasswordlessVerifyCode, payload, config)
+
+		this.tokens.fromResponse(data)
+		let { user } = await this.sessionService.fromResponse(data)
+
+		this.sessionService.activate(data.session)
+		return user
+	}
+
 	/**
 	 * The callback will be called when the session information changes
 	 * 
@@ -567,9 +918,42 @@ export class AuthClient implements IAuthClient {
 		return this.sessionService.active
 	}
 
+	/**
+	 * Headers to authenticate a request with an access token. When the project binds tokens
+	 * to the session key (DPoP), the token is sent with the `DPoP` scheme and a proof that is
+	 * only valid for the given method and url.
+	 * 
+	 * ```js
+	 *  let res = await auth.withToken(async token => {
+	 *      let headers = await auth.authHeaders(token, 'GET', 'https://api.your.app/posts')
+	 *      return fetch('https://api.your.app/posts', { headers })
+	 *  })
+	 * ```
+	 * 
+	 * @param sessionId when sessionId is undefined, the currently active session will be used
+	*/
+	async authHeaders(token: string, method: string, url: string, sessionId?: string): Promise<Record<string, string>> {
+		let session = sessionId ?? this.sessionService.active?.id
+
+		if (!session) {
+			return { 'Authorization': `Bearer ${token}` }
+		}
+
+		return this.sessionService.authHeaders(session, token, method, url)
+	}
+
+	private async authorize(headers: Headers, token: string, method: string, path: string, sessionId?: string) {
+		let url = this.httpService.url?.(path) ?? path
+		let authHeaders = await this.authHeaders(token, method, url, sessionId)
+
+		Object.entries(authHeaders).forEach(([name, value]) => {
+			headers.set(name, value)
+		})
+	}
+
 	/**
 	 * `withToken` can be used to handle authenticated http calls, the callback will receive the
-	 * current access token.
+	 * current access token, use {@link AuthClient.authHeaders} when tokens are bound to the session key.
 	 * When the request returns a `401`, a new access token will be requested and the request will
 	 * be retried once.
 	 * 
@@ -619,6 +1003,25 @@ export class AuthClient implements IAuthClient {
 			.then(res => res.data.items)
 	}
 
+	/**
+	 * get the CAPTCHA provider and site key of the project, `null` when the
+	 * project doesn't require a CAPTCHA. The response of the widget is passed
+	 * as `captchaToken` to {@link AuthClient.signUp}, {@link AuthClient.passwordless}
+	 * and {@link AuthClient.resetPassword}
+	 * 
+	 * ```js
+	 *   let captcha = await auth.captcha()
+	 *   // render the provider's widget with captcha.site_key
+	 *   await auth.signUp(email, password, { captchaToken })
+	 * ```
+	*/
+	async captcha(config?: RequestConfig): Promise<Captcha | null> {
+		let url = Url.Captcha.replace(':projectId', this.projectId)
+		return this.httpService
+			.get<Captcha | null>(url, config)
+			.then(res => res.data)
+	}
+
 	/**
 	 * get the currently active user
 	*/
@@ -630,11 +1033,13 @@ export class AuthClient implements IAuthClient {
 	/**
 	 * get the authorization url for a given OAuth provider
 	*/
-	async oAuthGetAuthorizeUrl(provider: 'google', config?: RequestConfig): Promise<string> {
+	async oAuthGetAuthorizeUrl(provider: 'google', redirectConfig?: RedirectConfig): Promise<string> {
+		let [redirect_url, config] = splitRedirect(redirectConfig)
 		let request_id = uuid()
 
 		let payload: OAuthAuthorizeUrlPayload = {
-			request_id
+			request_id,
+			redirect_url,
 		}
 
 		OAuthState.insert(provider, request_id)
@@ -644,6 +1049,31 @@ export class AuthClient implements IAuthClient {
 			.then(res => res.url)
 	}
 
+	/**
+	 * Same as {@link AuthClient.oAuthGetAuthorizeUrl}, but links the provider to the
+	 * current user, anonymous users are upgraded. The callback is handled by
+	 * {@link AuthClient.oAuthConfirm}
+	*/
+	async oAuthLinkUrl(provider: 'google', redirectConfig?: RedirectConfig): Promise<string> {
+		let [redirect_url, config] = splitRedirect(redirectConfig)
+		let request_id = uuid()
+
+		let payload: OAuthAuthorizeUrlPayload = {
+			request_id,
+			redirect_url,
+		}
+
+		let accessToken = await this.getToken()
+		let headers = new Headers(config?.headers)
+		await this.authorize(headers, accessToken, 'POST', `/user/link/${provider}/authorize_url`)
+
+		OAuthState.insert(provider, request_id)
+
+		return this.httpService
+			.post<OAuthAuthorizeUrlResponse>(`/user/link/${provider}/authorize_url`, payload, { ...config, headers })
+			.then(res => res.url)
+	}
+
 	/**
 	 * handle the OAuth callback and sign in the user if the
 	 * code and csrf_token are valid
@@ -698,11 +1128,12 @@ export class AuthClient implements IAuthClient {
 	/*
 	 * start the update email flow
 	*/
-	async updateEmail(new_email: string, config?: Partial<Request> | undefined): Promise<void> {
-	    await this.withToken(token => {
+	async updateEmail(new_email: string, redirectConfig?: RedirectConfig): Promise<void> {
+	    let [redirect_url, config] = splitRedirect(redirectConfig)
+	    await this.withToken(async token => {
 	    	let headers = new Headers(config?.headers)
-	    	headers.append('Authorization', `Bearer ${token}`)
-	    	let payload: UpdateEmailPayload = { new_email }
+	    	await this.authorize(headers, token, 'POST', Url.UpdateEmail)
+	    	let payload: UpdateEmailPayload = { new_email, redirect_url }
 	    	return this.httpService.post(Url.UpdateEmail, payload, { ...config, headers })
 	    })
 	}
@@ -730,9 +1161,9 @@ export class AuthClient implements IAuthClient {
 	 * generate a new API key
 	*/
 	async generateApiKey(payload: GenerateApiKey = {}, config?: RequestConfig): Promise<GenerateApiKeyResponse> {
-		let { data } = await this.withToken(token => {
+		let { data } = await this.withToken(async token => {
 	    	let headers = new Headers(config?.headers)
-	    	headers.append('Authorization', `Bearer ${token}`)
+	    	await this.authorize(headers, token, 'POST', Url.GenerateApiKey)
 	    	return this.httpService
 	    		.post<GenerateApiKeyResponse>(Url.GenerateApiKey, payload, { ...config, headers })
 	    })
@@ -745,9 +1176,9 @@ export class AuthClient implements IAuthClient {
 	 * list all API keys for a user
 	*/
 	async listApiKeys(config?: Partial<Request> | undefined): Promise<ApiKeys> {
-	    let res = await this.withToken(token => {
+	    let res = await this.withToken(async token => {
 	    	let headers = new Headers(config?.headers)
-	    	headers.append('Authorization', `Bearer ${token}`)
+	    	await this.authorize(headers, token, 'GET', Url.ListApiKeys)
 	    	return this.httpService.get<ApiKeys>(Url.ListApiKeys, { ...config, headers })
 	    })
 
@@ -759,11 +1190,191 @@ export class AuthClient implements IAuthClient {
 	 * delete API key
 	*/
 	async deleteApiKey(id: string, config?: Partial<Request> | undefined): Promise<void> {
-	    await this.withToken(token => {
+	    await this.withToken(async token => {
 	    	let headers = new Headers(config?.headers)
-	    	headers.append('Authorization', `Bearer ${token}`)
+	    	await this.authorize(headers, token, 'POST', Url.DeleteApiKey)
 	    	let payload: DeleteApiKeyPayload = { id }
 	    	return this.httpService.post(Url.DeleteApiKey, payload, { ...config, headers })
 	    })
 	}
+
+
+	/*
+	 * list the devices the current user is signed in with
+	*/
+	async listSessions(config?: RequestConfig): Promise<Array<UserSession>> {
+		let { data } = await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'GET', Url.UserSessions)
+			return this.httpService.get<Array<UserSession>>(Url.UserSessions, { ...config, headers })
+		})
+
+		return data
+	}
+
+
+	/*
+	 * sign out one of the user's devices, the device's access token
+	 * stays valid until it expires
+	*/
+	async revokeSession(id: string, config?: RequestConfig): Promise<void> {
+		await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.RevokeUserSession)
+			let payload: RevokeSessionPayload = { id }
+			return this.httpService.post(Url.RevokeUserSession, payload, { ...config, headers })
+		})
+	}
+
+	/*
+	 * create an organization, the current user becomes its owner
+	*/
+	async createOrganization(name: string, config?: RequestConfig): Promise<Organization> {
+		let { data } = await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.Organization)
+			return this.httpService.post<Organization>(Url.Organization, { name }, { ...config, headers })
+		})
+
+		return data
+	}
+
+
+	/*
+	 * list all organizations the current user is a member of
+	*/
+	async listOrganizations(config?: RequestConfig): Promise<Organizations> {
+		let { data } = await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'GET', Url.OrganizationList)
+			return this.httpService.get<Organizations>(Url.OrganizationList, { ...config, headers })
+		})
+
+		return data
+	}
+
+
+	/*
+	 * invite an email address to an organization, returns the invitation id
+	*/
+	async inviteToOrganization(
+		organizationId: string,
+		email: string,
+		role: OrganizationRole = 'member',
+		config?: RequestConfig
+	): Promise<string> {
+		let { data } = await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.OrganizationInvite)
+			let payload: InviteMemberPayload = { organization_id: organizationId, email, role }
+			return this.httpService.post<{ id: string }>(Url.OrganizationInvite, payload, { ...config, headers })
+		})
+
+		return data.id
+	}
+
+
+	/*
+	 * accept an invitation with the id and token from the invitation email
+	*/
+	async acceptInvitation(id: string, token: string, config?: RequestConfig): Promise<void> {
+		await this.withToken(async accessToken => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, accessToken, 'POST', Url.OrganizationAccept)
+			let payload: AcceptInvitationPayload = { id, token }
+			return this.httpService.post(Url.OrganizationAccept, payload, { ...config, headers })
+		})
+	}
+
+
+	/*
+	 * set the active organization of the current session, `null` clears it.
+	 * Returns the new access token that contains the organization claims
+	*/
+	async switchOrganization(organizationId: string | null, config?: RequestConfig): Promise<string> {
+		let currentSession = this.sessionService.current()
+
+		if (!currentSession) {
+			throw new SessionNotFoundError()
+		}
+
+		let { data } = await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.OrganizationSwitch, currentSession!.id)
+			let payload: SwitchOrganizationPayload = {
+				session: currentSession!.id,
+				organization_id: organizationId,
+			}
+			return this.httpService.post<SessionResponse>(Url.OrganizationSwitch, payload, { ...config, headers })
+		}, currentSession.id)
+
+		this.tokens.fromResponse(data)
+		return data.access_token
+	}
+
+
+	/*
+	 * leave an organization, the last owner can not leave as long as
+	 * there are other members
+	*/
+	async leaveOrganization(organizationId: string, config?: RequestConfig): Promise<void> {
+		await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.OrganizationLeave)
+			return this.httpService.post(Url.OrganizationLeave, { organization_id: organizationId }, { ...config, headers })
+		})
+	}
+
+
+	/*
+	 * get the authorization request of a third-party OAuth client,
+	 * the request id is passed to the consent page as `request_id`
+	*/
+	async getAuthorizationRequest(id: string, config?: RequestConfig): Promise<AuthorizationRequest> {
+		let { data } = await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			let url = Url.OAuthAuthorizeRequest.replace(':id', id)
+			await this.authorize(headers, token, 'GET', url)
+			return this.httpService.get<AuthorizationRequest>(url, { ...config, headers })
+		})
+
+		return data
+	}
+
+
+	/*
+	 * approve the authorization request with the current session,
+	 * returns the URL the user should be redirected to
+	*/
+	async confirmAuthorization(id: string, config?: RequestConfig): Promise<string> {
+		let currentSession = this.sessionService.current()
+
+		if (!currentSession) {
+			throw new SessionNotFoundError()
+		}
+
+		let { data } = await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.OAuthAuthorizeConfirm, currentSession!.id)
+			let payload: ConfirmAuthorizationPayload = { id, session: currentSession!.id }
+			return this.httpService.post<AuthorizationRedirect>(Url.OAuthAuthorizeConfirm, payload, { ...config, headers })
+		}, currentSession.id)
+
+		return data.redirect_to
+	}
+
+
+	/*
+	 * deny the authorization request, returns the URL the user
+	 * should be redirected to
+	*/
+	async denyAuthorization(id: string, config?: RequestConfig): Promise<string> {
+		let { data } = await this.withToken(async token => {
+			let headers = new Headers(config?.headers)
+			await this.authorize(headers, token, 'POST', Url.OAuthAuthorizeDeny)
+			return this.httpService.post<AuthorizationRedirect>(Url.OAuthAuthorizeDeny, { id }, { ...config, headers })
+		})
+
+		return data.redirect_to
+	}
 }
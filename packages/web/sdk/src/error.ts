@@ -20,6 +20,7 @@ export enum ErrorCode {
 	TokenNotFound = 'token/not_found',
 	TokenInvalid = 'token/invalid',
 	TokenExpired = 'token/expired',
+	TokenInvalidProof = 'token/invalid_proof',
 
 	ResetInvalidToken = 'reset/invalid_token',
 	ResetTokenNotFound = 'reset/token_not_found',
@@ -29,16 +30,39 @@ export enum ErrorCode {
 	PasswordlessAwaitConfirm = 'passwordless/await_confirm',
 	PasswordlessTokenExpire = 'passwordless/token_expire',
 	PasswordlessInvalidToken = 'passwordless/invalid_token',
+	PasswordlessTooManyAttempts = 'passwordless/too_many_attempts',
+
+	SmsInvalidPhone = 'sms/invalid_phone',
+	SmsInvalidCode = 'sms/invalid_code',
+	SmsCodeExpired = 'sms/code_expired',
+	SmsTooManyAttempts = 'sms/too_many_attempts',
+	SmsTooManyRequests = 'sms/too_many_requests',
+	SmsNotConfigured = 'sms/not_configured',
 
 	ClientUserIdNotFound = 'client/user_id_not_found',
 
 	SessionNotFound = 'session/not_found',
 	SessionKeysNotFound = 'session/keys_not_found',
 	SessionExpired = 'session/expired',
+	SessionLimitReached = 'session/limit_reached',
+	SessionInvalidCsrfToken = 'session/invalid_csrf_token',
+	SessionReauthenticationRequired = 'session/reauthentication_required',
+	SessionTooManyAttempts = 'session/too_many_attempts',
+
+	RedirectUrlNotAllowed = 'redirect_url/not_allowed',
+	CaptchaRequired = 'captcha/required',
+	CaptchaInvalid = 'captcha/invalid',
 
 	UserDisabled = 'user/disabled',
+	UserEmailNotVerified = 'user/email_not_verified',
+	UserEmailDomainNotAllowed = 'user/email_domain_not_allowed',
 	UserDuplicate = 'user/duplicate',
 	UserExists = 'user/exists',
+	UserProviderLinked = 'user/provider_linked',
+	UserLastProvider = 'user/last_provider',
+
+	OrganizationLastOwner = 'organization/last_owner',
+	OrganizationMemberExists = 'organization/member_exists',
 
 	InvalidArguments = 'invalid/arguments'
 }
@@ -74,6 +98,12 @@ export function errorFromResponse(response: Response, data: ErrorResponse): ApiE
 				return new GenericError(response, data.code)
 
 			case ErrorCode.UserDisabled:
+			case ErrorCode.UserEmailNotVerified:
+			case ErrorCode.UserEmailDomainNotAllowed:
+			case ErrorCode.UserProviderLinked:
+			case ErrorCode.UserLastProvider:
+			case ErrorCode.OrganizationLastOwner:
+			case ErrorCode.OrganizationMemberExists:
 			case ErrorCode.AuthTokenMissing:
 			case ErrorCode.PasswordMinLength:
 			case ErrorCode.PasswordMaxLength:
@@ -88,11 +118,24 @@ export function errorFromResponse(response: Response, data: ErrorResponse): ApiE
 			case ErrorCode.PasswordlessAwaitConfirm:
 			case ErrorCode.PasswordlessTokenExpire:
 			case ErrorCode.PasswordlessInvalidToken:
+			case ErrorCode.PasswordlessTooManyAttempts:
+			case ErrorCode.SmsInvalidPhone:
+			case ErrorCode.SmsInvalidCode:
+			case ErrorCode.SmsCodeExpired:
+			case ErrorCode.SmsTooManyAttempts:
+			case ErrorCode.SmsTooManyRequests:
 			case ErrorCode.SessionExpired:
+			case ErrorCode.SessionLimitReached:
+			case ErrorCode.SessionInvalidCsrfToken:
+			case ErrorCode.SessionReauthenticationRequired:
+			case ErrorCode.SessionTooManyAttempts:
 			case ErrorCode.TokenGenerate:
 			case ErrorCode.TokenInvalid:
 			case ErrorCode.TokenExpired:
+			case ErrorCode.TokenInvalidProof:
 			case ErrorCode.TokenNotFound:
+			case ErrorCode.CaptchaRequired:
+			case ErrorCode.CaptchaInvalid:
 				return new AuthError(data.code, response)
 
 			default:
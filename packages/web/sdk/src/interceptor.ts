@@ -15,11 +15,12 @@ export function addToken(axios: AxiosInstance, auth: AuthClient) {
 	axios.interceptors.request.use(async function (config: AxiosRequestConfig) {
 		try {
 			let token = await auth.getToken()
+			let headers = await auth.authHeaders(token, config.method ?? 'GET', axios.getUri(config))
 			return {
 				...config,
 				headers: {
 					...config.headers,
-					'Authorization': `Bearer ${token}`,
+					...headers,
 				}
 			}
 		} catch (err) {
@@ -40,12 +41,13 @@ export function addToken(axios: AxiosInstance, auth: AuthClient) {
 
 		if (status === 401) {
 			let token = await auth.forceToken()
+			let headers = await auth.authHeaders(token, error.config.method ?? 'GET', axios.getUri(error.config))
 		    
 		    let config = {
 		    	...error.config,
 		    	headers: {
 		    		...(error.config.headers ?? {}),
-		    		'Authorization': `Bearer ${token}`,
+		    		...headers,
 		    	}
 		    }
 
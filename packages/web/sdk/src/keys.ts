@@ -9,6 +9,27 @@ export async function getPublicKey(session: Session): Promise<Array<number>> {
 	return Array.from(enc.encode(pemExported))
 }
 
+// The public key of the session as JWK, only the members that are
+// part of the thumbprint
+export async function getPublicJwk(session: Key): Promise<JsonWebKey> {
+	let jwk = await crypto.subtle.exportKey("jwk", session.publicKey)
+
+	return jwk.kty === 'RSA'
+		? { kty: jwk.kty, n: jwk.n, e: jwk.e }
+		: { kty: jwk.kty, crv: jwk.crv, x: jwk.x, y: jwk.y }
+}
+
+// Bound access tokens (DPoP) carry the thumbprint of the session key in `cnf`
+export function isBound(token: string): boolean {
+	try {
+		let [, payload] = token.split('.')
+		let json = globalThis.atob(payload.replaceAll('-', '+').replaceAll('_', '/'))
+		return JSON.parse(json).cnf !== undefined
+	} catch {
+		return false
+	}
+}
+
 // rat := Refresh Access Token
 export function ratPayload() {
 	let now = new Date()
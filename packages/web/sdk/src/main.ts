@@ -22,6 +22,8 @@ export type {
 	UserAuthState,
 	ApiKey,
 	ApiKeys,
+	UserSession,
+	RevokeSessionPayload,
 	UpdateEmailPayload,
 	ConfirmUpdateEmailPayload,
 	RejectUpdateEmailPayload,
@@ -32,6 +34,7 @@ export type {
 	PasswordResetPayload,
 	VerifyResetTokenPayload,
 	VerifyPasswordlessPayload,
+	VerifyPasswordlessCodePayload,
 	RequestPasswordlessPayload,
 	ConfirmPasswordlessPayload,
 	RefreshAccessTokenPayload,
@@ -39,12 +42,30 @@ export type {
 	UpdateUserPayload,
 	Claims,
 	PasswordlessResponse,
+	SignInAnonymouslyPayload,
+	LinkPasswordPayload,
+	RequestSmsPayload,
+	SmsResponse,
+	VerifySmsPayload,
+	OrganizationRole,
+	Organization,
+	Organizations,
+	InviteMemberPayload,
+	AcceptInvitationPayload,
+	AcceptUserInvitationPayload,
+	SwitchOrganizationPayload,
+	ReauthenticatePayload,
+	ReauthenticationCodePayload,
+	AuthorizationRequest,
+	ConfirmAuthorizationPayload,
+	AuthorizationRedirect,
+	Captcha,
 } from './types'
 
-export { UserState, Flag, Url } from './types'
+export { UserState, Flag, Url, PasswordlessMode } from './types'
 export type { SessionId } from './types'
 export { AuthClient } from './client'
-export type { ClientDep, IAuthClient } from './client'
+export type { ClientDep, IAuthClient, RequestConfig, RedirectConfig } from './client'
 export { ErrorCode, AuthError, isErrorCode } from './error'
 export type { ApiError, ErrorResponse, GenericError, Code } from './error'
 export { SessionService } from './session'
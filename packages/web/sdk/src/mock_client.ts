@@ -9,6 +9,10 @@ import {
 	UserState,
 	GenerateApiKey,
     ApiKeys,
+    PasswordlessMode,
+    Organization,
+    OrganizationRole,
+    UserSession,
 } from './types'
 import { uuid } from './utils'
 import { faker } from '@faker-js/faker'
@@ -67,10 +71,40 @@ export class MockAuthClient implements IAuthClient {
 		.fn<IAuthClient['verifyToken']>()
 		.mockImplementation(async (_id: string, _token: string) => {})
 
+	signInAnonymously = jest
+		.fn<IAuthClient['signInAnonymously']>()
+		.mockImplementation(async () => {
+			return getUser()
+		})
+
+	linkPassword = jest
+		.fn<IAuthClient['linkPassword']>()
+		.mockImplementation(async (_email: string, _password: string) => {})
+
+	linkPasswordless = jest
+		.fn<IAuthClient['linkPasswordless']>()
+		.mockImplementation(async (_email: string) => {
+			return { id: uuid(), session: uuid(), mode: PasswordlessMode.Link }
+		})
+
+	oAuthLinkUrl = jest
+		.fn<IAuthClient['oAuthLinkUrl']>()
+		.mockImplementation(async (_provider: 'google') => {
+			return faker.internet.url()
+		})
+
+	addPassword = jest
+		.fn<IAuthClient['addPassword']>()
+		.mockImplementation(async (_password: string) => {})
+
+	unlinkProvider = jest
+		.fn<IAuthClient['unlinkProvider']>()
+		.mockImplementation(async (_provider: 'password' | 'google') => {})
+
 	passwordless = jest
 		.fn<IAuthClient['passwordless']>()
 		.mockImplementation(async (_email: string) => {
-			return { id: uuid(), session: uuid() }
+			return { id: uuid(), session: uuid(), mode: PasswordlessMode.Link }
 		})
 
 	confirmPasswordless = jest
@@ -92,12 +126,33 @@ export class MockAuthClient implements IAuthClient {
 		.fn<IAuthClient['verifyEmail']>()
 		.mockImplementation(async (_id: string, _token: string) => {})
 
+	acceptUserInvitation = jest
+		.fn<IAuthClient['acceptUserInvitation']>()
+		.mockImplementation(async (_id: string, token: string, _password?: string) => {
+			errors(token)
+			return getUser()
+		})
+
 	verifyPasswordless = jest
 		.fn<IAuthClient['verifyPasswordless']>()
 		.mockImplementation(async (_id: string, _session: string) => {
 			return getUser()
 		})
 
+	verifyPasswordlessCode = jest
+		.fn<IAuthClient['verifyPasswordlessCode']>()
+		.mockImplementation(async (_id: string, _session: string, code: string) => {
+			switch(code) {
+				case 'code-invalid':
+					throw authError(ErrorCode.PasswordlessInvalidToken)
+
+				case 'too-many-attempts':
+					throw authError(ErrorCode.PasswordlessTooManyAttempts)
+			}
+
+			return getUser()
+		})
+
 	authStateChange = jest
 		.fn<IAuthClient['authStateChange']>()
 		.mockImplementation((_cb: AuthCallback) => {
@@ -114,12 +169,24 @@ export class MockAuthClient implements IAuthClient {
 	withToken = jest
 		.fn<IAuthClient['withToken']>()
 
+	authHeaders = jest
+		.fn<IAuthClient['authHeaders']>()
+		.mockImplementation(async (token) => {
+			return { 'Authorization': `Bearer ${token}` }
+		})
+
 	flags = jest
 		.fn<IAuthClient['flags']>()
 		.mockImplementation(async () => {
 			return []
 		})
 
+	captcha = jest
+		.fn<IAuthClient['captcha']>()
+		.mockImplementation(async () => {
+			return null
+		})
+
 	getUser = jest
 		.fn<IAuthClient['getUser']>()
 		.mockImplementation(() => {
@@ -179,6 +246,117 @@ export class MockAuthClient implements IAuthClient {
 		.mockImplementation(async (id: string) => {
 			errors(id)
 		})
+
+	listSessions = jest
+		.fn<IAuthClient['listSessions']>()
+		.mockImplementation(async () => {
+			let session: UserSession = {
+				id: uuid(),
+				device_name: 'Firefox on macOS',
+				user_agent: faker.internet.userAgent(),
+				ip: faker.internet.ip(),
+				created_at: faker.date.past().toISOString(),
+				last_refreshed_at: null,
+				expire_at: faker.date.future().toISOString(),
+			}
+
+			return [session]
+		})
+
+	revokeSession = jest
+		.fn<IAuthClient['revokeSession']>()
+		.mockImplementation(async (id: string) => {
+			errors(id)
+		})
+
+	createOrganization = jest
+		.fn<IAuthClient['createOrganization']>()
+		.mockImplementation(async (name: string) => {
+			return getOrganization(name)
+		})
+
+	listOrganizations = jest
+		.fn<IAuthClient['listOrganizations']>()
+		.mockImplementation(async () => {
+			return { items: [getOrganization()] }
+		})
+
+	inviteToOrganization = jest
+		.fn<IAuthClient['inviteToOrganization']>()
+		.mockImplementation(async (_organizationId: string, _email: string, _role?: OrganizationRole) => {
+			return uuid()
+		})
+
+	acceptInvitation = jest
+		.fn<IAuthClient['acceptInvitation']>()
+		.mockImplementation(async (_id: string, token: string) => {
+			errors(token)
+		})
+
+	reauthenticate = jest
+		.fn<IAuthClient['reauthenticate']>()
+		.mockImplementation(async (password: string) => {
+			errors(password)
+		})
+
+	requestReauthenticationCode = jest
+		.fn<IAuthClient['requestReauthenticationCode']>()
+		.mockImplementation(async () => {
+			return faker.datatype.uuid()
+		})
+
+	reauthenticateWithCode = jest
+		.fn<IAuthClient['reauthenticateWithCode']>()
+		.mockImplementation(async (_id: string, code: string) => {
+			errors(code)
+		})
+
+	switchOrganization = jest
+		.fn<IAuthClient['switchOrganization']>()
+		.mockImplementation(async (_organizationId: string | null) => {
+			return faker.datatype.uuid()
+		})
+
+	leaveOrganization = jest
+		.fn<IAuthClient['leaveOrganization']>()
+		.mockImplementation(async (organizationId: string) => {
+			errors(organizationId)
+		})
+
+	getAuthorizationRequest = jest
+		.fn<IAuthClient['getAuthorizationRequest']>()
+		.mockImplementation(async (id: string) => {
+			errors(id)
+			return {
+				id,
+				client: { id: uuid(), name: faker.company.name() },
+				scopes: ['email', 'openid', 'profile'],
+				consent: false,
+			}
+		})
+
+	confirmAuthorization = jest
+		.fn<IAuthClient['confirmAuthorization']>()
+		.mockImplementation(async (id: string) => {
+			errors(id)
+			return `${faker.internet.url()}?code=${id}`
+		})
+
+	denyAuthorization = jest
+		.fn<IAuthClient['denyAuthorization']>()
+		.mockImplementation(async (id: string) => {
+			errors(id)
+			return `${faker.internet.url()}?error=access_denied`
+		})
+}
+
+function getOrganization(name: string = faker.company.name()): Organization {
+	return {
+		id: uuid(),
+		name,
+		role: 'owner',
+		created_at: faker.date.past().toISOString(),
+	}
 }
 
 function getUser(email: string = faker.internet.email()): User {
@@ -189,8 +367,10 @@ function getUser(email: string = faker.internet.email()): User {
 		email_verified: faker.datatype.boolean(),
 		photo_url: faker.image.imageUrl(),
 		traits: [],
-		data: {},
+		user_metadata: {},
+		app_metadata: {},
 		provider_id: 'email',
+		providers: ['email'],
 		created_at: faker.date.past().toISOString(),
 		updated_at: faker.date.past().toISOString(),
 		state: UserState.Active,
@@ -8,9 +8,9 @@ import {
 	SessionInfo,
     Url,
 } from './types'
-import { IStorage, ISessionsStorage, IKeyStorage, Session } from './storage'
+import { IStorage, ISessionsStorage, IKeyStorage, Session, Key } from './storage'
 import { makeId, IHttpService, uuid } from './utils'
-import { ab2str, base64url, generateKeys, isRsa } from './keys'
+import { ab2str, base64url, generateKeys, getPublicJwk, isBound, isRsa } from './keys'
 
 type Listener = {
 	id: number;
@@ -98,22 +98,57 @@ export class SessionService {
 			return null
 		}
 
-		let header = isRsa(session.privateKey.algorithm.name)
-			?	{
-					alg: "RS256",
-					typ: "JWT"
-				}
-			:	{
-					alg: "ES384",
-					typ: "JWT"
-				}
+		return this.sign(session, { typ: "JWT" }, claims)
+	}
+
+	/**
+	 * Headers to authenticate a request with the access token, tokens that are bound
+	 * to the session key are sent with the `DPoP` scheme and a proof for the method and url
+	*/
+	async authHeaders(sessionId: string, token: AccessToken, method: string, url: string): Promise<Record<string, string>> {
+		if (!isBound(token)) {
+			return { 'Authorization': `Bearer ${token}` }
+		}
+
+		let proof = await this.generateProof(sessionId, token, method, url)
+
+		return proof === null
+			? { 'Authorization': `DPoP ${token}` }
+			: { 'Authorization': `DPoP ${token}`, 'DPoP': proof }
+	}
+
+	async generateProof(sessionId: string, token: AccessToken, method: string, url: string): Promise<string | null> {
+		let session = await this.keyStorage.get(sessionId)
+
+		if (!session) {
+			return null
+		}
+
+		let enc = new TextEncoder()
+		let hash = await globalThis.crypto.subtle.digest("SHA-256", enc.encode(token))
+		let jwk = await getPublicJwk(session)
+
+		let claims = {
+			htm: method.toUpperCase(),
+			htu: new URL(url, globalThis.location?.href).href,
+			iat: Math.floor(Date.now() / 1000),
+			ath: base64url(ab2str(hash)),
+		}
+
+		return this.sign(session, { typ: "dpop+jwt", jwk }, claims)
+	}
+
+	private async sign(session: Key, header: Object, claims: Object): Promise<string> {
+		let alg = isRsa(session.privateKey.algorithm.name)
+			? "RS256"
+			: "ES384"
 
 		let payload = {
 			...claims,
 			jti: uuid(),
 		}
 
-		let h = base64url(JSON.stringify(header))
+		let h = base64url(JSON.stringify({ ...header, alg }))
 		let p = base64url(JSON.stringify(payload))
 
 		let enc = new TextEncoder()
@@ -167,7 +202,7 @@ export class SessionService {
 	}
 
 	async fromResponse(data: FromSessionResponse): Promise<SessionInfo> {
-		let user = await this.getUser(data.access_token)
+		let user = await this.getUser(data.access_token, data.session)
 
 		let staleSessions = this.sessionStorage
 			.getAll()
@@ -191,9 +226,9 @@ export class SessionService {
 		return session
 	}
 
-	async getUser(token: AccessToken): Promise<User> {
-		let headers = new Headers()
-		headers.set('Authorization', `Bearer ${token}`)
+	async getUser(token: AccessToken, sessionId: string): Promise<User> {
+		let url = this.http.url?.(Url.UserGet) ?? Url.UserGet
+		let headers = new Headers(await this.authHeaders(sessionId, token, 'GET', url))
 		let { data } = await this.http.get<User>(Url.UserGet, { headers })
 		return data
 	}
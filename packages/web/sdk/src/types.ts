@@ -6,10 +6,14 @@ export type EmailPasswordPayload = {
 	public_key: Array<number>;
 	session: string;
 	device_languages?: Array<string>;
+	redirect_url?: string;
+	captcha_token?: string;
 }
 
 export type PasswordResetPayload = {
 	email: string;
+	redirect_url?: string;
+	captcha_token?: string;
 }
 
 export type VerifyResetTokenPayload = {
@@ -33,11 +37,19 @@ export type RequestPasswordlessPayload = {
 	public_key: Array<number>;
 	session: string;
 	device_languages: Array<string>;
+	redirect_url?: string;
+	captcha_token?: string;
+}
+
+export enum PasswordlessMode {
+	Link = 'link',
+	Code = 'code',
 }
 
 export type PasswordlessResponse = {
 	id: string;
 	session: string;
+	mode: PasswordlessMode;
 }
 
 export type ConfirmPasswordlessPayload = {
@@ -52,6 +64,46 @@ export type VerifyPasswordlessPayload = {
 	device_languages: Array<string>;
 }
 
+export type VerifyPasswordlessCodePayload = {
+	id: string;
+	code: string;
+	token: string;
+	session: string;
+	device_languages: Array<string>;
+}
+
+export type SignInAnonymouslyPayload = {
+	public_key: Array<number>;
+	session: string;
+	device_languages: Array<string>;
+}
+
+export type LinkPasswordPayload = {
+	email: string;
+	password: string;
+	redirect_url?: string;
+}
+
+export type RequestSmsPayload = {
+	phone: string;
+	public_key: Array<number>;
+	session: string;
+	device_languages?: Array<string>;
+}
+
+export type SmsResponse = {
+	id: string;
+	session: string;
+}
+
+export type VerifySmsPayload = {
+	id: string;
+	code: string;
+	token: string;
+	session: string;
+	device_languages: Array<string>;
+}
+
 export type RefreshAccessTokenPayload = {
 	value: string;
 }
@@ -61,6 +113,11 @@ export type VerifyEmailPayload =  {
 	token: string;
 }
 
+export type ResendEmailVerificationPayload = {
+	email: string;
+	redirect_url?: string;
+}
+
 export type SessionInfo = {
 	id: SessionId,
 	user?: User | null,
@@ -71,17 +128,22 @@ export enum UserState {
 	Active = 'active',
 	Disabled = 'disabled',
 	SetPassword = 'set_password',
+	Deleted = 'deleted',
 }
 
 export type User = {
 	id: string;
 	display_name?: string,
-	email: string,
+	email: string | null,
 	email_verified: boolean,
+	phone: string | null,
+	phone_verified: boolean,
 	photo_url?: string,
 	traits: Array<string>,
-	data: Object,
+	user_metadata: Object,
+	app_metadata: Object,
 	provider_id: string,
+	providers: Array<string>,
 	created_at: string,
 	updated_at: string,
 	state: UserState,
@@ -90,10 +152,7 @@ export type User = {
 
 export type UpdateUserPayload = Pick<User,
 	"display_name" |
-	"email" |
-	"traits" |
-	"photo_url" |
-	"data" 
+	"user_metadata"
 >
 
 export enum ApiError {
@@ -104,6 +163,13 @@ export type Claims = {
 	sub: string;
 	exp: number,
 	traits: Array<string>,
+	org_id?: string,
+	org_role?: OrganizationRole,
+	permissions?: Array<string>,
+	/** when the user last authenticated the session, as a unix timestamp */
+	auth_time?: number,
+	/** how the user last authenticated the session, e.g. `pwd` or `otp` */
+	amr?: Array<string>,
 }
 
 export type Token = {
@@ -119,6 +185,7 @@ export type TokenResponse = {
 
 
 export type SessionResponse = {
+	/** left out for cookie sessions, the token is in the `vulpo_access_token` cookie */
 	access_token: string;
 	created: boolean;
 	user_id: string;
@@ -154,12 +221,98 @@ export type ApiKeys = {
 	keys: Array<ApiKey>;
 }
 
+/** a device the user is signed in with */
+export type UserSession = {
+	id: string;
+	/** e.g. `Firefox on macOS`, derived from the user agent */
+	device_name: string | null;
+	user_agent: string | null;
+	ip: string | null;
+	created_at: string;
+	last_refreshed_at: string | null;
+	expire_at: string;
+}
+
+export type RevokeSessionPayload = {
+	id: string;
+}
+
+export type OrganizationRole = 'member' | 'admin' | 'owner'
+
+export type Organization = {
+	id: string;
+	name: string;
+	role: OrganizationRole;
+	created_at: string;
+}
+
+export type Organizations = {
+	items: Array<Organization>;
+}
+
+export type InviteMemberPayload = {
+	organization_id: string;
+	email: string;
+	role: OrganizationRole;
+}
+
+export type AcceptInvitationPayload = {
+	id: string;
+	token: string;
+}
+
+export type AcceptUserInvitationPayload = {
+	id: string;
+	token: string;
+	password?: string;
+	public_key: Array<number>;
+	session: string;
+	device_languages: Array<string>;
+}
+
+export type SwitchOrganizationPayload = {
+	session: string;
+	organization_id: string | null;
+}
+
+export type ReauthenticatePayload = {
+	token?: string;
+} & (
+	| { method: 'password'; password: string }
+	| { method: 'code'; id: string; code: string }
+)
+
+export type ReauthenticationCodePayload = {
+	token?: string;
+}
+
+export type AuthorizationRequest = {
+	id: string;
+	client: {
+		id: string;
+		name: string;
+	};
+	scopes: Array<string>;
+	/** the user already granted the scopes to the client */
+	consent: boolean;
+}
+
+export type ConfirmAuthorizationPayload = {
+	id: string;
+	session: string;
+}
+
+export type AuthorizationRedirect = {
+	redirect_to: string;
+}
+
 export type DeleteApiKeyPayload = {
 	id: string;
 }
 
 export type UpdateEmailPayload = {
 	new_email: string;
+	redirect_url?: string;
 }
 
 export type ConfirmUpdateEmailPayload = {
@@ -201,20 +354,50 @@ export enum Url {
 	Passwordless = '/passwordless/',
 	PasswordlessConfim = '/passwordless/confirm',
 	PasswordlessVerify = '/passwordless/verify',
+	PasswordlessVerifyCode = '/passwordless/verify_code',
+
+	Sms = '/sms/',
+	SmsVerify = '/sms/verify',
+
+	Anonymous = '/anonymous/',
+	AnonymousLinkPassword = '/anonymous/link/password',
+	AnonymousLinkPasswordless = '/anonymous/link/passwordless',
 
 	UserVerifyEmail = '/user/verify_email',
+	UserResendEmailVerification = '/user/resend_email_verification',
+	UserAcceptInvitation = '/user/invitation/accept',
 	UserGet = '/user/get',
 	UserDeleteAccount = '/user/delete_account/:session',
 	UserUpdate = '/user/update',
 	UserSetPassword = '/user/set_password',
+	UserLinkPassword = '/user/link/password',
+	UserUnlink = '/user/unlink',
+
+	Organization = '/organization/',
+	OrganizationList = '/organization/list',
+	OrganizationInvite = '/organization/invite',
+	OrganizationAccept = '/organization/accept',
+	OrganizationSwitch = '/organization/switch',
+	OrganizationLeave = '/organization/leave',
+
+	OAuthAuthorizeRequest = '/oauth/authorize/request?id=:id',
+	OAuthAuthorizeConfirm = '/oauth/authorize/confirm',
+	OAuthAuthorizeDeny = '/oauth/authorize/deny',
 
 	TokenRefresh = '/token/refresh/:session',
+	Reauthenticate = '/token/reauthenticate/:session',
+	ReauthenticationCode = '/token/reauthenticate/:session/code',
+	CookieRefresh = '/token/refresh',
+	CookieSignOut = '/token/sign_out',
 
 	Flags = '/project/flags?project=:projectId',
+	Captcha = '/project/captcha?project=:projectId',
 
 	UpdateEmail = '/user/email/update',
 	ConfirmUpdateEmail = '/user/email/update/confirm',
 	RejectUpdateEmail = '/user/email/update/reset',
+	UserSessions = '/user/sessions',
+	RevokeUserSession = '/user/sessions/revoke',
 
 	GenerateApiKey = '/api_key/generate',
 	ListApiKeys = '/api_key/list',
@@ -224,16 +407,28 @@ export enum Url {
 export enum Flag {
 	SignIn = 'auth::signin',
 	SignUp = 'auth::signup',
+	Anonymous = 'auth::anonymous',
 	PasswordReset = 'action::password_reset',
 	VerifyEmail = 'action::verify_email',
 	EmailAndPassword = 'method::email_password',
 	AuthenticationLink = 'method::authentication_link',
+	Sms = 'method::sms',
+	Organizations = 'auth::organizations',
+	DPoP = 'token::dpop',
+	CookieSession = 'session::cookie',
+	HostedPages = 'hosted::pages',
 
 	OAuthGoogle = 'oauth::google',
 }
 
+export type Captcha = {
+	provider: 'turnstile' | 'hcaptcha' | 'recaptcha';
+	site_key: string;
+}
+
 export type OAuthAuthorizeUrlPayload = {
 	request_id: string,
+	redirect_url?: string,
 }
 
 export type OAuthAuthorizeUrlResponse = {
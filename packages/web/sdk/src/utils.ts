@@ -38,6 +38,7 @@ export type HttpError<T = any> = {
 export interface IHttpService {
 	get<R>(url: string, config?: Partial<Request>): Promise<HttpResponse<R>>;
 	post<R>(url: string, data?: unknown, config?: Partial<Request>): Promise<HttpResponse<R>>;
+	url?(path: string): string;
 }
 
 export class HttpSerivce implements IHttpService {
@@ -67,6 +68,10 @@ export class HttpSerivce implements IHttpService {
 		return `${baseURL}/${pathname}`
 	}
 
+	url(path: string): string {
+		return this.getUrl(path)
+	}
+
 	private getMode() {
 		return this.baseURL === '/' ? 'same-origin' : 'cors'
 	}
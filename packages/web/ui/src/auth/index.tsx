@@ -12,6 +12,7 @@ import VerifyEmail from '../verify_email'
 import { useTranslation, useError } from '../context/translation'
 import { FlagsCtx } from '../context/config'
 import SetPassword from '../user/set_password'
+import Invitation from '../user/invitation'
 import OAuthConfirm from '../oauth'
 import RejectUpdateEmail from '../user/update_email/reject'
 import ConfirmUpdateEmail from '../user/update_email/confirm'
@@ -72,6 +73,7 @@ let Auth = () => {
 		<FlagsCtx.Provider value={flags}>
 			<Routes>
 				<Route path={`user/set_password/*`} element={<SetPassword />} />
+				<Route path={`invitation/*`} element={<Invitation />} />
 				<Route path={'user/change-email/reset'} element={<RejectUpdateEmail />} />
 				<Route path={'user/change-email/confirm'} element={<ConfirmUpdateEmail />} />
 				
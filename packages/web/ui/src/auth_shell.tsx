@@ -76,7 +76,7 @@ let AuthShell: FunctionComponent<AuthShellProps> = (props) => {
 	let location = useLocation()
 
 	let [referrer] = useState(() => {
-		let { pathname } = window.location
+		let { pathname, search } = window.location
 
 		let useDefaultRedirect = (
 			pathname.startsWith(`/${basename}`)
@@ -86,7 +86,8 @@ let AuthShell: FunctionComponent<AuthShellProps> = (props) => {
 			return props.redirect ?? '/'
 		}
 
-		return pathname
+		// keep the query, e.g. the request_id of the OAuth consent page
+		return `${pathname}${search}`
 	})
 
 	let auth = useAuth()
@@ -19,6 +19,7 @@ export type ErrorMessage = {
 
 	/* Sign In */
 	invalid_email_password: string;
+	session_limit_reached: string;
 	
 	/* General */
 	generic: string;
@@ -140,12 +141,18 @@ export let DefaultTranslation = {
 		reset_token_not_found: 'Reset Token not found',
 
 		invalid_email_password: 'Invalid Email or Password',
+		session_limit_reached: 'You are signed in on too many devices, sign out on another device first',
 		
 		generic: 'Something went wrong',
 		not_allowed: 'Not Allowed',
 		unavailable: 'Authentication Service is Unavailable',
 	},
 
+	invitation: {
+		title: 'Accept Invitation',
+		label: 'Accept Invitation',
+	},
+
 	verify_email: {
 		title: 'Verifying Email',
 		success: 'Your email has been verified',
@@ -162,6 +169,20 @@ export let DefaultTranslation = {
 		title: 'Confirm Change Email Request',
 		label: 'Change Email',
 		submitted: 'Your email has been changed.',
+	},
+
+	authorize: {
+		title: 'Authorize App',
+		info: ({ client }: { client: string }) => {
+			return <p className="vulpo-auth-text"><strong>{client}</strong> would like to access your account:</p>
+		},
+		scopes: {
+			openid: 'Sign you in',
+			profile: 'Your name and profile picture',
+			email: 'Your email address',
+		} as Record<string, string>,
+		allow: 'Allow',
+		deny: 'Deny',
 	}
 }
 
@@ -190,6 +211,9 @@ export function useError(code: ErrorCode | null): string | null {
 			case ErrorCode.InvalidEmailPassword:
 				return t.error.invalid_email_password
 
+			case ErrorCode.SessionLimitReached:
+				return t.error.session_limit_reached
+
 			case ErrorCode.NotAllowed:
 				return t.error.not_allowed
 
@@ -21,5 +21,10 @@ export {
 	SetPassword as SetPasswordComponent
 } from './user/set_password'
 
+export {
+	default as Authorize,
+	Authorize as AuthorizeComponent
+} from './oauth/authorize'
+
 export { default as AuthShell, useUser } from './auth_shell'
 export type { AuthShellProps } from './auth_shell'
@@ -0,0 +1,118 @@
+import { useEffect, useState } from 'react'
+import { useAuth } from '@vulpo-dev/auth-react'
+import { AuthorizationRequest, ErrorCode } from '@vulpo-dev/auth-sdk'
+
+import { useTranslation, useError } from '../context/translation'
+import { useQueryParams } from '../utils'
+import { Flow } from '../component/loading'
+import Card from '../component/card'
+import { Button } from '../component/button'
+
+export type Props = {
+	request: AuthorizationRequest | null;
+	loading: boolean;
+	error: ErrorCode | null;
+	onAllow: () => void;
+	onDeny: () => void;
+}
+
+export let Authorize = ({ request, loading, error, onAllow, onDeny }: Props) => {
+	let t = useTranslation()
+	let errorMessage = useError(error)
+
+	return (
+		<Card className="vulpo-auth-authorize">
+			<header className="vulpo-card-header">
+				<h3 className="vulpo-auth-card-title">{t.authorize.title}</h3>
+			</header>
+
+			{ (request === null && error === null) &&
+				<div className="vulpo-auth-loading-wrapper">
+					<Flow />
+				</div>
+			}
+
+			{ request !== null &&
+				<section className="vulpo-auth-form-section">
+					<t.authorize.info client={request.client.name} />
+					<ul className="vulpo-auth-authorize-scopes">
+						{ request.scopes.map(scope => (
+							<li key={scope}>{t.authorize.scopes[scope] ?? scope}</li>
+						))}
+					</ul>
+				</section>
+			}
+
+			{ error !== null &&
+				<p className="vulpo-auth-error">{errorMessage}</p>
+			}
+
+			{ request !== null &&
+				<div className="vulpo-auth-authorize-actions">
+					<Button outline disabled={loading} onClick={onDeny}>{t.authorize.deny}</Button>
+					<Button loading={loading} onClick={onAllow}>{t.authorize.allow}</Button>
+				</div>
+			}
+		</Card>
+	)
+}
+
+/**
+ * Consent page for third-party OAuth clients, mount it as a private
+ * route under `/oauth/authorize`. Requests the user already consented
+ * to are approved right away.
+ */
+let AuthorizeContainer = () => {
+	let auth = useAuth()
+	let query = useQueryParams()
+	let requestId = query.get('request_id')
+
+	let [request, setRequest] = useState<AuthorizationRequest | null>(null)
+	let [error, setError] = useState<ErrorCode | null>(null)
+	let [loading, setLoading] = useState<boolean>(false)
+
+	useEffect(() => {
+		if (requestId === null) {
+			setError(ErrorCode.NotFound)
+			return
+		}
+
+		auth.getAuthorizationRequest(requestId)
+			.then(async (request) => {
+				if (!request.consent) {
+					setRequest(request)
+					return
+				}
+
+				let redirectTo = await auth.confirmAuthorization(request.id)
+				window.location.assign(redirectTo)
+			})
+			.catch(err => setError(err.code))
+	}, [requestId, auth])
+
+	function handle(action: (id: string) => Promise<string>) {
+		if (request === null) {
+			return
+		}
+
+		setLoading(true)
+		action(request.id)
+			.then(redirectTo => window.location.assign(redirectTo))
+			.catch(err => {
+				setError(err.code)
+				setLoading(false)
+			})
+	}
+
+	return (
+		<Authorize
+			request={request}
+			loading={loading}
+			error={error}
+			onAllow={() => handle(id => auth.confirmAuthorization(id))}
+			onDeny={() => handle(id => auth.denyAuthorization(id))}
+		/>
+	)
+}
+
+export default AuthorizeContainer
@@ -0,0 +1,99 @@
+import { FormEvent, useState } from 'react'
+import { ErrorCode, ErrorResponse, Flag } from '@vulpo-dev/auth-sdk'
+import { useAuth } from '@vulpo-dev/auth-react'
+
+import { SetPassword } from './set_password'
+import { Button } from '../component/button'
+import Card from '../component/card'
+import { useQueryParams } from '../utils'
+import { useError, useTranslation } from '../context/translation'
+import { useFlags } from '../context/config'
+
+export type Props = {
+	loading: boolean;
+	error: ErrorCode | null;
+	onSubmit: () => void;
+}
+
+/**
+ * Projects without email and password sign in, the invited user
+ * signs in with authentication links afterwards
+ */
+export let Invitation = ({ loading, error, onSubmit }: Props) => {
+	let t = useTranslation()
+	let errorMessage = useError(error)
+
+	let handleSubmit = (e: FormEvent) => {
+		e.preventDefault()
+		onSubmit()
+	}
+
+	return (
+		<Card className="vulpo-auth-user-invitation">
+			<header className="vulpo-card-header">
+				<h3 className="vulpo-auth-card-title">{t.invitation.title}</h3>
+			</header>
+
+			<form className="vulpo-auth-button-form" onSubmit={handleSubmit}>
+				<Button loading={loading}>
+					{ t.invitation.label }
+				</Button>
+			</form>
+
+			{ error !== null &&
+				<p className="vulpo-auth-error">{errorMessage}</p>
+			}
+		</Card>
+	)
+}
+
+let InvitationContainer = () => {
+	let auth = useAuth()
+	let query = useQueryParams()
+	let flags = useFlags()
+
+	let [error, setError] = useState<ErrorCode | null>(null)
+	let [loading, setLoading] = useState<boolean>(false)
+
+	async function accept(password?: string) {
+		let id = query.get('id')
+		let token = query.get('token')
+
+		if (!id || !token) {
+			setError(ErrorCode.TokenNotFound)
+			return
+		}
+
+		setLoading(true)
+		setError(null)
+
+		try {
+			await auth.acceptUserInvitation(id, token, password)
+		} catch(error) {
+			setLoading(false)
+
+			let err = error as ErrorResponse
+			setError(err.code)
+		}
+	}
+
+	if (flags.includes(Flag.EmailAndPassword)) {
+		return (
+			<SetPassword
+				onSubmit={form => accept(form.password1)}
+				loading={loading}
+				error={error}
+			/>
+		)
+	}
+
+	return (
+		<Invitation
+			onSubmit={() => accept()}
+			loading={loading}
+			error={error}
+		/>
+	)
+}
+
+export default InvitationContainer
@@ -96,6 +96,30 @@ app.get("/keys", async (
 	res.json(payload)
 })
 
+type Revocations = {
+	users: Record<string, number>;
+	projects: Record<string, number>;
+}
+
+let REVOCATIONS: Revocations = {
+	users: {},
+	projects: {},
+}
+
+// always expired, so the SDK fetches the feed for every token
+app.get('/keys/revocations', (_, res) => {
+	res.json({
+		expire_at: new Date().toISOString(),
+		...REVOCATIONS,
+	})
+})
+
+app.post('/keys/revocations', (req: Request, res: Response) => {
+	let valid_after = Math.floor(Date.now() / 1000)
+	REVOCATIONS.users[req.body.user] = valid_after
+	res.json({ valid_after })
+})
+
 app.post('/api_key/verify', async (
 	req: Request,
 	res: Response
@@ -136,6 +160,9 @@ app.listen(7000, () => {
 
     GET /keys
     	returns the public keys 
+
+    GET /keys/revocations
+        returns the revoked users, POST { user } to revoke a user
 	`)
 })
 
@@ -13,6 +13,11 @@ let redirects = {
 	confirm_email_change: "/auth/user/change-email/confirm",
 	password_reset: "/auth/forgot-password/set-password",
 	passwordless: "/auth/signin/link/confirm",
+	passwordless_code: "/auth/signin/code",
+	organization_invite: "/auth/organization/accept",
+	admin_invite: "/dashboard/auth/invitation",
+	invite: "/auth/invitation",
+	account_deleted: "/",
 	verify_email: "/auth/verify-email",
 	password_changed: "/auth/forgot-password`",
 }
@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+
+drop table if exists sms_codes;
+drop table if exists sms_settings;
+drop type if exists sms_provider;
+
+drop index if exists user_phone_idx;
+alter table users drop constraint if exists users_project_id_phone_key;
+alter table users drop column if exists phone_verified;
+alter table users drop column if exists phone;
+
+delete from users where email is null;
+alter table users alter column email set not null;
@@ -0,0 +1,36 @@
+-- Your SQL goes here
+
+alter table users alter column email drop not null;
+alter table users add column if not exists phone text;
+alter table users add column if not exists phone_verified boolean not null default false;
+alter table users add constraint users_project_id_phone_key unique(project_id, phone);
+
+create index user_phone_idx on users using btree(phone);
+
+
+create type sms_provider as enum('log', 'http');
+
+create table if not exists sms_settings
+	( project_id uuid primary key references projects(id) on delete cascade
+	, provider sms_provider not null default 'log'
+	, url text
+	, auth_header text
+	, sender text
+	);
+
+
+create table if not exists sms_codes
+	( id uuid primary key default uuid_generate_v4()
+	, created_at timestamptz not null default now()
+	, expire_at timestamptz not null default now() + '10 minutes'
+	, user_id uuid references users(id) on delete cascade
+	, phone text not null
+	, code text not null
+	, attempts integer not null default 0
+	, is_valid boolean not null default True
+	, project_id uuid not null references projects(id) on delete cascade
+	, session_id uuid not null references sessions(id) on delete cascade
+	);
+
+create index sms_codes_project_idx on sms_codes(project_id);
+create index sms_codes_phone_idx on sms_codes(phone);
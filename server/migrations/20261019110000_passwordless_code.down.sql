@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+
+alter table passwordless drop column if exists attempts;
+alter table passwordless drop column if exists mode;
+
+alter table project_settings drop column if exists passwordless_mode;
+
+drop type if exists passwordless_mode;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+
+create type passwordless_mode as enum('link', 'code');
+
+alter table project_settings add column if not exists passwordless_mode passwordless_mode not null default 'link';
+
+alter table passwordless add column if not exists mode passwordless_mode not null default 'link';
+alter table passwordless add column if not exists attempts integer not null default 0;
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+
+drop index if exists user_provider_idx;
+
+alter table oauth_request_state drop column if exists user_id;
+
+alter table project_settings drop column if exists anonymous_ttl_days;
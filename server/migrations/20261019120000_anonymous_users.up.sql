@@ -0,0 +1,7 @@
+-- Your SQL goes here
+
+alter table project_settings add column if not exists anonymous_ttl_days integer default 30;
+
+alter table oauth_request_state add column if not exists user_id uuid references users(id) on delete cascade;
+
+create index user_provider_idx on users using btree(provider_id);
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+
+alter table project_settings drop column if exists auto_link_verified_email;
+
+drop trigger if exists set_providers on users;
+drop function if exists set_user_providers();
+
+alter table users drop column if exists providers;
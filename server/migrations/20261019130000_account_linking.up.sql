@@ -0,0 +1,31 @@
+-- Your SQL goes here
+
+alter table users add column if not exists providers text[] not null default '{}';
+
+update users
+   set providers = array_remove(array[
+           provider_id
+         , case when exists(select 1 from oauth_data where oauth_data.user_id = users.id and oauth_data.provider = 'google')
+                 and provider_id <> 'google'
+                then 'google'
+            end
+         , case when exists(select 1 from passwords where passwords.user_id = users.id)
+                 and provider_id not in ('password', 'email')
+                then 'password'
+            end
+       ], null);
+
+-- new users start out with the provider they signed up with
+create or replace function set_user_providers() returns trigger as $$
+begin
+    if cardinality(new.providers) = 0 then
+        new.providers := array[new.provider_id];
+    end if;
+    return new;
+end;
+$$ language plpgsql;
+
+create trigger set_providers before insert on users
+   for each row execute procedure set_user_providers();
+
+alter table project_settings add column if not exists auto_link_verified_email boolean not null default false;
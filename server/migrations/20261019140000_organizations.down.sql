@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+
+alter table sessions drop column if exists organization_id;
+
+drop table if exists organization_invitations;
+drop table if exists organization_members;
+drop table if exists organizations;
+
+drop type if exists organization_role;
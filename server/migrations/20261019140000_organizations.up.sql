@@ -0,0 +1,40 @@
+-- Your SQL goes here
+
+-- ordered by privilege, so roles can be compared
+create type organization_role as enum ('member', 'admin', 'owner');
+
+create table if not exists organizations
+	( id uuid primary key default uuid_generate_v4()
+	, project_id uuid not null references projects(id) on delete cascade
+	, name text not null
+	, created_at timestamptz not null default now()
+	, updated_at timestamptz not null default now()
+	);
+
+create index organizations_project_id_idx on organizations(project_id);
+
+select diesel_manage_updated_at('organizations');
+
+create table if not exists organization_members
+	( organization_id uuid not null references organizations(id) on delete cascade
+	, user_id uuid not null references users(id) on delete cascade
+	, role organization_role not null default 'member'
+	, created_at timestamptz not null default now()
+	, primary key (organization_id, user_id)
+	);
+
+create index organization_members_user_id_idx on organization_members(user_id);
+
+create table if not exists organization_invitations
+	( id uuid primary key default uuid_generate_v4()
+	, organization_id uuid not null references organizations(id) on delete cascade
+	, email text not null
+	, role organization_role not null default 'member'
+	, token text not null
+	, invited_by uuid references users(id) on delete set null
+	, expire_at timestamptz not null
+	, created_at timestamptz not null default now()
+	, unique (organization_id, email)
+	);
+
+alter table sessions add column if not exists organization_id uuid references organizations(id) on delete set null;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+drop table if exists user_roles;
+drop table if exists roles;
+drop table if exists permissions;
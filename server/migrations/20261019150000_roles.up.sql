@@ -0,0 +1,32 @@
+-- Your SQL goes here
+
+create table if not exists permissions
+	( id uuid primary key default uuid_generate_v4()
+	, project_id uuid not null references projects(id) on delete cascade
+	, name text not null
+	, description text
+	, created_at timestamptz not null default now()
+	, constraint permissions_project_id_name_key unique (project_id, name)
+	);
+
+create table if not exists roles
+	( id uuid primary key default uuid_generate_v4()
+	, project_id uuid not null references projects(id) on delete cascade
+	, name text not null
+	, description text
+	, permissions text[] not null default '{}'
+	, created_at timestamptz not null default now()
+	, updated_at timestamptz not null default now()
+	, constraint roles_project_id_name_key unique (project_id, name)
+	);
+
+select diesel_manage_updated_at('roles');
+
+create table if not exists user_roles
+	( user_id uuid not null references users(id) on delete cascade
+	, role_id uuid not null references roles(id) on delete cascade
+	, created_at timestamptz not null default now()
+	, primary key (user_id, role_id)
+	);
+
+create index user_roles_role_id_idx on user_roles(role_id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+
+alter table users drop column if exists app_metadata;
+
+alter index if exists users_user_metadata_idx rename to user_data_idx;
+alter table users rename column user_metadata to data;
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+
+-- existing data was writable by the user, so it stays user metadata
+alter table users rename column data to user_metadata;
+alter index if exists user_data_idx rename to users_user_metadata_idx;
+
+alter table users add column if not exists app_metadata jsonb not null default '{}'::jsonb;
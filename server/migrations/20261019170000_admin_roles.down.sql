@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+
+drop table if exists admin_invitations;
+drop table if exists admin_roles;
+
+drop type if exists admin_role;
@@ -0,0 +1,29 @@
+-- Your SQL goes here
+
+-- ordered by privilege, so roles can be compared. super admins are
+-- identified by the "Admin" trait and have access to every project
+create type admin_role as enum ('viewer', 'editor', 'owner', 'super_admin');
+
+create table if not exists admin_roles
+	( user_id uuid not null references users(id) on delete cascade
+	, project_id uuid not null references projects(id) on delete cascade
+	, role admin_role not null
+	, created_at timestamptz not null default now()
+	, primary key (user_id, project_id)
+	, constraint admin_roles_role_check check (role <> 'super_admin')
+	);
+
+create index admin_roles_project_id_idx on admin_roles(project_id);
+
+create table if not exists admin_invitations
+	( id uuid primary key default uuid_generate_v4()
+	, email text not null
+	-- null for super admin invitations
+	, project_id uuid references projects(id) on delete cascade
+	, role admin_role not null
+	, token text not null
+	, invited_by uuid references users(id) on delete set null
+	, expire_at timestamptz not null
+	, created_at timestamptz not null default now()
+	, constraint admin_invitations_project_check check ((role = 'super_admin') = (project_id is null))
+	);
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+
+create table if not exists service_accounts
+	( id uuid primary key default uuid_generate_v4()
+	, project_id uuid not null references projects(id) on delete cascade
+	, name text not null
+	, secret text not null
+	, scopes text[] not null default '{}'
+	, created_at timestamptz not null default now()
+	);
+
+create index service_accounts_project_id_idx on service_accounts(project_id);
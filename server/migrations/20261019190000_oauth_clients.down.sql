@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+drop table if exists oauth_consents;
+drop table if exists oauth_authorizations;
+drop table if exists oauth_clients;
@@ -0,0 +1,37 @@
+-- Your SQL goes here
+
+create table if not exists oauth_clients
+	( id uuid primary key default uuid_generate_v4()
+	, project_id uuid not null references projects(id) on delete cascade
+	, name text not null
+	-- public clients (e.g. single page apps) don't have a secret
+	, secret text
+	, redirect_uris text[] not null default '{}'
+	, created_at timestamptz not null default now()
+	);
+
+create index oauth_clients_project_id_idx on oauth_clients(project_id);
+
+create table if not exists oauth_authorizations
+	( id uuid primary key default uuid_generate_v4()
+	, client_id uuid not null references oauth_clients(id) on delete cascade
+	, redirect_uri text not null
+	, scopes text[] not null default '{}'
+	, state text
+	, nonce text
+	, code_challenge text not null
+	-- set once the user approved the request
+	, user_id uuid references users(id) on delete cascade
+	, session_id uuid references sessions(id) on delete cascade
+	, code text
+	, expire_at timestamptz not null
+	, created_at timestamptz not null default now()
+	);
+
+create table if not exists oauth_consents
+	( user_id uuid not null references users(id) on delete cascade
+	, client_id uuid not null references oauth_clients(id) on delete cascade
+	, scopes text[] not null default '{}'
+	, created_at timestamptz not null default now()
+	, primary key (user_id, client_id)
+	);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+drop index if exists users_tokens_valid_after_idx;
+alter table projects drop column if exists tokens_valid_after;
+alter table users drop column if exists tokens_valid_after;
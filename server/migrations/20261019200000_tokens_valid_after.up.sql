@@ -0,0 +1,8 @@
+-- Your SQL goes here
+
+-- access tokens issued before the watermark are rejected
+alter table users add column if not exists tokens_valid_after timestamptz;
+alter table projects add column if not exists tokens_valid_after timestamptz;
+
+create index users_tokens_valid_after_idx on users(tokens_valid_after)
+ where tokens_valid_after is not null;
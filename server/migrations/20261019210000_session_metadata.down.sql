@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+
+drop index if exists session_user_idx;
+alter table sessions drop column if exists last_refreshed_at;
+alter table sessions drop column if exists device_name;
+alter table sessions drop column if exists ip;
+alter table sessions drop column if exists user_agent;
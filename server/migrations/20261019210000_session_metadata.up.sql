@@ -0,0 +1,8 @@
+-- Your SQL goes here
+
+alter table sessions add column if not exists user_agent text;
+alter table sessions add column if not exists ip text;
+alter table sessions add column if not exists device_name text;
+alter table sessions add column if not exists last_refreshed_at timestamptz;
+
+create index if not exists session_user_idx on sessions(user_id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+
+alter table project_settings drop column if exists session_limit_mode;
+alter table project_settings drop column if exists max_sessions;
+
+drop type if exists session_limit_mode;
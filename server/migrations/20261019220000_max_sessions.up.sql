@@ -0,0 +1,6 @@
+-- Your SQL goes here
+
+create type session_limit_mode as enum('evict', 'reject');
+
+alter table project_settings add column if not exists max_sessions integer;
+alter table project_settings add column if not exists session_limit_mode session_limit_mode not null default 'evict';
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+
+create table if not exists dpop_proofs
+	( jkt text not null
+	, jti text not null
+	, expire_at timestamptz not null
+	, primary key (jkt, jti)
+	);
+
+create index if not exists dpop_proofs_expire_at_idx on dpop_proofs(expire_at);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+
+alter table sessions drop column if exists cookie_hash;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+
+alter table sessions add column if not exists cookie_hash text;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+
+alter table project_settings drop column if exists cors_origins;
+alter table project_settings drop column if exists cors_methods;
+alter table project_settings drop column if exists cors_headers;
+alter table project_settings drop column if exists cors_max_age;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+
+alter table project_settings add column if not exists cors_origins text[] not null default '{}';
+alter table project_settings add column if not exists cors_methods text[] not null default '{GET,POST,OPTIONS}';
+alter table project_settings add column if not exists cors_headers text[] not null default '{Content-Type,Vulpo-Project,Authorization,DPoP,X-CSRF-Token}';
+alter table project_settings add column if not exists cors_max_age integer;
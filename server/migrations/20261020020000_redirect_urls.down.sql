@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+alter table project_settings drop column if exists redirect_urls;
+alter table oauth_request_state drop column if exists redirect_url;
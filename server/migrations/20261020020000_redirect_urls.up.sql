@@ -0,0 +1,4 @@
+-- Your SQL goes here
+
+alter table project_settings add column if not exists redirect_urls text[] not null default '{}';
+alter table oauth_request_state add column if not exists redirect_url text;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+alter table project_settings drop column if exists hosted_logo_url;
+alter table project_settings drop column if exists hosted_primary_color;
+alter table project_settings drop column if exists hosted_background_color;
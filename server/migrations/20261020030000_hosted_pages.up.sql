@@ -0,0 +1,5 @@
+-- Your SQL goes here
+
+alter table project_settings add column if not exists hosted_logo_url text;
+alter table project_settings add column if not exists hosted_primary_color text;
+alter table project_settings add column if not exists hosted_background_color text;
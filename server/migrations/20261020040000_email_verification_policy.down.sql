@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+
+alter table verify_email drop column if exists email;
+
+drop index if exists verify_email_created_at_idx;
+
+alter table project_settings drop column if exists email_verification;
+
+drop type if exists email_verification_policy;
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+
+create type email_verification_policy as enum('off', 'block', 'mark');
+
+alter table project_settings add column if not exists email_verification email_verification_policy not null default 'off';
+
+create index if not exists verify_email_created_at_idx on verify_email(user_id, created_at);
+
+-- tokens are only valid for the address they were sent to, the
+-- existing tokens don't have one
+delete from verify_email;
+alter table verify_email add column if not exists email text not null;
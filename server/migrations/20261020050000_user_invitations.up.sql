@@ -0,0 +1,15 @@
+-- Your SQL goes here
+
+-- invited users are created in the "set_password" state, the invitation is
+-- removed once it was accepted
+create table if not exists user_invitations
+	( id uuid primary key default uuid_generate_v4()
+	, user_id uuid not null unique references users(id) on delete cascade
+	, project_id uuid not null references projects(id) on delete cascade
+	, token text not null
+	, invited_by uuid references users(id) on delete set null
+	, expire_at timestamptz not null
+	, created_at timestamptz not null default now()
+	);
+
+create index user_invitations_project_id_idx on user_invitations(project_id);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+alter table project_settings drop column if exists email_domains_allow;
+alter table project_settings drop column if exists email_domains_deny;
+alter table project_settings drop column if exists block_disposable_emails;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+
+alter table project_settings add column if not exists email_domains_allow text[] not null default '{}';
+alter table project_settings add column if not exists email_domains_deny text[] not null default '{}';
+alter table project_settings add column if not exists block_disposable_emails boolean not null default false;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+drop table if exists captcha_settings;
+drop type if exists captcha_provider;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+
+create type captcha_provider as enum('turnstile', 'hcaptcha', 'recaptcha');
+
+create table if not exists captcha_settings
+	( project_id uuid primary key references projects(id) on delete cascade
+	, enabled boolean not null default true
+	, provider captcha_provider not null
+	, site_key text not null
+	, secret text not null
+	);
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+
+alter table project_settings drop column if exists reauthentication_max_age;
+
+alter table sessions drop column if exists reauthentication_attempts;
+alter table sessions drop column if exists auth_methods;
+alter table sessions drop column if exists auth_time;
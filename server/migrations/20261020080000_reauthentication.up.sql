@@ -0,0 +1,8 @@
+-- Your SQL goes here
+
+-- sessions that predate auth_time have to re-authenticate for sensitive operations
+alter table sessions add column if not exists auth_time timestamptz;
+alter table sessions add column if not exists auth_methods text[] not null default '{}';
+alter table sessions add column if not exists reauthentication_attempts integer not null default 0;
+
+alter table project_settings add column if not exists reauthentication_max_age integer default 600;
@@ -0,0 +1,21 @@
+-- This file should undo anything in `up.sql`
+
+alter table project_settings drop column if exists deletion_email;
+alter table project_settings drop column if exists deletion_grace_days;
+
+-- enum values can't be dropped, users that wait for the purge stay disabled
+update users
+   set state = 'disabled'
+ where state = 'deleted';
+
+alter table users drop column if exists state_before_delete;
+alter table users drop column if exists deleted_at;
+
+alter type user_state rename to user_state_old;
+create type user_state as enum('active', 'disabled', 'set_password');
+
+alter table users alter column state drop default;
+alter table users alter column state type user_state using state::text::user_state;
+alter table users alter column state set default 'active';
+
+drop type user_state_old;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+
+alter type user_state add value if not exists 'deleted';
+
+-- deleted users can be restored until they are purged after the project's
+-- deletion_grace_days
+alter table users add column if not exists deleted_at timestamptz;
+
+-- restored users get their state back, e.g. users that were disabled before
+alter table users add column if not exists state_before_delete user_state;
+
+-- null removes users right away
+alter table project_settings add column if not exists deletion_grace_days integer;
+alter table project_settings add column if not exists deletion_email boolean not null default false;
@@ -22,6 +22,7 @@ pub async fn create_admin(
 
     let admin = NewAdmin { password, ..body };
 
-    let id = Admin::create(&pool, admin, project_id).await?;
+    let traits = [String::from("Admin")];
+    let id = Admin::create(&pool, admin, project_id, &traits).await?;
     Ok(id)
 }
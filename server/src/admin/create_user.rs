@@ -1,4 +1,4 @@
-use crate::admin::data::{Admin, NewUser};
+use crate::admin::data::{Admin, AdminRole, NewUser};
 use crate::password;
 use crate::password::data::Password;
 use crate::project::data::Project as ProjectData;
@@ -30,7 +30,10 @@ pub async fn create_user(pool: &Db, user: &NewUser) -> Result<Uuid, ApiError> {
 }
 
 #[post("/create_user", format = "json", data = "<body>")]
-pub async fn handler(pool: Db, body: Json<NewUser>, _admin: Admin) -> Result<Json<User>, ApiError> {
+pub async fn handler(pool: Db, body: Json<NewUser>, admin: Admin) -> Result<Json<User>, ApiError> {
+    admin
+        .require_users(&pool, &body.project_id, AdminRole::Editor)
+        .await?;
     let user_id = create_user(&pool, &body).await?;
     let user = User::get_by_id(&pool, &user_id, &body.project_id)
         .await?
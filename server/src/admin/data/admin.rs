@@ -1,11 +1,12 @@
 use crate::api_key::verify::verify_api_key;
-use crate::keys::data::{NewProjectKeys, ProjectKeys};
+use crate::keys::data::NewProjectKeys;
+use crate::project::data::Project as ProjectData;
 use crate::project::Project;
 use crate::session::data::{AccessToken, Claims};
 use vulpo_auth_types::error::ApiError;
 
 use futures::join;
-use rocket::http::Status;
+use rocket::http::{Method, Status};
 use rocket::request::Outcome;
 use rocket::request::{self, FromRequest, Request};
 use serde::{Deserialize, Serialize};
@@ -42,20 +43,137 @@ pub struct NewUser {
     pub project_id: Uuid,
     pub password: Option<String>,
     pub display_name: Option<String>,
-    pub data: Option<Value>,
+    pub user_metadata: Option<Value>,
+    pub app_metadata: Option<Value>,
     pub provider_id: String,
 }
 
+#[derive(sqlx::Type, PartialEq, PartialOrd, Debug, Copy, Clone, Deserialize, Serialize)]
+#[sqlx(type_name = "admin_role")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AdminRole {
+    /// Read-only access, e.g. for support
+    Viewer,
+    /// Manage users, templates and settings
+    Editor,
+    /// Editor plus deleting the project, OAuth config and inviting admins
+    Owner,
+    /// Access to every project, granted through the "Admin" trait
+    SuperAdmin,
+}
+
+#[derive(Debug)]
+pub struct ProjectRole {
+    pub project_id: Uuid,
+    pub role: AdminRole,
+}
+
 #[derive(Debug)]
-pub struct Admin(Claims);
+pub struct Admin {
+    claims: Claims,
+    roles: Vec<ProjectRole>,
+}
 
 impl Admin {
-    pub async fn create(pool: &PgPool, body: NewAdmin, project: &Uuid) -> sqlx::Result<Uuid> {
+    pub fn user_id(&self) -> Uuid {
+        self.claims.sub
+    }
+
+    pub fn is_super_admin(&self) -> bool {
+        self.claims.traits.contains(&String::from("Admin"))
+    }
+
+    /// The role of the admin for the given project, `None` when the
+    /// admin has no access to the project
+    pub fn role(&self, project_id: &Uuid) -> Option<AdminRole> {
+        if self.is_super_admin() {
+            return Some(AdminRole::SuperAdmin);
+        }
+
+        self.roles
+            .iter()
+            .find(|role| &role.project_id == project_id)
+            .map(|role| role.role)
+    }
+
+    pub fn require(&self, project_id: &Uuid, role: AdminRole) -> Result<(), ApiError> {
+        if self.role(project_id) >= Some(role) {
+            Ok(())
+        } else {
+            Err(ApiError::AdminAuth)
+        }
+    }
+
+    pub fn require_super_admin(&self) -> Result<(), ApiError> {
+        if self.is_super_admin() {
+            Ok(())
+        } else {
+            Err(ApiError::AdminAuth)
+        }
+    }
+
+    /// Same as `require`, for routes that change the users of a project.
+    /// Users of the admin project are admins and their traits grant super
+    /// admin access, only super admins can change them
+    pub async fn require_users(
+        &self,
+        pool: &PgPool,
+        project_id: &Uuid,
+        role: AdminRole,
+    ) -> Result<(), ApiError> {
+        self.require(project_id, role)?;
+
+        if self.is_super_admin() {
+            return Ok(());
+        }
+
+        if ProjectData::is_admin(pool, project_id).await? {
+            return Err(ApiError::AdminAuth);
+        }
+
+        Ok(())
+    }
+
+    /// Same as `require_users`, for routes that only receive a user id
+    pub async fn require_user(
+        &self,
+        pool: &PgPool,
+        user_id: &Uuid,
+        role: AdminRole,
+    ) -> Result<(), ApiError> {
+        let project_id = Admin::user_project(pool, user_id)
+            .await?
+            .ok_or(ApiError::NotFound)?;
+
+        self.require_users(pool, &project_id, role).await
+    }
+
+    pub async fn roles(pool: &PgPool, user_id: &Uuid) -> sqlx::Result<Vec<ProjectRole>> {
+        sqlx::query_file_as!(ProjectRole, "src/admin/sql/get_admin_roles.sql", user_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn user_project(pool: &PgPool, user_id: &Uuid) -> sqlx::Result<Option<Uuid>> {
+        sqlx::query_file!("src/admin/sql/get_user_project.sql", user_id)
+            .fetch_optional(pool)
+            .await
+            .map(|row| row.map(|row| row.project_id))
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        body: NewAdmin,
+        project: &Uuid,
+        traits: &[String],
+    ) -> sqlx::Result<Uuid> {
         let row = sqlx::query_file!(
             "src/admin/sql/create_admin.sql",
             &body.email,
             &body.password,
-            &project
+            &project,
+            traits,
         )
         .fetch_one(pool)
         .await?;
@@ -114,13 +232,15 @@ impl Admin {
     }
 
     pub async fn create_user(pool: &PgPool, user: &NewUser) -> Result<Uuid, ApiError> {
-        let data = user.data.clone().unwrap_or(json!({}));
+        let user_metadata = user.user_metadata.clone().unwrap_or(json!({}));
+        let app_metadata = user.app_metadata.clone().unwrap_or(json!({}));
 
         let row = sqlx::query_file!(
             "src/admin/sql/create_user.sql",
             user.email,
             user.display_name,
-            data,
+            user_metadata,
+            app_metadata,
             user.provider_id,
             user.project_id,
         )
@@ -168,22 +288,10 @@ impl<'r> FromRequest<'r> for Admin {
         };
 
         let claims = match token_type.to_lowercase().as_str() {
-            "bearer" => {
-                let key = match ProjectKeys::get_public_key(&db, &project.id).await {
-                    Ok(key) => key,
-                    Err(_) => {
-                        return Outcome::Failure((
-                            Status::InternalServerError,
-                            ApiError::AuthTokenMissing,
-                        ));
-                    }
-                };
-
-                match AccessToken::decode(token, &key) {
-                    Ok(token) => token,
-                    Err(_) => {
-                        return Outcome::Failure((Status::Unauthorized, ApiError::BadRequest));
-                    }
+            "bearer" | "dpop" => {
+                match AccessToken::verify(req, &db, &project.id, token_type, token).await {
+                    Ok(claims) => claims,
+                    Err(failure) => return Outcome::Failure(failure),
                 }
             }
 
@@ -208,10 +316,34 @@ impl<'r> FromRequest<'r> for Admin {
             _ => return Outcome::Failure((Status::BadRequest, ApiError::AuthTokenMissing)),
         };
 
-        if !claims.traits.contains(&String::from("Admin")) {
+        let roles = match Admin::roles(&db, &claims.sub).await {
+            Ok(roles) => roles,
+            Err(_) => {
+                return Outcome::Failure((Status::InternalServerError, ApiError::AdminAuth));
+            }
+        };
+
+        let admin = Admin { claims, roles };
+
+        if !admin.is_super_admin() && admin.roles.is_empty() {
             return Outcome::Failure((Status::Forbidden, ApiError::AdminAuth));
         }
 
-        Outcome::Success(Admin(claims))
+        // routes that receive the project as query parameter are checked
+        // here, routes that receive it in the body call `Admin::require`
+        let required = match req.method() {
+            Method::Get => AdminRole::Viewer,
+            _ => AdminRole::Editor,
+        };
+
+        for name in ["project", "project_id"] {
+            if let Some(Ok(project_id)) = req.query_value::<Uuid>(name) {
+                if admin.require(&project_id, required).is_err() {
+                    return Outcome::Failure((Status::Forbidden, ApiError::AdminAuth));
+                }
+            }
+        }
+
+        Outcome::Success(admin)
     }
 }
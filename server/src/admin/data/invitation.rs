@@ -0,0 +1,51 @@
+use crate::admin::data::AdminRole;
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub struct AdminInvitation {
+    pub id: Uuid,
+    pub email: String,
+    pub token: String,
+    pub expire_at: DateTime<Utc>,
+}
+
+impl AdminInvitation {
+    pub async fn create(
+        pool: &PgPool,
+        email: &str,
+        project_id: Option<Uuid>,
+        role: AdminRole,
+        hashed_token: &str,
+        invited_by: &Uuid,
+        expire_at: &DateTime<Utc>,
+    ) -> sqlx::Result<Uuid> {
+        sqlx::query_file!(
+            "src/admin/sql/create_invitation.sql",
+            email,
+            project_id,
+            role as AdminRole,
+            hashed_token,
+            invited_by,
+            expire_at,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|row| row.id)
+    }
+
+    pub async fn get(pool: &PgPool, id: &Uuid) -> sqlx::Result<Option<AdminInvitation>> {
+        sqlx::query_file_as!(AdminInvitation, "src/admin/sql/get_invitation.sql", id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn accept(pool: &PgPool, id: &Uuid, user_id: &Uuid) -> sqlx::Result<()> {
+        sqlx::query_file!("src/admin/sql/accept_invitation.sql", id, user_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
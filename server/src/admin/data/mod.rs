@@ -1,2 +1,5 @@
 mod admin;
-pub use admin::{Admin, NewAdmin, NewProject, NewUser, PartialProject};
+mod invitation;
+
+pub use admin::{Admin, AdminRole, NewAdmin, NewProject, NewUser, PartialProject};
+pub use invitation::AdminInvitation;
@@ -0,0 +1,161 @@
+use crate::admin::data::{Admin, AdminInvitation, AdminRole, NewAdmin};
+use crate::crypto::Token;
+use crate::password::data::{Password, PasswordAlg};
+use crate::password::validate_password_length;
+use crate::settings::data::ProjectEmail;
+use crate::template::{Template, Templates};
+use crate::user::data::User;
+
+use chrono::{Duration, Utc};
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+const EXPIRE_IN_DAYS: i64 = 7;
+
+#[derive(Deserialize)]
+pub struct InviteAdmin {
+    pub email: String,
+    /// Required for all roles but `super_admin`
+    pub project: Option<Uuid>,
+    pub role: AdminRole,
+}
+
+#[derive(Serialize)]
+pub struct InviteResponse {
+    pub id: Uuid,
+}
+
+pub async fn invite(pool: &Db, admin: &Admin, body: InviteAdmin) -> Result<Uuid, ApiError> {
+    match (body.role, body.project) {
+        (AdminRole::SuperAdmin, None) => admin.require_super_admin()?,
+        // owners can invite admins up to their own role
+        (role, Some(project_id)) if role != AdminRole::SuperAdmin => {
+            admin.require(&project_id, AdminRole::Owner)?;
+            admin.require(&project_id, role)?;
+        }
+        _ => return Err(ApiError::BadRequest),
+    };
+
+    let admin_project = Admin::get_project(&pool)
+        .await?
+        .ok_or(ApiError::ProjectNotFound)?;
+
+    let inviter = User::get_by_id(&pool, &admin.user_id(), &admin_project)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let email = body.email.trim().to_lowercase();
+    let token = Token::create();
+    let hashed_token = Token::hash(&token)?;
+    let expire_at = Utc::now() + Duration::days(EXPIRE_IN_DAYS);
+
+    let id = AdminInvitation::create(
+        &pool,
+        &email,
+        body.project,
+        body.role,
+        &hashed_token,
+        &inviter.id,
+        &expire_at,
+    )
+    .await?;
+
+    let settings =
+        ProjectEmail::from_project_template(&pool, &admin_project, Templates::AdminInvite).await?;
+
+    let link: String = format!(
+        "{}{}?id={}&token={}",
+        settings.domain, settings.redirect_to, id, token
+    );
+
+    let ctx = json!({
+        "href": link,
+        "project": settings.name.clone(),
+        "expire_in": EXPIRE_IN_DAYS,
+    });
+
+    let email = Template::create_email(
+        &pool,
+        &admin_project,
+        &inviter.device_languages,
+        &email,
+        &ctx,
+        &settings,
+        Templates::AdminInvite,
+    )
+    .await?;
+
+    email.send(settings.email).await?;
+
+    Ok(id)
+}
+
+#[post("/invite", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<InviteAdmin>,
+    admin: Admin,
+) -> Result<Json<InviteResponse>, ApiError> {
+    let id = invite(&pool, &admin, body.into_inner()).await?;
+    Ok(Json(InviteResponse { id }))
+}
+
+#[derive(Deserialize)]
+pub struct AcceptInvitation {
+    pub id: Uuid,
+    pub token: String,
+    /// Used to create the dashboard account when the invited email
+    /// address doesn't have one yet
+    pub password: Option<String>,
+}
+
+pub async fn accept(pool: &Db, body: AcceptInvitation) -> Result<(), ApiError> {
+    let invitation = AdminInvitation::get(&pool, &body.id)
+        .await?
+        .ok_or(ApiError::TokenNotFound)?;
+
+    if !Token::verify(&body.token, &invitation.token)? {
+        return Err(ApiError::TokenInvalid);
+    }
+
+    if Utc::now() > invitation.expire_at {
+        return Err(ApiError::TokenExpired);
+    }
+
+    let admin_project = Admin::get_project(&pool)
+        .await?
+        .ok_or(ApiError::ProjectNotFound)?;
+
+    let user_id = match User::get_by_email(&pool, &invitation.email, &admin_project).await? {
+        Some(user) => user.id,
+        None => {
+            let password = body.password.ok_or(ApiError::PasswordMinLength)?;
+            validate_password_length(&password)?;
+
+            let password = Password::hash(&password, &PasswordAlg::Argon2id)
+                .map_err(|_| ApiError::InternalServerError)?;
+
+            let admin = NewAdmin {
+                email: invitation.email.clone(),
+                password,
+            };
+
+            Admin::create(&pool, admin, &admin_project, &[]).await?
+        }
+    };
+
+    AdminInvitation::accept(&pool, &invitation.id, &user_id).await?;
+
+    Ok(())
+}
+
+#[post("/invite/accept", format = "json", data = "<body>")]
+pub async fn accept_handler(pool: Db, body: Json<AcceptInvitation>) -> Result<Status, ApiError> {
+    accept(&pool, body.into_inner()).await?;
+    Ok(Status::Ok)
+}
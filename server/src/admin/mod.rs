@@ -13,6 +13,7 @@ mod create;
 mod create_user;
 pub mod data;
 mod has_admin;
+mod invite;
 mod project;
 
 pub use create::create_admin;
@@ -88,6 +89,8 @@ pub fn routes() -> Vec<Route> {
     routes![
         create_user::handler,
         has_admin::handler,
+        invite::handler,
+        invite::accept_handler,
         project::has,
         project::create,
         project::list,
@@ -4,7 +4,7 @@ use crate::keys::data::ProjectKeys;
 
 use rocket::serde::json::Json;
 use rocket::State;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use sqlx::PgPool;
 use uuid::Uuid;
 use vulpo_auth_types::error::ApiError;
@@ -26,11 +26,6 @@ pub async fn has(pool: Db) -> Result<Json<Project>, ApiError> {
     Ok(Json(project))
 }
 
-#[derive(Deserialize)]
-pub struct CreateAdminProject {
-    pub host: String,
-}
-
 pub async fn create_admin_project(
     pool: &PgPool,
     host: &str,
@@ -73,14 +68,16 @@ pub async fn create(
     pool: Db,
     body: Json<NewProject>,
     secrets: &State<Secrets>,
-    _admin: Admin,
+    admin: Admin,
 ) -> Result<Json<[Uuid; 1]>, ApiError> {
+    admin.require_super_admin()?;
     let id = create_project(&pool, body.into_inner(), &secrets.passphrase).await?;
     Ok(Json([id]))
 }
 
 #[get("/project/list")]
-pub async fn list(pool: Db, _admin: Admin) -> Result<Json<Vec<PartialProject>>, ApiError> {
-    let projects = Admin::project_list(&pool).await?;
+pub async fn list(pool: Db, admin: Admin) -> Result<Json<Vec<PartialProject>>, ApiError> {
+    let mut projects = Admin::project_list(&pool).await?;
+    projects.retain(|project| admin.role(&project.id).is_some());
     Ok(Json(projects))
 }
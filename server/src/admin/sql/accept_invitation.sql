@@ -0,0 +1,17 @@
+with invitation as (
+	delete from admin_invitations
+	 where id = $1
+	returning project_id, role
+), project_role as (
+	insert into admin_roles(user_id, project_id, role)
+	select $2, project_id, role
+	  from invitation
+	 where invitation.role <> 'super_admin'
+	on conflict (user_id, project_id)
+	   do update set role = greatest(admin_roles.role, excluded.role)
+)
+update users
+   set traits = array_append(traits, 'Admin')
+ where id = $2
+   and exists (select 1 from invitation where invitation.role = 'super_admin')
+   and not 'Admin' = any(traits)
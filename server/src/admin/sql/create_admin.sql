@@ -4,13 +4,13 @@ with insert_admin as (
           ( email
           , project_id
           , traits
-          , data
+          , user_metadata
           , provider_id
           )
      values
           ( $1
           , $3
-          , '{ "Admin" }'
+          , $4
           , '{}'::jsonb
           , 'email'
           )
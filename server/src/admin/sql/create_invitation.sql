@@ -0,0 +1,3 @@
+insert into admin_invitations(email, project_id, role, token, invited_by, expire_at)
+values($1, $2, $3, $4, $5, $6)
+returning id
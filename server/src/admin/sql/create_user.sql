@@ -2,17 +2,19 @@
 insert into users
     ( email
     , display_name
-    , data
+    , user_metadata
+    , app_metadata
     , provider_id
     , project_id
     , state
     )
 select $1 as "email"
      , $2 as "display_name"
-     , $3::jsonb as "data"
-     , $4 as "provider_id"
-     , $5 as "project_id"
-     , case when $4 = 'link'
+     , $3::jsonb as "user_metadata"
+     , $4::jsonb as "app_metadata"
+     , $5 as "provider_id"
+     , $6 as "project_id"
+     , case when $5 = 'link'
             then 'active'::user_state
             else 'set_password'::user_state
         end as "state"
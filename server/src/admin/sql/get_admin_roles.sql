@@ -0,0 +1,4 @@
+select admin_roles.project_id
+     , admin_roles.role as "role: AdminRole"
+  from admin_roles
+ where admin_roles.user_id = $1
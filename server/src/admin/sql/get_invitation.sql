@@ -0,0 +1,6 @@
+select id
+     , email
+     , token
+     , expire_at
+  from admin_invitations
+ where id = $1
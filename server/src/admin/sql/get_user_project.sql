@@ -0,0 +1,3 @@
+select project_id
+  from users
+ where id = $1
@@ -0,0 +1,36 @@
+use crate::user::data::User;
+
+use rocket::fairing::AdHoc;
+use rocket::tokio::time::{interval, Duration};
+use sqlx::PgPool;
+use tracing::{error, info};
+
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Periodically removes anonymous users that are older than the project's
+/// `anonymous_ttl_days` and don't have an active session anymore
+pub fn fairing() -> AdHoc {
+    AdHoc::on_liftoff("Anonymous User Cleanup", |rocket| {
+        Box::pin(async move {
+            let pool = match rocket.state::<PgPool>() {
+                Some(pool) => pool.clone(),
+                None => {
+                    error!("Anonymous user cleanup: database pool not found");
+                    return;
+                }
+            };
+
+            rocket::tokio::spawn(async move {
+                let mut timer = interval(CLEANUP_INTERVAL);
+                loop {
+                    timer.tick().await;
+                    match User::remove_stale_anonymous(&pool).await {
+                        Ok(0) => {}
+                        Ok(count) => info!("Removed {} stale anonymous users", count),
+                        Err(err) => error!("Failed to remove anonymous users: {:?}", err),
+                    }
+                }
+            });
+        })
+    })
+}
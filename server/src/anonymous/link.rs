@@ -0,0 +1,161 @@
+use crate::oauth::google::{get_authorize_url, GetAuthUrlPayload, GetAuthUrlResponse};
+use crate::password::data::Password;
+use crate::password::validate_password_length;
+use crate::passwordless::request_passwordless::{
+    send_passwordless, PasswordlessResponse, RequestPasswordless,
+};
+use crate::project::data::Project as ProjectData;
+use crate::project::data::{EmailDomains, Flags, RedirectUrls};
+use crate::project::Project;
+use crate::session::data::{AccessToken, SessionMetadata};
+use crate::user::data::{UpgradeAnonymous, User};
+use crate::user::verify_email::send as send_email_verification;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+async fn get_anonymous(pool: &Db, user_id: &Uuid, project_id: &Uuid) -> Result<User, ApiError> {
+    let user = User::get_by_id(&pool, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if !user.is_anonymous() {
+        return Err(ApiError::Forbidden);
+    }
+
+    Ok(user)
+}
+
+#[derive(Deserialize)]
+pub struct LinkPassword {
+    pub email: String,
+    pub password: String,
+
+    /// Link of the verification email, has to be on the project's allow-list
+    pub redirect_url: Option<String>,
+}
+
+pub async fn link_password(
+    pool: &Db,
+    user_id: Uuid,
+    project_id: Uuid,
+    body: LinkPassword,
+) -> Result<(), ApiError> {
+    validate_password_length(&body.password)?;
+    RedirectUrls::verify(pool, &project_id, body.redirect_url.as_deref()).await?;
+
+    let user = get_anonymous(&pool, &user_id, &project_id).await?;
+    let email = body.email.trim().to_lowercase();
+    if User::get_by_email(&pool, &email, &project_id)
+        .await?
+        .is_some()
+    {
+        return Err(ApiError::UserExists);
+    }
+
+    EmailDomains::verify(&pool, &project_id, &email).await?;
+
+    let alg = ProjectData::password_alg(&pool, &project_id).await?;
+    Password::create_password(&pool, &user.id, &body.password, &alg, &project_id).await?;
+
+    let upgrade = UpgradeAnonymous {
+        email: email.clone(),
+        email_verified: false,
+        provider_id: String::from("password"),
+        display_name: None,
+        photo_url: None,
+    };
+
+    User::upgrade_anonymous(&pool, &user.id, &upgrade)
+        .await?
+        .ok_or(ApiError::Forbidden)?;
+
+    let verify = Flags::has_flags(&pool, &project_id, &[Flags::VerifyEmail]).await;
+
+    if verify.is_ok() {
+        send_email_verification(
+            pool,
+            &user.id,
+            &project_id,
+            &email,
+            body.redirect_url.as_deref(),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[post("/link/password", format = "json", data = "<body>")]
+pub async fn password_handler(
+    pool: Db,
+    body: Json<LinkPassword>,
+    project: Project,
+    token: AccessToken,
+) -> Result<Status, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::EmailAndPassword]).await?;
+    link_password(&pool, token.sub(), project.id, body.into_inner()).await?;
+    Ok(Status::Ok)
+}
+
+pub async fn link_passwordless(
+    pool: &Db,
+    user_id: Uuid,
+    project_id: Uuid,
+    body: RequestPasswordless,
+    metadata: &SessionMetadata,
+) -> Result<PasswordlessResponse, ApiError> {
+    let user = get_anonymous(&pool, &user_id, &project_id).await?;
+
+    let email = body.email.trim().to_lowercase();
+    if User::get_by_email(&pool, &email, &project_id)
+        .await?
+        .is_some()
+    {
+        return Err(ApiError::UserExists);
+    }
+
+    EmailDomains::verify(&pool, &project_id, &email).await?;
+
+    send_passwordless(&pool, body, project_id, Some(user), metadata).await
+}
+
+#[post("/link/passwordless", format = "json", data = "<body>")]
+pub async fn passwordless_handler(
+    pool: Db,
+    body: Json<RequestPasswordless>,
+    project: Project,
+    token: AccessToken,
+    metadata: SessionMetadata,
+) -> Result<Json<PasswordlessResponse>, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::AuthenticationLink]).await?;
+    RedirectUrls::verify(&pool, &project.id, body.redirect_url.as_deref()).await?;
+    let response =
+        link_passwordless(&pool, token.sub(), project.id, body.into_inner(), &metadata).await?;
+    Ok(Json(response))
+}
+
+#[post("/link/google/authorize_url", format = "json", data = "<body>")]
+pub async fn google_handler(
+    pool: Db,
+    body: Json<GetAuthUrlPayload>,
+    project: Project,
+    token: AccessToken,
+) -> Result<Json<GetAuthUrlResponse>, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::OAuthGoogle]).await?;
+    let user = get_anonymous(&pool, &token.sub(), &project.id).await?;
+    RedirectUrls::verify(&pool, &project.id, body.redirect_url.as_deref()).await?;
+    let url = get_authorize_url(
+        &pool,
+        body.request_id,
+        &project.id,
+        Some(user.id),
+        body.redirect_url.as_deref(),
+    )
+    .await?;
+    Ok(Json(GetAuthUrlResponse { url }))
+}
@@ -0,0 +1,16 @@
+mod cleanup;
+mod link;
+mod sign_in;
+
+use rocket::Route;
+
+pub use cleanup::fairing as cleanup;
+
+pub fn routes() -> Vec<Route> {
+    routes![
+        sign_in::handler,
+        link::password_handler,
+        link::passwordless_handler,
+        link::google_handler
+    ]
+}
@@ -0,0 +1,96 @@
+use crate::config::Secrets;
+use crate::keys::data::ProjectKeys;
+use crate::project::data::Flags;
+use crate::project::Project;
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{AccessToken, AuthMethod, Session, SessionMetadata};
+use crate::session::limit::enforce_session_limit;
+use crate::user::data::User;
+
+use chrono::{Duration, Utc};
+use rocket::serde::json::Json;
+use rocket::State;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use vulpo_auth_types::session::SessionResponse;
+use werkbank::rocket::{Cache, Db};
+
+#[derive(Deserialize)]
+pub struct SignInAnonymously {
+    pub session: Uuid,
+    pub public_key: Vec<u8>,
+    pub device_languages: Vec<String>,
+}
+
+pub async fn sign_in(
+    cache: &Cache,
+    pool: &Db,
+    body: SignInAnonymously,
+    project_id: Uuid,
+    passphrase: &str,
+    metadata: &SessionMetadata,
+) -> Result<SessionResponse, ApiError> {
+    AccessToken::check_binding(pool, &project_id, &body.public_key).await?;
+
+    let user = User::create_anonymous(&pool, &project_id, &body.device_languages).await?;
+
+    enforce_session_limit(&pool, &body.session, &user.id, &project_id).await?;
+
+    let session = Session {
+        id: body.session,
+        public_key: body.public_key.to_owned(),
+        user_id: Some(user.id),
+        expire_at: Utc::now() + Duration::days(30),
+        project_id,
+        auth_time: Some(Utc::now()),
+        auth_methods: vec![AuthMethod::Anonymous.to_string()],
+    };
+
+    let session = Session::create(&pool, session, metadata).await?;
+
+    let private_key = ProjectKeys::get_private_key(&cache, &pool, &project_id, &passphrase).await?;
+
+    let exp = Utc::now() + Duration::minutes(15);
+    let access_token = AccessToken::new(&user.id, &user.traits, exp)
+        .with_auth(&session)
+        .bind_to(&pool, &session)
+        .await?
+        .to_jwt(&project_id, &private_key)
+        .map_err(|_| ApiError::InternalServerError)?;
+
+    Ok(SessionResponse {
+        access_token,
+        created: true,
+        user_id: user.id,
+        session: session.id,
+        expire_at: session.expire_at,
+    })
+}
+
+#[post("/", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<SignInAnonymously>,
+    project: Project,
+    secrets: &State<Secrets>,
+    cache: Cache,
+    metadata: SessionMetadata,
+    cookies: SessionCookies<'_>,
+) -> Result<SessionResponse, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::Anonymous]).await?;
+    let mut session = sign_in(
+        &cache,
+        &pool,
+        body.into_inner(),
+        project.id,
+        &secrets.passphrase,
+        &metadata,
+    )
+    .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
+    Ok(session)
+}
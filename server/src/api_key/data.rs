@@ -18,6 +18,8 @@ pub struct ApiKey {
 pub struct ApiKeyToken {
     pub token: String,
     pub expire_at: Option<DateTime<Utc>>,
+    pub user_id: Uuid,
+    pub project_id: Uuid,
 }
 
 impl ApiKey {
@@ -51,9 +53,25 @@ impl ApiKey {
     }
 
     pub async fn get_claims(pool: &PgPool, id: &Uuid) -> sqlx::Result<Claims> {
-        sqlx::query_file_as!(Claims, "src/api_key/sql/get_claims.sql", id)
+        let row = sqlx::query_file!("src/api_key/sql/get_claims.sql", id)
             .fetch_one(pool)
-            .await
+            .await?;
+
+        Ok(Claims {
+            sub: row.sub,
+            exp: row.exp,
+            traits: row.traits,
+            org_id: None,
+            org_role: None,
+            permissions: row.permissions,
+            client_id: None,
+            scope: None,
+            iat: None,
+            cnf: None,
+            email_verified: None,
+            auth_time: None,
+            amr: vec![],
+        })
     }
 
     pub fn parse_token(token: &str) -> Result<(Uuid, String), ApiError> {
@@ -1,5 +1,6 @@
 use crate::api_key::data::ApiKey;
 use crate::crypto::Token;
+use crate::project::Project;
 use crate::session::data::AccessToken;
 use crate::user::data::User;
 
@@ -40,9 +41,12 @@ pub async fn generate_api_key(
 #[post("/generate", format = "json", data = "<body>")]
 pub async fn generate(
     pool: Db,
+    project: Project,
     access_token: AccessToken,
     body: Json<GenerateApiKeyPayload>,
 ) -> Result<Json<ApiKeyResponse>, ApiError> {
+    access_token.require_recent_auth(&pool, &project.id).await?;
+
     let user_id = access_token.sub();
     let (id, api_key) = generate_api_key(&pool, &user_id, body.into_inner()).await?;
     Ok(Json(ApiKeyResponse { id, api_key }))
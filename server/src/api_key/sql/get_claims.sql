@@ -1,7 +1,12 @@
-
 select users.id as "sub"
      , users.traits as "traits"
      , extract(epoch from now() + interval '15 minutes')::numeric::bigint as "exp!"
+     , ( select coalesce(array_agg(distinct permission order by permission), '{}')
+           from user_roles
+           join roles on roles.id = user_roles.role_id
+          cross join unnest(roles.permissions) as permission
+          where user_roles.user_id = users.id
+       ) as "permissions!"
   from api_keys
   join users on users.id = api_keys.user_id
- where api_keys.id = $1
\ No newline at end of file
+ where api_keys.id = $1
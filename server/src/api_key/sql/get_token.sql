@@ -1,5 +1,6 @@
-
 select api_keys.token
      , api_keys.expire_at
+     , api_keys.user_id
+     , api_keys.project_id
   from api_keys
  where id = $1
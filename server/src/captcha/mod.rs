@@ -0,0 +1,150 @@
+//! CAPTCHA verification for the public endpoints that create users or send
+//! emails. Projects enable it in the CAPTCHA settings, clients send the
+//! response of the provider's widget as `captcha_token` in the body or in
+//! the `Vulpo-Captcha` header.
+
+pub mod verifier;
+
+use crate::settings::data::{CaptchaProvider, CaptchaSettings, ProjectCaptcha};
+use verifier::{CaptchaVerifier, SiteVerify, Verifier};
+
+use rocket::request::{FromRequest, Outcome, Request};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use std::convert::Infallible;
+use std::net::IpAddr;
+use std::sync::Arc;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+
+const CAPTCHA_HEADER: &str = "Vulpo-Captcha";
+
+/// Token of the `Vulpo-Captcha` header and the client's IP address, which
+/// is passed on to the provider
+pub struct Captcha {
+    token: Option<String>,
+    remote_ip: Option<IpAddr>,
+    verifier: Verifier,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Captcha {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let verifier = match req.rocket().state::<Verifier>() {
+            Some(verifier) => Arc::clone(verifier),
+            None => Arc::new(SiteVerify::default()),
+        };
+
+        Outcome::Success(Captcha {
+            token: req.headers().get_one(CAPTCHA_HEADER).map(String::from),
+            remote_ip: req.client_ip(),
+            verifier,
+        })
+    }
+}
+
+impl Captcha {
+    /// Passes when the project didn't enable a CAPTCHA, `token` takes
+    /// precedence over the header
+    pub async fn verify(
+        &self,
+        pool: &PgPool,
+        project_id: &Uuid,
+        token: Option<&str>,
+    ) -> Result<(), ApiError> {
+        let settings = match ProjectCaptcha::from_project(pool, project_id).await? {
+            Some(settings) if settings.enabled => settings,
+            _ => return Ok(()),
+        };
+
+        let token = token.or(self.token.as_deref());
+        verify_token(self.verifier.as_ref(), &settings, token, self.remote_ip).await
+    }
+}
+
+pub async fn verify_token(
+    verifier: &(dyn CaptchaVerifier + Send + Sync),
+    settings: &CaptchaSettings,
+    token: Option<&str>,
+    remote_ip: Option<IpAddr>,
+) -> Result<(), ApiError> {
+    let token = match token {
+        Some(token) if !token.is_empty() => token,
+        _ => return Err(ApiError::CaptchaRequired),
+    };
+
+    match verifier.verify(settings, token, remote_ip).await? {
+        true => Ok(()),
+        false => Err(ApiError::CaptchaInvalid),
+    }
+}
+
+/// Script and class of the provider's widget, the hosted pages render it
+/// into their forms. Every widget is told to post its response as
+/// `g-recaptcha-response`, hCaptcha and reCAPTCHA always do.
+pub fn widget(settings: &CaptchaSettings) -> Value {
+    let (script, class) = match settings.provider {
+        CaptchaProvider::Turnstile => (
+            "https://challenges.cloudflare.com/turnstile/v0/api.js",
+            "cf-turnstile",
+        ),
+        CaptchaProvider::Hcaptcha => ("https://js.hcaptcha.com/1/api.js", "h-captcha"),
+        CaptchaProvider::Recaptcha => ("https://www.google.com/recaptcha/api.js", "g-recaptcha"),
+    };
+
+    json!({
+        "script": script,
+        "class": class,
+        "site_key": settings.site_key,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use verifier::MockVerifier;
+
+    fn settings() -> CaptchaSettings {
+        CaptchaSettings {
+            enabled: true,
+            provider: CaptchaProvider::Turnstile,
+            site_key: String::from("site-key"),
+            secret: String::from("secret"),
+        }
+    }
+
+    fn verifier() -> MockVerifier {
+        MockVerifier {
+            secret: String::from("secret"),
+            token: String::from("valid-token"),
+        }
+    }
+
+    #[rocket::async_test]
+    async fn requires_a_token() {
+        let result = verify_token(&verifier(), &settings(), None, None).await;
+        assert_eq!(result, Err(ApiError::CaptchaRequired));
+
+        let result = verify_token(&verifier(), &settings(), Some(""), None).await;
+        assert_eq!(result, Err(ApiError::CaptchaRequired));
+    }
+
+    #[rocket::async_test]
+    async fn rejects_invalid_tokens() {
+        let result = verify_token(&verifier(), &settings(), Some("invalid-token"), None).await;
+        assert_eq!(result, Err(ApiError::CaptchaInvalid));
+
+        let mut settings = settings();
+        settings.secret = String::from("other-secret");
+        let result = verify_token(&verifier(), &settings, Some("valid-token"), None).await;
+        assert_eq!(result, Err(ApiError::CaptchaInvalid));
+    }
+
+    #[rocket::async_test]
+    async fn accepts_valid_tokens() {
+        let result = verify_token(&verifier(), &settings(), Some("valid-token"), None).await;
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::config;
+use crate::settings::data::{CaptchaProvider, CaptchaSettings};
+
+use serde::Deserialize;
+use std::net::IpAddr;
+use std::sync::Arc;
+use vulpo_auth_types::error::ApiError;
+
+#[rocket::async_trait]
+pub trait CaptchaVerifier {
+    /// `Ok(false)` when the provider rejected the token
+    async fn verify(
+        &self,
+        settings: &CaptchaSettings,
+        token: &str,
+        remote_ip: Option<IpAddr>,
+    ) -> Result<bool, ApiError>;
+}
+
+/// Managed by Rocket, the `Captcha` guard verifies the tokens with it
+pub type Verifier = Arc<dyn CaptchaVerifier + Send + Sync>;
+
+pub fn from_config(config: &config::Captcha) -> Verifier {
+    Arc::new(SiteVerify {
+        url: config.verify_url.clone(),
+    })
+}
+
+fn siteverify_url(provider: CaptchaProvider) -> &'static str {
+    match provider {
+        CaptchaProvider::Turnstile => "https://challenges.cloudflare.com/turnstile/v0/siteverify",
+        CaptchaProvider::Hcaptcha => "https://api.hcaptcha.com/siteverify",
+        CaptchaProvider::Recaptcha => "https://www.google.com/recaptcha/api/siteverify",
+    }
+}
+
+#[derive(Deserialize)]
+struct SiteVerifyResponse {
+    success: bool,
+}
+
+/// Turnstile, hCaptcha and reCAPTCHA share the same API, the secret and the
+/// token are posted as a form and the response contains `success`.
+#[derive(Default)]
+pub struct SiteVerify {
+    /// Replaces the provider's siteverify endpoint, set in the server config
+    pub url: Option<String>,
+}
+
+#[rocket::async_trait]
+impl CaptchaVerifier for SiteVerify {
+    async fn verify(
+        &self,
+        settings: &CaptchaSettings,
+        token: &str,
+        remote_ip: Option<IpAddr>,
+    ) -> Result<bool, ApiError> {
+        let url = self
+            .url
+            .as_deref()
+            .unwrap_or_else(|| siteverify_url(settings.provider));
+
+        let mut form = vec![
+            ("secret", settings.secret.clone()),
+            ("response", token.to_string()),
+        ];
+
+        if let Some(ip) = remote_ip {
+            form.push(("remoteip", ip.to_string()));
+        }
+
+        let response = reqwest::Client::new()
+            .post(url)
+            .form(&form)
+            .send()
+            .await
+            .and_then(|res| res.error_for_status())
+            .map_err(|_| ApiError::InternalServerError)?
+            .json::<SiteVerifyResponse>()
+            .await
+            .map_err(|_| ApiError::InternalServerError)?;
+
+        Ok(response.success)
+    }
+}
+
+/// Accepts `token` when it's sent with `secret`
+#[cfg(test)]
+pub struct MockVerifier {
+    pub secret: String,
+    pub token: String,
+}
+
+#[cfg(test)]
+#[rocket::async_trait]
+impl CaptchaVerifier for MockVerifier {
+    async fn verify(
+        &self,
+        settings: &CaptchaSettings,
+        token: &str,
+        _remote_ip: Option<IpAddr>,
+    ) -> Result<bool, ApiError> {
+        Ok(settings.secret == self.secret && token == self.token)
+    }
+}
@@ -34,3 +34,58 @@ pub fn admin(figment: &Figment) -> Option<Admin> {
         Err(_) => None,
     }
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Hosted {
+    /// Public URL of the server, e.g. `https://auth.example.com`. Links in
+    /// emails and OAuth redirects of the hosted pages point to this URL.
+    pub url: String,
+}
+
+pub fn hosted(figment: &Figment) -> Option<Hosted> {
+    figment
+        .clone()
+        .select("hosted")
+        .merge(Env::prefixed("VULPO_HOSTED_").global())
+        .extract::<Option<Hosted>>()
+        .unwrap_or(None)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct PublicUrl {
+    /// Public URL of the server, e.g. `https://auth.example.com`. It is the
+    /// OAuth issuer and the URL DPoP proofs are checked against.
+    pub url: Option<String>,
+
+    /// Takes the URL from the `X-Forwarded-Host` and `Host` headers when
+    /// `url` isn't set. Only enable it behind a proxy that sets the headers,
+    /// clients can send any host.
+    pub trust_forwarded_headers: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct Captcha {
+    /// Replaces the siteverify endpoint of every provider, e.g. with a local
+    /// mock for tests
+    pub verify_url: Option<String>,
+}
+
+pub fn captcha(figment: &Figment) -> Captcha {
+    figment
+        .clone()
+        .select("captcha")
+        .merge(Env::prefixed("VULPO_CAPTCHA_").global())
+        .extract::<Captcha>()
+        .unwrap_or_default()
+}
+
+pub fn public_url(figment: &Figment) -> PublicUrl {
+    figment
+        .clone()
+        .select("server")
+        .merge(Env::prefixed("VULPO_SERVER_").global())
+        .extract::<PublicUrl>()
+        .unwrap_or_default()
+}
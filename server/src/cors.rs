@@ -1,4 +1,4 @@
-use crate::project::data::Project;
+use crate::project::data::{CorsSettings, Project};
 
 use rocket::fairing::{Fairing, Info, Kind};
 use rocket::http::Status;
@@ -10,8 +10,93 @@ use std::io::Cursor;
 use uuid::Uuid;
 use werkbank::rocket::{Cache, Db};
 
+const DEFAULT_METHODS: &str = "POST, GET, OPTIONS";
+const DEFAULT_HEADERS: &str = "Content-Type, Vulpo-Project, Authorization, DPoP, X-CSRF-Token";
+
 pub struct CORS;
 
+/// CORS headers for an origin that is allowed by a project
+struct Allowed {
+    origin: String,
+    methods: Vec<String>,
+    headers: Vec<String>,
+    max_age: Option<i32>,
+}
+
+impl Allowed {
+    fn new(origin: &str, settings: CorsSettings) -> Allowed {
+        Allowed {
+            origin: origin.to_string(),
+            methods: settings.methods,
+            headers: settings.headers,
+            max_age: settings.max_age,
+        }
+    }
+
+    /// Preflight requests don't send `Vulpo-Project`, every project that
+    /// allows the origin is taken into account
+    fn merge(mut self, settings: CorsSettings) -> Allowed {
+        for method in settings.methods {
+            if !self.methods.contains(&method) {
+                self.methods.push(method);
+            }
+        }
+
+        for header in settings.headers {
+            if !self.headers.contains(&header) {
+                self.headers.push(header);
+            }
+        }
+
+        self.max_age = match (self.max_age, settings.max_age) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            _ => None,
+        };
+
+        self
+    }
+}
+
+impl CORS {
+    async fn project(
+        request: &Request<'_>,
+        project: &Uuid,
+        origin: Option<&str>,
+    ) -> Option<Allowed> {
+        let (db, cache) = join!(Db::from_request(request), Cache::from_request(request));
+        let (db, cache) = (db.succeeded()?, cache.succeeded()?);
+        let (domain, settings) = join!(
+            Project::domain(&cache, &db, project),
+            Project::cors(&cache, &db, project)
+        );
+        let (domain, settings) = (domain.ok()?, settings.ok()?);
+
+        match origin {
+            None => Some(Allowed::new(&domain, settings)),
+            Some(origin) if settings.allows(&domain, origin) => {
+                Some(Allowed::new(origin, settings))
+            }
+            Some(_) => None,
+        }
+    }
+
+    async fn preflight(request: &Request<'_>, origin: &str) -> Option<Allowed> {
+        let db = Db::from_request(request).await.succeeded()?;
+        let projects = Project::cors_by_origin(&db, origin).await.ok()?;
+
+        projects
+            .into_iter()
+            .filter(|(domain, settings)| settings.allows(domain, origin))
+            .fold(
+                None,
+                |allowed: Option<Allowed>, (_, settings)| match allowed {
+                    None => Some(Allowed::new(origin, settings)),
+                    Some(allowed) => Some(allowed.merge(settings)),
+                },
+            )
+    }
+}
+
 #[rocket::async_trait]
 impl Fairing for CORS {
     fn info(&self) -> Info {
@@ -22,39 +107,48 @@ impl Fairing for CORS {
     }
 
     async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        let origin = request.headers().get_one("Origin");
         let project = request
             .headers()
             .get_one("Vulpo-Project")
             .and_then(|id| Uuid::parse_str(id).ok());
 
-        match project {
-            None => {
-                response.set_header(Header::new("Access-Control-Allow-Origin", "*"));
-            }
-            Some(id) => {
-                let (db, cache) = join!(Db::from_request(request), Cache::from_request(request));
-
-                if let Some(db) = db.succeeded() {
-                    if let Some(cache) = cache.succeeded() {
-                        let row = Project::domain(&cache, &db, &id).await;
-                        if let Ok(domain) = row {
-                            response.set_header(Header::new("Access-Control-Allow-Origin", domain));
-                        }
-                    }
+        let allowed = match (project, origin) {
+            (Some(id), _) => CORS::project(request, &id, origin).await,
+            (None, Some(origin)) => CORS::preflight(request, origin).await,
+            (None, None) => None,
+        };
+
+        response.set_header(Header::new("Vary", "Origin"));
+
+        match allowed {
+            Some(allowed) => {
+                response.set_header(Header::new("Access-Control-Allow-Origin", allowed.origin));
+                // cookie sessions are only sent to allowed origins
+                response.set_header(Header::new("Access-Control-Allow-Credentials", "true"));
+                response.set_header(Header::new(
+                    "Access-Control-Allow-Methods",
+                    allowed.methods.join(", "),
+                ));
+                response.set_header(Header::new(
+                    "Access-Control-Allow-Headers",
+                    allowed.headers.join(", "),
+                ));
+
+                if let Some(max_age) = allowed.max_age {
+                    response.set_header(Header::new("Access-Control-Max-Age", max_age.to_string()));
                 }
             }
+            // requests without an origin that don't belong to a project, e.g. the
+            // public keys. Origins that no project allows don't get any headers
+            None if project.is_none() && origin.is_none() => {
+                response.set_header(Header::new("Access-Control-Allow-Origin", "*"));
+                response.set_header(Header::new("Access-Control-Allow-Methods", DEFAULT_METHODS));
+                response.set_header(Header::new("Access-Control-Allow-Headers", DEFAULT_HEADERS));
+            }
+            None => {}
         };
 
-        response.set_header(Header::new(
-            "Access-Control-Allow-Methods",
-            "POST, GET, OPTIONS",
-        ));
-        response.set_header(Header::new(
-            "Access-Control-Allow-Headers",
-            "Content-Type, Vulpo-Project, Authorization",
-        ));
-        response.set_header(Header::new("Access-Control-Allow-Credentials", "false"));
-
         if request.method() == Method::Options {
             response.set_header(ContentType::Plain);
             response.set_sized_body(0, Cursor::new(""));
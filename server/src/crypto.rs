@@ -15,6 +15,13 @@ impl Token {
             .collect()
     }
 
+    pub fn code(length: usize) -> String {
+        let mut rng = thread_rng();
+        (0..length)
+            .map(|_| char::from(b'0' + rng.gen_range(0..10)))
+            .collect()
+    }
+
     pub fn hash(token: &str) -> Result<String, ApiError> {
         bcrypt::hash(token.clone(), bcrypt::DEFAULT_COST).map_err(|_| ApiError::InternalServerError)
     }
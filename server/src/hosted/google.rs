@@ -0,0 +1,126 @@
+use crate::hosted::{session_keys, sign_in, Hosted, Page, SignInContext};
+use crate::oauth::google::{get_authorize_url, google_confirm, GoogleConfirmPayload};
+use crate::project::data::Flags;
+
+use rocket::http::Status;
+use rocket::response::Redirect;
+use rocket::time::Duration;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+const GOOGLE_COOKIE: &str = "vulpo_hosted_google";
+
+#[derive(Serialize, Deserialize)]
+struct GoogleState {
+    request_id: Uuid,
+    redirect_url: Option<String>,
+}
+
+async fn authorize(
+    hosted: &Hosted<'_>,
+    pool: &Db,
+    redirect_url: Option<&str>,
+) -> Result<String, ApiError> {
+    hosted.can_sign_in(&[Flags::OAuthGoogle])?;
+    hosted.redirect_target(pool, redirect_url).await?;
+
+    // Google has to know the callback as an authorized redirect URI
+    let callback = format!("{}/google/callback", hosted.url);
+    let request_id = Uuid::new_v4();
+    let url =
+        get_authorize_url(pool, request_id, &hosted.project_id, None, Some(&callback)).await?;
+
+    let state = GoogleState {
+        request_id,
+        redirect_url: redirect_url.map(String::from),
+    };
+
+    hosted.set_state(GOOGLE_COOKIE, &state, Duration::minutes(15));
+    Ok(url)
+}
+
+#[get("/<_>/google?<redirect_url>")]
+pub async fn handler(
+    hosted: Hosted<'_>,
+    pool: Db,
+    redirect_url: Option<String>,
+) -> Result<Page, Status> {
+    match authorize(&hosted, &pool, redirect_url.as_deref()).await {
+        Ok(url) => Ok(Page::Redirect(Box::new(Redirect::to(url)))),
+        Err(error) => sign_in::render(&hosted, &pool, None, None, Some(error)).await,
+    }
+}
+
+async fn finish(
+    hosted: &Hosted<'_>,
+    pool: &Db,
+    state: &GoogleState,
+    csrf_token: String,
+    code: String,
+    ctx: &SignInContext<'_>,
+) -> Result<String, ApiError> {
+    hosted.can_sign_in(&[Flags::OAuthGoogle])?;
+    let target = hosted
+        .redirect_target(pool, state.redirect_url.as_deref())
+        .await?;
+
+    let (public_key, _) = session_keys();
+    let payload = GoogleConfirmPayload {
+        request_id: state.request_id,
+        csrf_token,
+        code,
+        session: Uuid::new_v4(),
+        public_key,
+        device_languages: hosted.languages.clone(),
+    };
+
+    let mut session = google_confirm(
+        &ctx.cache,
+        pool,
+        payload,
+        hosted.project_id,
+        ctx.passphrase,
+        &ctx.metadata,
+    )
+    .await?;
+    ctx.cookies
+        .set(&ctx.cache, pool, &hosted.project_id, &mut session)
+        .await?;
+
+    Ok(target)
+}
+
+/// Google redirects here after the user signed in, `code` is missing when the
+/// user cancelled the sign in
+#[get("/<_>/google/callback?<state>&<code>")]
+pub async fn callback(
+    hosted: Hosted<'_>,
+    pool: Db,
+    state: String,
+    code: Option<String>,
+    ctx: SignInContext<'_>,
+) -> Result<Page, Status> {
+    let google_state = hosted.state::<GoogleState>(GOOGLE_COOKIE);
+    hosted.remove_state(GOOGLE_COOKIE);
+
+    let (google_state, code) = match (google_state, code) {
+        (Some(google_state), Some(code)) => (google_state, code),
+        (google_state, _) => {
+            let redirect_url = google_state.and_then(|state| state.redirect_url);
+            let redirect_url = redirect_url.as_deref();
+            return sign_in::render(&hosted, &pool, redirect_url, None, None).await;
+        }
+    };
+
+    let result = finish(&hosted, &pool, &google_state, state, code, &ctx).await;
+
+    match result {
+        Ok(target) => Ok(Page::Redirect(Box::new(Redirect::to(target)))),
+        Err(error) => {
+            let redirect_url = google_state.redirect_url.as_deref();
+            sign_in::render(&hosted, &pool, redirect_url, None, Some(error)).await
+        }
+    }
+}
@@ -0,0 +1,386 @@
+//! Sign in pages the server renders for a project, so apps don't have to build
+//! their own. The pages are enabled with the `hosted::pages` flag and live at
+//! `/hosted/<project_id>`. Sign ins finish with a cookie session, see
+//! `crate::session::cookie`, and a redirect back to the app.
+
+mod google;
+// `#[derive(FromForm)]` allows the removed `private_in_public` lint
+#[allow(renamed_and_removed_lints)]
+mod password_reset;
+#[allow(renamed_and_removed_lints)]
+mod passwordless;
+#[allow(renamed_and_removed_lints)]
+mod sign_in;
+#[allow(renamed_and_removed_lints)]
+mod sign_up;
+mod verify_email;
+
+use crate::captcha;
+use crate::config::{Hosted as HostedConfig, Secrets};
+use crate::crypto::Token;
+use crate::project::data::{Flags, HostedPages, Project as ProjectData, RedirectUrls};
+use crate::session::cookie::{Csrf, SessionCookies};
+use crate::session::data::SessionMetadata;
+use crate::settings::data::ProjectCaptcha;
+use crate::template::{Template, Translations};
+
+use ecdsa::SigningKey;
+use p384::NistP384;
+use pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding};
+use rand_core::OsRng;
+use rocket::http::{Cookie, CookieJar, SameSite, Status};
+use rocket::request::{FromRequest, Outcome, Request};
+use rocket::response::content::RawHtml;
+use rocket::response::Redirect;
+use rocket::time::Duration;
+use rocket::Route;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::{Cache, Db};
+
+const LAYOUT: &str = include_str!("pages/layout.hbs");
+const TRANSLATIONS: &str = include_str!("translations/en.json");
+
+/// Name of the template the translations of the pages are stored under
+const TEMPLATE_NAME: &str = "hosted_pages";
+
+const CSRF_COOKIE: &str = "vulpo_hosted_csrf";
+
+#[derive(Responder)]
+pub enum Page {
+    Html(RawHtml<String>),
+    Redirect(Box<Redirect>),
+}
+
+/// Project of a hosted page, the request fails with `404` when the project
+/// didn't enable the pages
+pub struct Hosted<'r> {
+    pub project_id: Uuid,
+    pub name: String,
+    pub domain: String,
+    pub branding: HostedPages,
+    pub flags: Vec<Flags>,
+    pub languages: Vec<String>,
+
+    /// `/hosted/<project_id>`, links between the pages are relative to the
+    /// server
+    pub path: String,
+
+    /// Absolute URL of the pages, used for links in emails and OAuth redirects
+    pub url: String,
+
+    jar: &'r CookieJar<'r>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Hosted<'r> {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let project_id = match req.param::<Uuid>(0) {
+            Some(Ok(id)) => id,
+            _ => return Outcome::Failure((Status::NotFound, ())),
+        };
+
+        let pool = match Db::from_request(req).await.succeeded() {
+            Some(pool) => pool,
+            None => return Outcome::Failure((Status::ServiceUnavailable, ())),
+        };
+
+        let flags = match Flags::from_project(&pool, &project_id).await {
+            Ok(flags) if flags.contains(&Flags::HostedPages) => flags,
+            _ => return Outcome::Failure((Status::NotFound, ())),
+        };
+
+        let (name, domain, branding) = match ProjectData::hosted_pages(&pool, &project_id).await {
+            Ok(settings) => settings,
+            Err(_) => return Outcome::Failure((Status::InternalServerError, ())),
+        };
+
+        let path = format!("/hosted/{}", project_id);
+
+        // the Host header can't be trusted, links in emails would point to
+        // whatever host the request was sent to
+        let url = match req.rocket().state::<Option<HostedConfig>>() {
+            Some(Some(config)) => format!("{}{}", config.url.trim_end_matches('/'), path),
+            _ => {
+                let config = req.rocket().config();
+                format!("http://{}:{}{}", config.address, config.port, path)
+            }
+        };
+
+        Outcome::Success(Hosted {
+            project_id,
+            name,
+            domain,
+            branding,
+            flags,
+            languages: accept_languages(req.headers().get_one("Accept-Language")),
+            path,
+            url,
+            jar: req.cookies(),
+        })
+    }
+}
+
+impl<'r> Hosted<'r> {
+    pub fn has(&self, flags: &[Flags]) -> bool {
+        flags.iter().all(|flag| self.flags.contains(flag))
+    }
+
+    /// Sign ins on the hosted pages end with a cookie session
+    pub fn can_sign_in(&self, flags: &[Flags]) -> Result<(), ApiError> {
+        match self.has(flags) && self.has(&[Flags::CookieSession]) {
+            true => Ok(()),
+            false => Err(ApiError::Forbidden),
+        }
+    }
+
+    /// Link to another page, `redirect_url` is passed on so the sign in can
+    /// return to the app
+    pub fn link(&self, page: &str, redirect_url: Option<&str>) -> String {
+        match redirect_url.filter(|url| !url.is_empty()) {
+            None => format!("{}/{}", self.path, page),
+            Some(url) => format!("{}/{}?redirect_url={}", self.path, page, encode(url)),
+        }
+    }
+
+    /// Where the user is sent after signing in, the project's domain when the
+    /// app didn't pass a `redirect_url`
+    pub async fn redirect_target(
+        &self,
+        pool: &Db,
+        redirect_url: Option<&str>,
+    ) -> Result<String, ApiError> {
+        let redirect_url = redirect_url.filter(|url| !url.is_empty());
+        RedirectUrls::verify(pool, &self.project_id, redirect_url).await?;
+
+        let target = redirect_url
+            .map(String::from)
+            .unwrap_or_else(|| self.domain.clone());
+
+        Ok(target)
+    }
+
+    /// Token of the double submit cookie that protects the forms, a new token
+    /// is created when the browser doesn't have one yet
+    pub fn csrf(&self) -> String {
+        if let Some(cookie) = self.jar.get(CSRF_COOKIE) {
+            return cookie.value().to_string();
+        }
+
+        let token = Token::create();
+        self.set_cookie(CSRF_COOKIE, token.clone(), Duration::days(1));
+        token
+    }
+
+    pub fn verify_csrf(&self, token: &str) -> Result<(), ApiError> {
+        match self.jar.get(CSRF_COOKIE) {
+            Some(cookie) if Csrf::matches(cookie.value(), token) => Ok(()),
+            _ => Err(ApiError::SessionInvalidCsrfToken),
+        }
+    }
+
+    /// State of a sign in that continues on another page, e.g. after the
+    /// user confirmed an email link
+    pub fn set_state<T: Serialize>(&self, name: &'static str, state: &T, max_age: Duration) {
+        if let Ok(json) = serde_json::to_string(state) {
+            self.set_cookie(name, base64_url::encode(&json), max_age);
+        }
+    }
+
+    pub fn state<T: DeserializeOwned>(&self, name: &str) -> Option<T> {
+        let cookie = self.jar.get(name)?;
+        let json = base64_url::decode(cookie.value()).ok()?;
+        serde_json::from_slice(&json).ok()
+    }
+
+    pub fn remove_state(&self, name: &'static str) {
+        let mut cookie = Cookie::named(name);
+        cookie.set_path(self.path.clone());
+        self.jar.remove(cookie);
+    }
+
+    /// Cookies of the hosted pages are only sent to the pages of the project
+    fn set_cookie(&self, name: &'static str, value: String, max_age: Duration) {
+        let cookie = Cookie::build(name, value)
+            .path(self.path.clone())
+            .secure(true)
+            .http_only(true)
+            .same_site(SameSite::Lax)
+            .max_age(max_age)
+            .finish();
+
+        self.jar.add(cookie);
+    }
+
+    /// Renders `page` inside the layout. The `title`, `message` and `error`
+    /// props are translation keys, an `error` is an `ApiError` code.
+    pub async fn render(&self, pool: &Db, page: &str, props: Value) -> Result<Page, Status> {
+        let mut props = props;
+        props["project"] = json!(self.name);
+        props["path"] = json!(self.path);
+        props["logo_url"] = json!(self.branding.logo_url);
+        props["primary_color"] = json!(self.branding.primary_color);
+        props["background_color"] = json!(self.branding.background_color);
+        props["language"] = json!(self.languages.first().map_or("en", String::as_str));
+
+        let captcha = ProjectCaptcha::from_project(pool, &self.project_id)
+            .await
+            .map_err(|_| Status::InternalServerError)?;
+
+        if let Some(settings) = captcha.filter(|settings| settings.enabled) {
+            props["captcha"] = captcha::widget(&settings);
+        }
+
+        let translations = self.translations(pool).await?;
+        let translations = Template::translate(&translations, &props);
+        let translate = |key: &str| translations.get(key).cloned();
+
+        if let Some(title) = props["title"].as_str().and_then(translate) {
+            props["title"] = json!(title);
+        }
+
+        if let Some(message) = props["message"].as_str().and_then(translate) {
+            props["message"] = json!(message);
+        }
+
+        if let Some(code) = props["error"].as_str() {
+            let error =
+                translate(&format!("error.{}", code)).or_else(|| translate("error.default"));
+            props["error"] = json!(error);
+        }
+
+        let body = Template::render(page, &props, &translations)
+            .await
+            .map_err(|_| Status::InternalServerError)?;
+
+        props["body"] = json!(body);
+
+        let html = Template::render(LAYOUT, &props, &translations)
+            .await
+            .map_err(|_| Status::InternalServerError)?;
+
+        Ok(Page::Html(RawHtml(html)))
+    }
+
+    /// English texts, overwritten by the project's translations for the
+    /// user's languages
+    async fn translations(&self, pool: &Db) -> Result<HashMap<String, String>, Status> {
+        let mut translations: HashMap<String, String> =
+            serde_json::from_str(TRANSLATIONS).map_err(|_| Status::InternalServerError)?;
+
+        let project =
+            Translations::find_by_languages(pool, &self.project_id, &self.languages, TEMPLATE_NAME)
+                .await
+                .map_err(|_| Status::InternalServerError)?;
+
+        if let Some(project) = project {
+            translations.extend(project);
+        }
+
+        Ok(translations)
+    }
+}
+
+/// What the pages need to create a session and set its cookies
+pub struct SignInContext<'r> {
+    pub cache: Cache,
+    pub passphrase: &'r str,
+    pub metadata: SessionMetadata,
+    pub cookies: SessionCookies<'r>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for SignInContext<'r> {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let cache = match Cache::from_request(req).await.succeeded() {
+            Some(cache) => cache,
+            None => return Outcome::Failure((Status::ServiceUnavailable, ())),
+        };
+
+        let secrets = match req.rocket().state::<Secrets>() {
+            Some(secrets) => secrets,
+            None => return Outcome::Failure((Status::InternalServerError, ())),
+        };
+
+        let metadata = match SessionMetadata::from_request(req).await.succeeded() {
+            Some(metadata) => metadata,
+            None => return Outcome::Failure((Status::BadRequest, ())),
+        };
+
+        let cookies = match SessionCookies::from_request(req).await.succeeded() {
+            Some(cookies) => cookies,
+            None => return Outcome::Failure((Status::BadRequest, ())),
+        };
+
+        Outcome::Success(SignInContext {
+            cache,
+            passphrase: &secrets.passphrase,
+            metadata,
+            cookies,
+        })
+    }
+}
+
+/// Key pair of a session that is created by the server, the private key is
+/// returned as PKCS#8 PEM
+pub fn session_keys() -> (Vec<u8>, String) {
+    let signing_key: SigningKey<NistP384> = SigningKey::random(&mut OsRng);
+
+    let public_key = signing_key
+        .verifying_key()
+        .to_public_key_pem(LineEnding::LF)
+        .unwrap()
+        .as_bytes()
+        .to_vec();
+
+    let private_key = signing_key
+        .to_pkcs8_pem(LineEnding::LF)
+        .unwrap()
+        .to_string();
+
+    (public_key, private_key)
+}
+
+pub fn encode(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+/// Language tags of the `Accept-Language` header in the order they were sent,
+/// the quality values are ignored
+fn accept_languages(header: Option<&str>) -> Vec<String> {
+    header
+        .unwrap_or("")
+        .split(',')
+        .filter_map(|entry| entry.split(';').next())
+        .map(|tag| tag.trim())
+        .filter(|tag| !tag.is_empty() && *tag != "*")
+        .map(String::from)
+        .collect()
+}
+
+pub fn routes() -> Vec<Route> {
+    routes![
+        sign_in::page,
+        sign_in::handler,
+        sign_up::page,
+        sign_up::handler,
+        passwordless::handler,
+        passwordless::wait,
+        passwordless::confirm,
+        google::handler,
+        google::callback,
+        password_reset::forgot_page,
+        password_reset::forgot_handler,
+        password_reset::reset_page,
+        password_reset::reset_handler,
+        verify_email::handler,
+    ]
+}
@@ -0,0 +1,152 @@
+use crate::captcha::Captcha;
+use crate::hosted::{Hosted, Page};
+use crate::password::reset::{password_reset, request_password_reset, verify_token, ResetPassword};
+use crate::project::data::Flags;
+
+use rocket::form::Form;
+use rocket::http::Status;
+use serde_json::{json, Value};
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+const FORGOT_PASSWORD: &str = include_str!("pages/forgot_password.hbs");
+const RESET_PASSWORD: &str = include_str!("pages/reset_password.hbs");
+
+fn forgot_props(hosted: &Hosted<'_>, email: Option<&str>, error: Option<ApiError>) -> Value {
+    json!({
+        "title": "forgot_password_title",
+        "sign_in_url": hosted.link("sign_in", None),
+        "email": email,
+        "csrf": hosted.csrf(),
+        "error": error.map(|error| error.to_string()),
+    })
+}
+
+#[get("/<_>/forgot_password")]
+pub async fn forgot_page(hosted: Hosted<'_>, pool: Db) -> Result<Page, Status> {
+    let props = forgot_props(&hosted, None, None);
+    hosted.render(&pool, FORGOT_PASSWORD, props).await
+}
+
+#[derive(FromForm)]
+pub struct ForgotPasswordForm {
+    pub email: String,
+    pub csrf: String,
+
+    /// Response of the CAPTCHA widget
+    #[field(name = "g-recaptcha-response")]
+    pub captcha: Option<String>,
+}
+
+async fn request_reset(
+    hosted: &Hosted<'_>,
+    pool: &Db,
+    form: &ForgotPasswordForm,
+    captcha: &Captcha,
+) -> Result<(), ApiError> {
+    hosted.verify_csrf(&form.csrf)?;
+
+    if !hosted.has(&[Flags::EmailAndPassword]) {
+        return Err(ApiError::Forbidden);
+    }
+
+    captcha
+        .verify(pool, &hosted.project_id, form.captcha.as_deref())
+        .await?;
+
+    // the email links to the hosted page
+    let link = format!("{}/reset_password", hosted.url);
+    request_password_reset(pool, &form.email, &hosted.project_id, Some(&link)).await
+}
+
+#[post("/<_>/forgot_password", data = "<form>")]
+pub async fn forgot_handler(
+    hosted: Hosted<'_>,
+    pool: Db,
+    form: Form<ForgotPasswordForm>,
+    captcha: Captcha,
+) -> Result<Page, Status> {
+    let props = match request_reset(&hosted, &pool, &form, &captcha).await {
+        Ok(()) => {
+            let mut props = forgot_props(&hosted, Some(&form.email), None);
+            props["sent"] = json!(true);
+            props
+        }
+        Err(error) => forgot_props(&hosted, Some(&form.email), Some(error)),
+    };
+
+    hosted.render(&pool, FORGOT_PASSWORD, props).await
+}
+
+fn reset_props(
+    hosted: &Hosted<'_>,
+    id: &Uuid,
+    token: Option<&str>,
+    error: Option<ApiError>,
+) -> Value {
+    json!({
+        "title": "reset_password_title",
+        "sign_in_url": hosted.link("sign_in", None),
+        "id": id,
+        "token": token,
+        "csrf": hosted.csrf(),
+        "error": error.map(|error| error.to_string()),
+    })
+}
+
+/// Target of the link in the password reset email
+#[get("/<_>/reset_password?<id>&<token>")]
+pub async fn reset_page(
+    hosted: Hosted<'_>,
+    pool: Db,
+    id: Uuid,
+    token: String,
+) -> Result<Page, Status> {
+    let props = match verify_token(&pool, &id, &token).await {
+        Ok(()) => reset_props(&hosted, &id, Some(&token), None),
+        Err(error) => reset_props(&hosted, &id, None, Some(error)),
+    };
+
+    hosted.render(&pool, RESET_PASSWORD, props).await
+}
+
+#[derive(FromForm)]
+pub struct ResetPasswordForm {
+    pub id: Uuid,
+    pub token: String,
+    pub password1: String,
+    pub password2: String,
+    pub csrf: String,
+}
+
+async fn reset(hosted: &Hosted<'_>, pool: &Db, form: &ResetPasswordForm) -> Result<(), ApiError> {
+    hosted.verify_csrf(&form.csrf)?;
+
+    let body = ResetPassword {
+        id: form.id,
+        token: form.token.clone(),
+        password1: form.password1.clone(),
+        password2: form.password2.clone(),
+    };
+
+    password_reset(pool, body, &hosted.project_id).await
+}
+
+#[post("/<_>/reset_password", data = "<form>")]
+pub async fn reset_handler(
+    hosted: Hosted<'_>,
+    pool: Db,
+    form: Form<ResetPasswordForm>,
+) -> Result<Page, Status> {
+    let props = match reset(&hosted, &pool, &form).await {
+        Ok(()) => {
+            let mut props = reset_props(&hosted, &form.id, None, None);
+            props["done"] = json!(true);
+            props
+        }
+        Err(error) => reset_props(&hosted, &form.id, Some(&form.token), Some(error)),
+    };
+
+    hosted.render(&pool, RESET_PASSWORD, props).await
+}
@@ -0,0 +1,226 @@
+use crate::captcha::Captcha;
+use crate::config::Secrets;
+use crate::hosted::{session_keys, sign_in, Hosted, Page};
+use crate::passwordless::confirm::confirm as confirm_token;
+use crate::passwordless::data::PasswordlessMode;
+use crate::passwordless::request_passwordless::{request_passwordless, RequestPasswordless};
+use crate::passwordless::verify::{verify, Veriy};
+use crate::project::data::{Flags, Project as ProjectData};
+use crate::session::cookie::SessionCookies;
+use crate::session::data::SessionMetadata;
+
+use rocket::form::Form;
+use rocket::http::Status;
+use rocket::response::Redirect;
+use rocket::time::Duration;
+use rocket::State;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use vulpo_auth_types::session::RefreshAccessToken;
+use werkbank::rocket::{Cache, Db};
+
+const MESSAGE: &str = include_str!("pages/message.hbs");
+
+const PASSWORDLESS_COOKIE: &str = "vulpo_hosted_passwordless";
+
+/// The browser that requested the link keeps the session's private key, the
+/// sign in is finished in this browser once the link was confirmed
+#[derive(Serialize, Deserialize)]
+struct PasswordlessState {
+    id: Uuid,
+    session: Uuid,
+    private_key: String,
+    email: String,
+    redirect_url: Option<String>,
+}
+
+#[derive(FromForm)]
+pub struct PasswordlessForm {
+    pub email: String,
+    pub redirect_url: Option<String>,
+    pub csrf: String,
+
+    /// Response of the CAPTCHA widget
+    #[field(name = "g-recaptcha-response")]
+    pub captcha: Option<String>,
+}
+
+async fn request(
+    hosted: &Hosted<'_>,
+    pool: &Db,
+    form: &PasswordlessForm,
+    metadata: &SessionMetadata,
+    captcha: &Captcha,
+) -> Result<(), ApiError> {
+    hosted.verify_csrf(&form.csrf)?;
+    hosted.can_sign_in(&[Flags::AuthenticationLink])?;
+    hosted
+        .redirect_target(pool, form.redirect_url.as_deref())
+        .await?;
+
+    // codes would need another page, the hosted pages only send links
+    let mode = ProjectData::passwordless_mode(pool, &hosted.project_id).await?;
+    if mode != PasswordlessMode::Link {
+        return Err(ApiError::Forbidden);
+    }
+
+    captcha
+        .verify(pool, &hosted.project_id, form.captcha.as_deref())
+        .await?;
+
+    let (public_key, private_key) = session_keys();
+    let body = RequestPasswordless {
+        email: form.email.clone(),
+        session: Uuid::new_v4(),
+        public_key,
+        device_languages: hosted.languages.clone(),
+        redirect_url: Some(format!("{}/passwordless/confirm", hosted.url)),
+        captcha_token: None,
+    };
+
+    let response = request_passwordless(pool, body, hosted.project_id, metadata).await?;
+
+    let state = PasswordlessState {
+        id: response.id,
+        session: response.session,
+        private_key,
+        email: form.email.trim().to_lowercase(),
+        redirect_url: form.redirect_url.clone(),
+    };
+
+    hosted.set_state(PASSWORDLESS_COOKIE, &state, Duration::minutes(30));
+    Ok(())
+}
+
+#[post("/<_>/passwordless", data = "<form>")]
+pub async fn handler(
+    hosted: Hosted<'_>,
+    pool: Db,
+    form: Form<PasswordlessForm>,
+    metadata: SessionMetadata,
+    captcha: Captcha,
+) -> Result<Page, Status> {
+    match request(&hosted, &pool, &form, &metadata, &captcha).await {
+        Ok(()) => {
+            let wait = hosted.link("passwordless/wait", None);
+            Ok(Page::Redirect(Box::new(Redirect::to(wait))))
+        }
+        Err(error) => {
+            let redirect_url = form.redirect_url.as_deref();
+            sign_in::render(&hosted, &pool, redirect_url, Some(&form.email), Some(error)).await
+        }
+    }
+}
+
+async fn finish(
+    hosted: &Hosted<'_>,
+    cache: &Cache,
+    pool: &Db,
+    state: &PasswordlessState,
+    passphrase: &str,
+    cookies: &SessionCookies<'_>,
+) -> Result<String, ApiError> {
+    hosted.can_sign_in(&[Flags::AuthenticationLink])?;
+    let target = hosted
+        .redirect_target(pool, state.redirect_url.as_deref())
+        .await?;
+
+    let token = RefreshAccessToken::new(state.private_key.as_bytes())
+        .ok_or(ApiError::InternalServerError)?;
+
+    let body = Veriy {
+        token: token.value,
+        session: state.session,
+        id: state.id,
+        device_languages: hosted.languages.clone(),
+    };
+
+    let mut session = verify(cache, pool, body, hosted.project_id, passphrase).await?;
+    cookies
+        .set(cache, pool, &hosted.project_id, &mut session)
+        .await?;
+
+    Ok(target)
+}
+
+/// Reloads until the link was confirmed
+#[get("/<_>/passwordless/wait")]
+pub async fn wait(
+    hosted: Hosted<'_>,
+    pool: Db,
+    cache: Cache,
+    secrets: &State<Secrets>,
+    cookies: SessionCookies<'_>,
+) -> Result<Page, Status> {
+    let state = match hosted.state::<PasswordlessState>(PASSWORDLESS_COOKIE) {
+        Some(state) => state,
+        None => return sign_in::render(&hosted, &pool, None, None, None).await,
+    };
+
+    match finish(
+        &hosted,
+        &cache,
+        &pool,
+        &state,
+        &secrets.passphrase,
+        &cookies,
+    )
+    .await
+    {
+        Ok(target) => {
+            hosted.remove_state(PASSWORDLESS_COOKIE);
+            Ok(Page::Redirect(Box::new(Redirect::to(target))))
+        }
+        Err(ApiError::PasswordlessAwaitConfirm) => {
+            let props = json!({
+                "title": "check_email_title",
+                "message": "check_email",
+                "email": state.email,
+                "refresh": 3,
+            });
+
+            hosted.render(&pool, MESSAGE, props).await
+        }
+        Err(error) => {
+            hosted.remove_state(PASSWORDLESS_COOKIE);
+            let redirect_url = state.redirect_url.as_deref();
+            sign_in::render(
+                &hosted,
+                &pool,
+                redirect_url,
+                Some(&state.email),
+                Some(error),
+            )
+            .await
+        }
+    }
+}
+
+/// Target of the link in the email
+#[get("/<_>/passwordless/confirm?<id>&<token>")]
+pub async fn confirm(
+    hosted: Hosted<'_>,
+    pool: Db,
+    id: Uuid,
+    token: String,
+) -> Result<Page, Status> {
+    if let Err(error) = confirm_token(&pool, &id, &token).await {
+        return sign_in::render(&hosted, &pool, None, None, Some(error)).await;
+    }
+
+    // the link was opened in the browser that requested it
+    let state = hosted.state::<PasswordlessState>(PASSWORDLESS_COOKIE);
+    if state.map_or(false, |state| state.id == id) {
+        let wait = hosted.link("passwordless/wait", None);
+        return Ok(Page::Redirect(Box::new(Redirect::to(wait))));
+    }
+
+    let props = json!({
+        "title": "link_confirmed_title",
+        "message": "link_confirmed",
+    });
+
+    hosted.render(&pool, MESSAGE, props).await
+}
@@ -0,0 +1,123 @@
+use crate::hosted::{session_keys, Hosted, Page, SignInContext};
+use crate::password::signin::sign_in;
+use crate::passwordless::data::PasswordlessMode;
+use crate::project::data::{Flags, Project as ProjectData};
+
+use rocket::form::Form;
+use rocket::http::Status;
+use rocket::response::Redirect;
+use serde_json::json;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use vulpo_auth_types::SignInPayload;
+use werkbank::rocket::Db;
+
+const SIGN_IN: &str = include_str!("pages/sign_in.hbs");
+
+/// Renders the sign in page with every method the project enabled
+pub async fn render(
+    hosted: &Hosted<'_>,
+    pool: &Db,
+    redirect_url: Option<&str>,
+    email: Option<&str>,
+    error: Option<ApiError>,
+) -> Result<Page, Status> {
+    let mode = ProjectData::passwordless_mode(pool, &hosted.project_id)
+        .await
+        .map_err(|_| Status::InternalServerError)?;
+
+    let password = hosted.has(&[Flags::SignIn, Flags::EmailAndPassword]);
+    let passwordless = hosted.has(&[Flags::AuthenticationLink]) && mode == PasswordlessMode::Link;
+    let sign_up = hosted.has(&[Flags::SignUp, Flags::EmailAndPassword]);
+
+    let props = json!({
+        "title": "sign_in_title",
+        "password": password,
+        "passwordless": passwordless,
+        "has_form": password || passwordless,
+        "google": hosted.has(&[Flags::OAuthGoogle]),
+        "google_url": hosted.link("google", redirect_url),
+        "sign_up_url": sign_up.then(|| hosted.link("sign_up", redirect_url)),
+        "forgot_password_url": password.then(|| hosted.link("forgot_password", None)),
+        "redirect_url": redirect_url,
+        "email": email,
+        "csrf": hosted.csrf(),
+        "error": error.map(|error| error.to_string()),
+    });
+
+    hosted.render(pool, SIGN_IN, props).await
+}
+
+#[get("/<_>/sign_in?<redirect_url>")]
+pub async fn page(
+    hosted: Hosted<'_>,
+    pool: Db,
+    redirect_url: Option<String>,
+) -> Result<Page, Status> {
+    match hosted.redirect_target(&pool, redirect_url.as_deref()).await {
+        Ok(_) => render(&hosted, &pool, redirect_url.as_deref(), None, None).await,
+        Err(error) => render(&hosted, &pool, None, None, Some(error)).await,
+    }
+}
+
+#[derive(FromForm)]
+pub struct SignInForm {
+    pub email: String,
+    pub password: String,
+    pub redirect_url: Option<String>,
+    pub csrf: String,
+}
+
+async fn password_sign_in(
+    hosted: &Hosted<'_>,
+    pool: &Db,
+    form: &SignInForm,
+    ctx: &SignInContext<'_>,
+) -> Result<String, ApiError> {
+    hosted.verify_csrf(&form.csrf)?;
+    hosted.can_sign_in(&[Flags::SignIn, Flags::EmailAndPassword])?;
+    let target = hosted
+        .redirect_target(pool, form.redirect_url.as_deref())
+        .await?;
+
+    let (public_key, _) = session_keys();
+    let payload = SignInPayload {
+        email: form.email.clone(),
+        password: form.password.clone(),
+        session: Uuid::new_v4(),
+        public_key,
+    };
+
+    let mut session = sign_in(
+        &ctx.cache,
+        pool,
+        payload,
+        hosted.project_id,
+        ctx.passphrase,
+        &ctx.metadata,
+    )
+    .await?;
+    ctx.cookies
+        .set(&ctx.cache, pool, &hosted.project_id, &mut session)
+        .await?;
+
+    Ok(target)
+}
+
+#[post("/<_>/sign_in", data = "<form>")]
+pub async fn handler(
+    hosted: Hosted<'_>,
+    pool: Db,
+    form: Form<SignInForm>,
+    ctx: SignInContext<'_>,
+) -> Result<Page, Status> {
+    let result = password_sign_in(&hosted, &pool, &form, &ctx).await;
+
+    match result {
+        Ok(target) => Ok(Page::Redirect(Box::new(Redirect::to(target)))),
+        Err(error) => {
+            let redirect_url = form.redirect_url.as_deref();
+            render(&hosted, &pool, redirect_url, Some(&form.email), Some(error)).await
+        }
+    }
+}
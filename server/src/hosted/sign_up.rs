@@ -0,0 +1,125 @@
+use crate::captcha::Captcha;
+use crate::hosted::{session_keys, Hosted, Page, SignInContext};
+use crate::password::signup::{sign_up, SignUp};
+use crate::project::data::Flags;
+
+use rocket::form::Form;
+use rocket::http::Status;
+use rocket::response::Redirect;
+use serde_json::json;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+const SIGN_UP: &str = include_str!("pages/sign_up.hbs");
+
+async fn render(
+    hosted: &Hosted<'_>,
+    pool: &Db,
+    redirect_url: Option<&str>,
+    email: Option<&str>,
+    error: Option<ApiError>,
+) -> Result<Page, Status> {
+    let props = json!({
+        "title": "sign_up_title",
+        "sign_in_url": hosted.link("sign_in", redirect_url),
+        "redirect_url": redirect_url,
+        "email": email,
+        "csrf": hosted.csrf(),
+        "error": error.map(|error| error.to_string()),
+    });
+
+    hosted.render(pool, SIGN_UP, props).await
+}
+
+#[get("/<_>/sign_up?<redirect_url>")]
+pub async fn page(
+    hosted: Hosted<'_>,
+    pool: Db,
+    redirect_url: Option<String>,
+) -> Result<Page, Status> {
+    if !hosted.has(&[Flags::SignUp, Flags::EmailAndPassword]) {
+        return render(&hosted, &pool, None, None, Some(ApiError::Forbidden)).await;
+    }
+
+    match hosted.redirect_target(&pool, redirect_url.as_deref()).await {
+        Ok(_) => render(&hosted, &pool, redirect_url.as_deref(), None, None).await,
+        Err(error) => render(&hosted, &pool, None, None, Some(error)).await,
+    }
+}
+
+#[derive(FromForm)]
+pub struct SignUpForm {
+    pub email: String,
+    pub password: String,
+    pub redirect_url: Option<String>,
+    pub csrf: String,
+
+    /// Response of the CAPTCHA widget
+    #[field(name = "g-recaptcha-response")]
+    pub captcha: Option<String>,
+}
+
+async fn password_sign_up(
+    hosted: &Hosted<'_>,
+    pool: &Db,
+    form: &SignUpForm,
+    ctx: &SignInContext<'_>,
+    captcha: &Captcha,
+) -> Result<String, ApiError> {
+    hosted.verify_csrf(&form.csrf)?;
+    hosted.can_sign_in(&[Flags::SignUp, Flags::EmailAndPassword])?;
+    let target = hosted
+        .redirect_target(pool, form.redirect_url.as_deref())
+        .await?;
+
+    captcha
+        .verify(pool, &hosted.project_id, form.captcha.as_deref())
+        .await?;
+
+    let (public_key, _) = session_keys();
+    let body = SignUp {
+        email: form.email.clone(),
+        password: form.password.clone(),
+        session: Uuid::new_v4(),
+        public_key,
+        device_languages: hosted.languages.clone(),
+        // the verification email links to the hosted page
+        redirect_url: Some(format!("{}/verify_email", hosted.url)),
+        captcha_token: None,
+    };
+
+    let mut session = sign_up(
+        &ctx.cache,
+        pool,
+        body,
+        hosted.project_id,
+        ctx.passphrase,
+        &ctx.metadata,
+    )
+    .await?;
+    ctx.cookies
+        .set(&ctx.cache, pool, &hosted.project_id, &mut session)
+        .await?;
+
+    Ok(target)
+}
+
+#[post("/<_>/sign_up", data = "<form>")]
+pub async fn handler(
+    hosted: Hosted<'_>,
+    pool: Db,
+    form: Form<SignUpForm>,
+    ctx: SignInContext<'_>,
+    captcha: Captcha,
+) -> Result<Page, Status> {
+    let result = password_sign_up(&hosted, &pool, &form, &ctx, &captcha).await;
+
+    match result {
+        Ok(target) => Ok(Page::Redirect(Box::new(Redirect::to(target)))),
+        Err(error) => {
+            let redirect_url = form.redirect_url.as_deref();
+            render(&hosted, &pool, redirect_url, Some(&form.email), Some(error)).await
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::hosted::{Hosted, Page};
+use crate::user::verify_email::{verify_email, Verify};
+
+use rocket::http::Status;
+use serde_json::json;
+use uuid::Uuid;
+use werkbank::rocket::Db;
+
+const MESSAGE: &str = include_str!("pages/message.hbs");
+
+/// Target of the link in the verification email
+#[get("/<_>/verify_email?<id>&<token>")]
+pub async fn handler(
+    hosted: Hosted<'_>,
+    pool: Db,
+    id: Uuid,
+    token: String,
+) -> Result<Page, Status> {
+    let props = match verify_email(&pool, Verify { id, token }).await {
+        Ok(()) => json!({
+            "title": "verify_email_title",
+            "message": "email_verified",
+            "continue_url": hosted.domain,
+        }),
+        Err(error) => json!({
+            "title": "verify_email_title",
+            "error": error.to_string(),
+        }),
+    };
+
+    hosted.render(&pool, MESSAGE, props).await
+}
@@ -122,3 +122,28 @@ impl PublicKey {
             .await
     }
 }
+
+/// A user or project whose access tokens issued before `valid_after` are revoked
+pub struct Revocation {
+    pub id: Uuid,
+    /// `user` or `project`
+    pub kind: String,
+    pub valid_after: i64,
+}
+
+impl Revocation {
+    pub async fn since(
+        pool: &PgPool,
+        project_id: &Uuid,
+        since: &DateTime<Utc>,
+    ) -> sqlx::Result<Vec<Revocation>> {
+        sqlx::query_file_as!(
+            Revocation,
+            "src/keys/sql/get_revocations.sql",
+            since,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
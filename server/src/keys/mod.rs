@@ -3,7 +3,8 @@ use rocket::Route;
 pub mod data;
 mod keys;
 mod public_keys;
+mod revocations;
 
 pub fn routes() -> Vec<Route> {
-    routes![public_keys::handler, keys::public]
+    routes![public_keys::handler, keys::public, revocations::handler]
 }
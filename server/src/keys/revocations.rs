@@ -0,0 +1,64 @@
+use crate::keys::data::Revocation;
+use crate::oauth::token::{authenticate_service_account, BasicAuth};
+use crate::project::Project;
+
+use chrono::{DateTime, Duration, Utc};
+use rocket::serde::json::Json;
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+/// Access tokens expire after 15 minutes, older watermarks don't
+/// affect any valid token
+const TOKEN_LIFETIME_IN_MINUTES: i64 = 15;
+const EXPIRE_IN_SECONDS: i64 = 30;
+
+#[derive(Serialize)]
+pub struct Revocations {
+    pub expire_at: DateTime<Utc>,
+    pub users: HashMap<Uuid, i64>,
+    pub projects: HashMap<Uuid, i64>,
+}
+
+pub async fn get_revocations(pool: &Db, project_id: &Uuid) -> Result<Revocations, ApiError> {
+    let since = Utc::now() - Duration::minutes(TOKEN_LIFETIME_IN_MINUTES);
+    let rows = Revocation::since(pool, project_id, &since).await?;
+
+    let mut revocations = Revocations {
+        expire_at: Utc::now() + Duration::seconds(EXPIRE_IN_SECONDS),
+        users: HashMap::new(),
+        projects: HashMap::new(),
+    };
+
+    for row in rows {
+        match row.kind.as_str() {
+            "project" => revocations.projects.insert(row.id, row.valid_after),
+            _ => revocations.users.insert(row.id, row.valid_after),
+        };
+    }
+
+    Ok(revocations)
+}
+
+/// The feed contains user ids, it is only available to the project's
+/// service accounts
+#[get("/revocations")]
+pub async fn handler(
+    pool: Db,
+    project: Project,
+    basic_auth: Option<BasicAuth>,
+) -> Result<Json<Revocations>, ApiError> {
+    let basic_auth = basic_auth.ok_or(ApiError::OAuthInvalidClient)?;
+    let client =
+        authenticate_service_account(&pool, &basic_auth.client_id, &basic_auth.client_secret)
+            .await?;
+
+    if client.project_id != project.id {
+        return Err(ApiError::Forbidden);
+    }
+
+    let revocations = get_revocations(&pool, &project.id).await?;
+    Ok(Json(revocations))
+}
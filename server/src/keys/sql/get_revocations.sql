@@ -0,0 +1,13 @@
+select id as "id!"
+     , 'user' as "kind!"
+     , extract(epoch from tokens_valid_after)::bigint as "valid_after!"
+  from users
+ where tokens_valid_after > $1
+   and project_id = $2
+ union all
+select id as "id!"
+     , 'project' as "kind!"
+     , extract(epoch from tokens_valid_after)::bigint as "valid_after!"
+  from projects
+ where tokens_valid_after > $1
+   and id = $2
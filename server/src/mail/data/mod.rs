@@ -1,2 +1,2 @@
 mod verify_email;
-pub use verify_email::VerifyEmail;
+pub use verify_email::{EmailVerificationPolicy, VerifyEmail};
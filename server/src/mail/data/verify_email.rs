@@ -1,10 +1,27 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use uuid::Uuid;
 
+/// What happens when a user with an unverified email address signs in
+#[derive(sqlx::Type, Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[sqlx(type_name = "email_verification_policy")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum EmailVerificationPolicy {
+    /// Unverified users can sign in
+    Off,
+    /// Rejects the sign in with `user/email_not_verified`
+    Block,
+    /// Adds the `email_verified` claim to the user's access tokens
+    Mark,
+}
+
 pub struct VerifyEmail {
     pub token: String,
     pub user_id: Uuid,
+    /// The address the email was sent to
+    pub email: String,
     pub created_at: DateTime<Utc>,
     pub expire_at: DateTime<Utc>,
 }
@@ -15,12 +32,16 @@ impl VerifyEmail {
         user_id: &Uuid,
         token: String,
         project_id: &Uuid,
+        expire_at: &DateTime<Utc>,
+        email: &str,
     ) -> sqlx::Result<Uuid> {
         sqlx::query_file!(
             "src/mail/sql/insert_verify_email.sql",
             token,
             user_id,
-            project_id
+            project_id,
+            expire_at,
+            email,
         )
         .fetch_one(pool)
         .await
@@ -33,18 +54,39 @@ impl VerifyEmail {
             .await
     }
 
-    pub async fn verify(pool: &PgPool, user_id: &Uuid) -> sqlx::Result<()> {
-        sqlx::query_file!("src/mail/sql/verify_email.sql", user_id)
+    /// Removes the token, returns `false` when it was already used
+    pub async fn claim(pool: &PgPool, id: &Uuid) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/mail/sql/claim_verify_email.sql", id)
             .execute(pool)
-            .await?;
+            .await
+            .map(|result| result.rows_affected() == 1)
+    }
 
-        Ok(())
+    /// Marks the email as verified and removes the user's remaining tokens,
+    /// `false` when the user changed the email in the meantime
+    pub async fn verify(pool: &PgPool, user_id: &Uuid, email: &str) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/mail/sql/verify_email.sql", user_id, email)
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected() == 1)
     }
 
-    pub async fn unverify(pool: &PgPool, user_id: &Uuid) -> sqlx::Result<String> {
+    pub async fn unverify(pool: &PgPool, user_id: &Uuid) -> sqlx::Result<Option<String>> {
         sqlx::query_file!("src/mail/sql/unverify_email.sql", user_id)
             .fetch_one(pool)
             .await
             .map(|row| row.email)
     }
+
+    /// Number of verification emails sent to the user since `since`
+    pub async fn count_since(
+        pool: &PgPool,
+        user_id: &Uuid,
+        since: &DateTime<Utc>,
+    ) -> sqlx::Result<i64> {
+        sqlx::query_file!("src/mail/sql/count_verify_email.sql", user_id, since)
+            .fetch_one(pool)
+            .await
+            .map(|row| row.count.unwrap_or(0))
+    }
 }
@@ -0,0 +1,4 @@
+select count(*)
+  from verify_email
+ where user_id = $1
+   and created_at > $2
\ No newline at end of file
@@ -1,5 +1,6 @@
 select token
      , user_id
+     , email
      , created_at
      , expire_at
   from verify_email
@@ -1,3 +1,3 @@
-insert into verify_email (token, user_id, project_id)
-values ($1, $2, $3)
+insert into verify_email (token, user_id, project_id, expire_at, email)
+values ($1, $2, $3, $4, $5)
 returning id
\ No newline at end of file
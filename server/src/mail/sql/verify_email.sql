@@ -1,9 +1,8 @@
-with delete_token as (
+with delete_tokens as (
     delete from verify_email
      where user_id = $1
-     returning user_id
 )
 update users
    set email_verified = true
-  from delete_token
- where id = delete_token.user_id
\ No newline at end of file
+ where id = $1
+   and email = $2
\ No newline at end of file
@@ -1,21 +1,28 @@
 mod admin;
+mod anonymous;
 mod api_key;
+mod captcha;
 mod cli;
 mod config;
 mod cors;
 mod crypto;
 mod file;
+mod hosted;
 mod init;
 mod keys;
 mod mail;
 mod migration;
 mod oauth;
+mod organization;
 mod password;
 mod passwordless;
 mod project;
+mod role;
 mod server;
+mod service_account;
 mod session;
 mod settings;
+mod sms;
 mod template;
 mod user;
 
@@ -0,0 +1,307 @@
+use crate::crypto::Token;
+use crate::oauth::data::authorization::{Authorization, Consent, NewAuthorization, SCOPES};
+use crate::oauth::data::client::{OAuthClient, RegisteredClient};
+use crate::project::data::Project as ProjectData;
+use crate::project::Project;
+use crate::session::data::{AccessToken, Session};
+use crate::user::data::User;
+
+use chrono::{Duration, Utc};
+use rocket::form::FromForm;
+use rocket::response::Redirect;
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::{Cache, Db};
+
+const EXPIRE_IN_MINUTES: i64 = 10;
+
+/// The page of the project's app that asks the user for consent
+const CONSENT_PATH: &'static str = "/oauth/authorize";
+
+#[derive(FromForm)]
+pub struct AuthorizeParams {
+    pub response_type: Option<String>,
+    pub client_id: Option<Uuid>,
+    pub redirect_uri: Option<String>,
+    pub scope: Option<String>,
+    pub state: Option<String>,
+    pub nonce: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+}
+
+/// Appends the query parameters to the client's redirect URI
+pub fn redirect_uri(uri: &str, params: &[(&str, &str)], state: &Option<String>) -> String {
+    let mut url = match Url::parse(uri) {
+        Ok(url) => url,
+        Err(_) => return uri.to_string(),
+    };
+
+    {
+        let mut query = url.query_pairs_mut();
+        for (key, value) in params {
+            query.append_pair(key, value);
+        }
+
+        if let Some(state) = state {
+            query.append_pair("state", state);
+        }
+    }
+
+    url.to_string()
+}
+
+pub fn parse_scopes(scope: Option<&str>) -> Option<Vec<String>> {
+    let mut scopes: Vec<String> = scope
+        .unwrap_or("openid")
+        .split_whitespace()
+        .map(String::from)
+        .collect();
+
+    scopes.sort();
+    scopes.dedup();
+
+    match scopes.iter().all(|scope| SCOPES.contains(&scope.as_str())) {
+        true => Some(scopes),
+        false => None,
+    }
+}
+
+/// Validates the authorization request and returns the URL of the consent
+/// page. Errors are only redirected to the client once the client and the
+/// redirect URI are known to be valid.
+pub async fn authorize(
+    pool: &Db,
+    cache: &Cache,
+    params: AuthorizeParams,
+) -> Result<String, ApiError> {
+    let client_id = params.client_id.ok_or(ApiError::OAuthInvalidClient)?;
+    let client = OAuthClient::get(&pool, &client_id)
+        .await?
+        .ok_or(ApiError::OAuthInvalidClient)?;
+
+    let redirect_to = params
+        .redirect_uri
+        .ok_or(ApiError::OAuthInvalidRedirectUri)?;
+
+    if !client.has_redirect_uri(&redirect_to) {
+        return Err(ApiError::OAuthInvalidRedirectUri);
+    }
+
+    let state = params.state;
+    let error = |error: &str| Ok(redirect_uri(&redirect_to, &[("error", error)], &state));
+
+    if params.response_type.as_deref() != Some("code") {
+        return error("unsupported_response_type");
+    }
+
+    let scopes = match parse_scopes(params.scope.as_deref()) {
+        Some(scopes) => scopes,
+        None => return error("invalid_scope"),
+    };
+
+    // PKCE is required for all clients, `plain` is not supported
+    let code_challenge = match (
+        params.code_challenge,
+        params.code_challenge_method.as_deref(),
+    ) {
+        (Some(challenge), Some("S256")) if !challenge.is_empty() => challenge,
+        _ => return error("invalid_request"),
+    };
+
+    let request = NewAuthorization {
+        client_id: client.id,
+        redirect_uri: redirect_to.clone(),
+        scopes,
+        state: state.clone(),
+        nonce: params.nonce,
+        code_challenge,
+        expire_at: Utc::now() + Duration::minutes(EXPIRE_IN_MINUTES),
+    };
+
+    let id = Authorization::create(&pool, &request).await?;
+    let domain = ProjectData::domain(&cache, &pool, &client.project_id).await?;
+
+    Ok(format!("{}{}?request_id={}", domain, CONSENT_PATH, id))
+}
+
+#[get("/authorize?<params..>")]
+pub async fn handler(
+    pool: Db,
+    cache: Cache,
+    params: AuthorizeParams,
+) -> Result<Redirect, ApiError> {
+    let location = authorize(&pool, &cache, params).await?;
+    Ok(Redirect::to(location))
+}
+
+/// Returns the authorization request if it can still be approved by a user
+/// of the project
+async fn get_pending(
+    pool: &Db,
+    id: &Uuid,
+    project_id: &Uuid,
+) -> Result<(Authorization, RegisteredClient), ApiError> {
+    let authorization = Authorization::get(&pool, &id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if authorization.code.is_some() {
+        return Err(ApiError::NotFound);
+    }
+
+    if authorization.is_expired() {
+        return Err(ApiError::TokenExpired);
+    }
+
+    let client = OAuthClient::get(&pool, &authorization.client_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if &client.project_id != project_id {
+        return Err(ApiError::NotFound);
+    }
+
+    Ok((authorization, client))
+}
+
+#[derive(Serialize)]
+pub struct ClientInfo {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(Serialize)]
+pub struct AuthorizationRequest {
+    pub id: Uuid,
+    pub client: ClientInfo,
+    pub scopes: Vec<String>,
+    /// `true` when the user already granted the scopes to the client
+    pub consent: bool,
+}
+
+#[get("/authorize/request?<id>")]
+pub async fn request_handler(
+    pool: Db,
+    id: Uuid,
+    project: Project,
+    access_token: AccessToken,
+) -> Result<Json<AuthorizationRequest>, ApiError> {
+    let (authorization, client) = get_pending(&pool, &id, &project.id).await?;
+    let consent = Consent::covers(
+        &pool,
+        &access_token.sub(),
+        &client.id,
+        &authorization.scopes,
+    )
+    .await?;
+
+    Ok(Json(AuthorizationRequest {
+        id: authorization.id,
+        client: ClientInfo {
+            id: client.id,
+            name: client.name,
+        },
+        scopes: authorization.scopes,
+        consent,
+    }))
+}
+
+#[derive(Serialize)]
+pub struct AuthorizationRedirect {
+    pub redirect_to: String,
+}
+
+#[derive(Deserialize)]
+pub struct ConfirmAuthorization {
+    pub id: Uuid,
+    /// The session of the signed in user, the issued tokens are bound to it
+    pub session: Uuid,
+}
+
+pub async fn confirm(
+    pool: &Db,
+    body: ConfirmAuthorization,
+    project_id: &Uuid,
+    user_id: &Uuid,
+) -> Result<String, ApiError> {
+    let (authorization, client) = get_pending(&pool, &body.id, &project_id).await?;
+
+    let session = Session::get(&pool, &body.session).await?;
+    if session.user_id.as_ref() != Some(user_id)
+        || &session.project_id != project_id
+        || session.expire_at < Utc::now()
+    {
+        return Err(ApiError::Forbidden);
+    }
+
+    let user = User::get_by_id(&pool, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if user.is_disabled() {
+        return Err(ApiError::UserDisabled);
+    }
+
+    let token = Token::create();
+    let hashed_token = Token::hash(&token)?;
+
+    if !Authorization::approve(
+        &pool,
+        &authorization.id,
+        &user.id,
+        &session.id,
+        &hashed_token,
+    )
+    .await?
+    {
+        return Err(ApiError::NotFound);
+    }
+
+    Consent::grant(&pool, &user.id, &client.id, &authorization.scopes).await?;
+
+    let code = format!("{}.{}", authorization.id, token);
+    Ok(redirect_uri(
+        &authorization.redirect_uri,
+        &[("code", &code)],
+        &authorization.state,
+    ))
+}
+
+#[post("/authorize/confirm", format = "json", data = "<body>")]
+pub async fn confirm_handler(
+    pool: Db,
+    body: Json<ConfirmAuthorization>,
+    project: Project,
+    access_token: AccessToken,
+) -> Result<Json<AuthorizationRedirect>, ApiError> {
+    let redirect_to = confirm(&pool, body.into_inner(), &project.id, &access_token.sub()).await?;
+    Ok(Json(AuthorizationRedirect { redirect_to }))
+}
+
+#[derive(Deserialize)]
+pub struct DenyAuthorization {
+    pub id: Uuid,
+}
+
+#[post("/authorize/deny", format = "json", data = "<body>")]
+pub async fn deny_handler(
+    pool: Db,
+    body: Json<DenyAuthorization>,
+    project: Project,
+    _access_token: AccessToken,
+) -> Result<Json<AuthorizationRedirect>, ApiError> {
+    let (authorization, _) = get_pending(&pool, &body.id, &project.id).await?;
+    Authorization::remove(&pool, &authorization.id).await?;
+
+    let redirect_to = redirect_uri(
+        &authorization.redirect_uri,
+        &[("error", "access_denied")],
+        &authorization.state,
+    );
+
+    Ok(Json(AuthorizationRedirect { redirect_to }))
+}
@@ -0,0 +1,155 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::crypto::Token;
+use crate::oauth::data::client::{OAuthClient, OAuthClientData};
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Serialize)]
+pub struct OAuthClients {
+    pub items: Vec<OAuthClient>,
+}
+
+#[get("/client/list?<project>")]
+pub async fn list_handler(
+    pool: Db,
+    project: Uuid,
+    _admin: Admin,
+) -> Result<Json<OAuthClients>, ApiError> {
+    let items = OAuthClient::list(&pool, &project).await?;
+    Ok(Json(OAuthClients { items }))
+}
+
+/// Trims the name and makes sure that all redirect URIs are absolute URLs
+/// without a fragment
+pub fn validate_client(client: OAuthClientData) -> Result<OAuthClientData, ApiError> {
+    let name = client.name.trim().to_string();
+    if name.is_empty() {
+        return Err(ApiError::BadRequest);
+    }
+
+    let mut redirect_uris = client.redirect_uris;
+    redirect_uris.sort();
+    redirect_uris.dedup();
+
+    let valid = redirect_uris.iter().all(|uri| match Url::parse(uri) {
+        Ok(url) => url.fragment().is_none(),
+        Err(_) => false,
+    });
+
+    if !valid {
+        return Err(ApiError::OAuthInvalidRedirectUri);
+    }
+
+    Ok(OAuthClientData {
+        name,
+        redirect_uris,
+    })
+}
+
+#[derive(Deserialize)]
+pub struct CreateClient {
+    pub project: Uuid,
+    #[serde(flatten)]
+    pub client: OAuthClientData,
+    /// Public clients, e.g. single page apps, don't get a secret
+    #[serde(default = "default_confidential")]
+    pub confidential: bool,
+}
+
+fn default_confidential() -> bool {
+    true
+}
+
+/// The secret is only returned once, it is stored hashed
+#[derive(Serialize)]
+pub struct CreateClientResponse {
+    pub client_id: Uuid,
+    pub client_secret: Option<String>,
+}
+
+pub async fn create_client(
+    pool: &Db,
+    body: CreateClient,
+) -> Result<CreateClientResponse, ApiError> {
+    let client = validate_client(body.client)?;
+
+    let secret = match body.confidential {
+        true => Some(Token::create()),
+        false => None,
+    };
+
+    let hashed_secret = match &secret {
+        Some(secret) => Some(Token::hash(secret)?),
+        None => None,
+    };
+
+    let id = OAuthClient::create(&pool, &body.project, &client, hashed_secret.as_deref()).await?;
+
+    Ok(CreateClientResponse {
+        client_id: id,
+        client_secret: secret,
+    })
+}
+
+#[post("/client/create", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    body: Json<CreateClient>,
+    admin: Admin,
+) -> Result<Json<CreateClientResponse>, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+    let client = create_client(&pool, body.into_inner()).await?;
+    Ok(Json(client))
+}
+
+#[derive(Deserialize)]
+pub struct UpdateClient {
+    pub id: Uuid,
+    pub project: Uuid,
+    #[serde(flatten)]
+    pub client: OAuthClientData,
+}
+
+#[post("/client/update", format = "json", data = "<body>")]
+pub async fn update_handler(
+    pool: Db,
+    body: Json<UpdateClient>,
+    admin: Admin,
+) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+
+    let body = body.into_inner();
+    let client = validate_client(body.client)?;
+
+    if !OAuthClient::update(&pool, &body.id, &body.project, &client).await? {
+        return Err(ApiError::NotFound);
+    }
+
+    Ok(Status::Ok)
+}
+
+#[derive(Deserialize)]
+pub struct DeleteClient {
+    pub id: Uuid,
+    pub project: Uuid,
+}
+
+#[post("/client/delete", format = "json", data = "<body>")]
+pub async fn delete_handler(
+    pool: Db,
+    body: Json<DeleteClient>,
+    admin: Admin,
+) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+    if !OAuthClient::remove(&pool, &body.id, &body.project).await? {
+        return Err(ApiError::NotFound);
+    }
+
+    Ok(Status::Ok)
+}
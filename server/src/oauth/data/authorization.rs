@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Scopes a client can request, `openid` is required for an ID token
+pub const SCOPES: [&str; 3] = ["openid", "profile", "email"];
+
+/// A pending authorization request, `code` is set (hashed) once the user
+/// approved it and the request is removed when the code is exchanged
+pub struct Authorization {
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    pub state: Option<String>,
+    pub nonce: Option<String>,
+    pub code_challenge: String,
+    pub user_id: Option<Uuid>,
+    pub session_id: Option<Uuid>,
+    pub code: Option<String>,
+    pub expire_at: DateTime<Utc>,
+}
+
+pub struct NewAuthorization {
+    pub client_id: Uuid,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+    pub state: Option<String>,
+    pub nonce: Option<String>,
+    pub code_challenge: String,
+    pub expire_at: DateTime<Utc>,
+}
+
+impl Authorization {
+    pub async fn create(pool: &PgPool, request: &NewAuthorization) -> sqlx::Result<Uuid> {
+        sqlx::query_file!(
+            "src/oauth/sql/create_authorization.sql",
+            request.client_id,
+            request.redirect_uri,
+            &request.scopes,
+            request.state,
+            request.nonce,
+            request.code_challenge,
+            request.expire_at,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|row| row.id)
+    }
+
+    pub async fn get(pool: &PgPool, id: &Uuid) -> sqlx::Result<Option<Authorization>> {
+        sqlx::query_file_as!(Authorization, "src/oauth/sql/get_authorization.sql", id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn approve(
+        pool: &PgPool,
+        id: &Uuid,
+        user_id: &Uuid,
+        session_id: &Uuid,
+        code: &str,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_file!(
+            "src/oauth/sql/approve_authorization.sql",
+            id,
+            user_id,
+            session_id,
+            code
+        )
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected() > 0)
+    }
+
+    pub async fn remove(pool: &PgPool, id: &Uuid) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/oauth/sql/remove_authorization.sql", id)
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected() > 0)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Utc::now() > self.expire_at
+    }
+}
+
+pub struct Consent;
+
+impl Consent {
+    /// `true` when the user already granted all scopes to the client
+    pub async fn covers(
+        pool: &PgPool,
+        user_id: &Uuid,
+        client_id: &Uuid,
+        scopes: &[String],
+    ) -> sqlx::Result<bool> {
+        let granted = sqlx::query_file!("src/oauth/sql/get_consent.sql", user_id, client_id)
+            .fetch_optional(pool)
+            .await?
+            .map(|row| row.scopes)
+            .unwrap_or_default();
+
+        Ok(scopes.iter().all(|scope| granted.contains(scope)))
+    }
+
+    pub async fn grant(
+        pool: &PgPool,
+        user_id: &Uuid,
+        client_id: &Uuid,
+        scopes: &[String],
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/oauth/sql/upsert_consent.sql",
+            user_id,
+            client_id,
+            scopes
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
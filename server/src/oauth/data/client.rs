@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize)]
+pub struct OAuthClient {
+    pub id: Uuid,
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    /// Confidential clients authenticate with a secret at the token endpoint
+    pub confidential: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OAuthClientData {
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+}
+
+/// A registered client as used by the authorization flow, `secret` is hashed
+pub struct RegisteredClient {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub secret: Option<String>,
+    pub redirect_uris: Vec<String>,
+}
+
+impl RegisteredClient {
+    /// Redirect URIs have to match exactly, no wildcards
+    pub fn has_redirect_uri(&self, redirect_uri: &str) -> bool {
+        self.redirect_uris.iter().any(|uri| uri == redirect_uri)
+    }
+}
+
+impl OAuthClient {
+    pub async fn list(pool: &PgPool, project_id: &Uuid) -> sqlx::Result<Vec<OAuthClient>> {
+        sqlx::query_file_as!(OAuthClient, "src/oauth/sql/list_clients.sql", project_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        project_id: &Uuid,
+        client: &OAuthClientData,
+        secret: Option<&str>,
+    ) -> sqlx::Result<Uuid> {
+        sqlx::query_file!(
+            "src/oauth/sql/create_client.sql",
+            project_id,
+            client.name,
+            secret,
+            &client.redirect_uris,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|row| row.id)
+    }
+
+    pub async fn update(
+        pool: &PgPool,
+        id: &Uuid,
+        project_id: &Uuid,
+        client: &OAuthClientData,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_file!(
+            "src/oauth/sql/update_client.sql",
+            id,
+            project_id,
+            client.name,
+            &client.redirect_uris,
+        )
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected() > 0)
+    }
+
+    pub async fn remove(pool: &PgPool, id: &Uuid, project_id: &Uuid) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/oauth/sql/remove_client.sql", id, project_id)
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected() > 0)
+    }
+
+    pub async fn get(pool: &PgPool, id: &Uuid) -> sqlx::Result<Option<RegisteredClient>> {
+        sqlx::query_file_as!(RegisteredClient, "src/oauth/sql/get_client.sql", id)
+            .fetch_optional(pool)
+            .await
+    }
+}
@@ -81,4 +81,11 @@ pub struct GooglePhotos {
 #[serde(rename_all = "camelCase")]
 pub struct GoogleEmails {
     pub value: String,
+    pub metadata: Option<GoogleFieldMetaData>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GoogleFieldMetaData {
+    pub verified: Option<bool>,
 }
@@ -2,13 +2,18 @@ use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
+pub mod authorization;
+pub mod client;
 pub mod google;
+pub mod userinfo;
 
 pub struct OAuthRequestState {
     pub request_id: Uuid,
     pub csrf_token: String,
     pub pkce_code_verifier: Option<String>,
     pub created_at: DateTime<Utc>,
+    pub user_id: Option<Uuid>,
+    pub redirect_url: Option<String>,
 }
 
 impl OAuthRequestState {
@@ -18,6 +23,8 @@ impl OAuthRequestState {
         csrf_token: Option<&str>,
         pkce_code_verifier: Option<&str>,
         project_id: &Uuid,
+        user_id: Option<Uuid>,
+        redirect_url: Option<&str>,
     ) -> sqlx::Result<()> {
         sqlx::query_file!(
             "src/oauth/sql/insert_oauth_request_state.sql",
@@ -25,6 +32,8 @@ impl OAuthRequestState {
             csrf_token,
             pkce_code_verifier,
             project_id,
+            user_id,
+            redirect_url,
         )
         .execute(pool)
         .await?;
@@ -84,4 +93,12 @@ impl OAuthData {
 
         Ok(())
     }
+
+    pub async fn remove(pool: &PgPool, user_id: &Uuid, provider: &str) -> sqlx::Result<()> {
+        sqlx::query_file!("src/oauth/sql/remove_oauth_data.sql", user_id, provider)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,72 @@
+use crate::user::data::User;
+
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde::Serialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+
+/// Standard OpenID Connect claims, limited by the granted scopes
+#[derive(Serialize)]
+pub struct UserInfo {
+    pub sub: Uuid,
+
+    /// `profile` scope
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture: Option<String>,
+
+    /// `email` scope
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+}
+
+impl UserInfo {
+    pub fn new(user: &User, scopes: &[String]) -> UserInfo {
+        let has_scope = |scope: &str| scopes.iter().any(|s| s == scope);
+
+        let mut info = UserInfo {
+            sub: user.id,
+            name: None,
+            picture: None,
+            email: None,
+            email_verified: None,
+        };
+
+        if has_scope("profile") {
+            info.name = user.display_name.clone();
+            info.picture = user.photo_url.clone();
+        }
+
+        if has_scope("email") && user.email.is_some() {
+            info.email = user.email.clone();
+            info.email_verified = Some(user.email_verified);
+        }
+
+        info
+    }
+}
+
+#[derive(Serialize)]
+pub struct IdToken {
+    pub iss: String,
+    pub aud: Uuid,
+    pub exp: i64,
+    pub iat: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    #[serde(flatten)]
+    pub user: UserInfo,
+}
+
+impl IdToken {
+    pub fn to_jwt(&self, project: &Uuid, key: &[u8]) -> Result<String, ApiError> {
+        let encoding_key =
+            EncodingKey::from_ec_pem(key).map_err(|_| ApiError::InternalServerError)?;
+        let mut header = Header::new(Algorithm::ES384);
+        header.kid = Some(project.to_string());
+        encode(&header, &self, &encoding_key).map_err(|_| ApiError::InternalServerError)
+    }
+}
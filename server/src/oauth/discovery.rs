@@ -0,0 +1,175 @@
+use crate::config::PublicUrl;
+use crate::keys::data::PublicKey;
+use crate::oauth::data::authorization::SCOPES;
+
+use ecdsa::VerifyingKey;
+use p384::NistP384;
+use pkcs8::DecodePublicKey;
+use rocket::outcome::Outcome;
+use rocket::request::{self, FromRequest, Request};
+use rocket::serde::json::Json;
+use serde::Serialize;
+use std::str;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+/// The public URL of the server, see `config::PublicUrl`. Without a URL, it
+/// is the server's address and port.
+pub struct BaseUrl(pub String);
+
+impl BaseUrl {
+    /// Every project is its own issuer because tokens are signed with the
+    /// project's key
+    pub fn issuer(&self, project: &Uuid) -> String {
+        format!("{}/api/oauth/{}", self.0, project)
+    }
+
+    pub fn endpoint(&self, path: &str) -> String {
+        format!("{}/api/oauth/{}", self.0, path)
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for BaseUrl {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let config = req
+            .rocket()
+            .state::<PublicUrl>()
+            .cloned()
+            .unwrap_or_default();
+
+        if let Some(url) = config.url {
+            return Outcome::Success(BaseUrl(url.trim_end_matches('/').to_string()));
+        }
+
+        // the headers are sent by the client, they can only be trusted when
+        // a proxy overwrites them
+        let headers = req.headers();
+        let host = match config.trust_forwarded_headers {
+            false => None,
+            true => headers
+                .get_one("X-Forwarded-Host")
+                .or_else(|| headers.get_one("Host")),
+        };
+
+        let url = match host {
+            Some(host) => {
+                let scheme = headers.get_one("X-Forwarded-Proto").unwrap_or("http");
+                format!("{}://{}", scheme, host)
+            }
+            None => {
+                let config = req.rocket().config();
+                format!("http://{}:{}", config.address, config.port)
+            }
+        };
+
+        Outcome::Success(BaseUrl(url))
+    }
+}
+
+#[derive(Serialize)]
+pub struct Configuration {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub userinfo_endpoint: String,
+    pub introspection_endpoint: String,
+    pub revocation_endpoint: String,
+    pub jwks_uri: String,
+    pub scopes_supported: Vec<&'static str>,
+    pub response_types_supported: Vec<&'static str>,
+    pub grant_types_supported: Vec<&'static str>,
+    pub subject_types_supported: Vec<&'static str>,
+    pub id_token_signing_alg_values_supported: Vec<&'static str>,
+    pub token_endpoint_auth_methods_supported: Vec<&'static str>,
+    pub code_challenge_methods_supported: Vec<&'static str>,
+    pub claims_supported: Vec<&'static str>,
+}
+
+#[get("/<project>/.well-known/openid-configuration")]
+pub async fn configuration(
+    pool: Db,
+    project: Uuid,
+    base_url: BaseUrl,
+) -> Result<Json<Configuration>, ApiError> {
+    if PublicKey::get_by_project(&pool, &project).await?.is_empty() {
+        return Err(ApiError::ProjectNotFound);
+    }
+
+    let issuer = base_url.issuer(&project);
+
+    Ok(Json(Configuration {
+        jwks_uri: format!("{}/jwks", issuer),
+        issuer,
+        authorization_endpoint: base_url.endpoint("authorize"),
+        token_endpoint: base_url.endpoint("token"),
+        userinfo_endpoint: base_url.endpoint("userinfo"),
+        introspection_endpoint: base_url.endpoint("introspect"),
+        revocation_endpoint: base_url.endpoint("revoke"),
+        scopes_supported: SCOPES.to_vec(),
+        response_types_supported: vec!["code"],
+        grant_types_supported: vec!["authorization_code", "client_credentials"],
+        subject_types_supported: vec!["public"],
+        id_token_signing_alg_values_supported: vec!["ES384"],
+        token_endpoint_auth_methods_supported: vec![
+            "client_secret_basic",
+            "client_secret_post",
+            "none",
+        ],
+        code_challenge_methods_supported: vec!["S256"],
+        claims_supported: vec!["sub", "name", "picture", "email", "email_verified"],
+    }))
+}
+
+#[derive(Serialize)]
+pub struct Jwk {
+    pub kty: &'static str,
+    pub crv: &'static str,
+    pub alg: &'static str,
+    #[serde(rename = "use")]
+    pub key_use: &'static str,
+    pub kid: Uuid,
+    pub x: String,
+    pub y: String,
+}
+
+impl Jwk {
+    pub fn from_pem(kid: Uuid, pem: &[u8]) -> Option<Jwk> {
+        let pem = str::from_utf8(pem).ok()?;
+        let key = VerifyingKey::<NistP384>::from_public_key_pem(pem).ok()?;
+        let point = key.to_encoded_point(false);
+
+        Some(Jwk {
+            kty: "EC",
+            crv: "P-384",
+            alg: "ES384",
+            key_use: "sig",
+            kid,
+            x: base64::encode_config(point.x()?, base64::URL_SAFE_NO_PAD),
+            y: base64::encode_config(point.y()?, base64::URL_SAFE_NO_PAD),
+        })
+    }
+}
+
+#[derive(Serialize)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+#[get("/<project>/jwks")]
+pub async fn jwks(pool: Db, project: Uuid) -> Result<Json<JwkSet>, ApiError> {
+    let keys: Vec<Jwk> = PublicKey::get_by_project(&pool, &project)
+        .await?
+        .iter()
+        .filter_map(|key| Jwk::from_pem(key.id, &key.key))
+        .collect();
+
+    if keys.is_empty() {
+        return Err(ApiError::ProjectNotFound);
+    }
+
+    Ok(Json(JwkSet { keys }))
+}
@@ -0,0 +1,62 @@
+use rocket::http::{ContentType, Header, Status};
+use rocket::request::Request;
+use rocket::response::{self, Responder, Response};
+use serde_json::json;
+use std::io::Cursor;
+use vulpo_auth_types::error::ApiError;
+
+/// Errors of the token, introspection and revocation endpoints, they respond
+/// with `{ "error": "invalid_grant" }` as defined in RFC 6749 section 5.2
+#[derive(Debug)]
+pub struct OAuthError(pub ApiError);
+
+impl From<ApiError> for OAuthError {
+    fn from(error: ApiError) -> Self {
+        OAuthError(error)
+    }
+}
+
+impl OAuthError {
+    fn code(&self) -> Option<&'static str> {
+        let code = match self.0 {
+            ApiError::OAuthInvalidClient => "invalid_client",
+            ApiError::OAuthInvalidGrant => "invalid_grant",
+            ApiError::OAuthInvalidScope => "invalid_scope",
+            ApiError::OAuthInvalidRequest | ApiError::BadRequest => "invalid_request",
+            ApiError::OAuthUnsupportedGrantType => "unsupported_grant_type",
+            ApiError::OAuthUnsupportedTokenType => "unsupported_token_type",
+            _ => return None,
+        };
+
+        Some(code)
+    }
+}
+
+impl<'r> Responder<'r, 'static> for OAuthError {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        // errors that aren't defined by OAuth, e.g. internal errors
+        let code = match self.code() {
+            Some(code) => code,
+            None => return self.0.respond_to(req),
+        };
+
+        let body = json!({ "error": code }).to_string();
+        let mut response = Response::build();
+
+        response
+            .sized_body(body.len(), Cursor::new(body))
+            .header(ContentType::JSON)
+            .header(Header::new("Cache-Control", "no-store"));
+
+        // clients have to authenticate again, see RFC 6749 section 5.2
+        if self.0 == ApiError::OAuthInvalidClient {
+            response
+                .status(Status::Unauthorized)
+                .header(Header::new("WWW-Authenticate", "Basic"));
+        } else {
+            response.status(Status::BadRequest);
+        }
+
+        response.ok()
+    }
+}
@@ -1,13 +1,20 @@
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 use crate::config::Secrets;
 use crate::keys::data::ProjectKeys;
 use crate::oauth::data::google::GoogleMeResponse;
 use crate::oauth::data::OAuthData;
 use crate::oauth::data::{google::GoogleConfig, OAuthRequestState};
+use crate::project::data::EmailDomains;
 use crate::project::data::Flags;
+use crate::project::data::Project as ProjectData;
+use crate::project::data::RedirectUrls;
 use crate::project::Project;
-use crate::session::data::{AccessToken, Session};
-use crate::user::data::{User, UserProvider, UserState};
+use crate::role::data::Permission;
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{AccessToken, AuthMethod, Session, SessionMetadata};
+use crate::session::limit::enforce_session_limit;
+use crate::user::data::{UpgradeAnonymous, User, UserProvider};
+use crate::user::verify_email::enforce_email_verification;
 
 use chrono::{Duration, Utc};
 use oauth2::reqwest::async_http_client;
@@ -35,6 +42,10 @@ const GOOGLE: &'static str = "google";
 #[derive(Deserialize)]
 pub struct GetAuthUrlPayload {
     pub request_id: Uuid,
+
+    /// Replaces the configured redirect URI, has to be on the project's
+    /// allow-list and registered with Google
+    pub redirect_url: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -42,12 +53,16 @@ pub struct GetAuthUrlResponse {
     pub url: String,
 }
 
+/// `redirect_url` replaces the configured redirect URI, it has to be verified
+/// with `RedirectUrls::verify`
 pub async fn get_authorize_url(
     pool: &Db,
     request_id: Uuid,
     project_id: &Uuid,
+    user_id: Option<Uuid>,
+    redirect_url: Option<&str>,
 ) -> Result<String, ApiError> {
-    let client = get_client(&pool, &project_id).await?;
+    let client = get_client(pool, project_id, redirect_url).await?;
 
     let (pkce_code_challenge, pkce_code_verifier) = PkceCodeChallenge::new_random_sha256();
     let (authorize_url, csrf_state) = client
@@ -64,6 +79,8 @@ pub async fn get_authorize_url(
         Some(csrf_state.secret()),
         Some(pkce_code_verifier.secret()),
         &project_id,
+        user_id,
+        redirect_url,
     )
     .await?;
 
@@ -77,7 +94,15 @@ pub async fn get_auth_url(
     project: Project,
 ) -> Result<Json<GetAuthUrlResponse>, ApiError> {
     Flags::has_flags(&db, &project.id, &[Flags::OAuthGoogle]).await?;
-    let url = get_authorize_url(&db, body.request_id, &project.id).await?;
+    RedirectUrls::verify(&db, &project.id, body.redirect_url.as_deref()).await?;
+    let url = get_authorize_url(
+        &db,
+        body.request_id,
+        &project.id,
+        None,
+        body.redirect_url.as_deref(),
+    )
+    .await?;
     let response = Json(GetAuthUrlResponse { url });
     Ok(response)
 }
@@ -99,7 +124,10 @@ pub async fn google_confirm(
     payload: GoogleConfirmPayload,
     project_id: Uuid,
     passphrase: &str,
+    metadata: &SessionMetadata,
 ) -> Result<SessionResponse, ApiError> {
+    AccessToken::check_binding(db, &project_id, &payload.public_key).await?;
+
     let request_state = OAuthRequestState::get(&db, &payload.request_id).await?;
 
     let csrf_token = CsrfToken::new(payload.csrf_token.clone());
@@ -107,7 +135,8 @@ pub async fn google_confirm(
         return Err(ApiError::BadRequest);
     }
 
-    let client = get_client(&db, &project_id).await?;
+    // the code is only valid for the redirect URI of the authorization request
+    let client = get_client(db, &project_id, request_state.redirect_url.as_deref()).await?;
 
     let code = AuthorizationCode::new(payload.code.clone());
 
@@ -145,15 +174,76 @@ pub async fn google_confirm(
     let google_id = get_google_id(&res)?;
     let user_id = OAuthData::get_user_id(&db, &google_id, GOOGLE, &project_id).await?;
 
-    let user = match user_id {
-        Some(uid) => User::get_by_id(&db, &uid, &project_id)
+    let user = match (user_id, request_state.user_id) {
+        (Some(uid), Some(linking_id)) if uid != linking_id => {
+            return Err(ApiError::UserProviderLinked);
+        }
+        (None, Some(linking_id)) => {
+            let email_verified = is_email_verified(&res);
+            let provider_user = get_user(res, payload.device_languages.clone())?;
+
+            let user = User::get_by_id(&db, &linking_id, &project_id)
+                .await?
+                .ok_or(ApiError::NotFound)?;
+
+            let user = if user.is_anonymous() {
+                EmailDomains::verify(&db, &project_id, &provider_user.email).await?;
+
+                let upgrade = UpgradeAnonymous {
+                    email: provider_user.email.clone(),
+                    email_verified,
+                    provider_id: String::from(GOOGLE),
+                    display_name: provider_user.display_name,
+                    photo_url: provider_user.photo_url,
+                };
+
+                User::upgrade_anonymous(&db, &user.id, &upgrade)
+                    .await?
+                    .ok_or(ApiError::Forbidden)?
+            } else {
+                User::add_provider(&db, &user.id, GOOGLE).await?;
+                user
+            };
+
+            OAuthData::upsert(
+                &db,
+                &google_id,
+                GOOGLE,
+                &provider_user.email,
+                &user.id,
+                &project_id,
+            )
+            .await?;
+
+            user
+        }
+        (Some(uid), _) => User::get_by_id(&db, &uid, &project_id)
             .await?
             .ok_or(ApiError::InternalServerError)?,
-        None => {
-            Flags::has_flags(&db, &project_id, &[Flags::SignUp]).await?;
-
+        (None, None) => {
+            let email_verified = is_email_verified(&res);
             let provider_user = get_user(res, payload.device_languages.clone())?;
-            let user = User::create_provider(&db, &provider_user, &project_id).await?;
+
+            let existing = User::get_by_email(&db, &provider_user.email, &project_id).await?;
+
+            let user = match existing {
+                Some(user) => {
+                    // Only link when both sides proved ownership of the email address,
+                    // otherwise an unverified account could take over the Google identity
+                    let auto_link = ProjectData::auto_link(&db, &project_id).await?;
+                    if !(auto_link && email_verified && user.email_verified) {
+                        return Err(ApiError::UserExists);
+                    }
+
+                    User::add_provider(&db, &user.id, GOOGLE).await?;
+                    user
+                }
+                None => {
+                    Flags::has_flags(&db, &project_id, &[Flags::SignUp]).await?;
+                    EmailDomains::verify(&db, &project_id, &provider_user.email).await?;
+                    User::create_provider(&db, &provider_user, &project_id).await?
+                }
+            };
 
             OAuthData::upsert(
                 &db,
@@ -169,24 +259,35 @@ pub async fn google_confirm(
         }
     };
 
-    if user.state == UserState::Disabled {
+    if user.is_disabled() {
         return Err(ApiError::UserDisabled);
     }
 
+    let email_verified = enforce_email_verification(&db, &user, &project_id).await?;
+    enforce_session_limit(&db, &payload.session, &user.id, &project_id).await?;
+
     let session = Session {
         id: payload.session,
         public_key: payload.public_key.to_owned(),
         user_id: Some(user.id),
         expire_at: Utc::now() + Duration::days(30),
         project_id,
+        auth_time: Some(Utc::now()),
+        auth_methods: vec![AuthMethod::Federated.to_string()],
     };
 
-    let session = Session::create(&db, session).await?;
+    let session = Session::create(&db, session, metadata).await?;
 
     let private_key = ProjectKeys::get_private_key(&cache, &db, &project_id, passphrase).await?;
 
+    let permissions = Permission::for_user(&db, &user.id).await?;
     let exp = Utc::now() + Duration::minutes(15);
     let access_token = AccessToken::new(&user.id, &user.traits, exp)
+        .with_permissions(permissions)
+        .with_email_verified(email_verified)
+        .with_auth(&session)
+        .bind_to(&db, &session)
+        .await?
         .to_jwt(&project_id, &private_key)
         .map_err(|_| ApiError::InternalServerError)?;
 
@@ -206,15 +307,20 @@ pub async fn exchange_code(
     project: Project,
     secrets: &State<Secrets>,
     cache: Cache,
+    metadata: SessionMetadata,
+    cookies: SessionCookies<'_>,
 ) -> Result<SessionResponse, ApiError> {
-    let session = google_confirm(
+    let mut session = google_confirm(
         &cache,
         &db,
         body.into_inner(),
         project.id,
         &secrets.passphrase,
+        &metadata,
     )
     .await?;
+
+    cookies.set(&cache, &db, &project.id, &mut session).await?;
     Ok(session)
 }
 
@@ -234,11 +340,12 @@ pub async fn upsert_config(
 
 #[post("/google/set_config?<project>", format = "json", data = "<config>")]
 pub async fn set_config(
-    _admin: Admin,
+    admin: Admin,
     db: Db,
     config: Json<GoogleConfig>,
     project: Uuid,
 ) -> Result<Status, ApiError> {
+    admin.require(&project, AdminRole::Owner)?;
     upsert_config(&db, config.into_inner(), &project).await?;
     Ok(Status::Ok)
 }
@@ -253,15 +360,20 @@ pub async fn get_client_config(
 
 #[get("/google/get_config?<project>")]
 pub async fn get_config(
-    _admin: Admin,
+    admin: Admin,
     db: Db,
     project: Uuid,
 ) -> Result<Json<Option<GoogleConfig>>, ApiError> {
+    admin.require(&project, AdminRole::Owner)?;
     let config = get_client_config(&db, &project).await?;
     Ok(Json(config))
 }
 
-async fn get_client(pool: &PgPool, project_id: &Uuid) -> Result<BasicClient, ApiError> {
+async fn get_client(
+    pool: &PgPool,
+    project_id: &Uuid,
+    redirect_url: Option<&str>,
+) -> Result<BasicClient, ApiError> {
     let config = GoogleConfig::get(&pool, &project_id)
         .await?
         .ok_or(ApiError::BadRequest)?;
@@ -273,8 +385,12 @@ async fn get_client(pool: &PgPool, project_id: &Uuid) -> Result<BasicClient, Api
         AuthUrl::new("https://accounts.google.com/o/oauth2/v2/auth".to_string()).unwrap();
     let token_url =
         TokenUrl::new("https://www.googleapis.com/oauth2/v3/token".to_string()).unwrap();
-    let redirect_utl =
-        RedirectUrl::new(config.redirect_uri).map_err(|_| ApiError::InternalServerError)?;
+    let redirect_utl = match redirect_url {
+        Some(url) => {
+            RedirectUrl::new(url.to_string()).map_err(|_| ApiError::RedirectUrlNotAllowed)?
+        }
+        None => RedirectUrl::new(config.redirect_uri).map_err(|_| ApiError::InternalServerError)?,
+    };
 
     let client = BasicClient::new(
         google_client_id,
@@ -295,6 +411,14 @@ fn get_google_id(data: &GoogleMeResponse) -> Result<String, ApiError> {
         .map(|source| source.id.clone())
 }
 
+fn is_email_verified(data: &GoogleMeResponse) -> bool {
+    data.email_addresses
+        .get(0)
+        .and_then(|email| email.metadata.as_ref())
+        .and_then(|metadata| metadata.verified)
+        .unwrap_or(false)
+}
+
 fn get_user(
     data: GoogleMeResponse,
     device_languages: Vec<String>,
@@ -0,0 +1,202 @@
+use crate::api_key::data::ApiKey;
+use crate::crypto::Token;
+use crate::keys::data::ProjectKeys;
+use crate::oauth::error::OAuthError;
+use crate::oauth::token::{authenticate_service_account, client_credentials_from, BasicAuth};
+use crate::session::data::{AccessToken, Session};
+use crate::user::data::User;
+
+use chrono::Utc;
+use rocket::form::{Form, FromForm};
+use rocket::serde::json::Json;
+use serde::Serialize;
+use uuid::Uuid;
+use vulpo::{Claims, Confirmation};
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+/// `token_type_hint` is ignored, the type is detected from the token
+#[derive(FromForm)]
+pub struct TokenForm {
+    pub token: String,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+}
+
+/// The kinds of tokens the server issues
+pub enum TokenKind {
+    AccessToken,
+    ApiKey(Uuid, String),
+    Session(Uuid),
+}
+
+impl TokenKind {
+    pub fn parse(token: &str) -> Option<TokenKind> {
+        if let Ok(id) = Uuid::parse_str(token) {
+            return Some(TokenKind::Session(id));
+        }
+
+        if token.split('.').count() == 3 {
+            return Some(TokenKind::AccessToken);
+        }
+
+        ApiKey::parse_token(token)
+            .ok()
+            .map(|(id, token)| TokenKind::ApiKey(id, token))
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct Introspection {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traits: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cnf: Option<Confirmation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+}
+
+impl Introspection {
+    fn inactive() -> Introspection {
+        Introspection::default()
+    }
+
+    fn from_claims(token_type: &'static str, claims: Claims, exp: Option<i64>) -> Introspection {
+        Introspection {
+            active: true,
+            token_type: Some(token_type),
+            sub: Some(claims.sub),
+            exp,
+            scope: claims.scope,
+            client_id: claims.client_id,
+            traits: Some(claims.traits),
+            permissions: Some(claims.permissions),
+            cnf: claims.cnf,
+            email_verified: claims.email_verified,
+        }
+    }
+}
+
+async fn access_token(pool: &Db, token: &str, project_id: &Uuid) -> Option<Introspection> {
+    // tokens of other projects fail to verify with the project's key
+    let key = ProjectKeys::get_public_key(&pool, &project_id).await.ok()?;
+    let claims = AccessToken::decode(token, &key).ok()?;
+    if AccessToken::is_revoked(&pool, &claims, &project_id)
+        .await
+        .ok()?
+    {
+        return None;
+    }
+
+    let exp = claims.exp;
+    Some(Introspection::from_claims(
+        "access_token",
+        claims,
+        Some(exp),
+    ))
+}
+
+async fn api_key(
+    pool: &Db,
+    id: &Uuid,
+    raw_token: &str,
+    project_id: &Uuid,
+) -> Result<Option<Introspection>, ApiError> {
+    let api_key = match ApiKey::get_token(&pool, &id).await? {
+        Some(api_key) if api_key.project_id == *project_id => api_key,
+        _ => return Ok(None),
+    };
+
+    if api_key
+        .expire_at
+        .map_or(false, |expire_at| Utc::now() > expire_at)
+    {
+        return Ok(None);
+    }
+
+    if !Token::verify(raw_token, &api_key.token)? {
+        return Ok(None);
+    }
+
+    let claims = ApiKey::get_claims(&pool, &id).await?;
+    let exp = api_key.expire_at.map(|expire_at| expire_at.timestamp());
+    Ok(Some(Introspection::from_claims("api_key", claims, exp)))
+}
+
+async fn session(
+    pool: &Db,
+    id: &Uuid,
+    project_id: &Uuid,
+) -> Result<Option<Introspection>, ApiError> {
+    let session = match Session::get(&pool, &id).await {
+        Ok(session) if session.project_id == *project_id && session.expire_at > Utc::now() => {
+            session
+        }
+        _ => return Ok(None),
+    };
+
+    let user = match session.user_id {
+        Some(user_id) => User::get_by_id(&pool, &user_id, &project_id).await?,
+        None => None,
+    };
+
+    let user = match user {
+        Some(user) if !user.is_disabled() => user,
+        _ => return Ok(None),
+    };
+
+    Ok(Some(Introspection {
+        active: true,
+        token_type: Some("session"),
+        sub: Some(user.id),
+        exp: Some(session.expire_at.timestamp()),
+        traits: Some(user.traits),
+        ..Introspection::default()
+    }))
+}
+
+/// Only tokens of the service account's project can be active
+pub async fn introspect(
+    pool: &Db,
+    token: &str,
+    project_id: &Uuid,
+) -> Result<Introspection, ApiError> {
+    let introspection = match TokenKind::parse(token) {
+        Some(TokenKind::AccessToken) => access_token(&pool, token, &project_id).await,
+        Some(TokenKind::ApiKey(id, raw_token)) => {
+            api_key(&pool, &id, &raw_token, &project_id).await?
+        }
+        Some(TokenKind::Session(id)) => session(&pool, &id, &project_id).await?,
+        None => None,
+    };
+
+    Ok(introspection.unwrap_or_else(Introspection::inactive))
+}
+
+#[post("/introspect", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Form<TokenForm>,
+    basic_auth: Option<BasicAuth>,
+) -> Result<Json<Introspection>, OAuthError> {
+    let (client_id, client_secret) =
+        client_credentials_from(basic_auth, &body.client_id, &body.client_secret)?;
+    let client_secret = client_secret.ok_or(ApiError::OAuthInvalidClient)?;
+    let client = authenticate_service_account(&pool, &client_id, &client_secret).await?;
+
+    let introspection = introspect(&pool, &body.token, &client.project_id).await?;
+    Ok(Json(introspection))
+}
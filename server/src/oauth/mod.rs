@@ -1,7 +1,19 @@
 use rocket::Route;
 
-mod data;
-mod google;
+// `#[derive(FromForm)]` allows the removed `private_in_public` lint
+#[allow(renamed_and_removed_lints)]
+mod authorize;
+mod client;
+pub mod data;
+pub mod discovery;
+pub mod error;
+pub mod google;
+#[allow(renamed_and_removed_lints)]
+pub mod introspect;
+mod revoke;
+#[allow(renamed_and_removed_lints)]
+pub mod token;
+mod userinfo;
 
 pub fn routes() -> Vec<Route> {
     routes![
@@ -9,5 +21,19 @@ pub fn routes() -> Vec<Route> {
         google::exchange_code,
         google::set_config,
         google::get_config,
+        token::handler,
+        introspect::handler,
+        revoke::handler,
+        client::list_handler,
+        client::create_handler,
+        client::update_handler,
+        client::delete_handler,
+        authorize::handler,
+        authorize::request_handler,
+        authorize::confirm_handler,
+        authorize::deny_handler,
+        userinfo::handler,
+        discovery::configuration,
+        discovery::jwks,
     ]
 }
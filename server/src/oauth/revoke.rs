@@ -0,0 +1,55 @@
+use crate::api_key::data::ApiKey;
+use crate::crypto::Token;
+use crate::oauth::error::OAuthError;
+use crate::oauth::introspect::{TokenForm, TokenKind};
+use crate::oauth::token::{authenticate_service_account, client_credentials_from, BasicAuth};
+use crate::session::data::Session;
+
+use rocket::form::Form;
+use rocket::http::Status;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+/// Unknown or invalid tokens are ignored, see RFC 7009 section 2.2
+pub async fn revoke(pool: &Db, token: &str, project_id: &Uuid) -> Result<(), ApiError> {
+    match TokenKind::parse(token) {
+        // access tokens can't be revoked, they expire after 15 minutes
+        Some(TokenKind::AccessToken) => return Err(ApiError::OAuthUnsupportedTokenType),
+        Some(TokenKind::ApiKey(id, raw_token)) => {
+            let api_key = match ApiKey::get_token(&pool, &id).await? {
+                Some(api_key) if api_key.project_id == *project_id => api_key,
+                _ => return Ok(()),
+            };
+
+            if Token::verify(&raw_token, &api_key.token)? {
+                ApiKey::delete(&pool, &id, &api_key.user_id, &project_id).await?;
+            }
+        }
+        Some(TokenKind::Session(id)) => {
+            if let Ok(session) = Session::get(&pool, &id).await {
+                if session.project_id == *project_id {
+                    Session::delete(&pool, &session.id).await?;
+                }
+            }
+        }
+        None => {}
+    };
+
+    Ok(())
+}
+
+#[post("/revoke", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Form<TokenForm>,
+    basic_auth: Option<BasicAuth>,
+) -> Result<Status, OAuthError> {
+    let (client_id, client_secret) =
+        client_credentials_from(basic_auth, &body.client_id, &body.client_secret)?;
+    let client_secret = client_secret.ok_or(ApiError::OAuthInvalidClient)?;
+    let client = authenticate_service_account(&pool, &client_id, &client_secret).await?;
+
+    revoke(&pool, &body.token, &client.project_id).await?;
+    Ok(Status::Ok)
+}
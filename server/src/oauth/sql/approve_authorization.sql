@@ -0,0 +1,6 @@
+update oauth_authorizations
+   set user_id = $2
+     , session_id = $3
+     , code = $4
+ where id = $1
+   and code is null
@@ -0,0 +1,3 @@
+insert into oauth_authorizations(client_id, redirect_uri, scopes, state, nonce, code_challenge, expire_at)
+values($1, $2, $3, $4, $5, $6, $7)
+returning id
@@ -0,0 +1,3 @@
+insert into oauth_clients(project_id, name, secret, redirect_uris)
+values($1, $2, $3, $4)
+returning id
@@ -0,0 +1,13 @@
+select id
+     , client_id
+     , redirect_uri
+     , scopes
+     , state
+     , nonce
+     , code_challenge
+     , user_id
+     , session_id
+     , code
+     , expire_at
+  from oauth_authorizations
+ where id = $1
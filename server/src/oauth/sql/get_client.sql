@@ -0,0 +1,7 @@
+select id
+     , project_id
+     , name
+     , secret
+     , redirect_uris
+  from oauth_clients
+ where id = $1
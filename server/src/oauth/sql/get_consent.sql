@@ -0,0 +1,4 @@
+select scopes
+  from oauth_consents
+ where user_id = $1
+   and client_id = $2
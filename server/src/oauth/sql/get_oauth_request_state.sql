@@ -1,7 +1,8 @@
-
 select request_id
      , csrf_token
      , pkce_code_verifier
      , created_at
+     , user_id
+     , redirect_url
   from oauth_request_state
- where request_id = $1
+ where request_id = $1
\ No newline at end of file
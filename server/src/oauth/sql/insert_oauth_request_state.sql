@@ -1,3 +1,2 @@
-
-insert into oauth_request_state(request_id, csrf_token, pkce_code_verifier, project_id)
-values($1, $2, $3, $4)
+insert into oauth_request_state(request_id, csrf_token, pkce_code_verifier, project_id, user_id, redirect_url)
+values($1, $2, $3, $4, $5, $6)
\ No newline at end of file
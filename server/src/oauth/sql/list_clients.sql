@@ -0,0 +1,8 @@
+select id
+     , name
+     , redirect_uris
+     , secret is not null as "confidential!"
+     , created_at
+  from oauth_clients
+ where project_id = $1
+ order by name
@@ -0,0 +1,2 @@
+delete from oauth_authorizations
+ where id = $1
@@ -0,0 +1,3 @@
+delete from oauth_clients
+ where id = $1
+   and project_id = $2
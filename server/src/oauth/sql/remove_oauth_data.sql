@@ -0,0 +1,3 @@
+delete from oauth_data
+ where user_id = $1
+   and provider = $2
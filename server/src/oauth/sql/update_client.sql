@@ -0,0 +1,5 @@
+update oauth_clients
+   set name = $3
+     , redirect_uris = $4
+ where id = $1
+   and project_id = $2
@@ -0,0 +1,4 @@
+insert into oauth_consents(user_id, client_id, scopes)
+values($1, $2, $3)
+on conflict (user_id, client_id) do update
+   set scopes = array( select distinct unnest(oauth_consents.scopes || excluded.scopes) )
@@ -0,0 +1,343 @@
+use crate::config::Secrets;
+use crate::crypto::Token;
+use crate::keys::data::ProjectKeys;
+use crate::oauth::data::authorization::Authorization;
+use crate::oauth::data::client::OAuthClient;
+use crate::oauth::data::userinfo::{IdToken, UserInfo};
+use crate::oauth::discovery::BaseUrl;
+use crate::oauth::error::OAuthError;
+use crate::service_account::data::{ServiceAccount, ServiceAccountClient};
+use crate::session::data::{AccessToken, Session};
+use crate::user::data::User;
+use crate::user::verify_email::enforce_email_verification;
+
+use chrono::{Duration, Utc};
+use oauth2::{PkceCodeChallenge, PkceCodeVerifier};
+use rocket::form::{Form, FromForm};
+use rocket::outcome::Outcome;
+use rocket::request::{self, FromRequest, Request};
+use rocket::serde::json::Json;
+use rocket::State;
+use serde::Serialize;
+use std::str;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::{Cache, Db};
+
+const EXPIRE_IN_MINUTES: i64 = 15;
+
+#[derive(FromForm)]
+pub struct TokenRequest {
+    pub grant_type: String,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+    /// Space separated, defaults to all scopes of the service account
+    pub scope: Option<String>,
+
+    /// `authorization_code` grant
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub code_verifier: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub token_type: &'static str,
+    pub expires_in: i64,
+    pub scope: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_token: Option<String>,
+}
+
+/// Client credentials sent with `Authorization: Basic base64(client_id:client_secret)`
+pub struct BasicAuth {
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+impl BasicAuth {
+    pub fn parse(header: &str) -> Option<BasicAuth> {
+        let encoded = header.strip_prefix("Basic ")?;
+        let decoded = base64::decode(encoded.trim()).ok()?;
+        let decoded = str::from_utf8(&decoded).ok()?;
+        let (client_id, client_secret) = decoded.split_once(':')?;
+
+        Some(BasicAuth {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+        })
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for BasicAuth {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        match req
+            .headers()
+            .get_one("Authorization")
+            .and_then(BasicAuth::parse)
+        {
+            Some(auth) => Outcome::Success(auth),
+            None => Outcome::Forward(()),
+        }
+    }
+}
+
+/// Returns the requested scopes if the service account was granted all of them
+pub fn grant_scopes(requested: Option<&str>, allowed: &[String]) -> Result<Vec<String>, ApiError> {
+    let requested = match requested.map(str::trim) {
+        None | Some("") => return Ok(allowed.to_vec()),
+        Some(scope) => scope,
+    };
+
+    let mut scopes: Vec<String> = requested.split_whitespace().map(String::from).collect();
+    scopes.sort();
+    scopes.dedup();
+
+    if !scopes.iter().all(|scope| allowed.contains(scope)) {
+        return Err(ApiError::OAuthInvalidScope);
+    }
+
+    Ok(scopes)
+}
+
+/// Client credentials can be sent with HTTP basic auth or in the form body
+pub fn client_credentials_from(
+    basic_auth: Option<BasicAuth>,
+    client_id: &Option<String>,
+    client_secret: &Option<String>,
+) -> Result<(String, Option<String>), ApiError> {
+    match (basic_auth, client_id) {
+        (Some(auth), _) => Ok((auth.client_id, Some(auth.client_secret))),
+        (None, Some(id)) => Ok((id.clone(), client_secret.clone())),
+        (None, None) => Err(ApiError::OAuthInvalidClient),
+    }
+}
+
+pub async fn authenticate_service_account(
+    pool: &Db,
+    client_id: &str,
+    client_secret: &str,
+) -> Result<ServiceAccountClient, ApiError> {
+    let client_id = Uuid::parse_str(client_id).map_err(|_| ApiError::OAuthInvalidClient)?;
+
+    let client = ServiceAccount::get_client(&pool, &client_id)
+        .await?
+        .ok_or(ApiError::OAuthInvalidClient)?;
+
+    if !Token::verify(client_secret, &client.secret)? {
+        return Err(ApiError::OAuthInvalidClient);
+    }
+
+    Ok(client)
+}
+
+pub async fn client_credentials(
+    pool: &Db,
+    cache: &Cache,
+    passphrase: &str,
+    client_id: &str,
+    client_secret: &str,
+    scope: Option<&str>,
+) -> Result<TokenResponse, ApiError> {
+    let client = authenticate_service_account(&pool, client_id, client_secret).await?;
+    let scopes = grant_scopes(scope, &client.scopes)?;
+
+    let private_key =
+        ProjectKeys::get_private_key(&cache, &pool, &client.project_id, passphrase).await?;
+
+    let exp = Utc::now() + Duration::minutes(EXPIRE_IN_MINUTES);
+    let access_token = AccessToken::for_client(&client.id, &scopes, exp)
+        .to_jwt(&client.project_id, &private_key)
+        .map_err(|_| ApiError::InternalServerError)?;
+
+    Ok(TokenResponse {
+        access_token,
+        token_type: "Bearer",
+        expires_in: EXPIRE_IN_MINUTES * 60,
+        scope: scopes.join(" "),
+        id_token: None,
+    })
+}
+
+/// Codes have the format `<authorization id>.<token>`
+fn parse_code(code: &str) -> Option<(Uuid, &str)> {
+    let (id, token) = code.split_once('.')?;
+    let id = Uuid::parse_str(id).ok()?;
+    Some((id, token))
+}
+
+pub async fn authorization_code(
+    pool: &Db,
+    cache: &Cache,
+    passphrase: &str,
+    base_url: &BaseUrl,
+    client_id: &str,
+    client_secret: Option<&str>,
+    body: &TokenRequest,
+) -> Result<TokenResponse, ApiError> {
+    let client_id = Uuid::parse_str(client_id).map_err(|_| ApiError::OAuthInvalidClient)?;
+    let client = OAuthClient::get(&pool, &client_id)
+        .await?
+        .ok_or(ApiError::OAuthInvalidClient)?;
+
+    // public clients only authenticate with PKCE
+    if let Some(hashed_secret) = &client.secret {
+        let secret = client_secret.ok_or(ApiError::OAuthInvalidClient)?;
+        if !Token::verify(secret, hashed_secret)? {
+            return Err(ApiError::OAuthInvalidClient);
+        }
+    }
+
+    let (code, redirect_uri, code_verifier) =
+        match (&body.code, &body.redirect_uri, &body.code_verifier) {
+            (Some(code), Some(uri), Some(verifier)) => (code, uri, verifier),
+            _ => return Err(ApiError::OAuthInvalidRequest),
+        };
+
+    let (id, token) = parse_code(code).ok_or(ApiError::OAuthInvalidGrant)?;
+    let authorization = Authorization::get(&pool, &id)
+        .await?
+        .ok_or(ApiError::OAuthInvalidGrant)?;
+
+    // codes can only be used once, even if the exchange fails
+    if !Authorization::remove(&pool, &authorization.id).await? {
+        return Err(ApiError::OAuthInvalidGrant);
+    }
+
+    let hashed_code = authorization
+        .code
+        .as_ref()
+        .ok_or(ApiError::OAuthInvalidGrant)?;
+
+    if !Token::verify(token, hashed_code)?
+        || authorization.is_expired()
+        || authorization.client_id != client.id
+        || &authorization.redirect_uri != redirect_uri
+    {
+        return Err(ApiError::OAuthInvalidGrant);
+    }
+
+    // RFC 7636 verifiers are between 43 and 128 characters long
+    if !(43..=128).contains(&code_verifier.len()) {
+        return Err(ApiError::OAuthInvalidGrant);
+    }
+
+    let verifier = PkceCodeVerifier::new(code_verifier.clone());
+    let challenge = PkceCodeChallenge::from_code_verifier_sha256(&verifier);
+    if challenge.as_str() != authorization.code_challenge {
+        return Err(ApiError::OAuthInvalidGrant);
+    }
+
+    let (user_id, session_id) = match (authorization.user_id, authorization.session_id) {
+        (Some(user_id), Some(session_id)) => (user_id, session_id),
+        _ => return Err(ApiError::OAuthInvalidGrant),
+    };
+
+    // the user has to still be signed in with the session that approved the request
+    let session = Session::get(&pool, &session_id)
+        .await
+        .map_err(|_| ApiError::OAuthInvalidGrant)?;
+
+    if session.user_id != Some(user_id) || session.expire_at < Utc::now() {
+        return Err(ApiError::OAuthInvalidGrant);
+    }
+
+    let user = User::get_by_id(&pool, &user_id, &client.project_id)
+        .await?
+        .ok_or(ApiError::OAuthInvalidGrant)?;
+
+    if user.is_disabled() {
+        return Err(ApiError::OAuthInvalidGrant);
+    }
+
+    if let Err(err) = enforce_email_verification(&pool, &user, &client.project_id).await {
+        return match err {
+            ApiError::UserEmailNotVerified => Err(ApiError::OAuthInvalidGrant),
+            err => Err(err),
+        };
+    }
+
+    let private_key =
+        ProjectKeys::get_private_key(&cache, &pool, &client.project_id, passphrase).await?;
+
+    let now = Utc::now();
+    let exp = now + Duration::minutes(EXPIRE_IN_MINUTES);
+    let scopes = authorization.scopes;
+
+    // clients only get the user and the granted scopes, traits and permissions
+    // are for the project's own APIs
+    let access_token = AccessToken::new(&user.id, &vec![], exp)
+        .with_client(&client.id, &scopes)
+        .to_jwt(&client.project_id, &private_key)?;
+
+    let id_token = match scopes.iter().any(|scope| scope == "openid") {
+        false => None,
+        true => {
+            let id_token = IdToken {
+                iss: base_url.issuer(&client.project_id),
+                aud: client.id,
+                exp: exp.timestamp(),
+                iat: now.timestamp(),
+                nonce: authorization.nonce,
+                user: UserInfo::new(&user, &scopes),
+            };
+
+            Some(id_token.to_jwt(&client.project_id, &private_key)?)
+        }
+    };
+
+    Ok(TokenResponse {
+        access_token,
+        token_type: "Bearer",
+        expires_in: EXPIRE_IN_MINUTES * 60,
+        scope: scopes.join(" "),
+        id_token,
+    })
+}
+
+#[post("/token", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    cache: Cache,
+    body: Form<TokenRequest>,
+    basic_auth: Option<BasicAuth>,
+    base_url: BaseUrl,
+    secrets: &State<Secrets>,
+) -> Result<Json<TokenResponse>, OAuthError> {
+    let (client_id, client_secret) =
+        client_credentials_from(basic_auth, &body.client_id, &body.client_secret)?;
+
+    let token = match body.grant_type.as_str() {
+        "client_credentials" => {
+            let client_secret = client_secret.ok_or(ApiError::OAuthInvalidClient)?;
+            client_credentials(
+                &pool,
+                &cache,
+                &secrets.passphrase,
+                &client_id,
+                &client_secret,
+                body.scope.as_deref(),
+            )
+            .await?
+        }
+        "authorization_code" => {
+            authorization_code(
+                &pool,
+                &cache,
+                &secrets.passphrase,
+                &base_url,
+                &client_id,
+                client_secret.as_deref(),
+                &body,
+            )
+            .await?
+        }
+        _ => return Err(ApiError::OAuthUnsupportedGrantType.into()),
+    };
+
+    Ok(Json(token))
+}
@@ -0,0 +1,74 @@
+use crate::keys::data::ProjectKeys;
+use crate::oauth::data::userinfo::UserInfo;
+use crate::session::data::AccessToken;
+use crate::user::data::User;
+
+use jsonwebtoken::decode_header;
+use rocket::outcome::Outcome;
+use rocket::request::{self, FromRequest, Request};
+use rocket::serde::json::Json;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+/// Third-party clients don't send the `Vulpo-Project` header, the project
+/// is taken from the `kid` of the token instead
+pub struct BearerToken(pub String);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for BearerToken {
+    type Error = ApiError;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        match req
+            .headers()
+            .get_one("Authorization")
+            .and_then(|header| header.strip_prefix("Bearer "))
+        {
+            Some(token) => Outcome::Success(BearerToken(token.trim().to_string())),
+            None => Outcome::Forward(()),
+        }
+    }
+}
+
+pub async fn userinfo(pool: &Db, token: &str) -> Result<UserInfo, ApiError> {
+    let project_id = decode_header(token)
+        .ok()
+        .and_then(|header| header.kid)
+        .and_then(|kid| Uuid::parse_str(&kid).ok())
+        .ok_or(ApiError::OAuthInvalidToken)?;
+
+    let key = ProjectKeys::get_public_key(&pool, &project_id)
+        .await
+        .map_err(|_| ApiError::OAuthInvalidToken)?;
+
+    let claims = AccessToken::decode(token, &key).map_err(|_| ApiError::OAuthInvalidToken)?;
+
+    if AccessToken::is_revoked(&pool, &claims, &project_id).await? {
+        return Err(ApiError::OAuthInvalidToken);
+    }
+
+    // only tokens issued to an OAuth client with the `openid` scope
+    if claims.client_id.is_none() || claims.is_service_account() || !claims.has_scope("openid") {
+        return Err(ApiError::Forbidden);
+    }
+
+    let user = User::get_by_id(&pool, &claims.sub, &project_id)
+        .await?
+        .ok_or(ApiError::OAuthInvalidToken)?;
+
+    let scopes: Vec<String> = claims
+        .scope
+        .unwrap_or_default()
+        .split(' ')
+        .map(String::from)
+        .collect();
+
+    Ok(UserInfo::new(&user, &scopes))
+}
+
+#[get("/userinfo")]
+pub async fn handler(pool: Db, token: BearerToken) -> Result<Json<UserInfo>, ApiError> {
+    let info = userinfo(&pool, &token.0).await?;
+    Ok(Json(info))
+}
@@ -0,0 +1,63 @@
+use crate::crypto::Token;
+use crate::organization::data::Invitation;
+use crate::project::Project;
+use crate::session::data::AccessToken;
+use crate::user::data::User;
+
+use chrono::Utc;
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct AcceptInvitation {
+    pub id: Uuid,
+    pub token: String,
+}
+
+pub async fn accept(
+    pool: &Db,
+    user_id: Uuid,
+    project_id: Uuid,
+    body: AcceptInvitation,
+) -> Result<(), ApiError> {
+    let invitation = Invitation::get(&pool, &body.id, &project_id)
+        .await?
+        .ok_or(ApiError::TokenNotFound)?;
+
+    if !Token::verify(&body.token, &invitation.token)? {
+        return Err(ApiError::TokenInvalid);
+    }
+
+    if Utc::now() > invitation.expire_at {
+        return Err(ApiError::TokenExpired);
+    }
+
+    let user = User::get_by_id(&pool, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    // the invitation is bound to the email address it was sent to
+    let email = user.email.unwrap_or_default().to_lowercase();
+    if email != invitation.email {
+        return Err(ApiError::Forbidden);
+    }
+
+    Invitation::accept(&pool, &invitation.id, &user.id).await?;
+
+    Ok(())
+}
+
+#[post("/accept", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<AcceptInvitation>,
+    project: Project,
+    token: AccessToken,
+) -> Result<Status, ApiError> {
+    accept(&pool, token.sub(), project.id, body.into_inner()).await?;
+    Ok(Status::Ok)
+}
@@ -0,0 +1,51 @@
+use crate::organization::data::Organization;
+use crate::project::data::Flags;
+use crate::project::Project;
+use crate::session::data::AccessToken;
+use crate::user::data::User;
+
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct CreateOrganization {
+    pub name: String,
+}
+
+pub async fn create(
+    pool: &Db,
+    user_id: Uuid,
+    project_id: Uuid,
+    body: CreateOrganization,
+) -> Result<Organization, ApiError> {
+    let name = body.name.trim();
+    if name.is_empty() {
+        return Err(ApiError::BadRequest);
+    }
+
+    let user = User::get_by_id(&pool, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if user.is_anonymous() {
+        return Err(ApiError::Forbidden);
+    }
+
+    let organization = Organization::create(&pool, &project_id, name, &user.id).await?;
+    Ok(organization)
+}
+
+#[post("/", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<CreateOrganization>,
+    project: Project,
+    token: AccessToken,
+) -> Result<Json<Organization>, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::Organizations]).await?;
+    let organization = create(&pool, token.sub(), project.id, body.into_inner()).await?;
+    Ok(Json(organization))
+}
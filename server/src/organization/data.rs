@@ -0,0 +1,237 @@
+use crate::session::data::OrgRole;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(sqlx::Type, PartialEq, PartialOrd, Debug, Copy, Clone, Deserialize, Serialize)]
+#[sqlx(type_name = "organization_role")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationRole {
+    Member,
+    Admin,
+    Owner,
+}
+
+impl From<OrganizationRole> for OrgRole {
+    fn from(role: OrganizationRole) -> Self {
+        match role {
+            OrganizationRole::Member => OrgRole::Member,
+            OrganizationRole::Admin => OrgRole::Admin,
+            OrganizationRole::Owner => OrgRole::Owner,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Organization {
+    pub id: Uuid,
+    pub name: String,
+    pub role: OrganizationRole,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct OrganizationName {
+    pub id: Uuid,
+    pub name: String,
+}
+
+impl Organization {
+    pub async fn create(
+        pool: &PgPool,
+        project_id: &Uuid,
+        name: &str,
+        owner: &Uuid,
+    ) -> sqlx::Result<Organization> {
+        sqlx::query_file_as!(
+            Organization,
+            "src/organization/sql/create_organization.sql",
+            project_id,
+            name,
+            owner,
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn get(
+        pool: &PgPool,
+        id: &Uuid,
+        project_id: &Uuid,
+    ) -> sqlx::Result<Option<OrganizationName>> {
+        sqlx::query_file_as!(
+            OrganizationName,
+            "src/organization/sql/get_organization.sql",
+            id,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn list(
+        pool: &PgPool,
+        user_id: &Uuid,
+        project_id: &Uuid,
+    ) -> sqlx::Result<Vec<Organization>> {
+        sqlx::query_file_as!(
+            Organization,
+            "src/organization/sql/list_organizations.sql",
+            user_id,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn remove(pool: &PgPool, id: &Uuid) -> sqlx::Result<()> {
+        sqlx::query_file!("src/organization/sql/remove_organization.sql", id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Membership {
+    pub organization_id: Uuid,
+    pub user_id: Uuid,
+    pub role: OrganizationRole,
+}
+
+pub struct MemberCount {
+    pub members: i64,
+    pub owners: i64,
+}
+
+impl Membership {
+    pub async fn get(
+        pool: &PgPool,
+        organization_id: &Uuid,
+        user_id: &Uuid,
+        project_id: &Uuid,
+    ) -> sqlx::Result<Option<Membership>> {
+        sqlx::query_file_as!(
+            Membership,
+            "src/organization/sql/get_membership.sql",
+            organization_id,
+            user_id,
+            project_id,
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// The membership of the organization that is active for the session
+    pub async fn from_session(
+        pool: &PgPool,
+        session_id: &Uuid,
+    ) -> sqlx::Result<Option<Membership>> {
+        sqlx::query_file_as!(
+            Membership,
+            "src/organization/sql/get_session_membership.sql",
+            session_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn set_active(
+        pool: &PgPool,
+        session_id: &Uuid,
+        user_id: &Uuid,
+        organization_id: Option<Uuid>,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_file!(
+            "src/organization/sql/set_session_organization.sql",
+            session_id,
+            user_id,
+            organization_id,
+        )
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected() > 0)
+    }
+
+    pub async fn count(pool: &PgPool, organization_id: &Uuid) -> sqlx::Result<MemberCount> {
+        sqlx::query_file_as!(
+            MemberCount,
+            "src/organization/sql/count_members.sql",
+            organization_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn remove(pool: &PgPool, organization_id: &Uuid, user_id: &Uuid) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/organization/sql/remove_member.sql",
+            organization_id,
+            user_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+pub struct Invitation {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub email: String,
+    pub role: OrganizationRole,
+    pub token: String,
+    pub expire_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    pub async fn upsert(
+        pool: &PgPool,
+        organization_id: &Uuid,
+        email: &str,
+        role: OrganizationRole,
+        hashed_token: &str,
+        invited_by: &Uuid,
+        expire_at: &DateTime<Utc>,
+    ) -> sqlx::Result<Uuid> {
+        sqlx::query_file!(
+            "src/organization/sql/upsert_invitation.sql",
+            organization_id,
+            email,
+            role as OrganizationRole,
+            hashed_token,
+            invited_by,
+            expire_at,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|row| row.id)
+    }
+
+    pub async fn get(
+        pool: &PgPool,
+        id: &Uuid,
+        project_id: &Uuid,
+    ) -> sqlx::Result<Option<Invitation>> {
+        sqlx::query_file_as!(
+            Invitation,
+            "src/organization/sql/get_invitation.sql",
+            id,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn accept(pool: &PgPool, id: &Uuid, user_id: &Uuid) -> sqlx::Result<()> {
+        sqlx::query_file!("src/organization/sql/accept_invitation.sql", id, user_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use crate::crypto::Token;
+use crate::organization::data::{Invitation, Membership, Organization, OrganizationRole};
+use crate::project::data::Flags;
+use crate::project::Project;
+use crate::session::data::AccessToken;
+use crate::settings::data::ProjectEmail;
+use crate::template::{Template, Templates};
+use crate::user::data::User;
+
+use chrono::{Duration, Utc};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+const EXPIRE_IN_DAYS: i64 = 7;
+
+#[derive(Deserialize)]
+pub struct InviteMember {
+    pub organization_id: Uuid,
+    pub email: String,
+    pub role: OrganizationRole,
+}
+
+#[derive(Serialize)]
+pub struct InviteResponse {
+    pub id: Uuid,
+}
+
+pub async fn invite(
+    pool: &Db,
+    user_id: Uuid,
+    project_id: Uuid,
+    body: InviteMember,
+) -> Result<Uuid, ApiError> {
+    let membership = Membership::get(&pool, &body.organization_id, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    // members can't invite, and only owners can invite other owners
+    if membership.role < OrganizationRole::Admin || body.role > membership.role {
+        return Err(ApiError::Forbidden);
+    }
+
+    let email = body.email.trim().to_lowercase();
+
+    if let Some(invitee) = User::get_by_email(&pool, &email, &project_id).await? {
+        let existing =
+            Membership::get(&pool, &body.organization_id, &invitee.id, &project_id).await?;
+        if existing.is_some() {
+            return Err(ApiError::OrganizationMemberExists);
+        }
+    }
+
+    let organization = Organization::get(&pool, &body.organization_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let inviter = User::get_by_id(&pool, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let token = Token::create();
+    let hashed_token = Token::hash(&token)?;
+    let expire_at = Utc::now() + Duration::days(EXPIRE_IN_DAYS);
+
+    let id = Invitation::upsert(
+        &pool,
+        &organization.id,
+        &email,
+        body.role,
+        &hashed_token,
+        &user_id,
+        &expire_at,
+    )
+    .await?;
+
+    let settings =
+        ProjectEmail::from_project_template(&pool, &project_id, Templates::OrganizationInvite)
+            .await?;
+
+    let link: String = format!(
+        "{}{}?id={}&token={}",
+        settings.domain, settings.redirect_to, id, token
+    );
+
+    let ctx = json!({
+        "href": link,
+        "project": settings.name.clone(),
+        "organization": organization.name,
+        "expire_in": EXPIRE_IN_DAYS,
+    });
+
+    let email = Template::create_email(
+        &pool,
+        &project_id,
+        &inviter.device_languages,
+        &email,
+        &ctx,
+        &settings,
+        Templates::OrganizationInvite,
+    )
+    .await?;
+
+    email.send(settings.email).await?;
+
+    Ok(id)
+}
+
+#[post("/invite", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<InviteMember>,
+    project: Project,
+    token: AccessToken,
+) -> Result<Json<InviteResponse>, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::Organizations]).await?;
+    let id = invite(&pool, token.sub(), project.id, body.into_inner()).await?;
+    Ok(Json(InviteResponse { id }))
+}
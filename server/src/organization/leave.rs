@@ -0,0 +1,53 @@
+use crate::organization::data::{Membership, Organization, OrganizationRole};
+use crate::project::Project;
+use crate::session::data::AccessToken;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct LeaveOrganization {
+    pub organization_id: Uuid,
+}
+
+pub async fn leave(
+    pool: &Db,
+    user_id: Uuid,
+    project_id: Uuid,
+    body: LeaveOrganization,
+) -> Result<(), ApiError> {
+    let membership = Membership::get(&pool, &body.organization_id, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let count = Membership::count(&pool, &membership.organization_id).await?;
+
+    // the last member removes the organization
+    if count.members == 1 {
+        Organization::remove(&pool, &membership.organization_id).await?;
+        return Ok(());
+    }
+
+    if membership.role == OrganizationRole::Owner && count.owners == 1 {
+        return Err(ApiError::OrganizationLastOwner);
+    }
+
+    Membership::remove(&pool, &membership.organization_id, &user_id).await?;
+
+    Ok(())
+}
+
+#[post("/leave", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<LeaveOrganization>,
+    project: Project,
+    token: AccessToken,
+) -> Result<Status, ApiError> {
+    leave(&pool, token.sub(), project.id, body.into_inner()).await?;
+    Ok(Status::Ok)
+}
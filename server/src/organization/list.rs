@@ -0,0 +1,23 @@
+use crate::organization::data::Organization;
+use crate::project::Project;
+use crate::session::data::AccessToken;
+
+use rocket::serde::json::Json;
+use serde::Serialize;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Serialize)]
+pub struct Organizations {
+    pub items: Vec<Organization>,
+}
+
+#[get("/list")]
+pub async fn handler(
+    pool: Db,
+    project: Project,
+    token: AccessToken,
+) -> Result<Json<Organizations>, ApiError> {
+    let items = Organization::list(&pool, &token.sub(), &project.id).await?;
+    Ok(Json(Organizations { items }))
+}
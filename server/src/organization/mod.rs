@@ -0,0 +1,20 @@
+use rocket::Route;
+
+mod accept;
+mod create;
+pub mod data;
+mod invite;
+mod leave;
+mod list;
+mod switch;
+
+pub fn routes() -> Vec<Route> {
+    routes![
+        create::handler,
+        list::handler,
+        invite::handler,
+        accept::handler,
+        switch::handler,
+        leave::handler,
+    ]
+}
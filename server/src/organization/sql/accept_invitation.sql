@@ -0,0 +1,10 @@
+with invitation as (
+	delete from organization_invitations
+	 where id = $1
+	returning organization_id, role
+)
+insert into organization_members(organization_id, user_id, role)
+select organization_id, $2, role
+  from invitation
+on conflict (organization_id, user_id)
+   do update set role = greatest(organization_members.role, excluded.role)
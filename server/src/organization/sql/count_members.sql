@@ -0,0 +1,4 @@
+select count(*) as "members!"
+     , count(*) filter (where role = 'owner') as "owners!"
+  from organization_members
+ where organization_id = $1
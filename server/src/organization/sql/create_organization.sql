@@ -0,0 +1,14 @@
+with organization as (
+	insert into organizations(project_id, name)
+	values($1, $2)
+	returning id, name, created_at
+), membership as (
+	insert into organization_members(organization_id, user_id, role)
+	select id, $3, 'owner'
+	  from organization
+)
+select id
+     , name
+     , 'owner'::organization_role as "role!: OrganizationRole"
+     , created_at
+  from organization
@@ -0,0 +1,10 @@
+select organization_invitations.id
+     , organization_invitations.organization_id
+     , organization_invitations.email
+     , organization_invitations.role as "role: OrganizationRole"
+     , organization_invitations.token
+     , organization_invitations.expire_at
+  from organization_invitations
+  join organizations on organizations.id = organization_invitations.organization_id
+ where organization_invitations.id = $1
+   and organizations.project_id = $2
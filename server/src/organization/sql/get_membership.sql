@@ -0,0 +1,8 @@
+select organization_members.organization_id
+     , organization_members.user_id
+     , organization_members.role as "role: OrganizationRole"
+  from organization_members
+  join organizations on organizations.id = organization_members.organization_id
+ where organization_members.organization_id = $1
+   and organization_members.user_id = $2
+   and organizations.project_id = $3
@@ -0,0 +1,5 @@
+select id
+     , name
+  from organizations
+ where id = $1
+   and project_id = $2
@@ -0,0 +1,7 @@
+select organization_members.organization_id
+     , organization_members.user_id
+     , organization_members.role as "role: OrganizationRole"
+  from sessions
+  join organization_members on organization_members.organization_id = sessions.organization_id
+                           and organization_members.user_id = sessions.user_id
+ where sessions.id = $1
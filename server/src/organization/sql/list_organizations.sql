@@ -0,0 +1,9 @@
+select organizations.id
+     , organizations.name
+     , organization_members.role as "role: OrganizationRole"
+     , organizations.created_at
+  from organization_members
+  join organizations on organizations.id = organization_members.organization_id
+ where organization_members.user_id = $1
+   and organizations.project_id = $2
+ order by organizations.created_at asc
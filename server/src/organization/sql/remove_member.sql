@@ -0,0 +1,11 @@
+with removed as (
+	delete from organization_members
+	 where organization_id = $1
+	   and user_id = $2
+	returning organization_id, user_id
+)
+update sessions
+   set organization_id = null
+  from removed
+ where sessions.user_id = removed.user_id
+   and sessions.organization_id = removed.organization_id
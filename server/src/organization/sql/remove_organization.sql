@@ -0,0 +1,2 @@
+delete from organizations
+ where id = $1
@@ -0,0 +1,4 @@
+update sessions
+   set organization_id = $3
+ where id = $1
+   and user_id = $2
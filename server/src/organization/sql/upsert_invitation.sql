@@ -0,0 +1,9 @@
+insert into organization_invitations(organization_id, email, role, token, invited_by, expire_at)
+values($1, $2, $3, $4, $5, $6)
+on conflict (organization_id, email)
+   do update set role = excluded.role
+               , token = excluded.token
+               , invited_by = excluded.invited_by
+               , expire_at = excluded.expire_at
+               , created_at = now()
+returning id
@@ -0,0 +1,115 @@
+use crate::config::Secrets;
+use crate::keys::data::ProjectKeys;
+use crate::organization::data::Membership;
+use crate::project::Project;
+use crate::role::data::Permission;
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{AccessToken, Session};
+use crate::user::data::User;
+use crate::user::verify_email::enforce_email_verification;
+
+use chrono::{Duration, Utc};
+use rocket::serde::json::Json;
+use rocket::State;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use vulpo_auth_types::session::SessionResponse;
+use werkbank::rocket::{Cache, Db};
+
+#[derive(Deserialize)]
+pub struct SwitchOrganization {
+    pub session: Uuid,
+    /// `None` clears the active organization
+    pub organization_id: Option<Uuid>,
+}
+
+pub async fn switch(
+    cache: &Cache,
+    pool: &Db,
+    user_id: Uuid,
+    project_id: Uuid,
+    body: SwitchOrganization,
+    passphrase: &str,
+) -> Result<SessionResponse, ApiError> {
+    let session = Session::get(&pool, &body.session).await?;
+
+    if session.user_id != Some(user_id) || session.project_id != project_id {
+        return Err(ApiError::Forbidden);
+    }
+
+    if Utc::now() > session.expire_at {
+        return Err(ApiError::SessionExpired);
+    }
+
+    let membership = match body.organization_id {
+        None => None,
+        Some(organization_id) => {
+            let membership = Membership::get(&pool, &organization_id, &user_id, &project_id)
+                .await?
+                .ok_or(ApiError::Forbidden)?;
+            Some(membership)
+        }
+    };
+
+    Membership::set_active(&pool, &session.id, &user_id, body.organization_id).await?;
+
+    let user = User::get_by_id(&pool, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let email_verified = enforce_email_verification(&pool, &user, &project_id).await?;
+    let private_key = ProjectKeys::get_private_key(&cache, &pool, &project_id, &passphrase).await?;
+
+    let permissions = Permission::for_user(&pool, &user.id).await?;
+    let exp = Utc::now() + Duration::minutes(15);
+    let mut access_token = AccessToken::new(&user.id, &user.traits, exp)
+        .with_permissions(permissions)
+        .with_email_verified(email_verified);
+
+    if let Some(membership) = membership {
+        access_token =
+            access_token.with_organization(membership.organization_id, membership.role.into());
+    }
+
+    let access_token = access_token
+        .with_auth(&session)
+        .bind_to(&pool, &session)
+        .await?
+        .to_jwt(&project_id, &private_key)
+        .map_err(|_| ApiError::InternalServerError)?;
+
+    Ok(SessionResponse {
+        access_token,
+        created: false,
+        user_id,
+        session: session.id,
+        expire_at: session.expire_at,
+    })
+}
+
+#[post("/switch", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<SwitchOrganization>,
+    project: Project,
+    token: AccessToken,
+    secrets: &State<Secrets>,
+    cache: Cache,
+    cookies: SessionCookies<'_>,
+) -> Result<SessionResponse, ApiError> {
+    let mut session = switch(
+        &cache,
+        &pool,
+        token.sub(),
+        project.id,
+        body.into_inner(),
+        &secrets.passphrase,
+    )
+    .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
+    Ok(session)
+}
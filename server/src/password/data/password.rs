@@ -4,7 +4,7 @@ use argon2::{
 };
 use bcrypt::{self, DEFAULT_COST};
 use pbkdf2::Pbkdf2;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 use vulpo_auth_types::error::ApiError;
 
@@ -42,7 +42,7 @@ impl Password {
     }
 
     pub async fn set_password(
-        pool: &PgPool,
+        conn: &mut PgConnection,
         user_id: &Uuid,
         password: &str,
         alg: &PasswordAlg,
@@ -57,7 +57,7 @@ impl Password {
             alg as &PasswordAlg,
             project_id,
         )
-        .execute(pool)
+        .execute(conn)
         .await
         .map_err(|_| ApiError::InternalServerError)?;
 
@@ -86,6 +86,14 @@ impl Password {
 
         Ok(())
     }
+
+    pub async fn remove(pool: &PgPool, user_id: &Uuid) -> sqlx::Result<()> {
+        sqlx::query_file!("src/password/sql/remove_password.sql", user_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
 }
 
 impl Password {
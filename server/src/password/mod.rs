@@ -1,7 +1,7 @@
 pub mod data;
-mod reset;
-mod signin;
-mod signup;
+pub mod reset;
+pub mod signin;
+pub mod signup;
 
 use rocket::Route;
 use vulpo_auth_types::error::ApiError;
@@ -1,11 +1,13 @@
 use std::str::FromStr;
 
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
+use crate::captcha::Captcha;
 use crate::crypto::Token;
 use crate::mail::Email;
 use crate::password::data::PasswordReset;
 use crate::password::validate_password_length;
 use crate::project::data::Project as ProjectData;
+use crate::project::data::RedirectUrls;
 use crate::project::Project;
 use crate::settings::data::ProjectEmail;
 use crate::template::{Template, TemplateCtx, Templates, Translations};
@@ -23,12 +25,22 @@ use super::data::Password;
 #[derive(Deserialize)]
 pub struct RequestPasswordReset {
     pub email: String,
+
+    /// Replaces the link of the email, has to be on the project's allow-list
+    pub redirect_url: Option<String>,
+
+    /// Response of the project's CAPTCHA widget, can be sent in the
+    /// `Vulpo-Captcha` header instead
+    pub captcha_token: Option<String>,
 }
 
+/// `redirect_url` replaces the link of the email, it has to be verified with
+/// `RedirectUrls::verify`
 pub async fn request_password_reset(
     pool: &Db,
     email: &str,
     project_id: &Uuid,
+    redirect_url: Option<&str>,
 ) -> Result<(), ApiError> {
     let to_email = email.trim().to_lowercase();
     let row = User::get_by_email(&pool, &to_email, &project_id).await;
@@ -50,10 +62,7 @@ pub async fn request_password_reset(
     let settings =
         ProjectEmail::from_project_template(&pool, &project_id, Templates::PasswordReset).await?;
 
-    let link: String = format!(
-        "{}{}?id={}&token={}",
-        settings.domain, settings.redirect_to, token_id, reset_token
-    );
+    let link = settings.link(redirect_url, &token_id, &reset_token);
 
     let user_id = user.id.clone();
     let ctx = TemplateCtx {
@@ -86,8 +95,20 @@ pub async fn request_password_reset_handler(
     pool: Db,
     body: Json<RequestPasswordReset>,
     project: Project,
+    captcha: Captcha,
 ) -> Result<Status, ApiError> {
-    request_password_reset(&pool, &body.email, &project.id).await?;
+    RedirectUrls::verify(&pool, &project.id, body.redirect_url.as_deref()).await?;
+    captcha
+        .verify(&pool, &project.id, body.captcha_token.as_deref())
+        .await?;
+
+    request_password_reset(
+        &pool,
+        &body.email,
+        &project.id,
+        body.redirect_url.as_deref(),
+    )
+    .await?;
     Ok(Status::Ok)
 }
 
@@ -100,10 +121,20 @@ pub async fn admin_request_password_reset_handler(
     pool: Db,
     body: Json<RequestPasswordReset>,
     project_id: String,
-    _admin: Admin,
+    admin: Admin,
 ) -> Result<Status, ApiError> {
     let project_id = Uuid::from_str(&project_id).map_err(|_err| ApiError::BadRequest)?;
-    request_password_reset(&pool, &body.email, &project_id).await?;
+    admin
+        .require_users(&pool, &project_id, AdminRole::Editor)
+        .await?;
+    RedirectUrls::verify(&pool, &project_id, body.redirect_url.as_deref()).await?;
+    request_password_reset(
+        &pool,
+        &body.email,
+        &project_id,
+        body.redirect_url.as_deref(),
+    )
+    .await?;
     Ok(Status::Ok)
 }
 
@@ -141,7 +172,16 @@ pub async fn password_reset(
     // todo: move "remove password reset token" into "set_password" query
     PasswordReset::remove(&pool, &reset.user_id).await?;
     let alg = ProjectData::password_alg_by_user(&pool, &reset.user_id).await?;
-    Password::set_password(&pool, &reset.user_id, &body.password1, &alg, &project_id).await?;
+    let mut conn = pool.acquire().await?;
+    Password::set_password(
+        &mut conn,
+        &reset.user_id,
+        &body.password1,
+        &alg,
+        &project_id,
+    )
+    .await?;
+    User::revoke_tokens(&pool, &reset.user_id).await?;
 
     Ok(())
 }
@@ -4,9 +4,12 @@ use crate::password::data::Password;
 use crate::project::data::Flags;
 use crate::project::data::Project as ProjectData;
 use crate::project::Project;
-use crate::session::data::{AccessToken, Session};
+use crate::role::data::Permission;
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{AccessToken, AuthMethod, Session, SessionMetadata};
+use crate::session::limit::enforce_session_limit;
 use crate::user::data::User;
-use crate::user::data::UserState;
+use crate::user::verify_email::enforce_email_verification;
 
 use chrono::{Duration, Utc};
 use rocket::serde::json::Json;
@@ -21,7 +24,10 @@ pub async fn sign_in(
     payload: SignInPayload,
     project_id: Uuid,
     passphrase: &str,
+    metadata: &SessionMetadata,
 ) -> Result<SessionResponse, ApiError> {
+    AccessToken::check_binding(pool, &project_id, &payload.public_key).await?;
+
     let email = payload.email.trim().to_lowercase();
 
     let user = User::get_by_email(&pool, &email, &project_id)
@@ -47,23 +53,34 @@ pub async fn sign_in(
         .await?;
     }
 
-    if user.state == UserState::Disabled {
+    if user.is_disabled() {
         return Err(ApiError::UserDisabled);
     }
 
+    let email_verified = enforce_email_verification(&pool, &user, &project_id).await?;
+    enforce_session_limit(&pool, &payload.session, &user.id, &project_id).await?;
+
     let session = Session {
         id: payload.session,
         public_key: payload.public_key.to_owned(),
         user_id: Some(user.id),
         expire_at: Utc::now() + Duration::days(30),
         project_id,
+        auth_time: Some(Utc::now()),
+        auth_methods: vec![AuthMethod::Password.to_string()],
     };
 
-    let session = Session::create(&pool, session).await?;
+    let session = Session::create(&pool, session, metadata).await?;
 
     let private_key = ProjectKeys::get_private_key(&cache, &pool, &project_id, passphrase).await?;
+    let permissions = Permission::for_user(&pool, &user.id).await?;
     let exp = Utc::now() + Duration::minutes(15);
     let access_token = AccessToken::new(&user.id, &user.traits, exp)
+        .with_permissions(permissions)
+        .with_email_verified(email_verified)
+        .with_auth(&session)
+        .bind_to(&pool, &session)
+        .await?
         .to_jwt(&project_id, &private_key)
         .map_err(|_| ApiError::InternalServerError)?;
 
@@ -83,6 +100,8 @@ pub async fn sign_in_handler(
     project: Project,
     secrets: &State<Secrets>,
     cache: Cache,
+    metadata: SessionMetadata,
+    cookies: SessionCookies<'_>,
 ) -> Result<SessionResponse, ApiError> {
     Flags::has_flags(
         &pool,
@@ -91,13 +110,18 @@ pub async fn sign_in_handler(
     )
     .await?;
 
-    let session = sign_in(
+    let mut session = sign_in(
         &cache,
         &pool,
         body.into_inner(),
         project.id,
         &secrets.passphrase,
+        &metadata,
     )
     .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
     Ok(session)
 }
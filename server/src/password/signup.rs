@@ -1,9 +1,12 @@
+use crate::captcha::Captcha;
 use crate::config::Secrets;
 use crate::keys::data::ProjectKeys;
+use crate::mail::data::EmailVerificationPolicy;
 use crate::password::validate_password_length;
-use crate::project::data::Flags;
+use crate::project::data::{EmailDomains, Flags, Project as ProjectData, RedirectUrls};
 use crate::project::Project;
-use crate::session::data::{AccessToken, Session};
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{AccessToken, AuthMethod, Session, SessionMetadata};
 use crate::user::data::User;
 use crate::user::verify_email::send as send_email_verification;
 
@@ -23,18 +26,32 @@ pub struct SignUp {
     pub session: Uuid,
     pub public_key: Vec<u8>,
     pub device_languages: Vec<String>,
+
+    /// Link of the verification email, has to be on the project's allow-list
+    pub redirect_url: Option<String>,
+
+    /// Response of the project's CAPTCHA widget, can be sent in the
+    /// `Vulpo-Captcha` header instead
+    pub captcha_token: Option<String>,
 }
 
+/// The `redirect_url` of the body has to be verified with `RedirectUrls::verify`.
+/// Projects that block unverified users only create the user and send the
+/// verification email, the sign up fails with `user/email_not_verified`
 pub async fn sign_up(
     cache: &Cache,
     pool: &Db,
     body: SignUp,
     project_id: Uuid,
     passphrase: &str,
+    metadata: &SessionMetadata,
 ) -> Result<SessionResponse, ApiError> {
     validate_password_length(&body.password)?;
+    AccessToken::check_binding(pool, &project_id, &body.public_key).await?;
 
     let email = body.email.trim().to_lowercase();
+    EmailDomains::verify(&pool, &project_id, &email).await?;
+
     let user_id = User::create(
         &pool,
         &email,
@@ -44,12 +61,23 @@ pub async fn sign_up(
     )
     .await?;
 
-    let private_key = ProjectKeys::get_private_key(&cache, &pool, &project_id, &passphrase).await?;
+    let policy = ProjectData::email_verification(&pool, &project_id).await?;
+    let verify = Flags::has_flags(&pool, &project_id, &[Flags::VerifyEmail]).await;
 
-    let exp = Utc::now() + Duration::minutes(15);
-    let access_token = AccessToken::new(&user_id, &vec![], exp)
-        .to_jwt(&project_id, &private_key)
-        .map_err(|_| ApiError::InternalServerError)?;
+    if verify.is_ok() || policy == EmailVerificationPolicy::Block {
+        send_email_verification(
+            pool,
+            &user_id,
+            &project_id,
+            &email,
+            body.redirect_url.as_deref(),
+        )
+        .await?;
+    }
+
+    if policy == EmailVerificationPolicy::Block {
+        return Err(ApiError::UserEmailNotVerified);
+    }
 
     let session = Session {
         id: body.session,
@@ -57,15 +85,23 @@ pub async fn sign_up(
         user_id: Some(user_id),
         expire_at: Utc::now() + Duration::days(30),
         project_id,
+        auth_time: Some(Utc::now()),
+        auth_methods: vec![AuthMethod::Password.to_string()],
     };
 
-    let session = Session::create(&pool, session).await?;
+    let session = Session::create(&pool, session, metadata).await?;
 
-    let verify = Flags::has_flags(&pool, &project_id, &[Flags::VerifyEmail]).await;
+    let private_key = ProjectKeys::get_private_key(&cache, &pool, &project_id, &passphrase).await?;
 
-    if verify.is_ok() {
-        send_email_verification(&pool, &user_id, &project_id, &email).await?;
-    }
+    let exp = Utc::now() + Duration::minutes(15);
+    let email_verified = (policy == EmailVerificationPolicy::Mark).then_some(false);
+    let access_token = AccessToken::new(&user_id, &vec![], exp)
+        .with_email_verified(email_verified)
+        .with_auth(&session)
+        .bind_to(&pool, &session)
+        .await?
+        .to_jwt(&project_id, &private_key)
+        .map_err(|_| ApiError::InternalServerError)?;
 
     Ok(SessionResponse {
         access_token,
@@ -83,6 +119,9 @@ pub async fn sign_up_handler(
     project: Project,
     secrets: &State<Secrets>,
     cache: Cache,
+    metadata: SessionMetadata,
+    cookies: SessionCookies<'_>,
+    captcha: Captcha,
 ) -> Result<SessionResponse, ApiError> {
     Flags::has_flags(
         &pool,
@@ -91,13 +130,23 @@ pub async fn sign_up_handler(
     )
     .await?;
 
-    let session = sign_up(
+    RedirectUrls::verify(&pool, &project.id, body.redirect_url.as_deref()).await?;
+    captcha
+        .verify(&pool, &project.id, body.captcha_token.as_deref())
+        .await?;
+
+    let mut session = sign_up(
         &cache,
         &pool,
         body.into_inner(),
         project.id,
         &secrets.passphrase,
+        &metadata,
     )
     .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
     Ok(session)
 }
@@ -0,0 +1,2 @@
+delete from passwords
+ where user_id = $1
@@ -1,4 +1,4 @@
-use crate::passwordless::data::Passwordless;
+use crate::passwordless::data::{Passwordless, PasswordlessMode};
 
 use chrono::Utc;
 use rocket::http::Status;
@@ -19,7 +19,7 @@ pub async fn confirm(pool: &Db, id: &Uuid, token: &str) -> Result<(), ApiError>
         .await?
         .ok_or_else(|| ApiError::NotFound)?;
 
-    if stored_token.is_valid == false {
+    if stored_token.mode != PasswordlessMode::Link || stored_token.is_valid == false {
         return Err(ApiError::PasswordlessInvalidToken);
     }
 
@@ -1,2 +1,2 @@
 mod passwordless;
-pub use passwordless::Passwordless;
+pub use passwordless::{Passwordless, PasswordlessMode};
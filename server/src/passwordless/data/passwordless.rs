@@ -1,8 +1,28 @@
 use bcrypt;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use uuid::Uuid;
 
+#[derive(sqlx::Type, Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[sqlx(type_name = "passwordless_mode")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PasswordlessMode {
+    Link,
+    Code,
+}
+
+impl PasswordlessMode {
+    /// Minutes until the token expires, codes are short and expire sooner
+    pub fn expire_in(&self) -> i32 {
+        match self {
+            PasswordlessMode::Link => 30,
+            PasswordlessMode::Code => 15,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Passwordless {
     pub id: Uuid,
@@ -14,6 +34,8 @@ pub struct Passwordless {
     pub is_valid: bool,
     pub project_id: Uuid,
     pub confirmed: bool,
+    pub session_id: Uuid,
+    pub mode: PasswordlessMode,
 }
 
 impl Passwordless {
@@ -24,6 +46,7 @@ impl Passwordless {
         verification_token: &str,
         project: &Uuid,
         session_id: &Uuid,
+        mode: PasswordlessMode,
     ) -> sqlx::Result<Uuid> {
         sqlx::query_file!(
             "src/passwordless/sql/insert_passwordless_token.sql",
@@ -31,7 +54,9 @@ impl Passwordless {
             email,
             verification_token,
             project,
-            session_id
+            session_id,
+            mode as PasswordlessMode,
+            mode.expire_in(),
         )
         .fetch_one(pool)
         .await
@@ -56,10 +81,29 @@ impl Passwordless {
         Ok(())
     }
 
-    pub async fn remove_all(pool: &PgPool, email: &str, project: &Uuid) -> sqlx::Result<()> {
-        sqlx::query_file!("src/passwordless/sql/remove_all.sql", email, project)
-            .execute(pool)
-            .await?;
+    /// Counts the attempt, `false` when the code already reached `max_attempts`
+    pub async fn add_attempt(pool: &PgPool, id: &Uuid, max_attempts: i32) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/passwordless/sql/add_attempt.sql", id, max_attempts)
+            .fetch_optional(pool)
+            .await
+            .map(|row| row.is_some())
+    }
+
+    /// Removes all tokens of `email` and the sessions waiting for them, except `session`
+    pub async fn remove_all(
+        pool: &PgPool,
+        email: &str,
+        project: &Uuid,
+        session: &Uuid,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/passwordless/sql/remove_all.sql",
+            email,
+            project,
+            session
+        )
+        .execute(pool)
+        .await?;
 
         Ok(())
     }
@@ -1,7 +1,8 @@
-mod confirm;
+pub mod confirm;
 pub mod data;
-mod request_passwordless;
-mod verify;
+pub mod request_passwordless;
+pub mod verify;
+pub mod verify_code;
 
 use rocket::Route;
 
@@ -9,6 +10,7 @@ pub fn routes() -> Vec<Route> {
     routes![
         request_passwordless::handler,
         confirm::handler,
-        verify::handler
+        verify::handler,
+        verify_code::handler
     ]
 }
@@ -1,15 +1,20 @@
+use crate::captcha::Captcha;
 use crate::crypto::Token;
 use crate::mail::Email;
-use crate::passwordless::data::Passwordless;
+use crate::passwordless::data::{Passwordless, PasswordlessMode};
+use crate::project::data::EmailDomains;
 use crate::project::data::Flags;
+use crate::project::data::Project as ProjectData;
+use crate::project::data::RedirectUrls;
 use crate::project::Project;
-use crate::session::data::Session;
-use crate::settings::data::ProjectEmail;
+use crate::session::data::{AccessToken, Session, SessionMetadata};
+use crate::settings::data::{EmailSettings, ProjectEmail};
 use crate::template::{Template, TemplateCtx, Templates, Translations};
-use crate::user::data::{User, UserState};
+use crate::user::data::User;
 
 use chrono::{Duration, Utc};
 use rocket::serde::{json::Json, Deserialize, Serialize};
+use serde_json::json;
 use uuid::Uuid;
 use vulpo_auth_types::error::ApiError;
 use werkbank::rocket::Db;
@@ -20,30 +25,58 @@ pub struct RequestPasswordless {
     pub session: Uuid,
     pub public_key: Vec<u8>,
     pub device_languages: Vec<String>,
+
+    /// Replaces the link of the email, has to be on the project's allow-list
+    pub redirect_url: Option<String>,
+
+    /// Response of the project's CAPTCHA widget, can be sent in the
+    /// `Vulpo-Captcha` header instead
+    pub captcha_token: Option<String>,
 }
 
 #[derive(Serialize)]
 pub struct PasswordlessResponse {
     pub id: Uuid,
     pub session: Uuid,
+    pub mode: PasswordlessMode,
 }
 
 pub async fn request_passwordless(
     pool: &Db,
     request: RequestPasswordless,
     project_id: Uuid,
+    metadata: &SessionMetadata,
 ) -> Result<PasswordlessResponse, ApiError> {
     let body_email = request.email.trim().to_lowercase();
 
     let user = User::get_by_email(&pool, &body_email, &project_id).await?;
 
-    if user
-        .clone()
-        .map_or(false, |u| u.state == UserState::Disabled)
-    {
+    if user.clone().map_or(false, |u| u.is_disabled()) {
         return Err(ApiError::UserDisabled);
     }
 
+    // new users are created on verify, invite-only projects have sign up disabled
+    if user.is_none() {
+        Flags::has_flags(&pool, &project_id, &[Flags::SignUp]).await?;
+        EmailDomains::verify(&pool, &project_id, &body_email).await?;
+    }
+
+    send_passwordless(&pool, request, project_id, user, metadata).await
+}
+
+/// Starts a passwordless sign in for `user`, a new user is created on verify when
+/// `user` is `None`. The `redirect_url` of the request has to be verified with
+/// `RedirectUrls::verify`.
+pub async fn send_passwordless(
+    pool: &Db,
+    request: RequestPasswordless,
+    project_id: Uuid,
+    user: Option<User>,
+    metadata: &SessionMetadata,
+) -> Result<PasswordlessResponse, ApiError> {
+    AccessToken::check_binding(pool, &project_id, &request.public_key).await?;
+
+    let body_email = request.email.trim().to_lowercase();
     let user_id = user.clone().map(|u| u.id);
 
     let session = Session {
@@ -52,11 +85,19 @@ pub async fn request_passwordless(
         user_id,
         expire_at: Utc::now() + Duration::days(30),
         project_id,
+        auth_time: None,
+        auth_methods: vec![],
     };
 
-    let session = Session::create(&pool, session).await?;
+    let session = Session::create(&pool, session, metadata).await?;
+
+    let mode = ProjectData::passwordless_mode(&pool, &project_id).await?;
+
+    let verification_token = match mode {
+        PasswordlessMode::Link => Token::create(),
+        PasswordlessMode::Code => Token::code(8),
+    };
 
-    let verification_token = Token::create();
     let hashed_token = Token::hash(&verification_token)?;
 
     let id = Passwordless::create_token(
@@ -66,22 +107,54 @@ pub async fn request_passwordless(
         &hashed_token,
         &project_id,
         &request.session,
+        mode,
     )
     .await?;
 
+    let (email, settings) = match mode {
+        PasswordlessMode::Link => {
+            link_email(&pool, &project_id, &request, id, &verification_token, user).await?
+        }
+        PasswordlessMode::Code => {
+            code_email(
+                &pool,
+                &project_id,
+                &request.email,
+                &request.device_languages,
+                &verification_token,
+                user,
+            )
+            .await?
+        }
+    };
+
+    email.send(settings).await?;
+
+    Ok(PasswordlessResponse {
+        id,
+        session: session.id,
+        mode,
+    })
+}
+
+async fn link_email(
+    pool: &Db,
+    project_id: &Uuid,
+    request: &RequestPasswordless,
+    id: Uuid,
+    token: &str,
+    user: Option<User>,
+) -> Result<(Email, EmailSettings), ApiError> {
     let settings =
         ProjectEmail::from_project_template(&pool, &project_id, Templates::Passwordless).await?;
 
-    let link: String = format!(
-        "{}{}?id={}&token={}",
-        settings.domain, settings.redirect_to, id, verification_token
-    );
+    let link = settings.link(request.redirect_url.as_deref(), &id, token);
 
     let ctx = TemplateCtx {
         href: link,
         project: settings.name,
         user,
-        expire_in: 15,
+        expire_in: PasswordlessMode::Link.expire_in(),
     };
 
     let translations = Translations::get_by_languages(
@@ -102,12 +175,41 @@ pub async fn request_passwordless(
         content,
     };
 
-    email.send(settings.email).await?;
+    Ok((email, settings.email))
+}
 
-    Ok(PasswordlessResponse {
-        id,
-        session: session.id,
-    })
+/// Email with the passwordless `code`, also used to re-authenticate a session
+pub async fn code_email(
+    pool: &Db,
+    project_id: &Uuid,
+    to_email: &str,
+    device_languages: &Vec<String>,
+    code: &str,
+    user: Option<User>,
+) -> Result<(Email, EmailSettings), ApiError> {
+    let settings =
+        ProjectEmail::from_project_template(&pool, &project_id, Templates::PasswordlessCode)
+            .await?;
+
+    let ctx = json!({
+        "code": code,
+        "project": settings.name.clone(),
+        "user": user,
+        "expire_in": PasswordlessMode::Code.expire_in(),
+    });
+
+    let email = Template::create_email(
+        &pool,
+        &project_id,
+        device_languages,
+        to_email,
+        &ctx,
+        &settings,
+        Templates::PasswordlessCode,
+    )
+    .await?;
+
+    Ok((email, settings.email))
 }
 
 #[post("/", format = "json", data = "<body>")]
@@ -115,8 +217,15 @@ pub async fn handler(
     pool: Db,
     project: Project,
     body: Json<RequestPasswordless>,
+    metadata: SessionMetadata,
+    captcha: Captcha,
 ) -> Result<Json<PasswordlessResponse>, ApiError> {
     Flags::has_flags(&pool, &project.id, &[Flags::AuthenticationLink]).await?;
-    let response = request_passwordless(&pool, body.into_inner(), project.id).await?;
+    RedirectUrls::verify(&pool, &project.id, body.redirect_url.as_deref()).await?;
+    captcha
+        .verify(&pool, &project.id, body.captcha_token.as_deref())
+        .await?;
+
+    let response = request_passwordless(&pool, body.into_inner(), project.id, &metadata).await?;
     Ok(Json(response))
 }
@@ -0,0 +1,5 @@
+update passwordless
+   set attempts = attempts + 1
+ where id = $1
+   and attempts < $2
+returning attempts
\ No newline at end of file
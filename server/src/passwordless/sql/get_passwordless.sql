@@ -7,5 +7,7 @@ select id
      , project_id
      , confirmed
      , expire_at
+     , session_id
+     , mode as "mode: PasswordlessMode"
   from passwordless
  where id = $1
\ No newline at end of file
@@ -1,3 +1,3 @@
-insert into passwordless (user_id, email, token, project_id, session_id)
-values ($1, $2, $3, $4, $5)
+insert into passwordless (user_id, email, token, project_id, session_id, mode, expire_at)
+values ($1, $2, $3, $4, $5, $6, now() + make_interval(mins => $7))
 returning id
\ No newline at end of file
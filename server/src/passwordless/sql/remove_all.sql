@@ -1,3 +1,10 @@
-delete from passwordless
- where email = $1
-   and project_id = $2
\ No newline at end of file
+with removed as (
+	delete from passwordless
+	 where email = $1
+	   and project_id = $2
+	returning session_id
+)
+-- the other sign ins can't be verified anymore
+delete from sessions
+ where id in (select session_id from removed)
+   and id <> $3
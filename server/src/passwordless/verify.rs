@@ -1,9 +1,14 @@
 use crate::config::Secrets;
 use crate::keys::data::ProjectKeys;
-use crate::passwordless::data::Passwordless;
+use crate::mail::data::VerifyEmail;
+use crate::passwordless::data::{Passwordless, PasswordlessMode};
 use crate::project::Project;
-use crate::session::data::{AccessToken, RefreshAccessToken, Session};
-use crate::user::data::User;
+use crate::role::data::Permission;
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{AccessToken, AuthMethod, RefreshAccessToken, Session};
+use crate::session::limit::enforce_session_limit;
+use crate::user::data::{UpgradeAnonymous, User};
+use crate::user::verify_email::enforce_email_verification;
 
 use chrono::{Duration, Utc};
 use rocket::serde::json::Json;
@@ -46,7 +51,7 @@ pub async fn verify(
         return Err(ApiError::PasswordlessTokenExpire);
     }
 
-    Passwordless::remove_all(&pool, &token.email, &token.project_id).await?;
+    Passwordless::remove_all(&pool, &token.email, &token.project_id, &body.session).await?;
 
     let current_session = Session::get(&pool, &body.session).await?;
 
@@ -66,19 +71,62 @@ pub async fn verify(
             User::create_passwordless(&pool, &token.email, &token.project_id, &device_languages)
                 .await?
         }
-        Some(user_id) => User::get_by_id(&pool, &user_id, &token.project_id)
-            .await?
-            .ok_or_else(|| ApiError::NotFound)?,
+        Some(user_id) => {
+            let user = User::get_by_id(&pool, &user_id, &token.project_id)
+                .await?
+                .ok_or_else(|| ApiError::NotFound)?;
+
+            if user.is_anonymous() {
+                let upgrade = UpgradeAnonymous {
+                    email: token.email.clone(),
+                    email_verified: true,
+                    provider_id: String::from("link"),
+                    display_name: None,
+                    photo_url: None,
+                };
+
+                User::upgrade_anonymous(&pool, &user.id, &upgrade)
+                    .await?
+                    .ok_or_else(|| ApiError::Forbidden)?
+            } else {
+                User::add_provider(&pool, &user.id, "link").await?;
+
+                // the link was sent to the user's email address
+                if !user.email_verified {
+                    VerifyEmail::verify(&pool, &user.id, &token.email).await?;
+                }
+
+                User {
+                    email_verified: true,
+                    ..user
+                }
+            }
+        }
+    };
+
+    let email_verified = enforce_email_verification(&pool, &user, &token.project_id).await?;
+    enforce_session_limit(&pool, &current_session.id, &user.id, &token.project_id).await?;
+
+    let method = match token.mode {
+        PasswordlessMode::Link => AuthMethod::Link,
+        PasswordlessMode::Code => AuthMethod::Code,
     };
 
     let expire_at = Utc::now() + Duration::days(30);
-    let session = Session::confirm(&pool, &current_session.id, &user.id, &expire_at).await?;
+    let session =
+        Session::confirm(&pool, &current_session.id, &user.id, &expire_at, method).await?;
 
     let private_key =
         ProjectKeys::get_private_key(&cache, &pool, &token.project_id, &passphrase).await?;
 
+    let permissions = Permission::for_user(&pool, &user.id).await?;
     let exp = Utc::now() + Duration::minutes(15);
     let access_token = AccessToken::new(&user.id, &user.traits, exp)
+        .with_permissions(permissions)
+        .with_email_verified(email_verified)
+        .with_auth(&session)
+        .bind_to(&pool, &session)
+        .await?
         .to_jwt(&project_id, &private_key)
         .map_err(|_| ApiError::InternalServerError)?;
 
@@ -98,13 +146,19 @@ pub async fn handler(
     secrets: &State<Secrets>,
     project: Project,
     cache: Cache,
+    cookies: SessionCookies<'_>,
 ) -> Result<SessionResponse, ApiError> {
-    verify(
+    let mut session = verify(
         &cache,
         &pool,
         body.into_inner(),
         project.id,
         &secrets.passphrase,
     )
-    .await
+    .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
+    Ok(session)
 }
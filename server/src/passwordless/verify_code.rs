@@ -0,0 +1,103 @@
+use crate::config::Secrets;
+use crate::passwordless::data::{Passwordless, PasswordlessMode};
+use crate::passwordless::verify::{verify, Veriy};
+use crate::project::data::Flags;
+use crate::project::Project;
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{RefreshAccessToken, Session};
+
+use chrono::Utc;
+use rocket::serde::json::Json;
+use rocket::State;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use vulpo_auth_types::session::SessionResponse;
+use werkbank::rocket::{Cache, Db};
+
+pub const MAX_ATTEMPTS: i32 = 5;
+
+#[derive(Deserialize)]
+pub struct VerifyCode {
+    pub id: Uuid,
+    pub code: String,
+    pub token: String,
+    pub session: Uuid,
+    pub device_languages: Vec<String>,
+}
+
+pub async fn verify_code(
+    cache: &Cache,
+    pool: &Db,
+    body: VerifyCode,
+    project_id: Uuid,
+    passphrase: &str,
+) -> Result<SessionResponse, ApiError> {
+    let stored_token = Passwordless::get(&pool, &body.id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound)?;
+
+    if stored_token.project_id != project_id || stored_token.session_id != body.session {
+        return Err(ApiError::Forbidden);
+    }
+
+    if stored_token.mode != PasswordlessMode::Code || stored_token.is_valid == false {
+        return Err(ApiError::PasswordlessInvalidToken);
+    }
+
+    if Utc::now() > stored_token.expire_at {
+        return Err(ApiError::PasswordlessTokenExpire);
+    }
+
+    // only check the signature here, the token itself is consumed by verify
+    let current_session = Session::get(&pool, &body.session).await?;
+    let rat = RefreshAccessToken {
+        value: body.token.clone(),
+    };
+    Session::validate_token(&current_session, &rat)?;
+
+    // counted before the code is compared, parallel guesses can't exceed the limit
+    if !Passwordless::add_attempt(&pool, &stored_token.id, MAX_ATTEMPTS).await? {
+        return Err(ApiError::PasswordlessTooManyAttempts);
+    }
+
+    if stored_token.compare(body.code.trim()) == false {
+        return Err(ApiError::PasswordlessInvalidToken);
+    }
+
+    Passwordless::confirm(&pool, &stored_token.id).await?;
+
+    let body = Veriy {
+        id: body.id,
+        token: body.token,
+        session: body.session,
+        device_languages: body.device_languages,
+    };
+
+    verify(&cache, &pool, body, project_id, &passphrase).await
+}
+
+#[post("/verify_code", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<VerifyCode>,
+    secrets: &State<Secrets>,
+    project: Project,
+    cache: Cache,
+    cookies: SessionCookies<'_>,
+) -> Result<SessionResponse, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::AuthenticationLink]).await?;
+    let mut session = verify_code(
+        &cache,
+        &pool,
+        body.into_inner(),
+        project.id,
+        &secrets.passphrase,
+    )
+    .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
+    Ok(session)
+}
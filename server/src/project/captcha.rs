@@ -0,0 +1,27 @@
+use crate::settings::data::{CaptchaProvider, ProjectCaptcha};
+
+use rocket::serde::uuid::Uuid;
+use rocket::serde::{json::Json, Serialize};
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+/// What a client needs to render the widget, `null` when the project didn't
+/// enable a CAPTCHA
+#[derive(Serialize)]
+pub struct Response {
+    pub provider: CaptchaProvider,
+    pub site_key: String,
+}
+
+#[get("/captcha?<project>")]
+pub async fn handler(pool: Db, project: Uuid) -> Result<Json<Option<Response>>, ApiError> {
+    let settings = ProjectCaptcha::from_project(&pool, &project)
+        .await?
+        .filter(|settings| settings.enabled)
+        .map(|settings| Response {
+            provider: settings.provider,
+            site_key: settings.site_key,
+        });
+
+    Ok(Json(settings))
+}
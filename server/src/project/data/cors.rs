@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// Cross origin requests a project accepts, the project's domain is always
+/// allowed
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CorsSettings {
+    /// `https://*.example.com` allows every sub domain, custom schemes like
+    /// `capacitor://localhost` have to match exactly
+    pub origins: Vec<String>,
+    pub methods: Vec<String>,
+    pub headers: Vec<String>,
+
+    /// Seconds a browser can cache the preflight response, `None` for the
+    /// browser's default
+    pub max_age: Option<i32>,
+}
+
+impl CorsSettings {
+    pub fn allows(&self, domain: &str, origin: &str) -> bool {
+        std::iter::once(domain)
+            .chain(self.origins.iter().map(String::as_str))
+            .any(|allowed| origin_matches(allowed, origin))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        let is_token = |value: &String| {
+            !value.is_empty()
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        };
+
+        self.origins.iter().all(|origin| is_valid_origin(origin))
+            && self.methods.iter().all(is_token)
+            && self.headers.iter().all(is_token)
+            && self.max_age.map_or(true, |max_age| max_age >= 0)
+    }
+}
+
+/// Origins are `scheme://host[:port]`, a wildcard is only allowed as the
+/// first label of the host
+fn is_valid_origin(origin: &str) -> bool {
+    let (scheme, host) = match origin.split_once("://") {
+        None => return false,
+        Some(parts) => parts,
+    };
+
+    let host = host.strip_prefix("*.").unwrap_or(host);
+
+    !scheme.is_empty()
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+        && !host.is_empty()
+        && !host.contains(['/', '*', '?', '#'])
+}
+
+pub fn origin_matches(allowed: &str, origin: &str) -> bool {
+    let allowed = allowed.trim_end_matches('/');
+
+    let (scheme, suffix) = match allowed.split_once("://*.") {
+        None => return allowed.eq_ignore_ascii_case(origin),
+        Some(parts) => parts,
+    };
+
+    let host = match origin.split_once("://") {
+        Some((origin_scheme, host)) if origin_scheme.eq_ignore_ascii_case(scheme) => host,
+        _ => return false,
+    };
+
+    let host = host.to_lowercase();
+    let suffix = format!(".{}", suffix.to_lowercase());
+    host.len() > suffix.len() && host.ends_with(&suffix)
+}
@@ -0,0 +1,99 @@
+use crate::project::data::Project;
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+
+const DISPOSABLE_DOMAINS: &str = include_str!("disposable_domains.txt");
+
+/// Email domains new users can sign up with, existing users are not affected
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EmailDomains {
+    /// Every domain is allowed when the list is empty. `*.example.com` allows
+    /// every sub domain, `example.com` itself has to be added separately.
+    pub allow: Vec<String>,
+
+    /// Takes precedence over `allow`
+    pub deny: Vec<String>,
+
+    /// Rejects the domains of throwaway inbox providers
+    pub block_disposable: bool,
+}
+
+impl EmailDomains {
+    pub fn allows(&self, email: &str) -> bool {
+        let domain = match email.rsplit_once('@') {
+            Some((local, domain)) if !local.is_empty() => {
+                domain.trim_end_matches('.').to_lowercase()
+            }
+            _ => return false,
+        };
+
+        if domain.is_empty() {
+            return false;
+        }
+
+        let allowed = self.allow.is_empty()
+            || self
+                .allow
+                .iter()
+                .any(|allowed| domain_matches(allowed, &domain));
+
+        allowed
+            && !self
+                .deny
+                .iter()
+                .any(|denied| domain_matches(denied, &domain))
+            && !(self.block_disposable && is_disposable(&domain))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.allow
+            .iter()
+            .chain(self.deny.iter())
+            .all(|domain| is_valid_domain(domain))
+    }
+
+    /// Returns `ApiError::UserEmailDomainNotAllowed` when new users can't sign
+    /// up with `email`
+    pub async fn verify(pool: &PgPool, project: &Uuid, email: &str) -> Result<(), ApiError> {
+        let domains = Project::email_domains(pool, project).await?;
+
+        match domains.allows(email) {
+            true => Ok(()),
+            false => Err(ApiError::UserEmailDomainNotAllowed),
+        }
+    }
+}
+
+fn domain_matches(allowed: &str, domain: &str) -> bool {
+    let allowed = allowed.to_lowercase();
+
+    match allowed.strip_prefix("*.") {
+        Some(parent) => domain
+            .strip_suffix(parent)
+            .map_or(false, |sub| sub.len() > 1 && sub.ends_with('.')),
+        None => allowed == domain,
+    }
+}
+
+/// Sub domains of a disposable domain are disposable as well
+fn is_disposable(domain: &str) -> bool {
+    DISPOSABLE_DOMAINS.lines().any(|disposable| {
+        domain == disposable
+            || domain
+                .strip_suffix(disposable)
+                .map_or(false, |sub| sub.ends_with('.'))
+    })
+}
+
+/// A wildcard is only allowed as the first label
+fn is_valid_domain(domain: &str) -> bool {
+    let domain = domain.strip_prefix("*.").unwrap_or(domain);
+
+    !domain.is_empty()
+        && domain.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
@@ -9,6 +9,10 @@ pub enum Flags {
     SignIn,
     #[serde(rename = "auth::signup")]
     SignUp,
+    #[serde(rename = "auth::anonymous")]
+    Anonymous,
+    #[serde(rename = "auth::organizations")]
+    Organizations,
     #[serde(rename = "action::password_reset")]
     PasswordReset,
     #[serde(rename = "action::verify_email")]
@@ -17,6 +21,16 @@ pub enum Flags {
     EmailAndPassword,
     #[serde(rename = "method::authentication_link")]
     AuthenticationLink,
+    #[serde(rename = "method::sms")]
+    Sms,
+
+    #[serde(rename = "token::dpop")]
+    DPoP,
+    #[serde(rename = "session::cookie")]
+    CookieSession,
+
+    #[serde(rename = "hosted::pages")]
+    HostedPages,
 
     #[serde(rename = "oauth::google")]
     OAuthGoogle,
@@ -78,11 +92,17 @@ impl Flags {
         match flag {
             "auth::signin" => Some(Flags::SignIn),
             "auth::signup" => Some(Flags::SignUp),
+            "auth::anonymous" => Some(Flags::Anonymous),
+            "auth::organizations" => Some(Flags::Organizations),
             "action::password_reset" => Some(Flags::PasswordReset),
             "method::email_password" => Some(Flags::EmailAndPassword),
             "method::authentication_link" => Some(Flags::AuthenticationLink),
+            "method::sms" => Some(Flags::Sms),
             "action::verify_email" => Some(Flags::VerifyEmail),
             "oauth::google" => Some(Flags::OAuthGoogle),
+            "token::dpop" => Some(Flags::DPoP),
+            "session::cookie" => Some(Flags::CookieSession),
+            "hosted::pages" => Some(Flags::HostedPages),
             _ => None,
         }
     }
@@ -93,11 +113,17 @@ impl ToString for Flags {
         match self {
             Flags::SignIn => "auth::signin".to_string(),
             Flags::SignUp => "auth::signup".to_string(),
+            Flags::Anonymous => "auth::anonymous".to_string(),
+            Flags::Organizations => "auth::organizations".to_string(),
             Flags::PasswordReset => "action::password_reset".to_string(),
             Flags::EmailAndPassword => "method::email_password".to_string(),
             Flags::AuthenticationLink => "method::authentication_link".to_string(),
+            Flags::Sms => "method::sms".to_string(),
             Flags::VerifyEmail => "action::verify_email".to_string(),
             Flags::OAuthGoogle => "oauth::google".to_string(),
+            Flags::DPoP => "token::dpop".to_string(),
+            Flags::CookieSession => "session::cookie".to_string(),
+            Flags::HostedPages => "hosted::pages".to_string(),
         }
     }
 }
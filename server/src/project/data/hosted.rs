@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// Branding of the hosted sign in pages, the pages fall back to a neutral
+/// theme when a value is not set
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct HostedPages {
+    pub logo_url: Option<String>,
+
+    /// Hex colors like `#1a73e8`
+    pub primary_color: Option<String>,
+    pub background_color: Option<String>,
+}
+
+impl HostedPages {
+    pub fn is_valid(&self) -> bool {
+        self.logo_url.as_deref().map_or(true, is_valid_logo_url)
+            && self.primary_color.as_deref().map_or(true, is_valid_color)
+            && self
+                .background_color
+                .as_deref()
+                .map_or(true, is_valid_color)
+    }
+}
+
+/// The logo is rendered inside an attribute, only plain http(s) URLs are allowed
+fn is_valid_logo_url(url: &str) -> bool {
+    (url.starts_with("https://") || url.starts_with("http://"))
+        && !url.contains(|c: char| c.is_whitespace() || c.is_control() || "\"'<>\\".contains(c))
+}
+
+/// `#rgb` or `#rrggbb`, the colors end up in the page's stylesheet
+fn is_valid_color(color: &str) -> bool {
+    match color.strip_prefix('#') {
+        Some(hex) => {
+            (hex.len() == 3 || hex.len() == 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+        None => false,
+    }
+}
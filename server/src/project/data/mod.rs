@@ -1,5 +1,13 @@
+mod cors;
+mod email_domains;
 mod flags;
+mod hosted;
 mod project;
+mod redirect;
 
+pub use cors::CorsSettings;
+pub use email_domains::EmailDomains;
 pub use flags::Flags;
+pub use hosted::HostedPages;
 pub use project::Project;
+pub use redirect::RedirectUrls;
@@ -1,4 +1,9 @@
+use super::{CorsSettings, EmailDomains, HostedPages, RedirectUrls};
+use crate::mail::data::EmailVerificationPolicy;
 use crate::password::data::PasswordAlg;
+use crate::passwordless::data::PasswordlessMode;
+use crate::session::data::{SessionLimit, SessionLimitMode};
+use crate::user::data::AccountDeletion;
 
 use sqlx::PgPool;
 use std::path::PathBuf;
@@ -59,6 +64,182 @@ impl Project {
         domain
     }
 
+    pub async fn cors(cache: &Cache, pool: &PgPool, project: &Uuid) -> sqlx::Result<CorsSettings> {
+        let mut key_path = PathBuf::from("vulpo_project_cors");
+        key_path.push(project.to_string());
+
+        if let Some(value) = cache.get(&key_path).await {
+            if let Ok(settings) = serde_json::from_str(&value) {
+                return Ok(settings);
+            }
+        }
+
+        let settings = sqlx::query_file_as!(
+            CorsSettings,
+            "src/project/sql/get_cors_settings.sql",
+            project
+        )
+        .fetch_one(pool)
+        .await?;
+
+        if let Ok(value) = serde_json::to_string(&settings) {
+            match cache.set(&key_path, &value).await {
+                Some(()) => info!("CACHE set cors {}", project),
+                None => error!("CACHE failed to set cors {}", project),
+            };
+        }
+
+        Ok(settings)
+    }
+
+    pub async fn set_cors(
+        cache: &Cache,
+        pool: &PgPool,
+        project: &Uuid,
+        settings: &CorsSettings,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/project/sql/set_cors_settings.sql",
+            project,
+            &settings.origins,
+            &settings.methods,
+            &settings.headers,
+            settings.max_age,
+        )
+        .execute(pool)
+        .await?;
+
+        let mut key_path = PathBuf::from("vulpo_project_cors");
+        key_path.push(project.to_string());
+
+        if let Ok(value) = serde_json::to_string(settings) {
+            match cache.set(&key_path, &value).await {
+                Some(_) => info!("CACHE UPDATE cors {}", project),
+                None => error!("CACHE failed to update cors {}", project),
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Domain and CORS settings of every project that accepts requests from
+    /// `origin`, used for preflight requests that don't name a project
+    pub async fn cors_by_origin(
+        pool: &PgPool,
+        origin: &str,
+    ) -> sqlx::Result<Vec<(String, CorsSettings)>> {
+        let rows = sqlx::query_file!("src/project/sql/get_cors_settings_by_origin.sql", origin)
+            .fetch_all(pool)
+            .await?;
+
+        let settings = rows
+            .into_iter()
+            .map(|row| {
+                let settings = CorsSettings {
+                    origins: row.origins,
+                    methods: row.methods,
+                    headers: row.headers,
+                    max_age: row.max_age,
+                };
+                (row.domain, settings)
+            })
+            .collect();
+
+        Ok(settings)
+    }
+
+    /// Domain and allowed redirect URLs of the project
+    pub async fn redirect_urls(
+        pool: &PgPool,
+        project: &Uuid,
+    ) -> sqlx::Result<(String, RedirectUrls)> {
+        let row = sqlx::query_file!("src/project/sql/get_redirect_urls.sql", project)
+            .fetch_one(pool)
+            .await?;
+
+        Ok((row.domain, RedirectUrls { urls: row.urls }))
+    }
+
+    pub async fn set_redirect_urls(
+        pool: &PgPool,
+        project: &Uuid,
+        redirect_urls: &RedirectUrls,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/project/sql/set_redirect_urls.sql",
+            project,
+            &redirect_urls.urls
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn email_domains(pool: &PgPool, project: &Uuid) -> sqlx::Result<EmailDomains> {
+        sqlx::query_file_as!(
+            EmailDomains,
+            "src/project/sql/get_email_domains.sql",
+            project
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn set_email_domains(
+        pool: &PgPool,
+        project: &Uuid,
+        email_domains: &EmailDomains,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/project/sql/set_email_domains.sql",
+            project,
+            &email_domains.allow,
+            &email_domains.deny,
+            email_domains.block_disposable,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Name, domain and branding of the hosted pages
+    pub async fn hosted_pages(
+        pool: &PgPool,
+        project: &Uuid,
+    ) -> sqlx::Result<(String, String, HostedPages)> {
+        let row = sqlx::query_file!("src/project/sql/get_hosted_pages.sql", project)
+            .fetch_one(pool)
+            .await?;
+
+        let hosted_pages = HostedPages {
+            logo_url: row.logo_url,
+            primary_color: row.primary_color,
+            background_color: row.background_color,
+        };
+
+        Ok((row.name, row.domain, hosted_pages))
+    }
+
+    pub async fn set_hosted_pages(
+        pool: &PgPool,
+        project: &Uuid,
+        hosted_pages: &HostedPages,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/project/sql/set_hosted_pages.sql",
+            project,
+            hosted_pages.logo_url,
+            hosted_pages.primary_color,
+            hosted_pages.background_color,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn is_admin(pool: &PgPool, project: &Uuid) -> sqlx::Result<bool> {
         sqlx::query_file!("src/project/sql/is_admin.sql", project)
             .fetch_one(pool)
@@ -74,6 +255,16 @@ impl Project {
         Ok(())
     }
 
+    /// Signs out all users of the project and rejects all access tokens
+    /// that were issued until now
+    pub async fn revoke_tokens(pool: &PgPool, project: &Uuid) -> sqlx::Result<()> {
+        sqlx::query_file!("src/project/sql/revoke_tokens.sql", project)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn password_alg(pool: &PgPool, project: &Uuid) -> sqlx::Result<PasswordAlg> {
         sqlx::query_file!("src/project/sql/get_password_alg.sql", project)
             .fetch_one(pool)
@@ -87,4 +278,175 @@ impl Project {
             .await
             .map(|row| row.alg)
     }
+
+    pub async fn passwordless_mode(
+        pool: &PgPool,
+        project: &Uuid,
+    ) -> sqlx::Result<PasswordlessMode> {
+        sqlx::query_file!("src/project/sql/get_passwordless_mode.sql", project)
+            .fetch_one(pool)
+            .await
+            .map(|row| row.mode)
+    }
+
+    pub async fn set_passwordless_mode(
+        pool: &PgPool,
+        project: &Uuid,
+        mode: PasswordlessMode,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/project/sql/set_passwordless_mode.sql",
+            project,
+            mode as PasswordlessMode
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn email_verification(
+        pool: &PgPool,
+        project: &Uuid,
+    ) -> sqlx::Result<EmailVerificationPolicy> {
+        sqlx::query_file!("src/project/sql/get_email_verification.sql", project)
+            .fetch_one(pool)
+            .await
+            .map(|row| row.policy)
+    }
+
+    pub async fn set_email_verification(
+        pool: &PgPool,
+        project: &Uuid,
+        policy: EmailVerificationPolicy,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/project/sql/set_email_verification.sql",
+            project,
+            policy as EmailVerificationPolicy
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn anonymous_ttl(pool: &PgPool, project: &Uuid) -> sqlx::Result<Option<i32>> {
+        sqlx::query_file!("src/project/sql/get_anonymous_ttl.sql", project)
+            .fetch_one(pool)
+            .await
+            .map(|row| row.ttl_days)
+    }
+
+    pub async fn set_anonymous_ttl(
+        pool: &PgPool,
+        project: &Uuid,
+        ttl_days: Option<i32>,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!("src/project/sql/set_anonymous_ttl.sql", project, ttl_days)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Seconds since the last authentication after which sensitive operations
+    /// require the user to re-authenticate, `None` disables the check
+    pub async fn reauthentication_max_age(
+        pool: &PgPool,
+        project: &Uuid,
+    ) -> sqlx::Result<Option<i32>> {
+        sqlx::query_file!("src/project/sql/get_reauthentication_max_age.sql", project)
+            .fetch_one(pool)
+            .await
+            .map(|row| row.max_age)
+    }
+
+    pub async fn set_reauthentication_max_age(
+        pool: &PgPool,
+        project: &Uuid,
+        max_age: Option<i32>,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/project/sql/set_reauthentication_max_age.sql",
+            project,
+            max_age
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn account_deletion(pool: &PgPool, project: &Uuid) -> sqlx::Result<AccountDeletion> {
+        sqlx::query_file_as!(
+            AccountDeletion,
+            "src/project/sql/get_account_deletion.sql",
+            project
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn set_account_deletion(
+        pool: &PgPool,
+        project: &Uuid,
+        settings: &AccountDeletion,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/project/sql/set_account_deletion.sql",
+            project,
+            settings.grace_days,
+            settings.send_email
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn session_limit(pool: &PgPool, project: &Uuid) -> sqlx::Result<SessionLimit> {
+        sqlx::query_file_as!(
+            SessionLimit,
+            "src/project/sql/get_session_limit.sql",
+            project
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn set_session_limit(
+        pool: &PgPool,
+        project: &Uuid,
+        max_sessions: Option<i32>,
+        mode: SessionLimitMode,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/project/sql/set_session_limit.sql",
+            project,
+            max_sessions,
+            mode as SessionLimitMode
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Whether an OAuth sign in is linked to an existing user with the same,
+    /// verified email address
+    pub async fn auto_link(pool: &PgPool, project: &Uuid) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/project/sql/get_auto_link.sql", project)
+            .fetch_one(pool)
+            .await
+            .map(|row| row.auto_link_verified_email)
+    }
+
+    pub async fn set_auto_link(pool: &PgPool, project: &Uuid, auto_link: bool) -> sqlx::Result<()> {
+        sqlx::query_file!("src/project/sql/set_auto_link.sql", project, auto_link)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,129 @@
+use crate::project::data::cors::origin_matches;
+use crate::project::data::Project;
+
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+
+/// URLs a client can send users to from email links and OAuth sign ins, every
+/// URL on the project's domain is always allowed
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RedirectUrls {
+    /// `https://*.example.com/auth` allows every sub domain, a trailing `*`
+    /// allows every path that starts with the given path. Custom schemes like
+    /// `myapp://auth` are used for mobile deep links.
+    pub urls: Vec<String>,
+}
+
+impl RedirectUrls {
+    pub fn allows(&self, domain: &str, url: &str) -> bool {
+        if !is_safe_url(url) {
+            return false;
+        }
+
+        let domain = format!("{}/*", domain.trim_end_matches('/'));
+
+        std::iter::once(domain.as_str())
+            .chain(self.urls.iter().map(String::as_str))
+            .any(|allowed| url_matches(allowed, url))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.urls.iter().all(|url| is_valid_url(url))
+    }
+
+    /// Returns `ApiError::RedirectUrlNotAllowed` when `url` is not on the
+    /// project's allow-list
+    pub async fn verify(pool: &PgPool, project: &Uuid, url: Option<&str>) -> Result<(), ApiError> {
+        let url = match url {
+            None => return Ok(()),
+            Some(url) => url,
+        };
+
+        let (domain, urls) = Project::redirect_urls(pool, project).await?;
+
+        match urls.allows(&domain, url) {
+            true => Ok(()),
+            false => Err(ApiError::RedirectUrlNotAllowed),
+        }
+    }
+}
+
+/// Splits a URL into `scheme://authority` and the path, query and fragment
+/// are removed
+fn split_url(url: &str) -> (&str, &str) {
+    let url = match url.find(['?', '#']) {
+        Some(index) => &url[..index],
+        None => url,
+    };
+
+    let authority_start = match url.find("://") {
+        Some(index) => index + 3,
+        None => return ("", url),
+    };
+
+    match url[authority_start..].find('/') {
+        Some(index) => url.split_at(authority_start + index),
+        None => (url, ""),
+    }
+}
+
+fn url_matches(allowed: &str, url: &str) -> bool {
+    let (allowed_origin, allowed_path) = split_url(allowed);
+    let (origin, path) = split_url(url);
+
+    let origin_matches = match (allowed_origin.is_empty(), origin.is_empty()) {
+        (true, true) => true,
+        (false, false) => origin_matches(allowed_origin, origin),
+        _ => false,
+    };
+
+    let path = if path.is_empty() { "/" } else { path };
+    let path_matches = match allowed_path.strip_suffix('*') {
+        Some(prefix) => path.starts_with(prefix),
+        None if allowed_path.is_empty() => path == "/",
+        None => path == allowed_path,
+    };
+
+    origin_matches && path_matches
+}
+
+/// Backslashes, whitespace, credentials and `..` segments are rejected,
+/// browsers don't agree on how to parse them
+fn is_safe_url(url: &str) -> bool {
+    let (origin, path) = split_url(url);
+
+    !url.is_empty()
+        && url.contains(':')
+        && !url.contains(|c: char| c == '\\' || c.is_whitespace() || c.is_control())
+        && !origin.contains('@')
+        && !path.split('/').any(|segment| segment == "..")
+}
+
+/// Allowed URLs need a scheme and can't contain a query or fragment, a
+/// wildcard is only allowed as the first label of the host or at the end of
+/// the path
+fn is_valid_url(url: &str) -> bool {
+    let scheme = match url.split_once(':') {
+        Some((scheme, _)) => scheme,
+        None => return false,
+    };
+
+    let (origin, path) = split_url(url);
+    let host = origin
+        .split_once("://")
+        .map(|(_, host)| host.strip_prefix("*.").unwrap_or(host))
+        .unwrap_or("");
+    let path = path.strip_suffix('*').unwrap_or(path);
+
+    !scheme.is_empty()
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && is_safe_url(url)
+        && !url.contains(['?', '#'])
+        && !host.contains('*')
+        && !path.contains('*')
+        && (origin.is_empty() || !host.is_empty())
+}
@@ -1,4 +1,4 @@
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 use crate::project::data::Project;
 
 use rocket::http::Status;
@@ -19,7 +19,8 @@ pub async fn delete(pool: &Db, project_id: &Uuid) -> Result<(), ApiError> {
 }
 
 #[post("/delete", data = "<body>")]
-pub async fn handler(pool: Db, body: Json<Payload>, _admin: Admin) -> Result<Status, ApiError> {
+pub async fn handler(pool: Db, body: Json<Payload>, admin: Admin) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Owner)?;
     delete(&pool, &body.project).await?;
     Ok(Status::Ok)
 }
@@ -4,9 +4,11 @@ use rocket::request::{self, FromRequest, Request};
 use rocket::Route;
 use uuid::Uuid;
 
+mod captcha;
 pub mod data;
 mod delete;
 mod flags;
+mod revoke_tokens;
 mod set_flags;
 
 #[derive(Debug)]
@@ -38,5 +40,11 @@ impl<'r> FromRequest<'r> for Project {
 }
 
 pub fn routes() -> Vec<Route> {
-    routes![flags::handler, set_flags::handler, delete::handler]
+    routes![
+        flags::handler,
+        captcha::handler,
+        set_flags::handler,
+        delete::handler,
+        revoke_tokens::handler
+    ]
 }
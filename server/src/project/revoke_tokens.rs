@@ -0,0 +1,21 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::project::data::Project;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct Payload {
+    pub project: Uuid,
+}
+
+#[post("/revoke_tokens", data = "<body>")]
+pub async fn handler(pool: Db, body: Json<Payload>, admin: Admin) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Owner)?;
+    Project::revoke_tokens(&pool, &body.project).await?;
+    Ok(Status::Ok)
+}
@@ -1,4 +1,4 @@
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 use crate::project::data::Flags;
 use rocket::http::Status;
 use uuid::Uuid;
@@ -9,12 +9,18 @@ use vulpo_auth_types::error::ApiError;
 use werkbank::rocket::Db;
 
 pub async fn set_flags(pool: &Db, flags: &[Flags], project_id: &Uuid) -> Result<(), ApiError> {
+    // cookie sessions can't sign DPoP proofs, bound tokens would be rejected
+    if flags.contains(&Flags::CookieSession) && flags.contains(&Flags::DPoP) {
+        return Err(ApiError::BadRequest);
+    }
+
     Flags::set_flags(&pool, &project_id, &flags).await?;
     Ok(())
 }
 
 #[post("/set_flags", format = "json", data = "<body>")]
-pub async fn handler(pool: Db, body: Json<Payload>, _admin: Admin) -> Result<Status, ApiError> {
+pub async fn handler(pool: Db, body: Json<Payload>, admin: Admin) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
     set_flags(&pool, &body.flags, &body.project).await?;
     Ok(Status::Ok)
 }
@@ -0,0 +1,4 @@
+select deletion_grace_days as "grace_days"
+     , deletion_email as "send_email"
+  from project_settings
+ where project_settings.project_id = $1
\ No newline at end of file
@@ -0,0 +1,3 @@
+select anonymous_ttl_days as "ttl_days"
+  from project_settings
+ where project_settings.project_id = $1
@@ -0,0 +1,3 @@
+select auto_link_verified_email
+  from project_settings
+ where project_settings.project_id = $1
@@ -0,0 +1,6 @@
+select cors_origins as origins
+     , cors_methods as methods
+     , cors_headers as headers
+     , cors_max_age as max_age
+  from project_settings
+ where project_id = $1
@@ -0,0 +1,12 @@
+select domain
+     , cors_origins as origins
+     , cors_methods as methods
+     , cors_headers as headers
+     , cors_max_age as max_age
+  from project_settings
+ where lower(rtrim(domain, '/')) = lower($1)
+    or exists (
+         select 1
+           from unnest(cors_origins) as allowed
+          where lower($1) like lower(replace(replace(allowed, '_', '\_'), '*', '%'))
+       )
@@ -0,0 +1,5 @@
+select email_domains_allow as allow
+     , email_domains_deny as deny
+     , block_disposable_emails as block_disposable
+  from project_settings
+ where project_id = $1
\ No newline at end of file
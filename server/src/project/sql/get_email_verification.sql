@@ -0,0 +1,3 @@
+select email_verification as "policy: EmailVerificationPolicy"
+  from project_settings
+ where project_settings.project_id = $1
\ No newline at end of file
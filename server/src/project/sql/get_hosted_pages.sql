@@ -0,0 +1,7 @@
+select name
+     , domain
+     , hosted_logo_url as logo_url
+     , hosted_primary_color as primary_color
+     , hosted_background_color as background_color
+  from project_settings
+ where project_id = $1
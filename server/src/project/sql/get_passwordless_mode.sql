@@ -0,0 +1,3 @@
+select passwordless_mode as "mode: PasswordlessMode"
+  from project_settings
+ where project_settings.project_id = $1
@@ -0,0 +1,3 @@
+select reauthentication_max_age as "max_age"
+  from project_settings
+ where project_settings.project_id = $1
@@ -0,0 +1,4 @@
+select domain
+     , redirect_urls as urls
+  from project_settings
+ where project_id = $1
\ No newline at end of file
@@ -0,0 +1,4 @@
+select max_sessions
+     , session_limit_mode as "mode: SessionLimitMode"
+  from project_settings
+ where project_settings.project_id = $1
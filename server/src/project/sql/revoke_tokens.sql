@@ -0,0 +1,11 @@
+with revoke_tokens as (
+    update projects
+       set tokens_valid_after = now()
+     where id = $1
+ returning id
+)
+delete from sessions
+ where project_id in (
+     select id as project_id
+       from revoke_tokens
+ )
@@ -0,0 +1,4 @@
+update project_settings
+   set deletion_grace_days = $2
+     , deletion_email = $3
+ where project_id = $1
\ No newline at end of file
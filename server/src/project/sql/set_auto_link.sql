@@ -0,0 +1,3 @@
+update project_settings
+   set auto_link_verified_email = $2
+ where project_id = $1
\ No newline at end of file
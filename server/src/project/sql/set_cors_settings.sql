@@ -0,0 +1,6 @@
+update project_settings
+   set cors_origins = $2
+     , cors_methods = $3
+     , cors_headers = $4
+     , cors_max_age = $5
+ where project_id = $1
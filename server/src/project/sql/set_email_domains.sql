@@ -0,0 +1,5 @@
+update project_settings
+   set email_domains_allow = $2
+     , email_domains_deny = $3
+     , block_disposable_emails = $4
+ where project_id = $1
\ No newline at end of file
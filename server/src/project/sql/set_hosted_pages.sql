@@ -0,0 +1,13 @@
+with settings as (
+    update project_settings
+       set hosted_logo_url = $2
+         , hosted_primary_color = $3
+         , hosted_background_color = $4
+     where project_id = $1
+ returning project_id
+)
+-- translations of the pages are stored like the ones of an email template
+insert into templates(body, name, project_id)
+select '', 'hosted_pages', settings.project_id
+  from settings
+    on conflict (name, project_id) do nothing
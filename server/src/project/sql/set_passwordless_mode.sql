@@ -0,0 +1,3 @@
+update project_settings
+   set passwordless_mode = $2
+ where project_id = $1
\ No newline at end of file
@@ -0,0 +1,3 @@
+update project_settings
+   set reauthentication_max_age = $2
+ where project_id = $1
@@ -0,0 +1,4 @@
+update project_settings
+   set max_sessions = $2
+     , session_limit_mode = $3
+ where project_id = $1
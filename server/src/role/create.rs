@@ -0,0 +1,64 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::role::data::{Permission, Role, RoleData};
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct CreateRole {
+    pub project: Uuid,
+    #[serde(flatten)]
+    pub role: RoleData,
+}
+
+#[derive(Serialize)]
+pub struct CreateRoleResponse {
+    pub id: Uuid,
+}
+
+/// Trims the name and makes sure that all permissions are part of the
+/// project's catalog
+pub async fn validate_role(
+    pool: &Db,
+    project_id: &Uuid,
+    role: RoleData,
+) -> Result<RoleData, ApiError> {
+    let name = role.name.trim().to_string();
+    if name.is_empty() {
+        return Err(ApiError::BadRequest);
+    }
+
+    let mut permissions = role.permissions;
+    permissions.sort();
+    permissions.dedup();
+
+    if !Permission::exist(&pool, &project_id, &permissions).await? {
+        return Err(ApiError::PermissionNotFound);
+    }
+
+    Ok(RoleData {
+        name,
+        description: role.description,
+        permissions,
+    })
+}
+
+pub async fn create_role(pool: &Db, body: CreateRole) -> Result<Uuid, ApiError> {
+    let role = validate_role(&pool, &body.project, body.role).await?;
+    let id = Role::create(&pool, &body.project, &role).await?;
+    Ok(id)
+}
+
+#[post("/create", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<CreateRole>,
+    admin: Admin,
+) -> Result<Json<CreateRoleResponse>, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+    let id = create_role(&pool, body.into_inner()).await?;
+    Ok(Json(CreateRoleResponse { id }))
+}
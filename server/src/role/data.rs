@@ -0,0 +1,173 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Permission {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl Permission {
+    /// Permission names are used as is in the access token, e.g. `posts:write`
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, ':' | '.' | '_' | '-'))
+    }
+
+    pub async fn list(pool: &PgPool, project_id: &Uuid) -> sqlx::Result<Vec<Permission>> {
+        sqlx::query_file_as!(Permission, "src/role/sql/list_permissions.sql", project_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        project_id: &Uuid,
+        permission: &Permission,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/role/sql/create_permission.sql",
+            project_id,
+            permission.name,
+            permission.description,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Removes the permission from the catalog and from all roles
+    pub async fn remove(pool: &PgPool, project_id: &Uuid, name: &str) -> sqlx::Result<()> {
+        sqlx::query_file!("src/role/sql/remove_permission.sql", project_id, name)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn exist(pool: &PgPool, project_id: &Uuid, names: &[String]) -> sqlx::Result<bool> {
+        let row = sqlx::query_file!("src/role/sql/count_permissions.sql", project_id, names)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(row.count == names.len() as i64)
+    }
+
+    /// All permissions granted to the user through their roles
+    pub async fn for_user(pool: &PgPool, user_id: &Uuid) -> sqlx::Result<Vec<String>> {
+        sqlx::query_file!("src/role/sql/get_user_permissions.sql", user_id)
+            .fetch_one(pool)
+            .await
+            .map(|row| row.permissions)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Role {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub permissions: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RoleData {
+    pub name: String,
+    pub description: Option<String>,
+    pub permissions: Vec<String>,
+}
+
+impl Role {
+    pub async fn list(pool: &PgPool, project_id: &Uuid) -> sqlx::Result<Vec<Role>> {
+        sqlx::query_file_as!(Role, "src/role/sql/list_roles.sql", project_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn create(pool: &PgPool, project_id: &Uuid, role: &RoleData) -> sqlx::Result<Uuid> {
+        sqlx::query_file!(
+            "src/role/sql/create_role.sql",
+            project_id,
+            role.name,
+            role.description,
+            &role.permissions,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|row| row.id)
+    }
+
+    pub async fn update(
+        pool: &PgPool,
+        id: &Uuid,
+        project_id: &Uuid,
+        role: &RoleData,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_file!(
+            "src/role/sql/update_role.sql",
+            id,
+            project_id,
+            role.name,
+            role.description,
+            &role.permissions,
+        )
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected() > 0)
+    }
+
+    pub async fn remove(pool: &PgPool, id: &Uuid, project_id: &Uuid) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/role/sql/remove_role.sql", id, project_id)
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected() > 0)
+    }
+
+    pub async fn exist(pool: &PgPool, project_id: &Uuid, ids: &[Uuid]) -> sqlx::Result<bool> {
+        let row = sqlx::query_file!("src/role/sql/count_roles.sql", project_id, ids)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(row.count == ids.len() as i64)
+    }
+
+    pub async fn for_user(
+        pool: &PgPool,
+        user_id: &Uuid,
+        project_id: &Uuid,
+    ) -> sqlx::Result<Vec<Role>> {
+        sqlx::query_file_as!(
+            Role,
+            "src/role/sql/list_user_roles.sql",
+            user_id,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Replaces the roles of a user, roles of other projects are ignored
+    pub async fn set_for_user(
+        pool: &PgPool,
+        user_id: &Uuid,
+        project_id: &Uuid,
+        roles: &[Uuid],
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/role/sql/set_user_roles.sql",
+            user_id,
+            roles,
+            project_id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::role::data::Role;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct DeleteRole {
+    pub id: Uuid,
+    pub project: Uuid,
+}
+
+#[post("/delete", format = "json", data = "<body>")]
+pub async fn handler(pool: Db, body: Json<DeleteRole>, admin: Admin) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+    if !Role::remove(&pool, &body.id, &body.project).await? {
+        return Err(ApiError::NotFound);
+    }
+
+    Ok(Status::Ok)
+}
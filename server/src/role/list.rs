@@ -0,0 +1,19 @@
+use crate::admin::data::Admin;
+use crate::role::data::Role;
+
+use rocket::serde::json::Json;
+use serde::Serialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Serialize)]
+pub struct Roles {
+    pub items: Vec<Role>,
+}
+
+#[get("/list?<project>")]
+pub async fn handler(pool: Db, project: Uuid, _admin: Admin) -> Result<Json<Roles>, ApiError> {
+    let items = Role::list(&pool, &project).await?;
+    Ok(Json(Roles { items }))
+}
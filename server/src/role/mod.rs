@@ -0,0 +1,23 @@
+use rocket::Route;
+
+mod create;
+pub mod data;
+mod delete;
+mod list;
+mod permission;
+mod update;
+mod user;
+
+pub fn routes() -> Vec<Route> {
+    routes![
+        list::handler,
+        create::handler,
+        update::handler,
+        delete::handler,
+        permission::list_handler,
+        permission::create_handler,
+        permission::delete_handler,
+        user::get_handler,
+        user::set_handler,
+    ]
+}
@@ -0,0 +1,75 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::role::data::Permission;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Serialize)]
+pub struct Permissions {
+    pub items: Vec<Permission>,
+}
+
+#[get("/permission/list?<project>")]
+pub async fn list_handler(
+    pool: Db,
+    project: Uuid,
+    _admin: Admin,
+) -> Result<Json<Permissions>, ApiError> {
+    let items = Permission::list(&pool, &project).await?;
+    Ok(Json(Permissions { items }))
+}
+
+#[derive(Deserialize)]
+pub struct CreatePermission {
+    pub project: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+pub async fn create_permission(pool: &Db, body: CreatePermission) -> Result<(), ApiError> {
+    let name = body.name.trim();
+
+    if !Permission::is_valid_name(name) {
+        return Err(ApiError::BadRequest);
+    }
+
+    let permission = Permission {
+        name: name.to_string(),
+        description: body.description,
+    };
+
+    Permission::create(&pool, &body.project, &permission).await?;
+    Ok(())
+}
+
+#[post("/permission/create", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    body: Json<CreatePermission>,
+    admin: Admin,
+) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+    create_permission(&pool, body.into_inner()).await?;
+    Ok(Status::Ok)
+}
+
+#[derive(Deserialize)]
+pub struct DeletePermission {
+    pub project: Uuid,
+    pub name: String,
+}
+
+#[post("/permission/delete", format = "json", data = "<body>")]
+pub async fn delete_handler(
+    pool: Db,
+    body: Json<DeletePermission>,
+    admin: Admin,
+) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+    Permission::remove(&pool, &body.project, &body.name).await?;
+    Ok(Status::Ok)
+}
@@ -0,0 +1,4 @@
+select count(*) as "count!"
+  from permissions
+ where project_id = $1
+   and name = any($2)
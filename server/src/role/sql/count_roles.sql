@@ -0,0 +1,4 @@
+select count(*) as "count!"
+  from roles
+ where project_id = $1
+   and id = any($2)
@@ -0,0 +1,2 @@
+insert into permissions(project_id, name, description)
+values($1, $2, $3)
@@ -0,0 +1,3 @@
+insert into roles(project_id, name, description, permissions)
+values($1, $2, $3, $4)
+returning id
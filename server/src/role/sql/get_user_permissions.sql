@@ -0,0 +1,5 @@
+select coalesce(array_agg(distinct permission order by permission), '{}') as "permissions!"
+  from user_roles
+  join roles on roles.id = user_roles.role_id
+ cross join unnest(roles.permissions) as permission
+ where user_roles.user_id = $1
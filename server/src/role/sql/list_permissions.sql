@@ -0,0 +1,5 @@
+select name
+     , description
+  from permissions
+ where project_id = $1
+ order by name
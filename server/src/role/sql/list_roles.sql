@@ -0,0 +1,8 @@
+select id
+     , name
+     , description
+     , permissions
+     , created_at
+  from roles
+ where project_id = $1
+ order by name
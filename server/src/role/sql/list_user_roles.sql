@@ -0,0 +1,10 @@
+select roles.id
+     , roles.name
+     , roles.description
+     , roles.permissions
+     , roles.created_at
+  from user_roles
+  join roles on roles.id = user_roles.role_id
+ where user_roles.user_id = $1
+   and roles.project_id = $2
+ order by roles.name
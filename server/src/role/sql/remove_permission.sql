@@ -0,0 +1,11 @@
+with removed as (
+	delete from permissions
+	 where project_id = $1
+	   and name = $2
+	returning project_id, name
+)
+update roles
+   set permissions = array_remove(roles.permissions, removed.name)
+  from removed
+ where roles.project_id = removed.project_id
+   and removed.name = any(roles.permissions)
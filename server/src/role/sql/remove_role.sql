@@ -0,0 +1,3 @@
+delete from roles
+ where id = $1
+   and project_id = $2
@@ -0,0 +1,11 @@
+with removed as (
+	delete from user_roles
+	 where user_id = $1
+	   and not (role_id = any($2))
+)
+insert into user_roles(user_id, role_id)
+select $1, roles.id
+  from roles
+ where roles.id = any($2)
+   and roles.project_id = $3
+on conflict do nothing
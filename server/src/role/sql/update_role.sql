@@ -0,0 +1,6 @@
+update roles
+   set name = $3
+     , description = $4
+     , permissions = $5
+ where id = $1
+   and project_id = $2
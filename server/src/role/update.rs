@@ -0,0 +1,35 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::role::create::validate_role;
+use crate::role::data::{Role, RoleData};
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct UpdateRole {
+    pub id: Uuid,
+    pub project: Uuid,
+    #[serde(flatten)]
+    pub role: RoleData,
+}
+
+pub async fn update_role(pool: &Db, body: UpdateRole) -> Result<(), ApiError> {
+    let role = validate_role(&pool, &body.project, body.role).await?;
+
+    if !Role::update(&pool, &body.id, &body.project, &role).await? {
+        return Err(ApiError::NotFound);
+    }
+
+    Ok(())
+}
+
+#[post("/update", format = "json", data = "<body>")]
+pub async fn handler(pool: Db, body: Json<UpdateRole>, admin: Admin) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+    update_role(&pool, body.into_inner()).await?;
+    Ok(Status::Ok)
+}
@@ -0,0 +1,61 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::role::data::Role;
+use crate::role::list::Roles;
+use crate::user::data::User;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[get("/user?<user>&<project>")]
+pub async fn get_handler(
+    pool: Db,
+    user: Uuid,
+    project: Uuid,
+    _admin: Admin,
+) -> Result<Json<Roles>, ApiError> {
+    let items = Role::for_user(&pool, &user, &project).await?;
+    Ok(Json(Roles { items }))
+}
+
+#[derive(Deserialize)]
+pub struct SetUserRoles {
+    pub user: Uuid,
+    pub project: Uuid,
+    pub roles: Vec<Uuid>,
+}
+
+/// Permissions are part of the access token, changes take effect with
+/// the next token refresh
+pub async fn set_user_roles(pool: &Db, body: SetUserRoles) -> Result<(), ApiError> {
+    User::get_by_id(&pool, &body.user, &body.project)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let mut roles = body.roles;
+    roles.sort();
+    roles.dedup();
+
+    if !Role::exist(&pool, &body.project, &roles).await? {
+        return Err(ApiError::NotFound);
+    }
+
+    Role::set_for_user(&pool, &body.user, &body.project, &roles).await?;
+    Ok(())
+}
+
+#[post("/user/set", format = "json", data = "<body>")]
+pub async fn set_handler(
+    pool: Db,
+    body: Json<SetUserRoles>,
+    admin: Admin,
+) -> Result<Status, ApiError> {
+    admin
+        .require_users(&pool, &body.project, AdminRole::Editor)
+        .await?;
+    set_user_roles(&pool, body.into_inner()).await?;
+    Ok(Status::Ok)
+}
@@ -1,14 +1,21 @@
 use crate::admin;
+use crate::anonymous;
 use crate::api_key;
-use crate::config::Secrets;
+use crate::captcha;
+use crate::config::{self, Secrets};
 use crate::cors::CORS;
+use crate::hosted;
 use crate::keys;
 use crate::oauth;
+use crate::organization;
 use crate::password;
 use crate::passwordless;
 use crate::project;
+use crate::role;
+use crate::service_account;
 use crate::session;
 use crate::settings;
+use crate::sms;
 use crate::template;
 use crate::user;
 
@@ -27,16 +34,35 @@ pub async fn start(figment: &Figment, port: Option<u16>, secrets: Secrets) {
         Some(port) => rocket_config.merge(("port", port)),
     };
 
+    let hosted_config = config::hosted(figment);
+    let public_url = config::public_url(figment);
+    let captcha_verifier = captcha::verifier::from_config(&config::captcha(figment));
+
     let _ = rocket::custom(config)
         .attach(TracingFairing)
         .attach(CORS)
         .attach(AdHoc::on_ignite("Add Secrets", |rocket| async move {
             rocket.manage(secrets)
         }))
+        .attach(AdHoc::on_ignite(
+            "Add Hosted Pages Config",
+            |rocket| async move { rocket.manage(hosted_config) },
+        ))
+        .attach(AdHoc::on_ignite("Add Public URL", |rocket| async move {
+            rocket.manage(public_url)
+        }))
+        .attach(AdHoc::on_ignite(
+            "Add CAPTCHA Verifier",
+            |rocket| async move { rocket.manage(captcha_verifier) },
+        ))
         .attach(Cache::fairing(&figment))
         .attach(db::create_pool(&figment))
+        .attach(anonymous::cleanup())
+        .attach(session::cleanup())
+        .attach(user::cleanup())
         .mount("/", admin::redirect())
         .mount("/dashboard", admin::dashboard())
+        .mount("/hosted", hosted::routes())
         .mount("/api/admin", admin::routes())
         .mount("/api/user", user::routes())
         .mount("/api/passwordless", passwordless::routes())
@@ -48,6 +74,11 @@ pub async fn start(figment: &Figment, port: Option<u16>, secrets: Secrets) {
         .mount("/api/keys", keys::routes())
         .mount("/api/api_key", api_key::routes())
         .mount("/api/oauth", oauth::routes())
+        .mount("/api/sms", sms::routes())
+        .mount("/api/anonymous", anonymous::routes())
+        .mount("/api/organization", organization::routes())
+        .mount("/api/role", role::routes())
+        .mount("/api/service_account", service_account::routes())
         .launch()
         .await;
 }
@@ -0,0 +1,68 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::crypto::Token;
+use crate::service_account::data::ServiceAccount;
+
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct CreateServiceAccount {
+    pub project: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+/// The secret is only returned once, it is stored hashed
+#[derive(Serialize)]
+pub struct ClientCredentials {
+    pub client_id: Uuid,
+    pub client_secret: String,
+}
+
+pub fn validate_scopes(scopes: Vec<String>) -> Result<Vec<String>, ApiError> {
+    let mut scopes: Vec<String> = scopes.iter().map(|s| s.trim().to_string()).collect();
+    scopes.sort();
+    scopes.dedup();
+
+    if !scopes.iter().all(|s| ServiceAccount::is_valid_scope(s)) {
+        return Err(ApiError::OAuthInvalidScope);
+    }
+
+    Ok(scopes)
+}
+
+pub async fn create_service_account(
+    pool: &Db,
+    body: CreateServiceAccount,
+) -> Result<ClientCredentials, ApiError> {
+    let name = body.name.trim();
+    if name.is_empty() {
+        return Err(ApiError::BadRequest);
+    }
+
+    let scopes = validate_scopes(body.scopes)?;
+    let secret = Token::create();
+    let hashed_secret = Token::hash(&secret)?;
+
+    let id = ServiceAccount::create(&pool, &body.project, name, &hashed_secret, &scopes).await?;
+
+    Ok(ClientCredentials {
+        client_id: id,
+        client_secret: secret,
+    })
+}
+
+#[post("/create", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<CreateServiceAccount>,
+    admin: Admin,
+) -> Result<Json<ClientCredentials>, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+    let credentials = create_service_account(&pool, body.into_inner()).await?;
+    Ok(Json(credentials))
+}
@@ -0,0 +1,99 @@
+use crate::role::data::Permission;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize)]
+pub struct ServiceAccount {
+    pub id: Uuid,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The credentials used by the client credentials grant, `secret` is hashed
+pub struct ServiceAccountClient {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub secret: String,
+    pub scopes: Vec<String>,
+}
+
+impl ServiceAccount {
+    /// Scopes use the same format as permissions, e.g. `invoices:read`
+    pub fn is_valid_scope(scope: &str) -> bool {
+        Permission::is_valid_name(scope)
+    }
+
+    pub async fn list(pool: &PgPool, project_id: &Uuid) -> sqlx::Result<Vec<ServiceAccount>> {
+        sqlx::query_file_as!(
+            ServiceAccount,
+            "src/service_account/sql/list_service_accounts.sql",
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &PgPool,
+        project_id: &Uuid,
+        name: &str,
+        secret: &str,
+        scopes: &[String],
+    ) -> sqlx::Result<Uuid> {
+        sqlx::query_file!(
+            "src/service_account/sql/create_service_account.sql",
+            project_id,
+            name,
+            secret,
+            scopes,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|row| row.id)
+    }
+
+    pub async fn set_secret(
+        pool: &PgPool,
+        id: &Uuid,
+        project_id: &Uuid,
+        secret: &str,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_file!(
+            "src/service_account/sql/set_secret.sql",
+            id,
+            project_id,
+            secret
+        )
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected() > 0)
+    }
+
+    pub async fn remove(pool: &PgPool, id: &Uuid, project_id: &Uuid) -> sqlx::Result<bool> {
+        sqlx::query_file!(
+            "src/service_account/sql/remove_service_account.sql",
+            id,
+            project_id
+        )
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected() > 0)
+    }
+
+    pub async fn get_client(
+        pool: &PgPool,
+        id: &Uuid,
+    ) -> sqlx::Result<Option<ServiceAccountClient>> {
+        sqlx::query_file_as!(
+            ServiceAccountClient,
+            "src/service_account/sql/get_client.sql",
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+}
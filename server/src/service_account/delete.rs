@@ -0,0 +1,30 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::service_account::data::ServiceAccount;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct DeleteServiceAccount {
+    pub id: Uuid,
+    pub project: Uuid,
+}
+
+/// Tokens that were already issued stay valid until they expire
+#[post("/delete", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<DeleteServiceAccount>,
+    admin: Admin,
+) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+    if !ServiceAccount::remove(&pool, &body.id, &body.project).await? {
+        return Err(ApiError::NotFound);
+    }
+
+    Ok(Status::Ok)
+}
@@ -0,0 +1,23 @@
+use crate::admin::data::Admin;
+use crate::service_account::data::ServiceAccount;
+
+use rocket::serde::json::Json;
+use serde::Serialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Serialize)]
+pub struct ServiceAccounts {
+    pub items: Vec<ServiceAccount>,
+}
+
+#[get("/list?<project>")]
+pub async fn handler(
+    pool: Db,
+    project: Uuid,
+    _admin: Admin,
+) -> Result<Json<ServiceAccounts>, ApiError> {
+    let items = ServiceAccount::list(&pool, &project).await?;
+    Ok(Json(ServiceAccounts { items }))
+}
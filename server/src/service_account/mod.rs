@@ -0,0 +1,16 @@
+use rocket::Route;
+
+mod create;
+pub mod data;
+mod delete;
+mod list;
+mod secret;
+
+pub fn routes() -> Vec<Route> {
+    routes![
+        list::handler,
+        create::handler,
+        delete::handler,
+        secret::handler,
+    ]
+}
@@ -0,0 +1,38 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::crypto::Token;
+use crate::service_account::create::ClientCredentials;
+use crate::service_account::data::ServiceAccount;
+
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize)]
+pub struct RotateSecret {
+    pub id: Uuid,
+    pub project: Uuid,
+}
+
+/// Replaces the client secret, the old secret stops working immediately
+#[post("/rotate_secret", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<RotateSecret>,
+    admin: Admin,
+) -> Result<Json<ClientCredentials>, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
+
+    let secret = Token::create();
+    let hashed_secret = Token::hash(&secret)?;
+
+    if !ServiceAccount::set_secret(&pool, &body.id, &body.project, &hashed_secret).await? {
+        return Err(ApiError::NotFound);
+    }
+
+    Ok(Json(ClientCredentials {
+        client_id: body.id,
+        client_secret: secret,
+    }))
+}
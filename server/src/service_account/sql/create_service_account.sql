@@ -0,0 +1,3 @@
+insert into service_accounts(project_id, name, secret, scopes)
+values($1, $2, $3, $4)
+returning id
@@ -0,0 +1,6 @@
+select id
+     , project_id
+     , secret
+     , scopes
+  from service_accounts
+ where id = $1
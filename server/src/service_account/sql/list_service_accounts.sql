@@ -0,0 +1,7 @@
+select id
+     , name
+     , scopes
+     , created_at
+  from service_accounts
+ where project_id = $1
+ order by name
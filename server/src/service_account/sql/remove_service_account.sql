@@ -0,0 +1,3 @@
+delete from service_accounts
+ where id = $1
+   and project_id = $2
@@ -0,0 +1,4 @@
+update service_accounts
+   set secret = $3
+ where id = $1
+   and project_id = $2
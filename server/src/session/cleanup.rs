@@ -0,0 +1,35 @@
+use crate::session::data::DPoPProof;
+
+use rocket::fairing::AdHoc;
+use rocket::tokio::time::{interval, Duration};
+use sqlx::PgPool;
+use tracing::{error, info};
+
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 10);
+
+/// Periodically removes the replay records of expired DPoP proofs
+pub fn fairing() -> AdHoc {
+    AdHoc::on_liftoff("DPoP Proof Cleanup", |rocket| {
+        Box::pin(async move {
+            let pool = match rocket.state::<PgPool>() {
+                Some(pool) => pool.clone(),
+                None => {
+                    error!("DPoP proof cleanup: database pool not found");
+                    return;
+                }
+            };
+
+            rocket::tokio::spawn(async move {
+                let mut timer = interval(CLEANUP_INTERVAL);
+                loop {
+                    timer.tick().await;
+                    match DPoPProof::remove_expired(&pool).await {
+                        Ok(0) => {}
+                        Ok(count) => info!("Removed {} expired DPoP proofs", count),
+                        Err(err) => error!("Failed to remove DPoP proofs: {:?}", err),
+                    }
+                }
+            });
+        })
+    })
+}
@@ -0,0 +1,302 @@
+//! Cookie sessions for server rendered apps. When a project enables
+//! `session::cookie`, every sign in sets the session and the access token as
+//! HttpOnly cookies, the session is refreshed with the cookie instead of a
+//! token signed by the client. Requests that are authenticated with a cookie
+//! have to send the `vulpo_csrf` cookie in the `X-CSRF-Token` header.
+
+use crate::config::Secrets;
+use crate::crypto::Token;
+use crate::project::data::{Flags, Project as ProjectData};
+use crate::project::Project;
+use crate::session::data::{Session, SessionMetadata};
+use crate::session::refresh::renew;
+
+use chrono::Utc;
+use rocket::http::{Cookie, CookieJar, Method, SameSite, Status};
+use rocket::request::{FromRequest, Outcome, Request};
+use rocket::time::Duration;
+use rocket::State;
+use std::convert::Infallible;
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use vulpo_auth_types::session::SessionResponse;
+use werkbank::rocket::{Cache, Db};
+
+pub const SESSION_COOKIE: &str = "vulpo_session";
+pub const ACCESS_TOKEN_COOKIE: &str = "vulpo_access_token";
+pub const CSRF_COOKIE: &str = "vulpo_csrf";
+pub const CSRF_HEADER: &str = "X-CSRF-Token";
+
+/// Access tokens expire after 15 minutes
+const ACCESS_TOKEN_MAX_AGE: i64 = 15 * 60;
+
+pub struct SessionCookies<'r> {
+    jar: &'r CookieJar<'r>,
+    host: Option<&'r str>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for SessionCookies<'r> {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let headers = req.headers();
+        let host = headers
+            .get_one("X-Forwarded-Host")
+            .or_else(|| headers.get_one("Host"))
+            .and_then(|host| host.split(':').next());
+
+        Outcome::Success(SessionCookies {
+            jar: req.cookies(),
+            host,
+        })
+    }
+}
+
+impl<'r> SessionCookies<'r> {
+    /// Sets the session cookies when the project uses cookie sessions. The
+    /// refresh secret is replaced every time. The access token moves from the
+    /// response body into its HttpOnly cookie, scripts can't read it.
+    pub async fn set(
+        &self,
+        cache: &Cache,
+        pool: &Db,
+        project_id: &Uuid,
+        session: &mut SessionResponse,
+    ) -> Result<(), ApiError> {
+        let flags = Flags::from_project(pool, project_id).await?;
+
+        if !flags.contains(&Flags::CookieSession) {
+            return Ok(());
+        }
+
+        let secret = Token::create();
+        let hash = Token::hash(&secret)?;
+        Session::set_cookie(pool, &session.session, Some(&hash)).await?;
+
+        let domain = self.domain(cache, pool, project_id).await;
+        let session_max_age = (session.expire_at - Utc::now()).num_seconds();
+        let value = format!("{}.{}", session.session, secret);
+
+        self.add(SESSION_COOKIE, value, session_max_age, true, &domain);
+        self.add(
+            ACCESS_TOKEN_COOKIE,
+            std::mem::take(&mut session.access_token),
+            ACCESS_TOKEN_MAX_AGE,
+            true,
+            &domain,
+        );
+        // readable by the app so it can be sent in the CSRF header
+        self.add(
+            CSRF_COOKIE,
+            Token::create(),
+            session_max_age,
+            false,
+            &domain,
+        );
+
+        Ok(())
+    }
+
+    pub async fn remove(&self, cache: &Cache, pool: &Db, project_id: &Uuid) {
+        let domain = self.domain(cache, pool, project_id).await;
+
+        for name in [SESSION_COOKIE, ACCESS_TOKEN_COOKIE, CSRF_COOKIE] {
+            let mut cookie = Cookie::named(name);
+            cookie.set_path("/");
+
+            if let Some(domain) = domain.clone() {
+                cookie.set_domain(domain);
+            }
+
+            self.jar.remove(cookie);
+        }
+    }
+
+    /// Session id and refresh secret of the session cookie
+    pub fn session(&self) -> Option<(Uuid, String)> {
+        let cookie = self.jar.get(SESSION_COOKIE)?;
+        let (id, secret) = cookie.value().split_once('.')?;
+        let id = Uuid::parse_str(id).ok()?;
+        Some((id, secret.to_string()))
+    }
+
+    /// The session of the session cookie, the cookie's secret has to match
+    pub async fn verified_session(&self, pool: &Db, project: &Uuid) -> Result<Session, ApiError> {
+        let (session_id, secret) = self.session().ok_or(ApiError::AuthRefreshTokenMissing)?;
+
+        let session = Session::get(pool, &session_id).await?;
+
+        if session.project_id != *project {
+            return Err(ApiError::Forbidden);
+        }
+
+        let hash = Session::cookie_hash(pool, &session.id)
+            .await?
+            .ok_or(ApiError::Forbidden)?;
+
+        if !Token::verify(&secret, &hash)? {
+            return Err(ApiError::Forbidden);
+        }
+
+        Ok(session)
+    }
+
+    fn add(
+        &self,
+        name: &'static str,
+        value: String,
+        max_age: i64,
+        http_only: bool,
+        domain: &Option<String>,
+    ) {
+        let mut cookie = Cookie::build(name, value)
+            .path("/")
+            .secure(true)
+            .http_only(http_only)
+            .same_site(SameSite::Lax)
+            .max_age(Duration::seconds(max_age))
+            .finish();
+
+        if let Some(domain) = domain.clone() {
+            cookie.set_domain(domain);
+        }
+
+        self.jar.add(cookie);
+    }
+
+    async fn domain(&self, cache: &Cache, pool: &Db, project_id: &Uuid) -> Option<String> {
+        let project_domain = ProjectData::domain(cache, pool, project_id).await.ok()?;
+        let project_host = url::Url::parse(&project_domain)
+            .ok()?
+            .host_str()?
+            .to_string();
+        cookie_domain(&project_host, self.host?)
+    }
+}
+
+/// Cookies are shared between the server and the project's domain on their
+/// common parent domain, e.g. `example.com` for `auth.example.com` and
+/// `app.example.com`. Without a common parent domain the cookies are only
+/// sent to the server.
+pub fn cookie_domain(project_host: &str, host: &str) -> Option<String> {
+    let is_ip = |host: &str| host.parse::<std::net::IpAddr>().is_ok();
+
+    if is_ip(project_host) || is_ip(host) {
+        return None;
+    }
+
+    let common: Vec<&str> = project_host
+        .rsplit('.')
+        .zip(host.rsplit('.'))
+        .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+        .map(|(label, _)| label)
+        .collect();
+
+    if common.len() < 2 {
+        return None;
+    }
+
+    let domain: Vec<&str> = common.into_iter().rev().collect();
+    Some(domain.join("."))
+}
+
+/// Double submit CSRF protection, the `X-CSRF-Token` header has to match the
+/// `vulpo_csrf` cookie
+pub struct Csrf;
+
+impl Csrf {
+    pub fn verify(req: &Request<'_>) -> bool {
+        let cookie = req.cookies().get(CSRF_COOKIE).map(|cookie| cookie.value());
+        let header = req.headers().get_one(CSRF_HEADER);
+
+        match (cookie, header) {
+            (Some(cookie), Some(header)) => Csrf::matches(cookie, header),
+            _ => false,
+        }
+    }
+
+    /// Compares the cookie with the submitted token in constant time, the
+    /// response time doesn't leak the cookie
+    pub fn matches(cookie: &str, token: &str) -> bool {
+        !cookie.is_empty() && bool::from(cookie.as_bytes().ct_eq(token.as_bytes()))
+    }
+
+    /// Requests that don't change anything don't need a CSRF token
+    pub fn is_required(req: &Request<'_>) -> bool {
+        !matches!(req.method(), Method::Get | Method::Head | Method::Options)
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Csrf {
+    type Error = ApiError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        if Csrf::verify(req) {
+            Outcome::Success(Csrf)
+        } else {
+            Outcome::Failure((Status::Forbidden, ApiError::SessionInvalidCsrfToken))
+        }
+    }
+}
+
+#[post("/refresh")]
+pub async fn refresh_handler(
+    pool: Db,
+    cache: Cache,
+    project: Project,
+    secrets: &State<Secrets>,
+    metadata: SessionMetadata,
+    cookies: SessionCookies<'_>,
+    csrf: Result<Csrf, ApiError>,
+) -> Result<SessionResponse, ApiError> {
+    csrf?;
+
+    Flags::has_flags(&pool, &project.id, &[Flags::CookieSession]).await?;
+
+    let session = cookies.verified_session(&pool, &project.id).await?;
+
+    if Utc::now() > session.expire_at {
+        cookies.remove(&cache, &pool, &project.id).await;
+        return Err(ApiError::SessionExpired);
+    }
+
+    let mut session = renew(
+        &cache,
+        &pool,
+        project.id,
+        session,
+        &secrets.passphrase,
+        &metadata,
+    )
+    .await?;
+
+    cookies.set(&cache, &pool, &project.id, &mut session).await?;
+    Ok(session)
+}
+
+#[post("/sign_out")]
+pub async fn sign_out_handler(
+    pool: Db,
+    cache: Cache,
+    project: Project,
+    cookies: SessionCookies<'_>,
+    csrf: Result<Csrf, ApiError>,
+) -> Result<Status, ApiError> {
+    csrf?;
+
+    if let Some((session_id, secret)) = cookies.session() {
+        let hash = Session::cookie_hash(&pool, &session_id).await?;
+
+        if let Some(hash) = hash {
+            if Token::verify(&secret, &hash)? {
+                Session::delete(&pool, &session_id).await?;
+            }
+        }
+    }
+
+    cookies.remove(&cache, &pool, &project.id).await;
+    Ok(Status::Ok)
+}
@@ -1,16 +1,23 @@
+use super::dpop::thumbprint;
 use crate::keys::data::ProjectKeys;
+use crate::oauth::discovery::BaseUrl;
+use crate::project::data::Flags;
 use crate::project::Project;
+use crate::session::cookie::{Csrf, ACCESS_TOKEN_COOKIE};
+use crate::session::data::{require_recent_auth, DPoPProof, Session};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use rocket::http::Status;
 use rocket::request::Outcome;
 use rocket::request::{FromRequest, Request};
+use sqlx::PgPool;
 use vulpo_auth_types::error::ApiError;
 use werkbank::rocket::Db;
 
 use uuid::Uuid;
-pub use vulpo::Claims;
+use vulpo::dpop;
+pub use vulpo::{Claims, Confirmation, OrgRole};
 
 pub struct AccessToken(Claims);
 
@@ -18,6 +25,12 @@ impl AccessToken {
     pub fn sub(&self) -> Uuid {
         self.0.sub
     }
+
+    /// Fails with `session/reauthentication_required` when the user didn't
+    /// authenticate recently, see [`require_recent_auth`]
+    pub async fn require_recent_auth(&self, pool: &PgPool, project: &Uuid) -> Result<(), ApiError> {
+        require_recent_auth(pool, project, self.0.auth_time).await
+    }
 }
 
 impl AccessToken {
@@ -26,33 +39,198 @@ impl AccessToken {
             sub: user_id.clone(),
             exp: exp.timestamp(),
             traits: traits.clone(),
+            org_id: None,
+            org_role: None,
+            permissions: vec![],
+            client_id: None,
+            scope: None,
+            iat: Some(Utc::now().timestamp()),
+            cnf: None,
+            email_verified: None,
+            auth_time: None,
+            amr: vec![],
         };
 
         AccessToken(claims)
     }
 
+    /// Token for a service account, `sub` and `client_id` are the service account id
+    pub fn for_client(client_id: &Uuid, scopes: &[String], exp: DateTime<Utc>) -> AccessToken {
+        AccessToken::new(client_id, &vec![], exp).with_client(client_id, scopes)
+    }
+
+    pub fn with_client(mut self, client_id: &Uuid, scopes: &[String]) -> AccessToken {
+        self.0.client_id = Some(client_id.clone());
+        self.0.scope = Some(scopes.join(" "));
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: Vec<String>) -> AccessToken {
+        self.0.permissions = permissions;
+        self
+    }
+
+    pub fn with_organization(mut self, org_id: Uuid, role: OrgRole) -> AccessToken {
+        self.0.org_id = Some(org_id);
+        self.0.org_role = Some(role);
+        self
+    }
+
+    /// `email_verified` is only set for projects that mark unverified users,
+    /// see `enforce_email_verification`
+    pub fn with_email_verified(mut self, email_verified: Option<bool>) -> AccessToken {
+        self.0.email_verified = email_verified;
+        self
+    }
+
+    /// Copies when and how the user authenticated the session
+    pub fn with_auth(mut self, session: &Session) -> AccessToken {
+        self.0.auth_time = session.auth_time.map(|auth_time| auth_time.timestamp());
+        self.0.amr = session.auth_methods.clone();
+        self
+    }
+
+    /// Fails when the project requires DPoP and the key can't be bound with
+    /// `bind_to`, sign ins check the key before they create the session
+    pub async fn check_binding(
+        pool: &PgPool,
+        project_id: &Uuid,
+        public_key: &[u8],
+    ) -> Result<(), ApiError> {
+        let flags = Flags::from_project(pool, project_id).await?;
+
+        if flags.contains(&Flags::DPoP) && thumbprint(public_key).is_none() {
+            return Err(ApiError::BadRequest);
+        }
+
+        Ok(())
+    }
+
+    /// Binds the token to the session's key when the project requires DPoP
+    pub async fn bind_to(
+        mut self,
+        pool: &PgPool,
+        session: &Session,
+    ) -> Result<AccessToken, ApiError> {
+        let flags = Flags::from_project(pool, &session.project_id).await?;
+
+        if flags.contains(&Flags::DPoP) {
+            let jkt = thumbprint(&session.public_key).ok_or(ApiError::BadRequest)?;
+            self.0.cnf = Some(Confirmation { jkt });
+        }
+
+        Ok(self)
+    }
+
     pub fn to_jwt(&self, project: &Uuid, key: &[u8]) -> Result<String, ApiError> {
-        println!("BBBB:: {:?}", String::from_utf8(key.to_vec()));
-        let encoding_key = EncodingKey::from_ec_pem(key).map_err(|err| {
-            println!("DEFUG: {:#?}", err);
-            ApiError::InternalServerError
-        })?;
+        let encoding_key =
+            EncodingKey::from_ec_pem(key).map_err(|_| ApiError::InternalServerError)?;
         let mut header = Header::new(Algorithm::ES384);
         header.kid = Some(project.to_string());
         encode(&header, &self.0, &encoding_key).map_err(|_| ApiError::InternalServerError)
     }
 
+    /// Tokens issued before the user's or the project's `tokens_valid_after`
+    /// are revoked, tokens without `iat` predate the watermark
+    pub async fn is_revoked(pool: &PgPool, claims: &Claims, project: &Uuid) -> sqlx::Result<bool> {
+        let row = sqlx::query_file!(
+            "src/session/sql/tokens_valid_after.sql",
+            claims.sub,
+            project
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let is_revoked = match (row.valid_after, claims.iat) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            // `iat` has seconds, tokens of the second of the revocation are revoked too
+            (Some(valid_after), Some(iat)) => iat <= valid_after.timestamp(),
+        };
+
+        Ok(is_revoked)
+    }
+
+    /// Verifies the token of the `Authorization` header, tokens that are bound
+    /// to a session key have to be sent with the `DPoP` scheme and a proof.
+    /// Tokens that were issued to an OAuth client are rejected.
+    pub async fn verify(
+        req: &Request<'_>,
+        pool: &PgPool,
+        project: &Uuid,
+        token_type: &str,
+        token: &str,
+    ) -> Result<Claims, (Status, ApiError)> {
+        let key = ProjectKeys::get_public_key(pool, project)
+            .await
+            .map_err(|_| (Status::InternalServerError, ApiError::AuthTokenMissing))?;
+
+        let claims = AccessToken::decode(token, &key)
+            .map_err(|_| (Status::Unauthorized, ApiError::BadRequest))?;
+
+        // tokens of OAuth clients and service accounts are meant for the
+        // client's APIs, not for the user and admin endpoints of the server
+        if claims.client_id.is_some() {
+            return Err((Status::Unauthorized, ApiError::TokenInvalid));
+        }
+
+        match (token_type.to_lowercase().as_str(), &claims.cnf) {
+            ("bearer", None) => {}
+            ("dpop", Some(cnf)) => AccessToken::verify_proof(req, pool, token, cnf).await?,
+            ("bearer", Some(_)) | ("dpop", None) => {
+                return Err((Status::Unauthorized, ApiError::TokenInvalidProof))
+            }
+            _ => return Err((Status::BadRequest, ApiError::AuthTokenMissing)),
+        };
+
+        match AccessToken::is_revoked(pool, &claims, project).await {
+            Ok(false) => Ok(claims),
+            Ok(true) => Err((Status::Unauthorized, ApiError::TokenInvalid)),
+            Err(_) => Err((Status::InternalServerError, ApiError::InternalServerError)),
+        }
+    }
+
+    async fn verify_proof(
+        req: &Request<'_>,
+        pool: &PgPool,
+        token: &str,
+        cnf: &Confirmation,
+    ) -> Result<(), (Status, ApiError)> {
+        let invalid = || (Status::Unauthorized, ApiError::TokenInvalidProof);
+
+        let proof = req.headers().get_one("DPoP").ok_or_else(invalid)?;
+        let base_url = match BaseUrl::from_request(req).await.succeeded() {
+            None => return Err(invalid()),
+            Some(base_url) => base_url,
+        };
+
+        let url = format!("{}{}", base_url.0, req.uri().path());
+        let now = Utc::now();
+        let proof = dpop::verify(
+            proof,
+            token,
+            cnf,
+            req.method().as_str(),
+            &url,
+            now.timestamp(),
+        )
+        .map_err(|_| invalid())?;
+
+        let expire_at = now + Duration::seconds(proof.iat - now.timestamp() + dpop::PROOF_MAX_AGE);
+        match DPoPProof::insert(pool, &cnf.jkt, &proof.jti, &expire_at).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(invalid()),
+            Err(_) => Err((Status::InternalServerError, ApiError::InternalServerError)),
+        }
+    }
+
     pub fn decode(token: &str, key: &[u8]) -> Result<Claims, ApiError> {
         let decoding_key =
             DecodingKey::from_ec_pem(key).map_err(|_| ApiError::InternalServerError)?;
 
-        match decode::<Claims>(&token, &decoding_key, &Validation::new(Algorithm::ES384)) {
-            Ok(token_data) => Ok(token_data.claims),
-            Err(err) => {
-                println!("{:?}", err);
-                Err(ApiError::InternalServerError)
-            }
-        }
+        decode::<Claims>(&token, &decoding_key, &Validation::new(Algorithm::ES384))
+            .map(|token_data| token_data.claims)
+            .map_err(|_| ApiError::InternalServerError)
     }
 }
 
@@ -61,11 +239,6 @@ impl<'r> FromRequest<'r> for AccessToken {
     type Error = ApiError;
 
     async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
-        let token_string = match req.headers().get_one("Authorization") {
-            None => return Outcome::Failure((Status::BadRequest, ApiError::AuthTokenMissing)),
-            Some(token) => token,
-        };
-
         let project = match Project::from_request(req).await.succeeded() {
             None => return Outcome::Failure((Status::BadRequest, ApiError::AuthTokenMissing)),
             Some(project) => project,
@@ -78,22 +251,24 @@ impl<'r> FromRequest<'r> for AccessToken {
             Some(pool) => pool,
         };
 
-        let key = match ProjectKeys::get_public_key(&db, &project.id).await {
-            Ok(key) => key,
-            Err(_) => {
-                return Outcome::Failure((Status::InternalServerError, ApiError::AuthTokenMissing));
-            }
+        let (token_type, token) = match req.headers().get_one("Authorization") {
+            Some(token_string) => match token_string.split_once(' ') {
+                None => return Outcome::Failure((Status::BadRequest, ApiError::AuthTokenMissing)),
+                Some((token_type, token)) => (token_type, token.trim()),
+            },
+            // cookie sessions, see `crate::session::cookie`
+            None => match req.cookies().get(ACCESS_TOKEN_COOKIE) {
+                None => return Outcome::Failure((Status::BadRequest, ApiError::AuthTokenMissing)),
+                Some(_) if Csrf::is_required(req) && !Csrf::verify(req) => {
+                    return Outcome::Failure((Status::Forbidden, ApiError::SessionInvalidCsrfToken))
+                }
+                Some(cookie) => ("bearer", cookie.value()),
+            },
         };
 
-        let end = token_string.len();
-        let start = "Bearer ".len();
-        let token = &token_string[start..end];
-        let claims = match AccessToken::decode(token, &key) {
-            Ok(token) => token,
-            Err(_) => {
-                return Outcome::Failure((Status::Unauthorized, ApiError::BadRequest));
-            }
-        };
-        Outcome::Success(AccessToken(claims))
+        match AccessToken::verify(req, &db, &project.id, token_type, token).await {
+            Ok(claims) => Outcome::Success(AccessToken(claims)),
+            Err(failure) => Outcome::Failure(failure),
+        }
     }
 }
@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use pkcs8::der::asn1::UIntRef;
+use pkcs8::der::{Reader, SliceReader};
+use pkcs8::{ObjectIdentifier, SubjectPublicKeyInfo};
+use sqlx::PgPool;
+use std::convert::TryFrom;
+use std::str;
+use vulpo::dpop;
+
+const EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const SECP256R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+const SECP384R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+/// The `jti` of every DPoP proof that was accepted, proofs can only be used once
+pub struct DPoPProof;
+
+impl DPoPProof {
+    /// Returns `false` when the proof was used before
+    pub async fn insert(
+        pool: &PgPool,
+        jkt: &str,
+        jti: &str,
+        expire_at: &DateTime<Utc>,
+    ) -> sqlx::Result<bool> {
+        let row = sqlx::query_file!("src/session/sql/insert_dpop_proof.sql", jkt, jti, expire_at)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(row.is_some())
+    }
+
+    pub async fn remove_expired(pool: &PgPool) -> sqlx::Result<u64> {
+        let result = sqlx::query_file!("src/session/sql/remove_expired_dpop_proofs.sql")
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+/// JWK thumbprint of a session's public key. The web SDK writes the PEM
+/// without line breaks, so the key is decoded by hand.
+pub fn thumbprint(public_key: &[u8]) -> Option<String> {
+    let pem = str::from_utf8(public_key).ok()?;
+    let body: String = pem
+        .replace("-----BEGIN PUBLIC KEY-----", "")
+        .replace("-----END PUBLIC KEY-----", "")
+        .split_whitespace()
+        .collect();
+
+    let der = base64::decode(body).ok()?;
+    let info = SubjectPublicKeyInfo::try_from(der.as_slice()).ok()?;
+    let encode = |bytes: &[u8]| base64::encode_config(bytes, base64::URL_SAFE_NO_PAD);
+
+    if info.algorithm.oid == EC_PUBLIC_KEY {
+        let curve = info.algorithm.parameters_oid().ok()?;
+        let crv = if curve == SECP384R1 {
+            "P-384"
+        } else if curve == SECP256R1 {
+            "P-256"
+        } else {
+            return None;
+        };
+
+        // uncompressed point: 0x04 || x || y
+        let point = info.subject_public_key;
+        if point.len() < 3 || point[0] != 0x04 {
+            return None;
+        }
+
+        let (x, y) = point[1..].split_at((point.len() - 1) / 2);
+        return Some(dpop::ec_thumbprint(crv, &encode(x), &encode(y)));
+    }
+
+    if info.algorithm.oid == RSA_ENCRYPTION {
+        let mut reader = SliceReader::new(info.subject_public_key).ok()?;
+        let (n, e) = reader
+            .sequence(|seq| {
+                let n: UIntRef = seq.decode()?;
+                let e: UIntRef = seq.decode()?;
+                Ok((n, e))
+            })
+            .ok()?;
+
+        return Some(dpop::rsa_thumbprint(
+            &encode(n.as_bytes()),
+            &encode(e.as_bytes()),
+        ));
+    }
+
+    None
+}
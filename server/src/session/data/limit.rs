@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// What happens when a user signs in on more devices than `max_sessions` allows
+#[derive(sqlx::Type, Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[sqlx(type_name = "session_limit_mode")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SessionLimitMode {
+    /// Signs out the least recently used device
+    Evict,
+    /// Rejects the new sign in with `session/limit_reached`
+    Reject,
+}
+
+#[derive(Debug)]
+pub struct SessionLimit {
+    /// `None` allows any number of sessions
+    pub max_sessions: Option<i32>,
+    pub mode: SessionLimitMode,
+}
@@ -0,0 +1,78 @@
+use rocket::outcome::Outcome;
+use rocket::request::{self, FromRequest, Request};
+
+/// Device information of the request that created or refreshed a session
+#[derive(Debug, Default)]
+pub struct SessionMetadata {
+    pub user_agent: Option<String>,
+    /// Taken from `X-Real-IP` when the server runs behind a proxy
+    pub ip: Option<String>,
+    /// Coarse device name derived from the user agent, e.g. `Firefox on macOS`
+    pub device_name: Option<String>,
+}
+
+impl SessionMetadata {
+    pub fn new(user_agent: Option<&str>, ip: Option<String>) -> SessionMetadata {
+        let user_agent = user_agent.map(|ua| ua.chars().take(512).collect::<String>());
+        let device_name = user_agent.as_deref().and_then(device_name);
+
+        SessionMetadata {
+            user_agent,
+            ip,
+            device_name,
+        }
+    }
+}
+
+fn browser(user_agent: &str) -> Option<&'static str> {
+    // order matters, most user agents also claim to be Safari or Chrome
+    let browsers = [
+        ("Edg/", "Edge"),
+        ("OPR/", "Opera"),
+        ("Firefox/", "Firefox"),
+        ("Chrome/", "Chrome"),
+        ("Safari/", "Safari"),
+    ];
+
+    browsers
+        .iter()
+        .find(|(token, _)| user_agent.contains(token))
+        .map(|(_, name)| *name)
+}
+
+fn operating_system(user_agent: &str) -> Option<&'static str> {
+    let systems = [
+        ("iPhone", "iOS"),
+        ("iPad", "iPadOS"),
+        ("Android", "Android"),
+        ("Windows", "Windows"),
+        ("Mac OS X", "macOS"),
+        ("CrOS", "ChromeOS"),
+        ("Linux", "Linux"),
+    ];
+
+    systems
+        .iter()
+        .find(|(token, _)| user_agent.contains(token))
+        .map(|(_, name)| *name)
+}
+
+fn device_name(user_agent: &str) -> Option<String> {
+    match (browser(user_agent), operating_system(user_agent)) {
+        (Some(browser), Some(os)) => Some(format!("{} on {}", browser, os)),
+        (Some(browser), None) => Some(browser.to_string()),
+        (None, Some(os)) => Some(os.to_string()),
+        (None, None) => None,
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for SessionMetadata {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let user_agent = req.headers().get_one("User-Agent");
+        let ip = req.client_ip().map(|ip| ip.to_string());
+        Outcome::Success(SessionMetadata::new(user_agent, ip))
+    }
+}
@@ -1,8 +1,16 @@
 mod access_token;
+mod dpop;
+mod limit;
+mod metadata;
+mod reauthentication;
 mod session;
 
-pub use access_token::{AccessToken, Claims};
-pub use session::Session;
+pub use access_token::{AccessToken, Claims, OrgRole};
+pub use dpop::DPoPProof;
+pub use limit::{SessionLimit, SessionLimitMode};
+pub use metadata::SessionMetadata;
+pub use reauthentication::{require_recent_auth, AuthMethod};
+pub use session::{Session, SessionInfo};
 pub use vulpo_auth_types::session::{
     RefreshAccessToken, RefreshAccessTokenClaims as SessionClaims,
 };
@@ -0,0 +1,57 @@
+use crate::project::data::Project;
+
+use chrono::Utc;
+use sqlx::PgPool;
+use std::fmt;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+
+/// How the user authenticated a session, stored in `sessions.auth_methods` and
+/// sent as the `amr` claim. The values follow RFC 8176 where it defines one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuthMethod {
+    Password,
+    /// Passwordless code
+    Code,
+    /// Passwordless link or invitation
+    Link,
+    Sms,
+    /// External provider, e.g. Google
+    Federated,
+    /// Anonymous sign in, the user didn't prove anything yet
+    Anonymous,
+}
+
+impl fmt::Display for AuthMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let method = match self {
+            AuthMethod::Password => "pwd",
+            AuthMethod::Code => "otp",
+            AuthMethod::Link => "link",
+            AuthMethod::Sms => "sms",
+            AuthMethod::Federated => "fed",
+            AuthMethod::Anonymous => "anonymous",
+        };
+
+        f.write_str(method)
+    }
+}
+
+/// Sensitive operations, e.g. changing the email address, require the user to
+/// have authenticated within the project's `reauthentication_max_age`, `auth_time`
+/// is the unix timestamp of the last authentication
+pub async fn require_recent_auth(
+    pool: &PgPool,
+    project: &Uuid,
+    auth_time: Option<i64>,
+) -> Result<(), ApiError> {
+    let max_age = match Project::reauthentication_max_age(pool, project).await? {
+        None => return Ok(()),
+        Some(max_age) => i64::from(max_age),
+    };
+
+    match auth_time {
+        Some(auth_time) if Utc::now().timestamp() - auth_time <= max_age => Ok(()),
+        _ => Err(ApiError::SessionReauthenticationRequired),
+    }
+}
@@ -1,4 +1,4 @@
-use crate::session::data::SessionClaims;
+use crate::session::data::{AuthMethod, SessionClaims, SessionMetadata};
 
 use chrono::{DateTime, TimeZone, Utc};
 use jsonwebtoken as jwt;
@@ -16,10 +16,33 @@ pub struct Session {
     pub public_key: Vec<u8>,
     pub expire_at: DateTime<Utc>,
     pub user_id: Option<Uuid>,
+
+    /// When the user last authenticated the session, `None` until the
+    /// sign in is confirmed
+    pub auth_time: Option<DateTime<Utc>>,
+
+    /// How the user last authenticated the session, see [`AuthMethod`]
+    pub auth_methods: Vec<String>,
+}
+
+/// A session as shown to the user, e.g. to sign out other devices
+#[derive(Serialize)]
+pub struct SessionInfo {
+    pub id: Uuid,
+    pub device_name: Option<String>,
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_refreshed_at: Option<DateTime<Utc>>,
+    pub expire_at: DateTime<Utc>,
 }
 
 impl Session {
-    pub async fn create(pool: &PgPool, session: Session) -> sqlx::Result<Session> {
+    pub async fn create(
+        pool: &PgPool,
+        session: Session,
+        metadata: &SessionMetadata,
+    ) -> sqlx::Result<Session> {
         sqlx::query_file_as!(
             Session,
             "src/session/sql/create_session.sql",
@@ -28,6 +51,11 @@ impl Session {
             session.expire_at,
             session.user_id,
             session.project_id,
+            metadata.user_agent,
+            metadata.ip,
+            metadata.device_name,
+            session.auth_time,
+            &session.auth_methods,
         )
         .fetch_one(pool)
         .await
@@ -47,30 +75,171 @@ impl Session {
         session: &Uuid,
         user_id: &Uuid,
         expire_at: &DateTime<Utc>,
+        method: AuthMethod,
     ) -> sqlx::Result<Session> {
         sqlx::query_file_as!(
             Session,
             "src/session/sql/confirm_session.sql",
             &session,
             &user_id,
-            &expire_at
+            &expire_at,
+            &vec![method.to_string()]
         )
         .fetch_one(pool)
         .await
     }
 
+    /// Sets the session's `auth_time` to now, e.g. after the user re-authenticated
+    pub async fn authenticate(
+        pool: &PgPool,
+        session: &Uuid,
+        method: AuthMethod,
+    ) -> sqlx::Result<Session> {
+        sqlx::query_file_as!(
+            Session,
+            "src/session/sql/authenticate_session.sql",
+            session,
+            &vec![method.to_string()]
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Returns `false` once the session used up its `max_attempts` to
+    /// re-authenticate with the password
+    pub async fn add_reauthentication_attempt(
+        pool: &PgPool,
+        session: &Uuid,
+        max_attempts: i32,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_file!(
+            "src/session/sql/add_reauthentication_attempt.sql",
+            session,
+            max_attempts
+        )
+        .fetch_optional(pool)
+        .await
+        .map(|row| row.is_some())
+    }
+
     pub async fn extend(
         pool: &PgPool,
         session: &Uuid,
         expire_at: &DateTime<Utc>,
+        metadata: &SessionMetadata,
     ) -> sqlx::Result<()> {
-        sqlx::query_file!("src/session/sql/extend_session.sql", session, expire_at)
-            .execute(pool)
-            .await?;
+        sqlx::query_file!(
+            "src/session/sql/extend_session.sql",
+            session,
+            expire_at,
+            metadata.user_agent,
+            metadata.ip,
+            metadata.device_name,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list(
+        pool: &PgPool,
+        user_id: &Uuid,
+        project_id: &Uuid,
+    ) -> sqlx::Result<Vec<SessionInfo>> {
+        sqlx::query_file_as!(
+            SessionInfo,
+            "src/session/sql/list_sessions.sql",
+            user_id,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Returns `false` if the session doesn't belong to the user
+    pub async fn delete_for_user(
+        pool: &PgPool,
+        session: &Uuid,
+        user_id: &Uuid,
+        project_id: &Uuid,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_file!(
+            "src/session/sql/remove_user_session.sql",
+            session,
+            user_id,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+        .map(|row| row.is_some())
+    }
+
+    /// Signed in sessions of the user except `session`
+    pub async fn count_active(
+        pool: &PgPool,
+        session: &Uuid,
+        user_id: &Uuid,
+        project_id: &Uuid,
+    ) -> sqlx::Result<i64> {
+        sqlx::query_file!(
+            "src/session/sql/count_active_sessions.sql",
+            user_id,
+            project_id,
+            session
+        )
+        .fetch_one(pool)
+        .await
+        .map(|row| row.count)
+    }
+
+    /// Removes the least recently used sessions of the user until `keep`
+    /// sessions, not counting `session`, are left
+    pub async fn evict(
+        pool: &PgPool,
+        session: &Uuid,
+        user_id: &Uuid,
+        project_id: &Uuid,
+        keep: i64,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/session/sql/evict_sessions.sql",
+            user_id,
+            project_id,
+            session,
+            keep
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Stores the hash of the refresh secret of a cookie session, `None`
+    /// signs the cookie out
+    pub async fn set_cookie(
+        pool: &PgPool,
+        session: &Uuid,
+        cookie_hash: Option<&str>,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/session/sql/set_session_cookie.sql",
+            session,
+            cookie_hash
+        )
+        .execute(pool)
+        .await?;
 
         Ok(())
     }
 
+    pub async fn cookie_hash(pool: &PgPool, session: &Uuid) -> sqlx::Result<Option<String>> {
+        sqlx::query_file!("src/session/sql/get_session_cookie.sql", session)
+            .fetch_optional(pool)
+            .await
+            .map(|row| row.and_then(|row| row.cookie_hash))
+    }
+
     pub async fn delete(pool: &PgPool, session: &Uuid) -> sqlx::Result<()> {
         sqlx::query_file!("src/session/sql/remove_session.sql", session)
             .execute(pool)
@@ -79,6 +248,7 @@ impl Session {
         Ok(())
     }
 
+    /// Removes all sessions of the session's user and revokes their access tokens
     pub async fn delete_all(pool: &PgPool, session: &Uuid) -> sqlx::Result<()> {
         sqlx::query_file!("src/session/sql/remove_all_sessions.sql", session)
             .execute(pool)
@@ -87,6 +257,7 @@ impl Session {
         Ok(())
     }
 
+    /// Removes all sessions of the user and revokes their access tokens
     pub async fn delete_by_user(pool: &PgPool, user: &Uuid) -> sqlx::Result<()> {
         sqlx::query_file!("src/session/sql/remove_user_sessions.sql", user)
             .execute(pool)
@@ -0,0 +1,36 @@
+use crate::project::data::Project;
+use crate::session::data::{Session, SessionLimitMode};
+
+use rocket::serde::uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+/// Makes room for `session` before it is signed in as `user_id`, either by
+/// removing the user's least recently used sessions or by rejecting the sign in
+pub async fn enforce_session_limit(
+    pool: &Db,
+    session: &Uuid,
+    user_id: &Uuid,
+    project_id: &Uuid,
+) -> Result<(), ApiError> {
+    let limit = Project::session_limit(pool, project_id).await?;
+
+    let max_sessions = match limit.max_sessions {
+        Some(max_sessions) => i64::from(max_sessions),
+        None => return Ok(()),
+    };
+
+    match limit.mode {
+        SessionLimitMode::Evict => {
+            Session::evict(pool, session, user_id, project_id, max_sessions - 1).await?;
+        }
+        SessionLimitMode::Reject => {
+            let active = Session::count_active(pool, session, user_id, project_id).await?;
+            if active >= max_sessions {
+                return Err(ApiError::SessionLimitReached);
+            }
+        }
+    }
+
+    Ok(())
+}
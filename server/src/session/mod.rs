@@ -1,8 +1,20 @@
 use rocket::Route;
 
+mod cleanup;
+pub mod cookie;
 pub mod data;
+pub mod limit;
+mod reauthenticate;
 mod refresh;
 
+pub use cleanup::fairing as cleanup;
+
 pub fn routes() -> Vec<Route> {
-    routes![refresh::handler]
+    routes![
+        refresh::handler,
+        reauthenticate::handler,
+        reauthenticate::request_code_handler,
+        cookie::refresh_handler,
+        cookie::sign_out_handler
+    ]
 }
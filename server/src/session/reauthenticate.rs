@@ -0,0 +1,265 @@
+//! Step-up authentication. Sensitive operations, e.g. changing the email
+//! address or deleting the account, require the user to have authenticated
+//! the session within the project's `reauthentication_max_age`. The session
+//! re-authenticates with the user's password or with a code sent to the
+//! user's email address and receives an access token with the new `auth_time`.
+
+use crate::config::Secrets;
+use crate::crypto::Token;
+use crate::password::data::Password;
+use crate::passwordless::data::{Passwordless, PasswordlessMode};
+use crate::passwordless::request_passwordless::code_email;
+use crate::passwordless::verify_code::MAX_ATTEMPTS;
+use crate::project::Project;
+use crate::session::cookie::{Csrf, SessionCookies};
+use crate::session::data::{AuthMethod, RefreshAccessToken, Session, SessionMetadata};
+use crate::session::refresh::renew;
+use crate::user::data::User;
+
+use chrono::Utc;
+use rocket::serde::json::Json;
+use rocket::State;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use vulpo_auth_types::session::SessionResponse;
+use werkbank::rocket::{Cache, Db};
+
+#[derive(Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum Credentials {
+    Password {
+        password: String,
+    },
+    /// Code requested with `/reauthenticate/<session_id>/code`
+    Code {
+        id: Uuid,
+        code: String,
+    },
+}
+
+#[derive(Deserialize)]
+pub struct Reauthenticate {
+    /// Refresh access token of the session, cookie sessions send the session
+    /// cookie instead
+    pub token: Option<String>,
+
+    #[serde(flatten)]
+    pub credentials: Credentials,
+}
+
+#[derive(Deserialize)]
+pub struct RequestCode {
+    /// Refresh access token of the session, cookie sessions send the session
+    /// cookie instead
+    pub token: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct RequestCodeResponse {
+    pub id: Uuid,
+}
+
+/// The signed in session `session_id` and its user
+async fn signed_in_session(
+    pool: &Db,
+    project_id: &Uuid,
+    session_id: &Uuid,
+    token: Option<String>,
+    cookies: &SessionCookies<'_>,
+    csrf: Result<Csrf, ApiError>,
+) -> Result<(Session, User), ApiError> {
+    let session = match token {
+        Some(value) => {
+            let session = Session::get(pool, session_id).await?;
+            let claims = Session::validate_token(&session, &RefreshAccessToken { value })?;
+
+            if !Session::is_valid(pool, &claims, &session.id, project_id).await? {
+                return Err(ApiError::Forbidden);
+            }
+
+            session
+        }
+        None => {
+            csrf?;
+            cookies.verified_session(pool, project_id).await?
+        }
+    };
+
+    if session.id != *session_id || session.project_id != *project_id {
+        return Err(ApiError::Forbidden);
+    }
+
+    if Utc::now() > session.expire_at {
+        return Err(ApiError::SessionExpired);
+    }
+
+    let user_id = session.user_id.ok_or(ApiError::Forbidden)?;
+    let user = User::get_by_id(pool, &user_id, project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if user.is_disabled() {
+        return Err(ApiError::UserDisabled);
+    }
+
+    Ok((session, user))
+}
+
+async fn verify_code(
+    pool: &Db,
+    session: &Session,
+    user: &User,
+    id: &Uuid,
+    code: &str,
+) -> Result<(), ApiError> {
+    let stored_token = Passwordless::get(pool, id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if stored_token.session_id != session.id || stored_token.user_id != Some(user.id) {
+        return Err(ApiError::Forbidden);
+    }
+
+    if stored_token.mode != PasswordlessMode::Code
+        || !stored_token.is_valid
+        || stored_token.confirmed
+    {
+        return Err(ApiError::PasswordlessInvalidToken);
+    }
+
+    if Utc::now() > stored_token.expire_at {
+        return Err(ApiError::PasswordlessTokenExpire);
+    }
+
+    // counted before the code is compared, parallel guesses can't exceed the limit
+    if !Passwordless::add_attempt(pool, &stored_token.id, MAX_ATTEMPTS).await? {
+        return Err(ApiError::PasswordlessTooManyAttempts);
+    }
+
+    if !stored_token.compare(code.trim()) {
+        return Err(ApiError::PasswordlessInvalidToken);
+    }
+
+    Passwordless::confirm(pool, &stored_token.id).await?;
+    Ok(())
+}
+
+pub async fn reauthenticate(
+    pool: &Db,
+    session: &Session,
+    user: &User,
+    credentials: Credentials,
+) -> Result<(), ApiError> {
+    let method = match credentials {
+        Credentials::Password { password } => {
+            // counted before the password is verified, a successful
+            // re-authentication resets the attempts
+            if !Session::add_reauthentication_attempt(pool, &session.id, MAX_ATTEMPTS).await? {
+                return Err(ApiError::SessionTooManyAttempts);
+            }
+
+            let email = user.email.as_deref().ok_or(ApiError::UserInvalidPassword)?;
+            let stored_password = Password::get_by_email(pool, email, &session.project_id).await?;
+
+            if !stored_password.verify(&password) {
+                return Err(ApiError::UserInvalidPassword);
+            }
+
+            AuthMethod::Password
+        }
+        Credentials::Code { id, code } => {
+            verify_code(pool, session, user, &id, &code).await?;
+            AuthMethod::Code
+        }
+    };
+
+    Session::authenticate(pool, &session.id, method).await?;
+    Ok(())
+}
+
+#[post("/reauthenticate/<session_id>", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    cache: Cache,
+    project: Project,
+    session_id: Uuid,
+    body: Json<Reauthenticate>,
+    secrets: &State<Secrets>,
+    metadata: SessionMetadata,
+    cookies: SessionCookies<'_>,
+    csrf: Result<Csrf, ApiError>,
+) -> Result<SessionResponse, ApiError> {
+    let body = body.into_inner();
+    let (session, user) =
+        signed_in_session(&pool, &project.id, &session_id, body.token, &cookies, csrf).await?;
+
+    reauthenticate(&pool, &session, &user, body.credentials).await?;
+
+    // the new access token carries the new auth_time
+    let session = Session::get(&pool, &session.id).await?;
+    let mut session = renew(
+        &cache,
+        &pool,
+        project.id,
+        session,
+        &secrets.passphrase,
+        &metadata,
+    )
+    .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
+    Ok(session)
+}
+
+/// Sends a code to re-authenticate the session to the user's email address
+pub async fn request_code(pool: &Db, session: &Session, user: User) -> Result<Uuid, ApiError> {
+    let email = user.email.clone().ok_or(ApiError::BadRequest)?;
+
+    let code = Token::code(8);
+    let hashed_code = Token::hash(&code)?;
+
+    let id = Passwordless::create_token(
+        pool,
+        Some(user.id),
+        &email,
+        &hashed_code,
+        &session.project_id,
+        &session.id,
+        PasswordlessMode::Code,
+    )
+    .await?;
+
+    let device_languages = user.device_languages.clone();
+    let (email, settings) = code_email(
+        pool,
+        &session.project_id,
+        &email,
+        &device_languages,
+        &code,
+        Some(user),
+    )
+    .await?;
+
+    email.send(settings).await?;
+    Ok(id)
+}
+
+#[post("/reauthenticate/<session_id>/code", format = "json", data = "<body>")]
+pub async fn request_code_handler(
+    pool: Db,
+    project: Project,
+    session_id: Uuid,
+    body: Json<RequestCode>,
+    cookies: SessionCookies<'_>,
+    csrf: Result<Csrf, ApiError>,
+) -> Result<Json<RequestCodeResponse>, ApiError> {
+    let body = body.into_inner();
+    let (session, user) =
+        signed_in_session(&pool, &project.id, &session_id, body.token, &cookies, csrf).await?;
+
+    let id = request_code(&pool, &session, user).await?;
+    Ok(Json(RequestCodeResponse { id }))
+}
@@ -1,8 +1,12 @@
 use crate::config::Secrets;
 use crate::keys::data::ProjectKeys;
+use crate::organization::data::Membership;
 use crate::project::Project;
-use crate::session::data::{AccessToken, RefreshAccessToken, Session};
+use crate::role::data::Permission;
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{AccessToken, RefreshAccessToken, Session, SessionMetadata};
 use crate::user::data::User;
+use crate::user::verify_email::enforce_email_verification;
 
 use chrono::{Duration, Utc};
 use rocket::serde::json::Json;
@@ -19,6 +23,7 @@ pub async fn refresh(
     session_id: Uuid,
     rat: RefreshAccessToken,
     passphrase: &str,
+    metadata: &SessionMetadata,
 ) -> Result<SessionResponse, ApiError> {
     let session = Session::get(&pool, &session_id).await?;
 
@@ -33,8 +38,20 @@ pub async fn refresh(
         return Err(ApiError::Forbidden);
     }
 
+    renew(cache, pool, project_id, session, passphrase, metadata).await
+}
+
+/// Extends the session and issues a new access token
+pub async fn renew(
+    cache: &Cache,
+    pool: &Db,
+    project_id: Uuid,
+    session: Session,
+    passphrase: &str,
+    metadata: &SessionMetadata,
+) -> Result<SessionResponse, ApiError> {
     let expire_at = Utc::now() + Duration::days(30);
-    Session::extend(&pool, &session.id, &expire_at).await?;
+    Session::extend(&pool, &session.id, &expire_at, metadata).await?;
 
     let private_key = ProjectKeys::get_private_key(&cache, &pool, &project_id, &passphrase).await?;
 
@@ -47,8 +64,22 @@ pub async fn refresh(
         .await?
         .ok_or_else(|| ApiError::NotFound)?;
 
+    let email_verified = enforce_email_verification(&pool, &user, &project_id).await?;
+    let permissions = Permission::for_user(&pool, &user.id).await?;
     let exp = Utc::now() + Duration::minutes(15);
-    let access_token = AccessToken::new(&user.id, &user.traits, exp)
+    let mut access_token = AccessToken::new(&user.id, &user.traits, exp)
+        .with_permissions(permissions)
+        .with_email_verified(email_verified);
+
+    if let Some(membership) = Membership::from_session(&pool, &session.id).await? {
+        access_token =
+            access_token.with_organization(membership.organization_id, membership.role.into());
+    }
+
+    let access_token = access_token
+        .with_auth(&session)
+        .bind_to(&pool, &session)
+        .await?
         .to_jwt(&project_id, &private_key)
         .map_err(|_| ApiError::InternalServerError)?;
 
@@ -69,15 +100,22 @@ pub async fn handler(
     rat: Json<RefreshAccessToken>,
     secrets: &State<Secrets>,
     cache: Cache,
+    metadata: SessionMetadata,
+    cookies: SessionCookies<'_>,
 ) -> Result<SessionResponse, ApiError> {
-    let session = refresh(
+    let mut session = refresh(
         &cache,
         &pool,
         project.id,
         session_id,
         rat.into_inner(),
         &secrets.passphrase,
+        &metadata,
     )
     .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
     Ok(session)
 }
@@ -0,0 +1,5 @@
+update sessions
+   set reauthentication_attempts = reauthentication_attempts + 1
+ where id = $1
+   and reauthentication_attempts < $2
+returning reauthentication_attempts
\ No newline at end of file
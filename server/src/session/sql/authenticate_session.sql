@@ -0,0 +1,6 @@
+update sessions
+   set auth_time = now()
+     , auth_methods = $2
+     , reauthentication_attempts = 0
+ where id = $1
+returning id, expire_at, user_id, public_key, project_id, auth_time, auth_methods
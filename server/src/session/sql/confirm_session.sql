@@ -1,5 +1,7 @@
 update sessions
    set user_id = $2
      , expire_at = $3
+     , auth_time = now()
+     , auth_methods = $4
  where id = $1
-returning id, expire_at, user_id, public_key, project_id
\ No newline at end of file
+returning id, expire_at, user_id, public_key, project_id, auth_time, auth_methods
@@ -0,0 +1,9 @@
+select count(*) as "count!"
+  from sessions
+ where user_id = $1
+   and project_id = $2
+   and id <> $3
+   and expire_at > now()
+   -- sign ins that still wait for the passwordless link or sms code
+   and not exists (select 1 from passwordless where passwordless.session_id = sessions.id)
+   and not exists (select 1 from sms_codes where sms_codes.session_id = sessions.id)
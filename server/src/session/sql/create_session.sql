@@ -1,6 +1,6 @@
-insert into sessions(id, public_key, expire_at, user_id, project_id)
-values($1, $2, $3, $4, $5)
+insert into sessions(id, public_key, expire_at, user_id, project_id, user_agent, ip, device_name, auth_time, auth_methods)
+values($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
 on conflict(id)
    do update
          set id = uuid_generate_v4()
-returning id, public_key, expire_at, user_id, project_id
\ No newline at end of file
+returning id, public_key, expire_at, user_id, project_id, auth_time, auth_methods
@@ -0,0 +1,13 @@
+delete from sessions
+ where id in (
+    select id
+      from sessions
+     where user_id = $1
+       and project_id = $2
+       and id <> $3
+       and expire_at > now()
+       and not exists (select 1 from passwordless where passwordless.session_id = sessions.id)
+       and not exists (select 1 from sms_codes where sms_codes.session_id = sessions.id)
+     order by coalesce(last_refreshed_at, created_at) desc
+    offset $4
+ )
@@ -1,3 +1,7 @@
 update sessions
    set expire_at = $2
- where id = $1
\ No newline at end of file
+     , last_refreshed_at = now()
+     , user_agent = coalesce($3, user_agent)
+     , ip = coalesce($4, ip)
+     , device_name = coalesce($5, device_name)
+ where id = $1
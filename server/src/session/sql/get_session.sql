@@ -3,5 +3,7 @@ select id
      , expire_at
      , user_id
      , project_id
+     , auth_time
+     , auth_methods
   from sessions
- where id = $1
\ No newline at end of file
+ where id = $1
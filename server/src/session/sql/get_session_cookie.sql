@@ -0,0 +1,3 @@
+select cookie_hash
+  from sessions
+ where id = $1
@@ -0,0 +1,4 @@
+insert into dpop_proofs(jkt, jti, expire_at)
+values ($1, $2, $3)
+on conflict do nothing
+returning jti
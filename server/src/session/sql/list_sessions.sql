@@ -0,0 +1,12 @@
+select id
+     , device_name
+     , user_agent
+     , ip
+     , created_at
+     , last_refreshed_at
+     , expire_at
+  from sessions
+ where user_id = $1
+   and project_id = $2
+   and expire_at > now()
+ order by coalesce(last_refreshed_at, created_at) desc
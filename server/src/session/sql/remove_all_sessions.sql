@@ -1,6 +1,15 @@
+with revoke_tokens as (
+    update users
+       set tokens_valid_after = now()
+     where id in (
+         select sessions.user_id
+           from sessions
+          where sessions.id = $1
+     )
+ returning id
+)
 delete from sessions
  where user_id in (
-     select sessions.user_id
-       from sessions
-      where sessions.id = $1 
- )
\ No newline at end of file
+     select id as user_id
+       from revoke_tokens
+ )
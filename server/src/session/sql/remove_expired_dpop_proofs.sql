@@ -0,0 +1,2 @@
+delete from dpop_proofs
+ where expire_at < now()
@@ -0,0 +1,5 @@
+delete from sessions
+ where id = $1
+   and user_id = $2
+   and project_id = $3
+returning id
@@ -1,2 +1,11 @@
+with revoke_tokens as (
+    update users
+       set tokens_valid_after = now()
+     where id = $1
+ returning id
+)
 delete from sessions
- where user_id = $1 
\ No newline at end of file
+ where user_id in (
+     select id as user_id
+       from revoke_tokens
+ )
@@ -0,0 +1,3 @@
+update sessions
+   set cookie_hash = $2
+ where id = $1
@@ -0,0 +1,10 @@
+select greatest(
+           ( select tokens_valid_after
+               from users
+              where id = $1
+           )
+         , ( select tokens_valid_after
+               from projects
+              where id = $2
+           )
+       ) as valid_after
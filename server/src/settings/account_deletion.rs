@@ -0,0 +1,69 @@
+use crate::admin::data::Admin;
+use crate::project::data::Project;
+use crate::user::data::AccountDeletion;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize, Serialize)]
+pub struct AccountDeletionSettings {
+    /// Days a deleted user can be restored before it is purged, `None` removes
+    /// users right away
+    pub grace_days: Option<i32>,
+    /// Send users an email when their account is deleted during a grace period
+    pub send_email: bool,
+}
+
+pub async fn get_account_deletion_settings(
+    pool: &Db,
+    project_id: Uuid,
+) -> Result<AccountDeletionSettings, ApiError> {
+    let settings = Project::account_deletion(pool, &project_id).await?;
+    Ok(AccountDeletionSettings {
+        grace_days: settings.grace_days,
+        send_email: settings.send_email,
+    })
+}
+
+#[get("/account_deletion?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<AccountDeletionSettings>, ApiError> {
+    let settings = get_account_deletion_settings(&pool, project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn set_account_deletion_settings(
+    pool: &Db,
+    settings: AccountDeletionSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if settings.grace_days.map_or(false, |days| days < 1) {
+        return Err(ApiError::BadRequest);
+    }
+
+    let settings = AccountDeletion {
+        grace_days: settings.grace_days,
+        send_email: settings.send_email,
+    };
+
+    Project::set_account_deletion(pool, &project_id, &settings).await?;
+    Ok(())
+}
+
+#[post("/account_deletion?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<AccountDeletionSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_account_deletion_settings(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
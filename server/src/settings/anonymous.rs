@@ -0,0 +1,57 @@
+use crate::admin::data::Admin;
+use crate::project::data::Project;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize, Serialize)]
+pub struct AnonymousSettings {
+    /// Stale anonymous users are removed after `ttl_days`, `None` keeps them forever
+    pub ttl_days: Option<i32>,
+}
+
+pub async fn get_anonymous_settings(
+    pool: &Db,
+    project_id: Uuid,
+) -> Result<AnonymousSettings, ApiError> {
+    let ttl_days = Project::anonymous_ttl(&pool, &project_id).await?;
+    Ok(AnonymousSettings { ttl_days })
+}
+
+#[get("/anonymous?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<AnonymousSettings>, ApiError> {
+    let settings = get_anonymous_settings(&pool, project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn set_anonymous_settings(
+    pool: &Db,
+    settings: AnonymousSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if settings.ttl_days.map_or(false, |days| days < 1) {
+        return Err(ApiError::BadRequest);
+    }
+
+    Project::set_anonymous_ttl(&pool, &project_id, settings.ttl_days).await?;
+    Ok(())
+}
+
+#[post("/anonymous?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<AnonymousSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_anonymous_settings(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
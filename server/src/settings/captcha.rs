@@ -0,0 +1,49 @@
+use crate::admin::data::Admin;
+use crate::settings::data::{CaptchaSettings, ProjectCaptcha};
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[get("/captcha?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<Option<CaptchaSettings>>, ApiError> {
+    let settings = ProjectCaptcha::from_project(&pool, &project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn create_captcha_settings(
+    pool: &Db,
+    mut settings: CaptchaSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if settings.secret.trim().is_empty() {
+        settings.secret = ProjectCaptcha::from_project(pool, &project_id)
+            .await?
+            .map(|current| current.secret)
+            .unwrap_or_default();
+    }
+
+    if settings.site_key.trim().is_empty() || settings.secret.trim().is_empty() {
+        return Err(ApiError::BadRequest);
+    }
+
+    ProjectCaptcha::insert(pool, &project_id, &settings).await?;
+    Ok(())
+}
+
+#[post("/captcha?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<CaptchaSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    create_captcha_settings(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
@@ -0,0 +1,45 @@
+use crate::admin::data::Admin;
+use crate::project::data::{CorsSettings, Project};
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::{Cache, Db};
+
+#[get("/cors?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    cache: Cache,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<CorsSettings>, ApiError> {
+    let settings = Project::cors(&cache, &pool, &project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn set_cors_settings(
+    cache: &Cache,
+    pool: &Db,
+    settings: CorsSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if !settings.is_valid() {
+        return Err(ApiError::BadRequest);
+    }
+
+    Project::set_cors(&cache, &pool, &project_id, &settings).await?;
+    Ok(())
+}
+
+#[post("/cors?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    cache: Cache,
+    project_id: Uuid,
+    body: Json<CorsSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_cors_settings(&cache, &pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
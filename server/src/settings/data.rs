@@ -95,6 +95,116 @@ impl ProjectEmail {
     }
 }
 
+pub struct ProjectSms;
+
+impl ProjectSms {
+    pub async fn from_project(
+        pool: &PgPool,
+        project_id: &Uuid,
+    ) -> sqlx::Result<Option<SmsSettings>> {
+        sqlx::query_file_as!(
+            SmsSettings,
+            "src/settings/sql/get_sms_settings.sql",
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn insert(
+        pool: &PgPool,
+        project_id: &Uuid,
+        settings: &SmsSettings,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/settings/sql/insert_sms_settings.sql",
+            project_id,
+            settings.provider as SmsProvider,
+            settings.url,
+            settings.auth_header,
+            settings.sender,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::Type, Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[sqlx(type_name = "sms_provider")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SmsProvider {
+    Log,
+    Http,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SmsSettings {
+    pub provider: SmsProvider,
+    pub url: Option<String>,
+    pub auth_header: Option<String>,
+    pub sender: Option<String>,
+}
+
+pub struct ProjectCaptcha;
+
+impl ProjectCaptcha {
+    pub async fn from_project(
+        pool: &PgPool,
+        project_id: &Uuid,
+    ) -> sqlx::Result<Option<CaptchaSettings>> {
+        sqlx::query_file_as!(
+            CaptchaSettings,
+            "src/settings/sql/get_captcha_settings.sql",
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn insert(
+        pool: &PgPool,
+        project_id: &Uuid,
+        settings: &CaptchaSettings,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!(
+            "src/settings/sql/insert_captcha_settings.sql",
+            project_id,
+            settings.enabled,
+            settings.provider as CaptchaProvider,
+            settings.site_key,
+            settings.secret,
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::Type, Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[sqlx(type_name = "captcha_provider")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CaptchaProvider {
+    Turnstile,
+    Hcaptcha,
+    Recaptcha,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CaptchaSettings {
+    pub enabled: bool,
+    pub provider: CaptchaProvider,
+    pub site_key: String,
+
+    /// Never returned to admins, an empty secret keeps the current one
+    #[serde(default, skip_serializing)]
+    pub secret: String,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct EmailSettings {
     pub from_name: String,
@@ -113,3 +223,22 @@ pub struct TemplateEmail {
     pub domain: String,
     pub name: String,
 }
+
+impl TemplateEmail {
+    /// Link to the template's page on the project's domain, `redirect_url`
+    /// replaces the page and has to be verified with `RedirectUrls::verify`
+    pub fn link(&self, redirect_url: Option<&str>, id: &Uuid, token: &str) -> String {
+        let url = match redirect_url {
+            Some(url) => url.to_string(),
+            None => format!("{}{}", self.domain, self.redirect_to),
+        };
+
+        let (url, fragment) = match url.split_once('#') {
+            Some((url, fragment)) => (url, format!("#{}", fragment)),
+            None => (url.as_str(), String::new()),
+        };
+
+        let separator = if url.contains('?') { '&' } else { '?' };
+        format!("{}{}id={}&token={}{}", url, separator, id, token, fragment)
+    }
+}
@@ -39,6 +39,7 @@ pub async fn create_handler(
     pool: Db,
     project_id: Uuid,
     body: Json<EmailSettings>,
+    _admin: Admin,
 ) -> Result<Status, ApiError> {
     let settings = body.into_inner();
     create_email_settings(&pool, settings, project_id).await?;
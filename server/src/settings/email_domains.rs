@@ -0,0 +1,42 @@
+use crate::admin::data::Admin;
+use crate::project::data::{EmailDomains, Project};
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[get("/email_domains?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<EmailDomains>, ApiError> {
+    let email_domains = Project::email_domains(&pool, &project_id).await?;
+    Ok(Json(email_domains))
+}
+
+pub async fn set_email_domains(
+    pool: &Db,
+    email_domains: EmailDomains,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if !email_domains.is_valid() {
+        return Err(ApiError::BadRequest);
+    }
+
+    Project::set_email_domains(pool, &project_id, &email_domains).await?;
+    Ok(())
+}
+
+#[post("/email_domains?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<EmailDomains>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_email_domains(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
@@ -0,0 +1,53 @@
+use crate::admin::data::Admin;
+use crate::mail::data::EmailVerificationPolicy;
+use crate::project::data::Project;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize, Serialize)]
+pub struct EmailVerificationSettings {
+    pub policy: EmailVerificationPolicy,
+}
+
+pub async fn get_email_verification_settings(
+    pool: &Db,
+    project_id: Uuid,
+) -> Result<EmailVerificationSettings, ApiError> {
+    let policy = Project::email_verification(&pool, &project_id).await?;
+    Ok(EmailVerificationSettings { policy })
+}
+
+#[get("/email_verification?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<EmailVerificationSettings>, ApiError> {
+    let settings = get_email_verification_settings(&pool, project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn set_email_verification_settings(
+    pool: &Db,
+    settings: EmailVerificationSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    Project::set_email_verification(&pool, &project_id, settings.policy).await?;
+    Ok(())
+}
+
+#[post("/email_verification?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<EmailVerificationSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_email_verification_settings(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
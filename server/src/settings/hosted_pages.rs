@@ -0,0 +1,42 @@
+use crate::admin::data::Admin;
+use crate::project::data::{HostedPages, Project};
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[get("/hosted_pages?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<HostedPages>, ApiError> {
+    let (_, _, hosted_pages) = Project::hosted_pages(&pool, &project_id).await?;
+    Ok(Json(hosted_pages))
+}
+
+pub async fn set_hosted_pages(
+    pool: &Db,
+    hosted_pages: HostedPages,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if !hosted_pages.is_valid() {
+        return Err(ApiError::BadRequest);
+    }
+
+    Project::set_hosted_pages(pool, &project_id, &hosted_pages).await?;
+    Ok(())
+}
+
+#[post("/hosted_pages?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<HostedPages>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_hosted_pages(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
@@ -0,0 +1,54 @@
+use crate::admin::data::Admin;
+use crate::project::data::Project;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize, Serialize)]
+pub struct LinkingSettings {
+    pub auto_link_verified_email: bool,
+}
+
+pub async fn get_linking_settings(
+    pool: &Db,
+    project_id: Uuid,
+) -> Result<LinkingSettings, ApiError> {
+    let auto_link_verified_email = Project::auto_link(&pool, &project_id).await?;
+    Ok(LinkingSettings {
+        auto_link_verified_email,
+    })
+}
+
+#[get("/linking?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<LinkingSettings>, ApiError> {
+    let settings = get_linking_settings(&pool, project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn set_linking_settings(
+    pool: &Db,
+    settings: LinkingSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    Project::set_auto_link(&pool, &project_id, settings.auto_link_verified_email).await?;
+    Ok(())
+}
+
+#[post("/linking?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<LinkingSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_linking_settings(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
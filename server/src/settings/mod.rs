@@ -1,9 +1,52 @@
 use rocket::Route;
 
+mod account_deletion;
+mod anonymous;
+mod captcha;
+mod cors;
 pub mod data;
 mod email;
+mod email_domains;
+mod email_verification;
+mod hosted_pages;
+mod linking;
+mod passwordless;
 mod project;
+mod reauthentication;
+mod redirect_urls;
+mod sessions;
+mod sms;
 
 pub fn routes() -> Vec<Route> {
-    routes![email::get_handler, email::create_handler, project::handler]
+    routes![
+        account_deletion::get_handler,
+        account_deletion::create_handler,
+        anonymous::get_handler,
+        anonymous::create_handler,
+        captcha::get_handler,
+        captcha::create_handler,
+        cors::get_handler,
+        cors::create_handler,
+        email::get_handler,
+        email::create_handler,
+        email_domains::get_handler,
+        email_domains::create_handler,
+        email_verification::get_handler,
+        email_verification::create_handler,
+        hosted_pages::get_handler,
+        hosted_pages::create_handler,
+        linking::get_handler,
+        linking::create_handler,
+        passwordless::get_handler,
+        passwordless::create_handler,
+        project::handler,
+        reauthentication::get_handler,
+        reauthentication::create_handler,
+        redirect_urls::get_handler,
+        redirect_urls::create_handler,
+        sessions::get_handler,
+        sessions::create_handler,
+        sms::get_handler,
+        sms::create_handler
+    ]
 }
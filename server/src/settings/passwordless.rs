@@ -0,0 +1,53 @@
+use crate::admin::data::Admin;
+use crate::passwordless::data::PasswordlessMode;
+use crate::project::data::Project;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize, Serialize)]
+pub struct PasswordlessSettings {
+    pub mode: PasswordlessMode,
+}
+
+pub async fn get_passwordless_settings(
+    pool: &Db,
+    project_id: Uuid,
+) -> Result<PasswordlessSettings, ApiError> {
+    let mode = Project::passwordless_mode(&pool, &project_id).await?;
+    Ok(PasswordlessSettings { mode })
+}
+
+#[get("/passwordless?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<PasswordlessSettings>, ApiError> {
+    let settings = get_passwordless_settings(&pool, project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn set_passwordless_settings(
+    pool: &Db,
+    settings: PasswordlessSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    Project::set_passwordless_mode(&pool, &project_id, settings.mode).await?;
+    Ok(())
+}
+
+#[post("/passwordless?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<PasswordlessSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_passwordless_settings(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
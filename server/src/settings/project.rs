@@ -1,4 +1,4 @@
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 use crate::project::data::Project;
 
 use rocket::http::Status;
@@ -36,8 +36,9 @@ pub async fn handler(
     pool: Db,
     body: Json<SetProjectSettings>,
     cache: Cache,
-    _admin: Admin,
+    admin: Admin,
 ) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
     set_project_settings(&cache, &pool, body.into_inner()).await?;
     Ok(Status::Ok)
 }
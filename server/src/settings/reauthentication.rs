@@ -0,0 +1,58 @@
+use crate::admin::data::Admin;
+use crate::project::data::Project;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize, Serialize)]
+pub struct ReauthenticationSettings {
+    /// Sensitive operations require an authentication within the last `max_age`
+    /// seconds, `None` disables the check
+    pub max_age: Option<i32>,
+}
+
+pub async fn get_reauthentication_settings(
+    pool: &Db,
+    project_id: Uuid,
+) -> Result<ReauthenticationSettings, ApiError> {
+    let max_age = Project::reauthentication_max_age(pool, &project_id).await?;
+    Ok(ReauthenticationSettings { max_age })
+}
+
+#[get("/reauthentication?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<ReauthenticationSettings>, ApiError> {
+    let settings = get_reauthentication_settings(&pool, project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn set_reauthentication_settings(
+    pool: &Db,
+    settings: ReauthenticationSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if settings.max_age.map_or(false, |max_age| max_age < 1) {
+        return Err(ApiError::BadRequest);
+    }
+
+    Project::set_reauthentication_max_age(pool, &project_id, settings.max_age).await?;
+    Ok(())
+}
+
+#[post("/reauthentication?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<ReauthenticationSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_reauthentication_settings(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
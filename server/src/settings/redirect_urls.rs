@@ -0,0 +1,42 @@
+use crate::admin::data::Admin;
+use crate::project::data::{Project, RedirectUrls};
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[get("/redirect_urls?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<RedirectUrls>, ApiError> {
+    let (_, redirect_urls) = Project::redirect_urls(&pool, &project_id).await?;
+    Ok(Json(redirect_urls))
+}
+
+pub async fn set_redirect_urls(
+    pool: &Db,
+    redirect_urls: RedirectUrls,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if !redirect_urls.is_valid() {
+        return Err(ApiError::BadRequest);
+    }
+
+    Project::set_redirect_urls(pool, &project_id, &redirect_urls).await?;
+    Ok(())
+}
+
+#[post("/redirect_urls?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<RedirectUrls>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_redirect_urls(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
@@ -0,0 +1,68 @@
+use crate::admin::data::Admin;
+use crate::project::data::Project;
+use crate::session::data::SessionLimitMode;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+#[derive(Deserialize, Serialize)]
+pub struct SessionSettings {
+    /// Maximum number of devices a user can be signed in with, `None` for no limit
+    pub max_sessions: Option<i32>,
+    pub limit_mode: SessionLimitMode,
+}
+
+pub async fn get_session_settings(
+    pool: &Db,
+    project_id: Uuid,
+) -> Result<SessionSettings, ApiError> {
+    let limit = Project::session_limit(&pool, &project_id).await?;
+    Ok(SessionSettings {
+        max_sessions: limit.max_sessions,
+        limit_mode: limit.mode,
+    })
+}
+
+#[get("/sessions?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<SessionSettings>, ApiError> {
+    let settings = get_session_settings(&pool, project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn set_session_settings(
+    pool: &Db,
+    settings: SessionSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if settings.max_sessions.map_or(false, |max| max < 1) {
+        return Err(ApiError::BadRequest);
+    }
+
+    Project::set_session_limit(
+        &pool,
+        &project_id,
+        settings.max_sessions,
+        settings.limit_mode,
+    )
+    .await?;
+    Ok(())
+}
+
+#[post("/sessions?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<SessionSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    set_session_settings(&pool, body.into_inner(), project_id).await?;
+    Ok(Status::Ok)
+}
@@ -0,0 +1,51 @@
+use crate::admin::data::Admin;
+use crate::settings::data::{ProjectSms, SmsProvider, SmsSettings};
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+pub async fn get_sms_settings(
+    pool: &Db,
+    project_id: Uuid,
+) -> Result<Option<SmsSettings>, ApiError> {
+    let settings = ProjectSms::from_project(&pool, &project_id).await?;
+    Ok(settings)
+}
+
+#[get("/sms?<project_id>")]
+pub async fn get_handler(
+    pool: Db,
+    project_id: Uuid,
+    _admin: Admin,
+) -> Result<Json<Option<SmsSettings>>, ApiError> {
+    let settings = get_sms_settings(&pool, project_id).await?;
+    Ok(Json(settings))
+}
+
+pub async fn create_sms_settings(
+    pool: &Db,
+    settings: SmsSettings,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if settings.provider == SmsProvider::Http && settings.url.is_none() {
+        return Err(ApiError::BadRequest);
+    }
+
+    ProjectSms::insert(&pool, &project_id, &settings).await?;
+    Ok(())
+}
+
+#[post("/sms?<project_id>", format = "json", data = "<body>")]
+pub async fn create_handler(
+    pool: Db,
+    project_id: Uuid,
+    body: Json<SmsSettings>,
+    _admin: Admin,
+) -> Result<Status, ApiError> {
+    let settings = body.into_inner();
+    create_sms_settings(&pool, settings, project_id).await?;
+    Ok(Status::Ok)
+}
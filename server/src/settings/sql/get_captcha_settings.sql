@@ -0,0 +1,6 @@
+select enabled
+     , provider as "provider: CaptchaProvider"
+     , site_key
+     , secret
+  from captcha_settings
+ where project_id = $1
\ No newline at end of file
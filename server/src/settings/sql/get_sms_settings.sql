@@ -0,0 +1,6 @@
+select provider as "provider: SmsProvider"
+     , url
+     , auth_header
+     , sender
+  from sms_settings
+ where project_id = $1
\ No newline at end of file
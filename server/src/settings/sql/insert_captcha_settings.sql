@@ -0,0 +1,8 @@
+insert into captcha_settings(project_id, enabled, provider, site_key, secret)
+values($1, $2, $3, $4, $5)
+on conflict (project_id)
+  do update
+        set enabled = $2
+          , provider = $3
+          , site_key = $4
+          , secret = $5
\ No newline at end of file
@@ -0,0 +1,16 @@
+with settings as (
+    insert into sms_settings(project_id, provider, url, auth_header, sender)
+    values($1, $2, $3, $4, $5)
+    on conflict (project_id)
+      do update
+            set provider = $2
+              , url = $3
+              , auth_header = $4
+              , sender = $5
+    returning project_id
+)
+-- translations of the messages are stored like the ones of an email template
+insert into templates(body, name, project_id)
+select '', 'sms', settings.project_id
+  from settings
+    on conflict (name, project_id) do nothing
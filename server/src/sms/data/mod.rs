@@ -0,0 +1,2 @@
+mod sms_code;
+pub use sms_code::SmsCode;
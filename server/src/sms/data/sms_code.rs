@@ -0,0 +1,106 @@
+use bcrypt;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct SmsCode {
+    pub id: Uuid,
+    pub expire_at: DateTime<Utc>,
+    pub user_id: Option<Uuid>,
+    pub phone: String,
+    pub code: String,
+    pub is_valid: bool,
+    pub project_id: Uuid,
+    pub session_id: Uuid,
+}
+
+impl SmsCode {
+    pub async fn create(
+        conn: &mut PgConnection,
+        user_id: Option<Uuid>,
+        phone: &str,
+        code: &str,
+        project: &Uuid,
+        session_id: &Uuid,
+    ) -> sqlx::Result<Uuid> {
+        sqlx::query_file!(
+            "src/sms/sql/insert_sms_code.sql",
+            user_id,
+            phone,
+            code,
+            project,
+            session_id
+        )
+        .fetch_one(conn)
+        .await
+        .map(|row| row.id)
+    }
+
+    pub async fn get(pool: &PgPool, id: &Uuid) -> sqlx::Result<Option<SmsCode>> {
+        sqlx::query_file_as!(SmsCode, "src/sms/sql/get_sms_code.sql", id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Counts the attempt, `false` when the code already reached `max_attempts`
+    pub async fn add_attempt(pool: &PgPool, id: &Uuid, max_attempts: i32) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/sms/sql/add_attempt.sql", id, max_attempts)
+            .fetch_optional(pool)
+            .await
+            .map(|row| row.is_some())
+    }
+
+    /// Serializes the rate limit of the project until the transaction ends,
+    /// concurrent requests would otherwise all pass the counts
+    pub async fn lock_project(conn: &mut PgConnection, project: &Uuid) -> sqlx::Result<()> {
+        sqlx::query_file!("src/sms/sql/lock_project.sql", project)
+            .fetch_one(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Number of codes sent to `phone` since `since`
+    pub async fn count_by_phone(
+        conn: &mut PgConnection,
+        phone: &str,
+        project: &Uuid,
+        since: &DateTime<Utc>,
+    ) -> sqlx::Result<i64> {
+        sqlx::query_file!("src/sms/sql/count_by_phone.sql", phone, project, since)
+            .fetch_one(conn)
+            .await
+            .map(|row| row.count.unwrap_or(0))
+    }
+
+    /// Number of codes the project sent since `since`
+    pub async fn count_by_project(
+        conn: &mut PgConnection,
+        project: &Uuid,
+        since: &DateTime<Utc>,
+    ) -> sqlx::Result<i64> {
+        sqlx::query_file!("src/sms/sql/count_by_project.sql", project, since)
+            .fetch_one(conn)
+            .await
+            .map(|row| row.count.unwrap_or(0))
+    }
+
+    /// Removes all codes of `phone` and the sessions waiting for them, except `session`
+    pub async fn remove_all(
+        pool: &PgPool,
+        phone: &str,
+        project: &Uuid,
+        session: &Uuid,
+    ) -> sqlx::Result<()> {
+        sqlx::query_file!("src/sms/sql/remove_all.sql", phone, project, session)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub fn compare(&self, code: &str) -> bool {
+        bcrypt::verify(code, &self.code).unwrap_or(false)
+    }
+}
@@ -0,0 +1,10 @@
+pub mod data;
+mod request_code;
+pub mod transport;
+mod verify;
+
+use rocket::Route;
+
+pub fn routes() -> Vec<Route> {
+    routes![request_code::handler, verify::handler]
+}
@@ -0,0 +1,205 @@
+use crate::crypto::Token;
+use crate::project::data::Flags;
+use crate::project::Project;
+use crate::session::data::{AccessToken, Session, SessionMetadata};
+use crate::settings::data::ProjectSms;
+use crate::sms::data::SmsCode;
+use crate::sms::transport::{self, Sms};
+use crate::template::{Template, Translations};
+use crate::user::data::User;
+
+use chrono::{Duration, Utc};
+use rocket::serde::{json::Json, Deserialize, Serialize};
+use serde_json::json;
+use sqlx::PgConnection;
+use std::collections::HashMap;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+const CODE_LENGTH: usize = 6;
+
+/// Codes expire after the `sms_codes.expire_at` default
+const EXPIRE_IN_MINUTES: i32 = 10;
+
+const PER_PHONE_PER_MINUTE: i64 = 1;
+const PER_PHONE_PER_HOUR: i64 = 5;
+const PER_PROJECT_PER_HOUR: i64 = 500;
+
+const TRANSLATIONS: &str = include_str!("translations/en.json");
+
+/// Name of the template the translations of the message are stored under
+const TEMPLATE_NAME: &str = "sms";
+
+#[derive(Deserialize)]
+pub struct RequestSms {
+    pub phone: String,
+    pub session: Uuid,
+    pub public_key: Vec<u8>,
+    #[serde(default)]
+    pub device_languages: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct SmsResponse {
+    pub id: Uuid,
+    pub session: Uuid,
+}
+
+pub async fn request_code(
+    pool: &Db,
+    request: RequestSms,
+    project_id: Uuid,
+    metadata: &SessionMetadata,
+) -> Result<SmsResponse, ApiError> {
+    let phone = normalize_phone(&request.phone)?;
+    AccessToken::check_binding(pool, &project_id, &request.public_key).await?;
+
+    let settings = ProjectSms::from_project(&pool, &project_id)
+        .await?
+        .ok_or(ApiError::SmsNotConfigured)?;
+
+    let user = User::get_by_phone(&pool, &phone, &project_id).await?;
+
+    if user.clone().map_or(false, |u| u.is_disabled()) {
+        return Err(ApiError::UserDisabled);
+    }
+
+    // new users are created on verify, invite-only projects have sign up disabled
+    if user.is_none() {
+        Flags::has_flags(&pool, &project_id, &[Flags::SignUp]).await?;
+    }
+
+    let languages = match &user {
+        Some(user) if !user.device_languages.is_empty() => &user.device_languages,
+        _ => &request.device_languages,
+    };
+
+    let code = Token::code(CODE_LENGTH);
+    let hashed_code = Token::hash(&code)?;
+    let message = message(pool, &project_id, languages, &code).await?;
+
+    let user_id = user.map(|u| u.id);
+
+    // the code is only inserted when the limits still allow it
+    let mut tx = pool.begin().await?;
+    SmsCode::lock_project(&mut tx, &project_id).await?;
+    enforce_rate_limit(&mut tx, &phone, &project_id).await?;
+
+    let session = Session {
+        id: request.session,
+        public_key: request.public_key.to_owned(),
+        user_id,
+        expire_at: Utc::now() + Duration::days(30),
+        project_id,
+        auth_time: None,
+        auth_methods: vec![],
+    };
+
+    let session = Session::create(&pool, session, metadata).await?;
+
+    let id = SmsCode::create(
+        &mut tx,
+        user_id,
+        &phone,
+        &hashed_code,
+        &project_id,
+        &session.id,
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    let sms = Sms {
+        to: phone,
+        from: settings.sender.clone(),
+        message,
+    };
+
+    transport::from_settings(&settings)?.send(&sms).await?;
+
+    Ok(SmsResponse {
+        id,
+        session: session.id,
+    })
+}
+
+/// Limits the codes sent to a phone number and by a project, every code
+/// costs the project money
+async fn enforce_rate_limit(
+    conn: &mut PgConnection,
+    phone: &str,
+    project_id: &Uuid,
+) -> Result<(), ApiError> {
+    let now = Utc::now();
+    let last_minute = now - Duration::minutes(1);
+    let last_hour = now - Duration::hours(1);
+
+    if SmsCode::count_by_phone(conn, phone, project_id, &last_minute).await? >= PER_PHONE_PER_MINUTE
+        || SmsCode::count_by_phone(conn, phone, project_id, &last_hour).await? >= PER_PHONE_PER_HOUR
+        || SmsCode::count_by_project(conn, project_id, &last_hour).await? >= PER_PROJECT_PER_HOUR
+    {
+        return Err(ApiError::SmsTooManyRequests);
+    }
+
+    Ok(())
+}
+
+/// English text, overwritten by the project's translations for the
+/// user's languages
+async fn message(
+    pool: &Db,
+    project_id: &Uuid,
+    languages: &Vec<String>,
+    code: &str,
+) -> Result<String, ApiError> {
+    let mut translations: HashMap<String, String> =
+        serde_json::from_str(TRANSLATIONS).map_err(|_| ApiError::InternalServerError)?;
+
+    if let Some(project) =
+        Translations::find_by_languages(pool, project_id, languages, TEMPLATE_NAME).await?
+    {
+        translations.extend(project);
+    }
+
+    let message = translations
+        .get("message")
+        .ok_or(ApiError::TemplateRender)?;
+
+    let ctx = json!({
+        "code": code,
+        "expire_in": EXPIRE_IN_MINUTES,
+    });
+
+    Template::render(message, &ctx, &HashMap::new()).await
+}
+
+/// Strips common formatting characters and checks that what is left
+/// looks like an E.164 number, e.g. `+43 660 123 45 67` -> `+436601234567`
+fn normalize_phone(phone: &str) -> Result<String, ApiError> {
+    let phone: String = phone
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '(' | ')' | '.'))
+        .collect();
+
+    let digits = phone.strip_prefix('+').ok_or(ApiError::SmsInvalidPhone)?;
+
+    if digits.len() < 6 || digits.len() > 15 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ApiError::SmsInvalidPhone);
+    }
+
+    Ok(phone)
+}
+
+#[post("/", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    project: Project,
+    body: Json<RequestSms>,
+    metadata: SessionMetadata,
+) -> Result<Json<SmsResponse>, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::Sms]).await?;
+    let response = request_code(&pool, body.into_inner(), project.id, &metadata).await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,5 @@
+select count(*)
+  from sms_codes
+ where phone = $1
+   and project_id = $2
+   and created_at > $3
@@ -0,0 +1,4 @@
+select count(*)
+  from sms_codes
+ where project_id = $1
+   and created_at > $2
@@ -0,0 +1,10 @@
+select id
+     , expire_at
+     , user_id
+     , phone
+     , code
+     , is_valid
+     , project_id
+     , session_id
+  from sms_codes
+ where id = $1
\ No newline at end of file
@@ -0,0 +1,3 @@
+insert into sms_codes (user_id, phone, code, project_id, session_id)
+values ($1, $2, $3, $4, $5)
+returning id
\ No newline at end of file
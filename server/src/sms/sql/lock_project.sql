@@ -0,0 +1,4 @@
+-- held until the end of the transaction, the codes of a project are
+-- counted and inserted one request at a time
+select true as locked
+  from pg_advisory_xact_lock(hashtext('sms_codes'), hashtext($1::uuid::text))
@@ -0,0 +1,10 @@
+with removed as (
+	delete from sms_codes
+	 where phone = $1
+	   and project_id = $2
+	returning session_id
+)
+-- the other sign ins can't be verified anymore
+delete from sessions
+ where id in (select session_id from removed)
+   and id <> $3
@@ -0,0 +1,70 @@
+use crate::settings::data::{SmsProvider, SmsSettings};
+
+use serde::Serialize;
+use tracing::info;
+use vulpo_auth_types::error::ApiError;
+
+#[derive(Debug, Serialize)]
+pub struct Sms {
+    pub to: String,
+    pub from: Option<String>,
+    pub message: String,
+}
+
+#[rocket::async_trait]
+pub trait SmsTransport {
+    async fn send(&self, sms: &Sms) -> Result<(), ApiError>;
+}
+
+pub fn from_settings(
+    settings: &SmsSettings,
+) -> Result<Box<dyn SmsTransport + Send + Sync>, ApiError> {
+    match settings.provider {
+        SmsProvider::Log => Ok(Box::new(LogTransport)),
+        SmsProvider::Http => {
+            let url = settings.url.clone().ok_or(ApiError::SmsNotConfigured)?;
+            Ok(Box::new(HttpTransport {
+                url,
+                auth_header: settings.auth_header.clone(),
+            }))
+        }
+    }
+}
+
+/// Writes the message to the server log instead of delivering it,
+/// meant for local development.
+pub struct LogTransport;
+
+#[rocket::async_trait]
+impl SmsTransport for LogTransport {
+    async fn send(&self, sms: &Sms) -> Result<(), ApiError> {
+        info!("SMS to {}: {}", sms.to, sms.message);
+        Ok(())
+    }
+}
+
+/// Posts the message as JSON (`{ to, from, message }`) to a gateway url.
+pub struct HttpTransport {
+    pub url: String,
+    pub auth_header: Option<String>,
+}
+
+#[rocket::async_trait]
+impl SmsTransport for HttpTransport {
+    async fn send(&self, sms: &Sms) -> Result<(), ApiError> {
+        let client = reqwest::Client::new();
+        let mut request = client.post(&self.url).json(sms);
+
+        if let Some(auth_header) = &self.auth_header {
+            request = request.header("Authorization", auth_header);
+        }
+
+        request
+            .send()
+            .await
+            .and_then(|res| res.error_for_status())
+            .map_err(|_| ApiError::InternalServerError)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use crate::config::Secrets;
+use crate::keys::data::ProjectKeys;
+use crate::project::data::Flags;
+use crate::project::Project;
+use crate::role::data::Permission;
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{AccessToken, AuthMethod, RefreshAccessToken, Session};
+use crate::session::limit::enforce_session_limit;
+use crate::sms::data::SmsCode;
+use crate::user::data::User;
+use crate::user::verify_email::enforce_email_verification;
+
+use chrono::{Duration, Utc};
+use rocket::serde::json::Json;
+use rocket::State;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use vulpo_auth_types::session::SessionResponse;
+use werkbank::rocket::{Cache, Db};
+
+const MAX_ATTEMPTS: i32 = 5;
+
+#[derive(Deserialize)]
+pub struct VerifySms {
+    pub id: Uuid,
+    pub code: String,
+    pub token: String,
+    pub session: Uuid,
+    #[serde(default)]
+    pub device_languages: Vec<String>,
+}
+
+pub async fn verify(
+    cache: &Cache,
+    pool: &Db,
+    body: VerifySms,
+    project_id: Uuid,
+    passphrase: &str,
+) -> Result<SessionResponse, ApiError> {
+    let sms_code = SmsCode::get(&pool, &body.id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound)?;
+
+    if sms_code.project_id != project_id || sms_code.session_id != body.session {
+        return Err(ApiError::Forbidden);
+    }
+
+    if sms_code.is_valid == false {
+        return Err(ApiError::SmsInvalidCode);
+    }
+
+    if Utc::now() > sms_code.expire_at {
+        return Err(ApiError::SmsCodeExpired);
+    }
+
+    let current_session = Session::get(&pool, &body.session).await?;
+
+    let rat = RefreshAccessToken { value: body.token };
+
+    let claims = Session::validate_token(&current_session, &rat)?;
+    let is_valid = Session::is_valid(&pool, &claims, &current_session.id, &project_id).await?;
+
+    if !is_valid {
+        return Err(ApiError::Forbidden);
+    }
+
+    // counted before the code is compared, parallel guesses can't exceed the limit
+    if !SmsCode::add_attempt(pool, &sms_code.id, MAX_ATTEMPTS).await? {
+        return Err(ApiError::SmsTooManyAttempts);
+    }
+
+    if sms_code.compare(body.code.trim()) == false {
+        return Err(ApiError::SmsInvalidCode);
+    }
+
+    SmsCode::remove_all(&pool, &sms_code.phone, &project_id, &current_session.id).await?;
+
+    let user = match sms_code.user_id {
+        None => {
+            User::create_phone(&pool, &sms_code.phone, &project_id, &body.device_languages).await?
+        }
+        Some(user_id) => {
+            let user = User::get_by_id(&pool, &user_id, &project_id)
+                .await?
+                .ok_or_else(|| ApiError::NotFound)?;
+
+            if user.is_disabled() {
+                return Err(ApiError::UserDisabled);
+            }
+
+            User::verify_phone(&pool, &user.id, &sms_code.phone).await?;
+            user
+        }
+    };
+
+    let email_verified = enforce_email_verification(&pool, &user, &project_id).await?;
+    enforce_session_limit(&pool, &current_session.id, &user.id, &project_id).await?;
+
+    let expire_at = Utc::now() + Duration::days(30);
+    let session = Session::confirm(
+        &pool,
+        &current_session.id,
+        &user.id,
+        &expire_at,
+        AuthMethod::Sms,
+    )
+    .await?;
+
+    let private_key = ProjectKeys::get_private_key(&cache, &pool, &project_id, &passphrase).await?;
+
+    let permissions = Permission::for_user(&pool, &user.id).await?;
+    let exp = Utc::now() + Duration::minutes(15);
+    let access_token = AccessToken::new(&user.id, &user.traits, exp)
+        .with_permissions(permissions)
+        .with_email_verified(email_verified)
+        .with_auth(&session)
+        .bind_to(&pool, &session)
+        .await?
+        .to_jwt(&project_id, &private_key)
+        .map_err(|_| ApiError::InternalServerError)?;
+
+    Ok(SessionResponse {
+        access_token,
+        created: sms_code.user_id.is_none(),
+        user_id: user.id,
+        session: session.id,
+        expire_at: session.expire_at,
+    })
+}
+
+#[post("/verify", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<VerifySms>,
+    secrets: &State<Secrets>,
+    project: Project,
+    cache: Cache,
+    cookies: SessionCookies<'_>,
+) -> Result<SessionResponse, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::Sms]).await?;
+    let mut session = verify(
+        &cache,
+        &pool,
+        body.into_inner(),
+        project.id,
+        &secrets.passphrase,
+    )
+    .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
+    Ok(session)
+}
@@ -18,8 +18,23 @@ pub enum Templates {
     #[serde(rename = "passwordless")]
     Passwordless,
 
+    #[serde(rename = "passwordless_code")]
+    PasswordlessCode,
+
     #[serde(rename = "verify_email")]
     VerifyEmail,
+
+    #[serde(rename = "organization_invite")]
+    OrganizationInvite,
+
+    #[serde(rename = "admin_invite")]
+    AdminInvite,
+
+    #[serde(rename = "invite")]
+    Invite,
+
+    #[serde(rename = "account_deleted")]
+    AccountDeleted,
 }
 
 impl Templates {
@@ -28,8 +43,13 @@ impl Templates {
             "change_email" => Some(Templates::ChangeEmail),
             "password_reset" => Some(Templates::PasswordReset),
             "passwordless" => Some(Templates::Passwordless),
+            "passwordless_code" => Some(Templates::PasswordlessCode),
             "verify_email" => Some(Templates::VerifyEmail),
             "confirm_email_change" => Some(Templates::ConfirmEmailChange),
+            "organization_invite" => Some(Templates::OrganizationInvite),
+            "admin_invite" => Some(Templates::AdminInvite),
+            "invite" => Some(Templates::Invite),
+            "account_deleted" => Some(Templates::AccountDeleted),
             _ => None,
         }
     }
@@ -41,31 +61,46 @@ impl ToString for Templates {
             Templates::ChangeEmail => String::from("change_email"),
             Templates::PasswordReset => String::from("password_reset"),
             Templates::Passwordless => String::from("passwordless"),
+            Templates::PasswordlessCode => String::from("passwordless_code"),
             Templates::VerifyEmail => String::from("verify_email"),
             Templates::PasswordChanged => String::from("password_changed"),
             Templates::ConfirmEmailChange => String::from("confirm_email_change"),
+            Templates::OrganizationInvite => String::from("organization_invite"),
+            Templates::AdminInvite => String::from("admin_invite"),
+            Templates::Invite => String::from("invite"),
+            Templates::AccountDeleted => String::from("account_deleted"),
         }
     }
 }
 
 pub enum DefaultRedirect {
     Passwordless,
+    PasswordlessCode,
     PasswordReset,
     VerifyEmail,
     ChangeEmail,
     ConfirmEmailChange,
     PasswordChanged,
+    OrganizationInvite,
+    AdminInvite,
+    Invite,
+    AccountDeleted,
 }
 
 impl ToString for DefaultRedirect {
     fn to_string(&self) -> String {
         let url = match self {
             DefaultRedirect::Passwordless => "/auth/signin/link/confirm",
+            DefaultRedirect::PasswordlessCode => "/auth/signin/code",
             DefaultRedirect::PasswordReset => "/auth/forgot-password/set-password",
             DefaultRedirect::PasswordChanged => "/auth/forgot-password",
             DefaultRedirect::VerifyEmail => "/auth/verify-email",
             DefaultRedirect::ChangeEmail => "/auth/user/change-email/reset",
             DefaultRedirect::ConfirmEmailChange => "/auth/user/change-email/confirm",
+            DefaultRedirect::OrganizationInvite => "/auth/organization/accept",
+            DefaultRedirect::AdminInvite => "/dashboard/auth/invitation",
+            DefaultRedirect::Invite => "/auth/invitation",
+            DefaultRedirect::AccountDeleted => "/",
         };
 
         String::from(url)
@@ -76,33 +111,48 @@ impl DefaultRedirect {
     pub fn from_template(template: Templates) -> String {
         match template {
             Templates::Passwordless => DefaultRedirect::Passwordless.to_string(),
+            Templates::PasswordlessCode => DefaultRedirect::PasswordlessCode.to_string(),
             Templates::PasswordReset => DefaultRedirect::PasswordReset.to_string(),
             Templates::VerifyEmail => DefaultRedirect::VerifyEmail.to_string(),
             Templates::ChangeEmail => DefaultRedirect::ChangeEmail.to_string(),
             Templates::ConfirmEmailChange => DefaultRedirect::ConfirmEmailChange.to_string(),
             Templates::PasswordChanged => DefaultRedirect::PasswordChanged.to_string(),
+            Templates::OrganizationInvite => DefaultRedirect::OrganizationInvite.to_string(),
+            Templates::AdminInvite => DefaultRedirect::AdminInvite.to_string(),
+            Templates::Invite => DefaultRedirect::Invite.to_string(),
+            Templates::AccountDeleted => DefaultRedirect::AccountDeleted.to_string(),
         }
     }
 }
 
 pub enum DefaultSubject {
     Passwordless,
+    PasswordlessCode,
     PasswordReset,
     VerifyEmail,
     ChangeEmail,
     PasswordChanged,
     ConfirmEmailChange,
+    OrganizationInvite,
+    AdminInvite,
+    Invite,
+    AccountDeleted,
 }
 
 impl ToString for DefaultSubject {
     fn to_string(&self) -> String {
         let url = match self {
             DefaultSubject::Passwordless => "Sign In",
+            DefaultSubject::PasswordlessCode => "Sign In Code",
             DefaultSubject::PasswordReset => "Reset Password",
             DefaultSubject::VerifyEmail => "Verify Email",
             DefaultSubject::ChangeEmail => "Email Change Requested",
             DefaultSubject::PasswordChanged => "Password Changed",
             DefaultSubject::ConfirmEmailChange => "Confirm Email Change",
+            DefaultSubject::OrganizationInvite => "Organization Invitation",
+            DefaultSubject::AdminInvite => "Admin Invitation",
+            DefaultSubject::Invite => "Invitation",
+            DefaultSubject::AccountDeleted => "Account Deleted",
         };
 
         String::from(url)
@@ -113,11 +163,16 @@ impl DefaultSubject {
     pub fn from_template(template: Templates) -> String {
         match template {
             Templates::Passwordless => DefaultSubject::Passwordless.to_string(),
+            Templates::PasswordlessCode => DefaultSubject::PasswordlessCode.to_string(),
             Templates::PasswordReset => DefaultSubject::PasswordReset.to_string(),
             Templates::VerifyEmail => DefaultSubject::VerifyEmail.to_string(),
             Templates::ChangeEmail => DefaultSubject::ChangeEmail.to_string(),
             Templates::PasswordChanged => DefaultSubject::PasswordChanged.to_string(),
             Templates::ConfirmEmailChange => DefaultSubject::ConfirmEmailChange.to_string(),
+            Templates::OrganizationInvite => DefaultSubject::OrganizationInvite.to_string(),
+            Templates::AdminInvite => DefaultSubject::AdminInvite.to_string(),
+            Templates::Invite => DefaultSubject::Invite.to_string(),
+            Templates::AccountDeleted => DefaultSubject::AccountDeleted.to_string(),
         }
     }
 }
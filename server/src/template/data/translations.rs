@@ -123,4 +123,29 @@ impl Translations {
 
         serde_json::from_value(translation.content).map_err(|_| ApiError::InternalServerError)
     }
+
+    /// Like `get_by_languages`, `None` when the project has no translation
+    /// for the template
+    pub async fn find_by_languages(
+        pool: &PgPool,
+        project_id: &Uuid,
+        languages: &Vec<String>,
+        name: &str,
+    ) -> Result<Option<HashMap<String, String>>, ApiError> {
+        let translation = sqlx::query_file!(
+            "src/template/sql/find_translation_by_lang_code.sql",
+            project_id,
+            languages,
+            name,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        match translation {
+            None => Ok(None),
+            Some(translation) => serde_json::from_value(translation.content)
+                .map(Some)
+                .map_err(|_| ApiError::InternalServerError),
+        }
+    }
 }
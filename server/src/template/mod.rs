@@ -8,7 +8,7 @@ pub use config::{DefaultRedirect, DefaultSubject};
 pub use data::Translations;
 pub use template::{Template, TemplateCtx, TemplateResponse};
 
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 
 use rocket::http::Status;
 use rocket::serde::json::Json;
@@ -75,8 +75,9 @@ pub async fn set_template(pool: &Db, payload: SetTemplateView) -> Result<(), Api
 async fn set_template_handler(
     pool: Db,
     body: Json<SetTemplateView>,
-    _admin: Admin,
+    admin: Admin,
 ) -> Result<Status, ApiError> {
+    admin.require(&body.project_id, AdminRole::Editor)?;
     let body = body.into_inner();
     set_template(&pool, body).await?;
     Ok(Status::Ok)
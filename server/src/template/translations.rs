@@ -1,4 +1,4 @@
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 use crate::template::data::{DeleteTranslation, SetTranslation, Translations};
 
 use rocket::http::Status;
@@ -40,8 +40,9 @@ pub async fn set_translation(pool: &Db, data: SetTranslation) -> Result<(), ApiE
 pub async fn set_translation_handler(
     pool: Db,
     body: Json<SetTranslation>,
-    _admin: Admin,
+    admin: Admin,
 ) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
     set_translation(&pool, body.into_inner()).await?;
     Ok(Status::Ok)
 }
@@ -55,8 +56,9 @@ pub async fn delete_translation(pool: &Db, delete: DeleteTranslation) -> Result<
 pub async fn delete_translation_handler(
     pool: Db,
     body: Json<DeleteTranslation>,
-    _admin: Admin,
+    admin: Admin,
 ) -> Result<Status, ApiError> {
+    admin.require(&body.project, AdminRole::Editor)?;
     delete_translation(&pool, body.into_inner()).await?;
     Ok(Status::Ok)
 }
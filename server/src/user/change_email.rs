@@ -1,4 +1,5 @@
 use crate::crypto::Token;
+use crate::project::data::RedirectUrls;
 use crate::project::Project;
 use crate::session::data::AccessToken;
 use crate::settings::data::ProjectEmail;
@@ -21,6 +22,9 @@ use super::data::EmailChangeState;
 #[derive(Deserialize)]
 pub struct EmailChangeRequestPayload {
     pub new_email: String,
+
+    /// Link of the confirmation email, has to be on the project's allow-list
+    pub redirect_url: Option<String>,
 }
 
 pub async fn create_email_change_request(
@@ -28,11 +32,16 @@ pub async fn create_email_change_request(
     new_email: &str,
     user_id: Uuid,
     project_id: Uuid,
+    redirect_url: Option<&str>,
 ) -> Result<(), ApiError> {
+    RedirectUrls::verify(pool, &project_id, redirect_url).await?;
+
     let user = User::get_by_id(&pool, &user_id, &project_id)
         .await?
         .ok_or(ApiError::NotFound)?;
 
+    let old_email = user.email.clone().ok_or(ApiError::BadRequest)?;
+
     let token = Token::create();
     let hashed_token = Token::hash(&token)?;
 
@@ -42,7 +51,7 @@ pub async fn create_email_change_request(
     let change_request = NewChangeRequest {
         user_id,
         new_email: new_email.clone().to_string(),
-        old_email: user.email.clone(),
+        old_email: old_email.clone(),
         token: hashed_token,
         reset_token: hashed_reset_token,
     };
@@ -58,17 +67,14 @@ pub async fn create_email_change_request(
     let confirm_settings = settings?;
     let reset_settings = reset_settings?;
 
-    let confirm_link: String = format!(
-        "{}{}?id={}&token={}",
-        confirm_settings.domain, confirm_settings.redirect_to, request_id, token
-    );
+    let confirm_link = confirm_settings.link(redirect_url, &request_id, &token);
 
     let confirm_ctx = json!({
         "href": confirm_link,
         "project": confirm_settings.name.clone(),
         "user": Some(user.clone()),
         "expire_in": 15,
-        "old_email": user.email.clone(),
+        "old_email": old_email.clone(),
         "new_email": new_email.clone(),
     });
 
@@ -82,7 +88,7 @@ pub async fn create_email_change_request(
         "project": reset_settings.name.clone(),
         "user": Some(user.clone()),
         "expire_in": 15,
-        "old_email": user.email.clone(),
+        "old_email": old_email.clone(),
         "new_email": new_email.clone(),
     });
 
@@ -100,7 +106,7 @@ pub async fn create_email_change_request(
             &pool,
             &project_id,
             &user.device_languages,
-            &user.email,
+            &old_email,
             &reset_ctx,
             &reset_settings,
             Templates::ChangeEmail,
@@ -125,8 +131,17 @@ pub async fn create_email_change_request_handler(
     access_token: AccessToken,
     project: Project,
 ) -> Result<Status, ApiError> {
+    access_token.require_recent_auth(&pool, &project.id).await?;
+
     let user_id = access_token.sub();
-    create_email_change_request(&pool, &body.new_email, user_id, project.id).await?;
+    create_email_change_request(
+        &pool,
+        &body.new_email,
+        user_id,
+        project.id,
+        body.redirect_url.as_deref(),
+    )
+    .await?;
     Ok(Status::Ok)
 }
 
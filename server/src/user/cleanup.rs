@@ -0,0 +1,36 @@
+use crate::user::data::User;
+
+use rocket::fairing::AdHoc;
+use rocket::tokio::time::{interval, Duration};
+use sqlx::PgPool;
+use tracing::{error, info};
+
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Periodically removes deleted users once the project's
+/// `deletion_grace_days` have passed
+pub fn fairing() -> AdHoc {
+    AdHoc::on_liftoff("Deleted User Purge", |rocket| {
+        Box::pin(async move {
+            let pool = match rocket.state::<PgPool>() {
+                Some(pool) => pool.clone(),
+                None => {
+                    error!("Deleted user purge: database pool not found");
+                    return;
+                }
+            };
+
+            rocket::tokio::spawn(async move {
+                let mut timer = interval(CLEANUP_INTERVAL);
+                loop {
+                    timer.tick().await;
+                    match User::purge_deleted(&pool).await {
+                        Ok(0) => {}
+                        Ok(count) => info!("Purged {} deleted users", count),
+                        Err(err) => error!("Failed to purge deleted users: {:?}", err),
+                    }
+                }
+            });
+        })
+    })
+}
@@ -0,0 +1,8 @@
+#[derive(Debug)]
+pub struct AccountDeletion {
+    /// Days a deleted user can be restored before it is purged, `None` removes
+    /// users right away
+    pub grace_days: Option<i32>,
+    /// Send the user an email once the account was deleted
+    pub send_email: bool,
+}
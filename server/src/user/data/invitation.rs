@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{postgres::PgDatabaseError, Error, PgConnection, PgPool};
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+
+pub struct NewInvitedUser {
+    pub email: String,
+    pub display_name: Option<String>,
+    pub traits: Vec<String>,
+    pub user_metadata: Option<Value>,
+    pub app_metadata: Option<Value>,
+}
+
+pub struct UserInvitation {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token: String,
+    pub expire_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+pub struct PendingInvitation {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub email: Option<String>,
+    pub expire_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl UserInvitation {
+    /// Creates the user in the `set_password` state
+    pub async fn create_user(
+        pool: &PgPool,
+        user: &NewInvitedUser,
+        project_id: &Uuid,
+    ) -> Result<Uuid, ApiError> {
+        sqlx::query_file!(
+            "src/user/sql/invitation/create_user.sql",
+            user.email,
+            user.display_name,
+            &user.traits,
+            user.user_metadata,
+            user.app_metadata,
+            project_id,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|row| row.id)
+        .map_err(|err| match err {
+            Error::Database(err) => {
+                let err = err.downcast::<PgDatabaseError>();
+                match err.constraint() {
+                    Some("users_project_id_fkey") => ApiError::ProjectNotFound,
+                    Some("users_project_id_email_key") => ApiError::UserExists,
+                    _ => ApiError::InternalServerError,
+                }
+            }
+            _ => ApiError::InternalServerError,
+        })
+    }
+
+    /// A new invitation replaces the pending one of the user
+    pub async fn upsert(
+        pool: &PgPool,
+        user_id: &Uuid,
+        project_id: &Uuid,
+        hashed_token: &str,
+        invited_by: &Uuid,
+        expire_at: &DateTime<Utc>,
+    ) -> sqlx::Result<Uuid> {
+        sqlx::query_file!(
+            "src/user/sql/invitation/upsert.sql",
+            user_id,
+            project_id,
+            hashed_token,
+            invited_by,
+            expire_at,
+        )
+        .fetch_one(pool)
+        .await
+        .map(|row| row.id)
+    }
+
+    pub async fn get(
+        pool: &PgPool,
+        id: &Uuid,
+        project_id: &Uuid,
+    ) -> sqlx::Result<Option<UserInvitation>> {
+        sqlx::query_file_as!(
+            UserInvitation,
+            "src/user/sql/invitation/get.sql",
+            id,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn list(pool: &PgPool, project_id: &Uuid) -> sqlx::Result<Vec<PendingInvitation>> {
+        sqlx::query_file_as!(
+            PendingInvitation,
+            "src/user/sql/invitation/list.sql",
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Returns `false` when the invitation was already used
+    pub async fn claim(conn: &mut PgConnection, id: &Uuid) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/user/sql/invitation/claim.sql", id)
+            .execute(conn)
+            .await
+            .map(|result| result.rows_affected() == 1)
+    }
+
+    /// Activates the user and marks the email address as verified, the
+    /// invitation was sent to it. Returns `false` when the user is no longer
+    /// waiting for the invitation
+    pub async fn accept(
+        conn: &mut PgConnection,
+        user_id: &Uuid,
+        provider: &str,
+        device_languages: &Vec<String>,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_file!(
+            "src/user/sql/invitation/accept.sql",
+            user_id,
+            provider,
+            device_languages,
+        )
+        .execute(conn)
+        .await
+        .map(|result| result.rows_affected() == 1)
+    }
+
+    /// Removes the invitation and the user when it never signed in,
+    /// returns `None` when the invitation doesn't exist
+    pub async fn revoke(pool: &PgPool, id: &Uuid, project_id: &Uuid) -> sqlx::Result<Option<Uuid>> {
+        sqlx::query_file!("src/user/sql/invitation/revoke.sql", id, project_id)
+            .fetch_optional(pool)
+            .await
+            .map(|row| row.map(|row| row.user_id))
+    }
+}
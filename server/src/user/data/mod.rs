@@ -1,8 +1,12 @@
+mod deletion;
 mod email;
+mod invitation;
 mod user;
 
+pub use deletion::AccountDeletion;
 pub use email::{EmailChangeRequest, EmailChangeState, NewChangeRequest};
+pub use invitation::{NewInvitedUser, PendingInvitation, UserInvitation};
 pub use user::{
-    Cursor, ParamError, PartialUser, SearchUser, SortDirection, TotalUsers, UpdateUser, User,
-    UserOrder, UserProvider, UserState,
+    Cursor, ParamError, PartialUser, SearchUser, SortDirection, TotalUsers, UpdateProfile,
+    UpdateUser, UpdatedUser, UpgradeAnonymous, User, UserOrder, UserProvider, UserState,
 };
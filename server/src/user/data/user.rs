@@ -50,39 +50,59 @@ pub enum UserState {
     Active,
     Disabled,
     SetPassword,
+    /// Waits to be purged after the project's deletion grace period
+    Deleted,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct User {
     pub id: Uuid,
     pub display_name: Option<String>,
-    pub email: String,
+    pub email: Option<String>,
     pub email_verified: bool,
+    pub phone: Option<String>,
+    pub phone_verified: bool,
     pub photo_url: Option<String>,
     pub traits: Vec<String>,
-    pub data: Value,
+    /// Profile data the user can edit
+    pub user_metadata: Value,
+    /// Data that can only be changed by admins and the server
+    pub app_metadata: Value,
     pub provider_id: String,
+    pub providers: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub state: UserState,
     pub device_languages: Vec<String>,
 }
 
+/// Admin and API key update, covers all user fields
 #[derive(Deserialize)]
 pub struct UpdateUser {
     pub display_name: Option<String>,
-    pub email: String,
+    pub email: Option<String>,
     pub traits: Vec<String>,
-    pub data: Value,
+    pub user_metadata: Value,
+    pub app_metadata: Value,
+}
+
+/// Self-service update, traits and `app_metadata` are rejected. The email
+/// can only be changed with `user::change_email`
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateProfile {
+    pub display_name: Option<String>,
+    pub user_metadata: Value,
 }
 
 pub struct UpdatedUser {
     pub id: Uuid,
     pub display_name: Option<String>,
-    pub email: String,
+    pub email: Option<String>,
     pub email_verified: bool,
     pub traits: Vec<String>,
-    pub data: Value,
+    pub user_metadata: Value,
+    pub app_metadata: Value,
 }
 
 pub struct UserProvider {
@@ -93,7 +113,24 @@ pub struct UserProvider {
     pub device_languages: Vec<String>,
 }
 
+pub struct UpgradeAnonymous {
+    pub email: String,
+    pub email_verified: bool,
+    pub provider_id: String,
+    pub display_name: Option<String>,
+    pub photo_url: Option<String>,
+}
+
 impl User {
+    pub fn is_anonymous(&self) -> bool {
+        self.provider_id == "anonymous"
+    }
+
+    /// Disabled and deleted users can't sign in
+    pub fn is_disabled(&self) -> bool {
+        matches!(self.state, UserState::Disabled | UserState::Deleted)
+    }
+
     pub async fn update(
         pool: &PgPool,
         user_id: &Uuid,
@@ -106,7 +143,24 @@ impl User {
             user.display_name,
             user.email,
             &user.traits,
-            user.data
+            user.user_metadata,
+            user.app_metadata,
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn update_profile(
+        pool: &PgPool,
+        user_id: &Uuid,
+        profile: &UpdateProfile,
+    ) -> sqlx::Result<UpdatedUser> {
+        sqlx::query_file_as!(
+            UpdatedUser,
+            "src/user/sql/set_profile.sql",
+            user_id,
+            profile.display_name,
+            profile.user_metadata,
         )
         .fetch_one(pool)
         .await
@@ -122,6 +176,16 @@ impl User {
             .await
     }
 
+    pub async fn get_by_phone(
+        pool: &PgPool,
+        phone: &str,
+        project: &Uuid,
+    ) -> sqlx::Result<Option<User>> {
+        sqlx::query_file_as!(User, "src/user/sql/get_by_phone.sql", phone, project)
+            .fetch_optional(pool)
+            .await
+    }
+
     pub async fn get_by_id(pool: &PgPool, id: &Uuid, project: &Uuid) -> sqlx::Result<Option<User>> {
         sqlx::query_file_as!(User, "src/user/sql/get_by_id.sql", id, project)
             .fetch_optional(pool)
@@ -178,6 +242,93 @@ impl User {
         .await
     }
 
+    pub async fn create_phone(
+        pool: &PgPool,
+        phone: &str,
+        project: &Uuid,
+        languages: &Vec<String>,
+    ) -> sqlx::Result<User> {
+        sqlx::query_file_as!(
+            User,
+            "src/user/sql/create_phone.sql",
+            phone,
+            project,
+            languages,
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn verify_phone(pool: &PgPool, user_id: &Uuid, phone: &str) -> sqlx::Result<()> {
+        sqlx::query_file!("src/user/sql/verify_phone.sql", user_id, phone)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_anonymous(
+        pool: &PgPool,
+        project: &Uuid,
+        languages: &Vec<String>,
+    ) -> sqlx::Result<User> {
+        sqlx::query_file_as!(
+            User,
+            "src/user/sql/create_anonymous.sql",
+            project,
+            languages,
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Returns `None` when the user is not an anonymous user (anymore)
+    pub async fn upgrade_anonymous(
+        pool: &PgPool,
+        user_id: &Uuid,
+        upgrade: &UpgradeAnonymous,
+    ) -> sqlx::Result<Option<User>> {
+        sqlx::query_file_as!(
+            User,
+            "src/user/sql/upgrade_anonymous.sql",
+            user_id,
+            upgrade.email,
+            upgrade.email_verified,
+            upgrade.provider_id,
+            upgrade.display_name,
+            upgrade.photo_url,
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn add_provider(pool: &PgPool, user_id: &Uuid, provider: &str) -> sqlx::Result<()> {
+        sqlx::query_file!("src/user/sql/add_provider.sql", user_id, provider)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns `false` when the provider is not linked or is the last one
+    pub async fn remove_provider(
+        pool: &PgPool,
+        user_id: &Uuid,
+        provider: &str,
+    ) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/user/sql/remove_provider.sql", user_id, provider)
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected() > 0)
+    }
+
+    pub async fn remove_stale_anonymous(pool: &PgPool) -> sqlx::Result<u64> {
+        sqlx::query_file!("src/user/sql/remove_stale_anonymous.sql")
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected())
+    }
+
     pub async fn create_provider(
         pool: &PgPool,
         user: &UserProvider,
@@ -241,6 +392,42 @@ impl User {
         Ok(())
     }
 
+    /// Marks the user as deleted until the user is purged, also removes the
+    /// sessions and API keys and revokes the access tokens of the user
+    pub async fn soft_delete(pool: &PgPool, user_id: &Uuid) -> sqlx::Result<()> {
+        sqlx::query_file!("src/user/sql/soft_delete_user.sql", user_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns false when the user isn't deleted
+    pub async fn restore(pool: &PgPool, user_id: &Uuid) -> sqlx::Result<bool> {
+        sqlx::query_file!("src/user/sql/restore_user.sql", user_id)
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected() > 0)
+    }
+
+    /// Removes deleted users whose project's grace period has passed
+    pub async fn purge_deleted(pool: &PgPool) -> sqlx::Result<u64> {
+        sqlx::query_file!("src/user/sql/purge_deleted.sql")
+            .execute(pool)
+            .await
+            .map(|result| result.rows_affected())
+    }
+
+    /// Rejects all access tokens of the user that were issued until now
+    pub async fn revoke_tokens(pool: &PgPool, user: &Uuid) -> sqlx::Result<()> {
+        sqlx::query_file!("src/user/sql/revoke_tokens.sql", user)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Also removes the sessions and revokes the access tokens of the user
     pub async fn disable(pool: &PgPool, user: &Uuid, project: &Uuid) -> sqlx::Result<()> {
         sqlx::query_file!("src/user/sql/disable_user.sql", user, project)
             .execute(pool)
@@ -321,9 +508,11 @@ impl FromStr for UserOrder {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PartialUser {
     pub id: Uuid,
-    pub email: String,
+    pub email: Option<String>,
     pub email_verified: bool,
+    pub phone: Option<String>,
     pub provider_id: String,
+    pub providers: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub state: UserState,
 }
@@ -1,16 +1,83 @@
-use crate::admin::data::Admin;
-use crate::session::data::{RefreshAccessToken, Session};
-use crate::user::data::User;
+use crate::admin::data::{Admin, AdminRole};
+use crate::project::data::Project;
+use crate::session::data::{require_recent_auth, RefreshAccessToken, Session};
+use crate::settings::data::ProjectEmail;
+use crate::template::{Template, Templates};
+use crate::user::data::{User, UserState};
 
 use rocket;
 use rocket::http::Status;
 use rocket::serde::json::Json;
 use rocket::serde::uuid::Uuid;
+use serde_json::json;
+use tracing::error;
 use vulpo_auth_types::error::ApiError;
 use werkbank::rocket::Db;
 
+/// Removes the user, projects with a deletion grace period keep the user as
+/// deleted until the user is purged. Deleting a deleted user removes it right away.
 pub async fn delete_account(pool: &Db, user_id: Uuid) -> Result<(), ApiError> {
-    User::remove(&pool, &user_id).await?;
+    let project_id = User::project(pool, &user_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let user = User::get_by_id(pool, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    let settings = Project::account_deletion(pool, &project_id).await?;
+
+    let grace_days = match settings.grace_days {
+        Some(grace_days) if user.state != UserState::Deleted => grace_days,
+        _ => {
+            User::remove(pool, &user_id).await?;
+            return Ok(());
+        }
+    };
+
+    User::soft_delete(pool, &user_id).await?;
+
+    if settings.send_email {
+        // the account is deleted either way
+        if let Err(err) = deletion_email(pool, &project_id, user, grace_days).await {
+            error!("Failed to send account deletion email: {:?}", err);
+        }
+    }
+
+    Ok(())
+}
+
+async fn deletion_email(
+    pool: &Db,
+    project_id: &Uuid,
+    user: User,
+    grace_days: i32,
+) -> Result<(), ApiError> {
+    let to_email = match user.email {
+        Some(email) => email,
+        None => return Ok(()),
+    };
+
+    let settings =
+        ProjectEmail::from_project_template(pool, project_id, Templates::AccountDeleted).await?;
+
+    let ctx = json!({
+        "project": settings.name.clone(),
+        "grace_days": grace_days,
+    });
+
+    let email = Template::create_email(
+        pool,
+        project_id,
+        &user.device_languages,
+        &to_email,
+        &ctx,
+        &settings,
+        Templates::AccountDeleted,
+    )
+    .await?;
+
+    email.send(settings.email).await?;
     Ok(())
 }
 
@@ -18,12 +85,32 @@ pub async fn delete_account(pool: &Db, user_id: Uuid) -> Result<(), ApiError> {
 pub async fn admin_delete_account_handler(
     pool: Db,
     user_id: Uuid,
-    _admin: Admin,
+    admin: Admin,
 ) -> Result<Status, ApiError> {
+    admin
+        .require_user(&pool, &user_id, AdminRole::Editor)
+        .await?;
     delete_account(&pool, user_id).await?;
     Ok(Status::Ok)
 }
 
+#[post("/admin/restore_account/<user_id>")]
+pub async fn admin_restore_account_handler(
+    pool: Db,
+    user_id: Uuid,
+    admin: Admin,
+) -> Result<Status, ApiError> {
+    admin
+        .require_user(&pool, &user_id, AdminRole::Editor)
+        .await?;
+
+    if !User::restore(&pool, &user_id).await? {
+        return Err(ApiError::NotFound);
+    }
+
+    Ok(Status::Ok)
+}
+
 pub async fn delete_account_by_session(
     pool: &Db,
     session_id: Uuid,
@@ -38,10 +125,12 @@ pub async fn delete_account_by_session(
         return Err(ApiError::Forbidden);
     }
 
+    let auth_time = session.auth_time.map(|auth_time| auth_time.timestamp());
+    require_recent_auth(pool, &session.project_id, auth_time).await?;
+
     let user_id = session.user_id.ok_or(ApiError::BadRequest)?;
 
-    User::remove(&pool, &user_id).await?;
-    Ok(())
+    delete_account(pool, user_id).await
 }
 
 #[post("/delete_account/<session_id>", format = "json", data = "<rat>")]
@@ -1,4 +1,4 @@
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 use crate::user::data::User;
 
 use rocket::http::Status;
@@ -26,7 +26,10 @@ pub async fn disable(pool: &Db, body: Disable) -> Result<(), ApiError> {
 }
 
 #[post("/disable", format = "json", data = "<body>")]
-pub async fn handler(pool: Db, body: Json<Disable>, _admin: Admin) -> Result<Status, ApiError> {
+pub async fn handler(pool: Db, body: Json<Disable>, admin: Admin) -> Result<Status, ApiError> {
+    admin
+        .require_user(&pool, &body.user, AdminRole::Editor)
+        .await?;
     disable(&pool, body.into_inner()).await?;
     Ok(Status::Ok)
 }
@@ -0,0 +1,317 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::config::Secrets;
+use crate::crypto::Token;
+use crate::keys::data::ProjectKeys;
+use crate::password::data::Password;
+use crate::password::validate_password_length;
+use crate::project::data::{Flags, Project as ProjectData, RedirectUrls};
+use crate::project::Project;
+use crate::role::data::Permission;
+use crate::session::cookie::SessionCookies;
+use crate::session::data::{AccessToken, AuthMethod, Session, SessionMetadata};
+use crate::settings::data::ProjectEmail;
+use crate::template::{Template, Templates};
+use crate::user::data::{NewInvitedUser, PendingInvitation, User, UserInvitation, UserState};
+use crate::user::verify_email::enforce_email_verification;
+
+use chrono::{Duration, Utc};
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use vulpo_auth_types::session::SessionResponse;
+use werkbank::rocket::{Cache, Db};
+
+const EXPIRE_IN_DAYS: i64 = 7;
+
+#[derive(Deserialize)]
+pub struct InviteUser {
+    pub project_id: Uuid,
+    pub email: String,
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub traits: Vec<String>,
+    pub user_metadata: Option<Value>,
+    pub app_metadata: Option<Value>,
+
+    /// Link of the invitation email, has to be on the project's allow-list
+    pub redirect_url: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct InviteResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+}
+
+/// Creates the user in the `set_password` state and sends the invitation.
+/// Users that were invited or created by an admin but never signed in get a
+/// new invitation, their traits and data are kept.
+pub async fn invite(
+    pool: &Db,
+    invited_by: &Uuid,
+    body: InviteUser,
+) -> Result<InviteResponse, ApiError> {
+    let project_id = body.project_id;
+    let email = body.email.trim().to_lowercase();
+
+    let user = match User::get_by_email(&pool, &email, &project_id).await? {
+        Some(user) if user.state == UserState::SetPassword => user,
+        Some(_) => return Err(ApiError::UserExists),
+        None => {
+            let new_user = NewInvitedUser {
+                email: email.clone(),
+                display_name: body.display_name,
+                traits: body.traits,
+                user_metadata: body.user_metadata,
+                app_metadata: body.app_metadata,
+            };
+
+            let user_id = UserInvitation::create_user(&pool, &new_user, &project_id).await?;
+            User::get_by_id(&pool, &user_id, &project_id)
+                .await?
+                .ok_or(ApiError::NotFound)?
+        }
+    };
+
+    let token = Token::create();
+    let hashed_token = Token::hash(&token)?;
+    let expire_at = Utc::now() + Duration::days(EXPIRE_IN_DAYS);
+
+    let id = UserInvitation::upsert(
+        &pool,
+        &user.id,
+        &project_id,
+        &hashed_token,
+        invited_by,
+        &expire_at,
+    )
+    .await?;
+
+    let settings =
+        ProjectEmail::from_project_template(&pool, &project_id, Templates::Invite).await?;
+    let link = settings.link(body.redirect_url.as_deref(), &id, &token);
+
+    let ctx = json!({
+        "href": link,
+        "project": settings.name.clone(),
+        "expire_in": EXPIRE_IN_DAYS,
+    });
+
+    let email = Template::create_email(
+        &pool,
+        &project_id,
+        &user.device_languages,
+        &email,
+        &ctx,
+        &settings,
+        Templates::Invite,
+    )
+    .await?;
+
+    email.send(settings.email).await?;
+
+    Ok(InviteResponse {
+        id,
+        user_id: user.id,
+    })
+}
+
+#[post("/invite", format = "json", data = "<body>")]
+pub async fn handler(
+    pool: Db,
+    body: Json<InviteUser>,
+    admin: Admin,
+) -> Result<Json<InviteResponse>, ApiError> {
+    admin
+        .require_users(&pool, &body.project_id, AdminRole::Editor)
+        .await?;
+
+    // traits can grant access, e.g. the `Admin` trait
+    if !body.traits.is_empty() {
+        admin.require_super_admin()?;
+    }
+
+    RedirectUrls::verify(&pool, &body.project_id, body.redirect_url.as_deref()).await?;
+    let response = invite(&pool, &admin.user_id(), body.into_inner()).await?;
+    Ok(Json(response))
+}
+
+#[get("/invitations?<project>")]
+pub async fn list_handler(
+    pool: Db,
+    project: Uuid,
+    admin: Admin,
+) -> Result<Json<Vec<PendingInvitation>>, ApiError> {
+    admin.require(&project, AdminRole::Viewer)?;
+    let invitations = UserInvitation::list(&pool, &project).await?;
+    Ok(Json(invitations))
+}
+
+#[derive(Deserialize)]
+pub struct RevokeInvitation {
+    pub id: Uuid,
+    pub project_id: Uuid,
+}
+
+#[post("/invite/revoke", format = "json", data = "<body>")]
+pub async fn revoke_handler(
+    pool: Db,
+    body: Json<RevokeInvitation>,
+    admin: Admin,
+) -> Result<Status, ApiError> {
+    admin
+        .require_users(&pool, &body.project_id, AdminRole::Editor)
+        .await?;
+    UserInvitation::revoke(&pool, &body.id, &body.project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    Ok(Status::Ok)
+}
+
+#[derive(Deserialize)]
+pub struct AcceptInvitation {
+    pub id: Uuid,
+    pub token: String,
+
+    /// Users without a password sign in with authentication links
+    pub password: Option<String>,
+    pub session: Uuid,
+    pub public_key: Vec<u8>,
+    #[serde(default)]
+    pub device_languages: Vec<String>,
+}
+
+pub async fn accept(
+    cache: &Cache,
+    pool: &Db,
+    body: AcceptInvitation,
+    project_id: Uuid,
+    passphrase: &str,
+    metadata: &SessionMetadata,
+) -> Result<SessionResponse, ApiError> {
+    let method = match body.password {
+        Some(_) => Flags::EmailAndPassword,
+        None => Flags::AuthenticationLink,
+    };
+
+    Flags::has_flags(&pool, &project_id, &[Flags::SignIn, method]).await?;
+    AccessToken::check_binding(pool, &project_id, &body.public_key).await?;
+
+    if let Some(password) = &body.password {
+        validate_password_length(password)?;
+    }
+
+    let invitation = UserInvitation::get(&pool, &body.id, &project_id)
+        .await?
+        .ok_or(ApiError::TokenNotFound)?;
+
+    if !Token::verify(&body.token, &invitation.token)? {
+        return Err(ApiError::TokenInvalid);
+    }
+
+    if Utc::now() > invitation.expire_at {
+        return Err(ApiError::TokenExpired);
+    }
+
+    let user = User::get_by_id(&pool, &invitation.user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if user.is_disabled() {
+        return Err(ApiError::UserDisabled);
+    }
+
+    let provider = if body.password.is_some() {
+        "password"
+    } else {
+        "link"
+    };
+
+    // the invitation stays valid when the user can't be activated
+    let mut tx = pool.begin().await?;
+
+    if !UserInvitation::claim(&mut tx, &invitation.id).await? {
+        return Err(ApiError::TokenNotFound);
+    }
+
+    if !UserInvitation::accept(&mut tx, &user.id, provider, &body.device_languages).await? {
+        return Err(ApiError::TokenInvalid);
+    }
+
+    if let Some(password) = &body.password {
+        let alg = ProjectData::password_alg(&pool, &project_id).await?;
+        Password::set_password(&mut tx, &user.id, password, &alg, &project_id).await?;
+    }
+
+    tx.commit().await?;
+
+    let user = User {
+        email_verified: true,
+        ..user
+    };
+
+    let email_verified = enforce_email_verification(&pool, &user, &project_id).await?;
+
+    let session = Session {
+        id: body.session,
+        public_key: body.public_key.to_owned(),
+        user_id: Some(user.id),
+        expire_at: Utc::now() + Duration::days(30),
+        project_id,
+        auth_time: Some(Utc::now()),
+        auth_methods: vec![AuthMethod::Link.to_string()],
+    };
+
+    let session = Session::create(&pool, session, metadata).await?;
+
+    let private_key = ProjectKeys::get_private_key(&cache, &pool, &project_id, passphrase).await?;
+    let permissions = Permission::for_user(&pool, &user.id).await?;
+    let exp = Utc::now() + Duration::minutes(15);
+    let access_token = AccessToken::new(&user.id, &user.traits, exp)
+        .with_permissions(permissions)
+        .with_email_verified(email_verified)
+        .with_auth(&session)
+        .bind_to(&pool, &session)
+        .await?
+        .to_jwt(&project_id, &private_key)
+        .map_err(|_| ApiError::InternalServerError)?;
+
+    Ok(SessionResponse {
+        access_token,
+        created: true,
+        user_id: user.id,
+        session: session.id,
+        expire_at: session.expire_at,
+    })
+}
+
+#[post("/invitation/accept", format = "json", data = "<body>")]
+pub async fn accept_handler(
+    pool: Db,
+    body: Json<AcceptInvitation>,
+    project: Project,
+    secrets: &State<Secrets>,
+    cache: Cache,
+    metadata: SessionMetadata,
+    cookies: SessionCookies<'_>,
+) -> Result<SessionResponse, ApiError> {
+    let mut session = accept(
+        &cache,
+        &pool,
+        body.into_inner(),
+        project.id,
+        &secrets.passphrase,
+        &metadata,
+    )
+    .await?;
+
+    cookies
+        .set(&cache, &pool, &project.id, &mut session)
+        .await?;
+    Ok(session)
+}
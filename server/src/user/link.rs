@@ -0,0 +1,156 @@
+use crate::oauth::data::OAuthData;
+use crate::oauth::google::{get_authorize_url, GetAuthUrlPayload, GetAuthUrlResponse};
+use crate::password::data::Password;
+use crate::password::validate_password_length;
+use crate::project::data::Project as ProjectData;
+use crate::project::data::{Flags, RedirectUrls};
+use crate::project::Project;
+use crate::session::data::AccessToken;
+use crate::user::data::User;
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use uuid::Uuid;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+const PASSWORD: &'static str = "password";
+const GOOGLE: &'static str = "google";
+
+async fn get_user(pool: &Db, user_id: &Uuid, project_id: &Uuid) -> Result<User, ApiError> {
+    let user = User::get_by_id(&pool, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if user.is_disabled() {
+        return Err(ApiError::UserDisabled);
+    }
+
+    Ok(user)
+}
+
+#[derive(Deserialize)]
+pub struct LinkPassword {
+    pub password: String,
+}
+
+pub async fn link_password(
+    pool: &Db,
+    user_id: Uuid,
+    project_id: Uuid,
+    body: LinkPassword,
+) -> Result<(), ApiError> {
+    validate_password_length(&body.password)?;
+
+    let user = get_user(&pool, &user_id, &project_id).await?;
+
+    // anonymous users are upgraded through /anonymous/link/password
+    if user.is_anonymous() {
+        return Err(ApiError::Forbidden);
+    }
+
+    if user.email.is_none() {
+        return Err(ApiError::BadRequest);
+    }
+
+    if user.providers.iter().any(|provider| provider == PASSWORD) {
+        return Err(ApiError::UserProviderLinked);
+    }
+
+    let alg = ProjectData::password_alg(&pool, &project_id).await?;
+    Password::create_password(&pool, &user.id, &body.password, &alg, &project_id).await?;
+    User::add_provider(&pool, &user.id, PASSWORD).await?;
+
+    Ok(())
+}
+
+#[post("/link/password", format = "json", data = "<body>")]
+pub async fn password_handler(
+    pool: Db,
+    body: Json<LinkPassword>,
+    project: Project,
+    token: AccessToken,
+) -> Result<Status, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::EmailAndPassword]).await?;
+    link_password(&pool, token.sub(), project.id, body.into_inner()).await?;
+    Ok(Status::Ok)
+}
+
+#[post("/link/google/authorize_url", format = "json", data = "<body>")]
+pub async fn google_handler(
+    pool: Db,
+    body: Json<GetAuthUrlPayload>,
+    project: Project,
+    token: AccessToken,
+) -> Result<Json<GetAuthUrlResponse>, ApiError> {
+    Flags::has_flags(&pool, &project.id, &[Flags::OAuthGoogle]).await?;
+
+    let user = get_user(&pool, &token.sub(), &project.id).await?;
+
+    if user.providers.iter().any(|provider| provider == GOOGLE) {
+        return Err(ApiError::UserProviderLinked);
+    }
+
+    RedirectUrls::verify(&pool, &project.id, body.redirect_url.as_deref()).await?;
+    let url = get_authorize_url(
+        &pool,
+        body.request_id,
+        &project.id,
+        Some(user.id),
+        body.redirect_url.as_deref(),
+    )
+    .await?;
+    Ok(Json(GetAuthUrlResponse { url }))
+}
+
+#[derive(Deserialize)]
+pub struct Unlink {
+    pub provider: String,
+}
+
+pub async fn unlink(
+    pool: &Db,
+    user_id: Uuid,
+    project_id: Uuid,
+    body: Unlink,
+) -> Result<(), ApiError> {
+    let user = get_user(&pool, &user_id, &project_id).await?;
+    let provider = body.provider.as_str();
+
+    if user.is_anonymous() {
+        return Err(ApiError::Forbidden);
+    }
+
+    // only providers that store a credential can be unlinked
+    if provider != PASSWORD && provider != GOOGLE {
+        return Err(ApiError::BadRequest);
+    }
+
+    if !user.providers.iter().any(|p| p == provider) {
+        return Err(ApiError::NotFound);
+    }
+
+    let removed = User::remove_provider(&pool, &user.id, provider).await?;
+    if !removed {
+        return Err(ApiError::UserLastProvider);
+    }
+
+    match provider {
+        PASSWORD => Password::remove(&pool, &user.id).await?,
+        _ => OAuthData::remove(&pool, &user.id, provider).await?,
+    };
+
+    Ok(())
+}
+
+#[post("/unlink", format = "json", data = "<body>")]
+pub async fn unlink_handler(
+    pool: Db,
+    body: Json<Unlink>,
+    project: Project,
+    token: AccessToken,
+) -> Result<Status, ApiError> {
+    unlink(&pool, token.sub(), project.id, body.into_inner()).await?;
+    Ok(Status::Ok)
+}
@@ -1,16 +1,22 @@
 use rocket::Route;
 
 mod change_email;
+mod cleanup;
 pub mod data;
 mod delete_account;
 mod disable;
 mod get;
+mod invite;
+mod link;
 mod list;
+mod sessions;
 mod set_password;
 mod sign_out;
 mod update;
 pub mod verify_email;
 
+pub use cleanup::fairing as cleanup;
+
 pub fn routes() -> Vec<Route> {
     routes![
         get::handler,
@@ -20,14 +26,27 @@ pub fn routes() -> Vec<Route> {
         sign_out::sign_out_handler,
         sign_out::sign_out_all_handler,
         sign_out::admin_sign_out_handler,
+        sessions::list_handler,
+        sessions::admin_list_handler,
+        sessions::revoke_handler,
+        sessions::admin_revoke_handler,
         delete_account::admin_delete_account_handler,
+        delete_account::admin_restore_account_handler,
         delete_account::delete_account_handler,
         verify_email::handler,
         verify_email::admin,
+        verify_email::resend_handler,
         disable::handler,
+        invite::handler,
+        invite::list_handler,
+        invite::revoke_handler,
+        invite::accept_handler,
         update::handler,
         update::admin_handler,
         set_password::handler,
+        link::password_handler,
+        link::google_handler,
+        link::unlink_handler,
         change_email::create_email_change_request_handler,
         change_email::confirm_email_change_handler,
         change_email::reset_email_change_handler,
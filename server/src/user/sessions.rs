@@ -0,0 +1,96 @@
+use crate::admin::data::{Admin, AdminRole};
+use crate::project::Project;
+use crate::session::data::{AccessToken, Session, SessionInfo};
+
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::serde::uuid::Uuid;
+use serde::Deserialize;
+use vulpo_auth_types::error::ApiError;
+use werkbank::rocket::Db;
+
+pub async fn list_sessions(
+    pool: &Db,
+    user_id: &Uuid,
+    project_id: &Uuid,
+) -> Result<Vec<SessionInfo>, ApiError> {
+    let sessions = Session::list(pool, user_id, project_id).await?;
+    Ok(sessions)
+}
+
+#[get("/sessions")]
+pub async fn list_handler(
+    pool: Db,
+    access_token: AccessToken,
+    project: Project,
+) -> Result<Json<Vec<SessionInfo>>, ApiError> {
+    let user_id = access_token.sub();
+    let sessions = list_sessions(&pool, &user_id, &project.id).await?;
+    Ok(Json(sessions))
+}
+
+#[get("/admin/sessions?<user>&<project>")]
+pub async fn admin_list_handler(
+    pool: Db,
+    user: Uuid,
+    project: Uuid,
+    admin: Admin,
+) -> Result<Json<Vec<SessionInfo>>, ApiError> {
+    admin.require(&project, AdminRole::Viewer)?;
+    let sessions = list_sessions(&pool, &user, &project).await?;
+    Ok(Json(sessions))
+}
+
+/// Signs out one of the user's devices. The device's access token stays
+/// valid until it expires.
+pub async fn revoke_session(
+    pool: &Db,
+    session_id: &Uuid,
+    user_id: &Uuid,
+    project_id: &Uuid,
+) -> Result<(), ApiError> {
+    let deleted = Session::delete_for_user(pool, session_id, user_id, project_id).await?;
+
+    if !deleted {
+        return Err(ApiError::NotFound);
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+pub struct RevokeSession {
+    pub id: Uuid,
+}
+
+#[post("/sessions/revoke", format = "json", data = "<body>")]
+pub async fn revoke_handler(
+    pool: Db,
+    access_token: AccessToken,
+    project: Project,
+    body: Json<RevokeSession>,
+) -> Result<Status, ApiError> {
+    let user_id = access_token.sub();
+    revoke_session(&pool, &body.id, &user_id, &project.id).await?;
+    Ok(Status::Ok)
+}
+
+#[derive(Deserialize)]
+pub struct AdminRevokeSession {
+    pub id: Uuid,
+    pub user: Uuid,
+    pub project: Uuid,
+}
+
+#[post("/admin/sessions/revoke", format = "json", data = "<body>")]
+pub async fn admin_revoke_handler(
+    pool: Db,
+    admin: Admin,
+    body: Json<AdminRevokeSession>,
+) -> Result<Status, ApiError> {
+    admin
+        .require_users(&pool, &body.project, AdminRole::Editor)
+        .await?;
+    revoke_session(&pool, &body.id, &body.user, &body.project).await?;
+    Ok(Status::Ok)
+}
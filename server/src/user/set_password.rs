@@ -36,7 +36,9 @@ pub async fn set_password(
     password::validate_password_length(&body.password)?;
 
     let alg = ProjectData::password_alg(&pool, &project_id).await?;
-    Password::set_password(&pool, &user_id, &body.password, &alg, &project_id).await?;
+    let mut conn = pool.acquire().await?;
+    Password::set_password(&mut conn, &user_id, &body.password, &alg, &project_id).await?;
+    User::revoke_tokens(&pool, &user_id).await?;
 
     let settings =
         ProjectEmail::from_project_template(&pool, &project_id, Templates::PasswordReset).await;
@@ -52,12 +54,13 @@ pub async fn set_password(
 
     let settings = settings.unwrap();
 
+    let user_email = user.email.clone().ok_or(ApiError::BadRequest)?;
+
     let link: String = format!(
         "{}{}?email={}",
-        settings.domain, settings.redirect_to, user.email
+        settings.domain, settings.redirect_to, user_email
     );
 
-    let user_email = user.email.clone();
     let device_languages = user.device_languages.clone();
 
     let ctx = TemplateCtx {
@@ -90,6 +93,8 @@ pub async fn handler(
     token: AccessToken,
     body: Json<Payload>,
 ) -> Result<Status, ApiError> {
+    token.require_recent_auth(&pool, &project.id).await?;
+
     let user_id = token.sub();
     set_password(&pool, user_id, project.id, body.into_inner()).await?;
     Ok(Status::Ok)
@@ -1,4 +1,4 @@
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 use crate::project::Project;
 use crate::session::data::{RefreshAccessToken, Session};
 
@@ -54,11 +54,10 @@ pub async fn admin_sign_out(pool: &Db, user_id: Uuid) -> Result<(), ApiError> {
 }
 
 #[post("/admin/sign_out/<user_id>")]
-pub async fn admin_sign_out_handler(
-    pool: Db,
-    user_id: Uuid,
-    _admin: Admin,
-) -> Result<(), ApiError> {
+pub async fn admin_sign_out_handler(pool: Db, user_id: Uuid, admin: Admin) -> Result<(), ApiError> {
+    admin
+        .require_user(&pool, &user_id, AdminRole::Editor)
+        .await?;
     admin_sign_out(&pool, user_id).await?;
     Ok(())
 }
@@ -0,0 +1,4 @@
+update users
+   set providers = array_append(providers, $2)
+ where id = $1
+   and not ($2 = any(providers))
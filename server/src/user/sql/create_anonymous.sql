@@ -0,0 +1,18 @@
+insert into users(project_id, provider_id, traits, device_languages)
+values($1, 'anonymous', '{anonymous}', $2)
+returning id
+        , display_name
+        , email
+        , email_verified
+        , phone
+        , phone_verified
+        , photo_url
+        , traits
+        , user_metadata
+        , app_metadata
+        , provider_id
+        , providers
+        , created_at
+        , updated_at
+        , state as "state: UserState"
+        , device_languages
\ No newline at end of file
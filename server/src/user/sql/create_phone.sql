@@ -0,0 +1,18 @@
+insert into users(phone, project_id, provider_id, phone_verified, device_languages)
+values($1, $2, 'sms', true, $3)
+returning id
+        , display_name
+        , email
+        , email_verified
+        , phone
+        , phone_verified
+        , photo_url
+        , traits
+        , user_metadata
+        , app_metadata
+        , provider_id
+        , providers
+        , created_at
+        , updated_at
+        , state as "state: UserState"
+        , device_languages
\ No newline at end of file
@@ -9,16 +9,18 @@ insert into users(
         project_id
 )
 values($1, true, $2, $3, $4, $5, $6)
-on conflict(email, project_id)
-   do update set email_verified = true
 returning id
         , display_name
         , email
         , email_verified
+        , phone
+        , phone_verified
         , photo_url
         , traits
-        , data
+        , user_metadata
+        , app_metadata
         , provider_id
+        , providers
         , created_at
         , updated_at
         , state as "state: UserState"
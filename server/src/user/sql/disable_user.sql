@@ -1,6 +1,7 @@
 with disable_user as (
     update users
        set state = 'disabled'
+         , tokens_valid_after = now()
      where id = $1
        and project_id = $2
  returning id
@@ -9,4 +10,4 @@ delete from sessions
  where user_id in (
     select id as user_id
       from disable_user
- )
\ No newline at end of file
+ )
@@ -2,10 +2,14 @@ select id
      , display_name
      , email
      , email_verified
+     , phone
+     , phone_verified
      , photo_url
      , traits
-     , data
+     , user_metadata
+     , app_metadata
      , provider_id
+     , providers
      , created_at
      , updated_at
      , state as "state: UserState"
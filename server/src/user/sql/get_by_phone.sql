@@ -0,0 +1,19 @@
+select id
+     , display_name
+     , email
+     , email_verified
+     , phone
+     , phone_verified
+     , photo_url
+     , traits
+     , user_metadata
+     , app_metadata
+     , provider_id
+     , providers
+     , created_at
+     , updated_at
+     , state as "state: UserState"
+     , device_languages
+  from users
+ where phone = $1
+   and project_id = $2
\ No newline at end of file
@@ -10,7 +10,9 @@
 select id
      , email
      , email_verified
+     , phone
      , provider_id
+     , providers
      , created_at
      , state as "state: UserState"
   from users
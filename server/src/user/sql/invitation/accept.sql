@@ -0,0 +1,8 @@
+update users
+   set state = 'active'
+     , email_verified = true
+     , provider_id = $2
+     , providers = array[$2]
+     , device_languages = coalesce(nullif($3::text[], '{}'), device_languages)
+ where id = $1
+   and state = 'set_password'
@@ -0,0 +1,2 @@
+delete from user_invitations
+ where id = $1
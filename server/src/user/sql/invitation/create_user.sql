@@ -0,0 +1,12 @@
+insert into users
+    ( email
+    , display_name
+    , traits
+    , user_metadata
+    , app_metadata
+    , provider_id
+    , project_id
+    , state
+    )
+values($1, $2, $3, coalesce($4::jsonb, '{}'), coalesce($5::jsonb, '{}'), 'password', $6, 'set_password')
+returning id
@@ -0,0 +1,7 @@
+select id
+     , user_id
+     , token
+     , expire_at
+  from user_invitations
+ where id = $1
+   and project_id = $2
@@ -0,0 +1,9 @@
+select user_invitations.id
+     , user_invitations.user_id
+     , users.email
+     , user_invitations.expire_at
+     , user_invitations.created_at
+  from user_invitations
+  join users on users.id = user_invitations.user_id
+ where user_invitations.project_id = $1
+ order by user_invitations.created_at desc
@@ -0,0 +1,14 @@
+with invitation as (
+	delete from user_invitations
+	 where id = $1
+	   and project_id = $2
+	returning user_id
+), remove_user as (
+	-- the user never signed in
+	delete from users
+	 using invitation
+	 where users.id = invitation.user_id
+	   and users.state = 'set_password'
+)
+select user_id
+  from invitation
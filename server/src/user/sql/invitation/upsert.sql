@@ -0,0 +1,8 @@
+insert into user_invitations(user_id, project_id, token, invited_by, expire_at)
+values($1, $2, $3, $4, $5)
+on conflict (user_id)
+   do update set token = excluded.token
+               , invited_by = excluded.invited_by
+               , expire_at = excluded.expire_at
+               , created_at = now()
+returning id
@@ -0,0 +1,9 @@
+delete from users
+ where id in (
+    select users.id
+      from users
+      left join project_settings
+        on project_settings.project_id = users.project_id
+     where users.state = 'deleted'
+       and users.deleted_at < now() - make_interval(days => coalesce(project_settings.deletion_grace_days, 0))
+ )
\ No newline at end of file
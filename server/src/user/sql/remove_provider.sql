@@ -0,0 +1,5 @@
+update users
+   set providers = array_remove(providers, $2)
+ where id = $1
+   and $2 = any(providers)
+   and cardinality(providers) > 1
@@ -0,0 +1,12 @@
+delete from users
+ using project_settings
+ where users.project_id = project_settings.project_id
+   and users.provider_id = 'anonymous'
+   and project_settings.anonymous_ttl_days is not null
+   and users.created_at < now() - make_interval(days => project_settings.anonymous_ttl_days)
+   and not exists (
+       select 1
+         from sessions
+        where sessions.user_id = users.id
+          and sessions.expire_at > now()
+   )
\ No newline at end of file
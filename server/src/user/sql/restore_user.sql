@@ -0,0 +1,6 @@
+update users
+   set state = coalesce(state_before_delete, 'active')
+     , state_before_delete = null
+     , deleted_at = null
+ where id = $1
+   and state = 'deleted'
\ No newline at end of file
@@ -0,0 +1,3 @@
+update users
+   set tokens_valid_after = now()
+ where id = $1
@@ -0,0 +1,11 @@
+update users
+   set display_name = $2
+     , user_metadata = $3
+ where id = $1
+returning id
+        , email_verified
+        , email
+        , traits
+        , display_name
+        , user_metadata
+        , app_metadata
\ No newline at end of file
@@ -2,7 +2,8 @@ update users
    set display_name = $2
      , email = $3
      , traits = $4
-     , data = $5
+     , user_metadata = $5
+     , app_metadata = $6
      , email_verified =
             case when email = $3::text
                 then True
@@ -14,4 +15,5 @@ returning id
         , email
         , traits
         , display_name
-        , data
\ No newline at end of file
+        , user_metadata
+        , app_metadata
\ No newline at end of file
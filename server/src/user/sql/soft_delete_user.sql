@@ -0,0 +1,20 @@
+with delete_user as (
+    update users
+       set state_before_delete = state
+         , state = 'deleted'
+         , deleted_at = now()
+         , tokens_valid_after = now()
+     where id = $1
+ returning id
+), delete_api_keys as (
+    delete from api_keys
+     where user_id in (
+        select id as user_id
+          from delete_user
+     )
+)
+delete from sessions
+ where user_id in (
+    select id as user_id
+      from delete_user
+ )
\ No newline at end of file
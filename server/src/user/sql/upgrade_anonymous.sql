@@ -0,0 +1,26 @@
+update users
+   set email = $2
+     , email_verified = $3
+     , provider_id = $4
+     , providers = array[$4]
+     , display_name = coalesce(users.display_name, $5)
+     , photo_url = coalesce(users.photo_url, $6)
+     , traits = array_remove(users.traits, 'anonymous')
+ where id = $1
+   and provider_id = 'anonymous'
+returning id
+        , display_name
+        , email
+        , email_verified
+        , phone
+        , phone_verified
+        , photo_url
+        , traits
+        , user_metadata
+        , app_metadata
+        , provider_id
+        , providers
+        , created_at
+        , updated_at
+        , state as "state: UserState"
+        , device_languages
\ No newline at end of file
@@ -0,0 +1,4 @@
+update users
+   set phone_verified = true
+ where id = $1
+   and phone = $2
\ No newline at end of file
@@ -1,7 +1,7 @@
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 use crate::project::Project;
 use crate::session::data::AccessToken;
-use crate::user::data::{UpdateUser, User};
+use crate::user::data::{UpdateProfile, UpdateUser, UpdatedUser, User};
 use crate::user::verify_email::{send_email_verification, SendEmailVerification};
 
 use rocket::http::Status;
@@ -17,10 +17,22 @@ pub async fn update_user(
     project_id: Uuid,
 ) -> Result<(), ApiError> {
     let user = User::update(&pool, &user_id, &body).await?;
+    verify_updated_email(&pool, &user, project_id).await
+}
+
+pub async fn update_profile(pool: &Db, user_id: Uuid, body: UpdateProfile) -> Result<(), ApiError> {
+    User::update_profile(&pool, &user_id, &body).await?;
+    Ok(())
+}
 
-    if user.email_verified == false {
+async fn verify_updated_email(
+    pool: &Db,
+    user: &UpdatedUser,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
+    if user.email.is_some() && user.email_verified == false {
         let options = SendEmailVerification {
-            user_id,
+            user_id: user.id,
             project_id,
         };
 
@@ -33,12 +45,12 @@ pub async fn update_user(
 #[post("/update", format = "json", data = "<body>")]
 pub async fn handler(
     pool: Db,
-    body: Json<UpdateUser>,
+    body: Json<UpdateProfile>,
     token: AccessToken,
-    project: Project,
+    _project: Project,
 ) -> Result<Status, ApiError> {
     let user_id = token.sub();
-    update_user(&pool, user_id, body.into_inner(), project.id).await?;
+    update_profile(&pool, user_id, body.into_inner()).await?;
     Ok(Status::Ok)
 }
 
@@ -52,8 +64,21 @@ pub async fn admin_handler(
     user_id: Uuid,
     project_id: Uuid,
     body: Json<UpdateUser>,
-    _admin: Admin,
+    admin: Admin,
 ) -> Result<Status, ApiError> {
+    admin
+        .require_user(&pool, &user_id, AdminRole::Editor)
+        .await?;
+
+    // traits can grant access, e.g. the `Admin` trait
+    let user = User::get_by_id(&pool, &user_id, &project_id)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+    if user.traits != body.traits {
+        admin.require_super_admin()?;
+    }
+
     update_user(&pool, user_id, body.into_inner(), project_id).await?;
     Ok(Status::Ok)
 }
@@ -1,12 +1,14 @@
-use crate::admin::data::Admin;
+use crate::admin::data::{Admin, AdminRole};
 use crate::crypto::Token;
-use crate::mail::data::VerifyEmail;
+use crate::mail::data::{EmailVerificationPolicy, VerifyEmail};
 use crate::mail::Email;
+use crate::project::data::{Flags, Project as ProjectData, RedirectUrls};
 use crate::project::Project;
 use crate::settings::data::ProjectEmail;
 use crate::template::{Template, TemplateCtx, Templates, Translations};
+use crate::user::data::User;
 
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use rocket::http::Status;
 use rocket::serde::json::Json;
 use serde::Deserialize;
@@ -14,6 +16,13 @@ use uuid::Uuid;
 use vulpo_auth_types::error::ApiError;
 use werkbank::rocket::Db;
 
+/// Minutes until the link of a verification email expires
+const EXPIRE_IN: i32 = 30;
+
+/// Verification emails a user can request per minute and per hour
+const RESEND_PER_MINUTE: i64 = 1;
+const RESEND_PER_HOUR: i64 = 5;
+
 #[derive(Deserialize)]
 pub struct Verify {
     pub id: Uuid,
@@ -35,7 +44,15 @@ pub async fn verify_email(pool: &Db, body: Verify) -> Result<(), ApiError> {
         return Err(ApiError::TokenExpired);
     }
 
-    VerifyEmail::verify(&pool, &verify.user_id).await?;
+    // the token can only be used once, even when the link is opened twice at the same time
+    if !VerifyEmail::claim(&pool, &body.id).await? {
+        return Err(ApiError::TokenNotFound);
+    }
+
+    // the link is only valid for the address it was sent to
+    if !VerifyEmail::verify(&pool, &verify.user_id, &verify.email).await? {
+        return Err(ApiError::TokenInvalid);
+    }
 
     Ok(())
 }
@@ -56,8 +73,11 @@ pub struct SendEmailVerification {
 pub async fn admin(
     pool: Db,
     body: Json<SendEmailVerification>,
-    _admin: Admin,
+    admin: Admin,
 ) -> Result<Status, ApiError> {
+    admin
+        .require_user(&pool, &body.user_id, AdminRole::Editor)
+        .await?;
     send_email_verification(&pool, body.into_inner()).await?;
     Ok(Status::Ok)
 }
@@ -66,8 +86,10 @@ pub async fn send_email_verification(
     pool: &Db,
     body: SendEmailVerification,
 ) -> Result<(), ApiError> {
-    let to_email = VerifyEmail::unverify(pool, &body.user_id).await?;
-    send(&pool, &body.user_id, &body.project_id, &to_email).await
+    let to_email = VerifyEmail::unverify(pool, &body.user_id)
+        .await?
+        .ok_or(ApiError::BadRequest)?;
+    send(pool, &body.user_id, &body.project_id, &to_email, None).await
 }
 
 pub async fn send(
@@ -75,24 +97,31 @@ pub async fn send(
     user_id: &Uuid,
     project_id: &Uuid,
     to_email: &String,
+    redirect_url: Option<&str>,
 ) -> Result<(), ApiError> {
     let settings =
         ProjectEmail::from_project_template(pool, &project_id, Templates::VerifyEmail).await?;
 
     let reset_token = Token::create();
     let hashed_token = Token::hash(&reset_token)?;
-    let token_id = VerifyEmail::insert(pool, &user_id, hashed_token, &project_id).await?;
-
-    let link: String = format!(
-        "{}{}?id={}&token={}",
-        settings.domain, settings.redirect_to, token_id, reset_token
-    );
+    let expire_at = Utc::now() + Duration::minutes(i64::from(EXPIRE_IN));
+    let token_id = VerifyEmail::insert(
+        pool,
+        &user_id,
+        hashed_token,
+        &project_id,
+        &expire_at,
+        to_email,
+    )
+    .await?;
+
+    let link = settings.link(redirect_url, &token_id, &reset_token);
 
     let ctx = TemplateCtx {
         href: link,
         project: settings.name,
         user: None,
-        expire_in: 15,
+        expire_in: EXPIRE_IN,
     };
 
     let translations =
@@ -111,3 +140,83 @@ pub async fn send(
 
     Ok(())
 }
+
+#[derive(Deserialize)]
+pub struct ResendEmailVerification {
+    pub email: String,
+
+    /// Replaces the link of the email, has to be on the project's allow-list
+    pub redirect_url: Option<String>,
+}
+
+/// Sends a new verification email, nothing is sent when the user does not
+/// exist, is already verified or requested too many emails. The response is
+/// the same in every case, so it doesn't tell whether the user exists. The
+/// `redirect_url` has to be verified with `RedirectUrls::verify`
+pub async fn resend_email_verification(
+    pool: &Db,
+    body: ResendEmailVerification,
+    project_id: &Uuid,
+) -> Result<(), ApiError> {
+    let email = body.email.trim().to_lowercase();
+    let user = match User::get_by_email(pool, &email, project_id).await? {
+        Some(user) if !user.email_verified => user,
+        _ => return Ok(()),
+    };
+
+    let now = Utc::now();
+    let last_minute =
+        VerifyEmail::count_since(pool, &user.id, &(now - Duration::minutes(1))).await?;
+    let last_hour = VerifyEmail::count_since(pool, &user.id, &(now - Duration::hours(1))).await?;
+
+    if last_minute >= RESEND_PER_MINUTE || last_hour >= RESEND_PER_HOUR {
+        return Ok(());
+    }
+
+    send(
+        pool,
+        &user.id,
+        project_id,
+        &email,
+        body.redirect_url.as_deref(),
+    )
+    .await
+}
+
+#[post("/resend_email_verification", format = "json", data = "<body>")]
+pub async fn resend_handler(
+    pool: Db,
+    body: Json<ResendEmailVerification>,
+    project: Project,
+) -> Result<Status, ApiError> {
+    let policy = ProjectData::email_verification(&pool, &project.id).await?;
+    if policy == EmailVerificationPolicy::Off {
+        Flags::has_flags(&pool, &project.id, &[Flags::VerifyEmail]).await?;
+    }
+
+    RedirectUrls::verify(&pool, &project.id, body.redirect_url.as_deref()).await?;
+    resend_email_verification(&pool, body.into_inner(), &project.id).await?;
+    Ok(Status::Ok)
+}
+
+/// Checks the user against the project's email verification policy, returns
+/// the `email_verified` claim for the user's access tokens. Users without an
+/// email address, e.g. anonymous and phone users, are not affected
+pub async fn enforce_email_verification(
+    pool: &Db,
+    user: &User,
+    project_id: &Uuid,
+) -> Result<Option<bool>, ApiError> {
+    if user.email.is_none() {
+        return Ok(None);
+    }
+
+    match ProjectData::email_verification(pool, project_id).await? {
+        EmailVerificationPolicy::Off => Ok(None),
+        EmailVerificationPolicy::Block if !user.email_verified => {
+            Err(ApiError::UserEmailNotVerified)
+        }
+        EmailVerificationPolicy::Block => Ok(None),
+        EmailVerificationPolicy::Mark => Ok(Some(user.email_verified)),
+    }
+}
@@ -0,0 +1,155 @@
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { PROJECT_ID } from '../utils/env'
+import { makeCreateUser, makeCleanUp } from '../utils/passwordless'
+import { generateScopedAdminToken } from '../utils/admin'
+
+const EMAIL = 'api.test_admin_roles@vulpo.dev'
+const ADMIN_ID = '9c0d5e1a-2b3c-4d5e-8f9a-0b1c2d3e4f5a'
+const USER_ID = '4b5c6d7e-8f90-4a1b-9c2d-3e4f5a6b7c8d'
+
+let createAdmin = makeCreateUser(ADMIN_ID, EMAIL, admin.id)
+let cleanUp = makeCleanUp(ADMIN_ID)
+let createUser = makeCreateUser(USER_ID, 'api.test_admin_roles_user@vulpo.dev', PROJECT_ID)
+let cleanUpUser = makeCleanUp(USER_ID)
+
+async function setRole(role: string, project = PROJECT_ID) {
+	await Db.query(`
+		insert into admin_roles(user_id, project_id, role)
+		values($1, $2, $3)
+		on conflict (user_id, project_id)
+		   do update set role = excluded.role
+	`, [ADMIN_ID, project, role])
+}
+
+beforeEach(async () => {
+	await Promise.all([cleanUp(), cleanUpUser()])
+	await Promise.all([createAdmin(), createUser()])
+})
+afterAll(() => Promise.all([cleanUp(), cleanUpUser()]))
+afterAll(() => Db.end())
+
+function options() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateScopedAdminToken(ADMIN_ID)}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+describe("Admin Roles", () => {
+	test("admins without a role are rejected", async () => {
+		let res = await Http
+			.get(`/settings/email?project_id=${PROJECT_ID}`, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe('admin/auth')
+	})
+
+	test("viewers can read but not write", async () => {
+		await setRole('viewer')
+
+		let read = await Http
+			.get(`/settings/email?project_id=${PROJECT_ID}`, options())
+			.catch(err => err.response)
+
+		expect(read.status).toBe(200)
+
+		let write = await Http
+			.post('/project/set_flags', { project: PROJECT_ID, flags: [] }, options())
+			.catch(err => err.response)
+
+		expect(write.status).toBe(403)
+	})
+
+	test("roles are scoped to a project", async () => {
+		await setRole('owner')
+
+		let res = await Http
+			.get(`/settings/email?project_id=${admin.id}`, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+
+		let { data } = await Http.get('/admin/project/list', options())
+		expect(data.map((project: { id: string }) => project.id)).toEqual([PROJECT_ID])
+	})
+
+	test("only owners can delete a project", async () => {
+		await setRole('editor')
+
+		let res = await Http
+			.post('/project/delete', { project: PROJECT_ID }, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+
+	test("only super admins can invite super admins", async () => {
+		await setRole('owner')
+
+		let res = await Http
+			.post('/admin/invite', { email: 'api.test_super@vulpo.dev', role: 'super_admin' }, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+
+	test("editors can't change admins", async () => {
+		await setRole('editor', admin.id)
+
+		let res = await Http
+			.post('/user/admin/update', {
+				display_name: null,
+				email: EMAIL,
+				traits: ['Admin'],
+				user_metadata: {},
+				app_metadata: {},
+			}, {
+				...options(),
+				params: { user_id: ADMIN_ID, project_id: admin.id },
+			})
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+
+	test("only super admins can set traits", async () => {
+		await setRole('editor')
+
+		let user = {
+			display_name: 'test',
+			email: 'api.test_admin_roles_user@vulpo.dev',
+			traits: [],
+			user_metadata: {},
+			app_metadata: {},
+		}
+
+		let update = (traits: Array<string>) => Http
+			.post('/user/admin/update', { ...user, traits }, {
+				...options(),
+				params: { user_id: USER_ID, project_id: PROJECT_ID },
+			})
+			.catch(err => err.response)
+
+		let res = await update([])
+		expect(res.status).toBe(200)
+
+		res = await update(['Admin'])
+		expect(res.status).toBe(403)
+
+		res = await Http
+			.post('/user/invite', {
+				project_id: PROJECT_ID,
+				email: 'api.test_admin_roles_invite@vulpo.dev',
+				traits: ['Admin'],
+			}, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+})
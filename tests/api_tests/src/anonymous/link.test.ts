@@ -0,0 +1,102 @@
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { v4 as uuid } from 'uuid'
+import { decode } from 'jsonwebtoken'
+import { disableAnonymous, enableAnonymous, signInAnonymously } from '../utils/anonymous'
+import { generateKeyPair } from '../utils/crypto'
+
+beforeAll(enableAnonymous)
+
+afterAll(async () => {
+	await disableAnonymous()
+	await Db.end()
+})
+
+function getEmail() {
+	return `api.test+anonymous_${uuid()}@vulpo.dev`
+}
+
+describe("Link Anonymous User", () => {
+	test("links email and password to the same user", async () => {
+		let guest = await signInAnonymously()
+		let email = getEmail()
+
+		await Db.query(`
+			update users
+			   set user_metadata = '{"score": 42}'
+			 where id = $1
+		`, [guest.user_id])
+
+		let res = await Http
+			.post('/anonymous/link/password', { email, password: 'password' }, {
+				headers: { 'Authorization': `Bearer ${guest.access_token}` }
+			})
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+
+		let { publicKey } = generateKeyPair()
+		let signIn = await Http
+			.post('/password/sign_in', {
+				email,
+				password: 'password',
+				session: uuid(),
+				public_key: Array.from(Buffer.from(publicKey)),
+			})
+			.catch(err => err.response)
+
+		expect(signIn.status).toBe(200)
+		expect(signIn.data.user_id).toBe(guest.user_id)
+
+		let claims = decode(signIn.data.access_token) as { traits: Array<string> }
+		expect(claims.traits).not.toContain('anonymous')
+
+		let { rows } = await Db.query(`
+			select provider_id
+			     , user_metadata
+			  from users
+			 where id = $1
+		`, [guest.user_id])
+
+		expect(rows[0].provider_id).toBe('password')
+		expect(rows[0].user_metadata.score).toBe(42)
+	})
+
+
+	test("fails for users that are not anonymous", async () => {
+		let guest = await signInAnonymously()
+		let email = getEmail()
+
+		await Http.post('/anonymous/link/password', { email, password: 'password' }, {
+			headers: { 'Authorization': `Bearer ${guest.access_token}` }
+		})
+
+		let res = await Http
+			.post('/anonymous/link/password', { email: getEmail(), password: 'password' }, {
+				headers: { 'Authorization': `Bearer ${guest.access_token}` }
+			})
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+
+
+	test("fails when the email is taken", async () => {
+		let first = await signInAnonymously()
+		let email = getEmail()
+
+		await Http.post('/anonymous/link/password', { email, password: 'password' }, {
+			headers: { 'Authorization': `Bearer ${first.access_token}` }
+		})
+
+		let guest = await signInAnonymously()
+		let res = await Http
+			.post('/anonymous/link/password', { email, password: 'password' }, {
+				headers: { 'Authorization': `Bearer ${guest.access_token}` }
+			})
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('user/exists')
+	})
+})
@@ -0,0 +1,54 @@
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { decode } from 'jsonwebtoken'
+import { disableAnonymous, enableAnonymous, signInPayload } from '../utils/anonymous'
+import { PROJECT_ID } from '../utils/env'
+
+beforeAll(enableAnonymous)
+
+afterAll(async () => {
+	await disableAnonymous()
+	await Db.end()
+})
+
+describe("Sign In Anonymously", () => {
+	test("creates a guest user and session", async () => {
+		let res = await Http
+			.post('/anonymous/', signInPayload())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+		expect(res.data.created).toBe(true)
+
+		let claims = decode(res.data.access_token) as { sub: string, traits: Array<string>, amr: Array<string> }
+		expect(claims.sub).toBe(res.data.user_id)
+		expect(claims.traits).toContain('anonymous')
+		expect(claims.amr).toEqual(['anonymous'])
+
+		let { rows } = await Db.query(`
+			select email
+			     , phone
+			     , provider_id
+			  from users
+			 where id = $1
+			   and project_id = $2
+		`, [res.data.user_id, PROJECT_ID])
+
+		expect(rows[0].email).toBeNull()
+		expect(rows[0].phone).toBeNull()
+		expect(rows[0].provider_id).toBe('anonymous')
+	})
+
+
+	test("fails when anonymous users are disabled", async () => {
+		await disableAnonymous()
+
+		let res = await Http
+			.post('/anonymous/', signInPayload())
+			.catch(err => err.response)
+
+		await enableAnonymous()
+
+		expect(res.status).toBe(403)
+	})
+})
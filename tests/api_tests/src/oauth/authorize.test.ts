@@ -0,0 +1,270 @@
+import { admin, projectKeys } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url } from '@vulpo-dev/auth-sdk'
+import * as crypto from 'crypto'
+import * as jwt from 'jsonwebtoken'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { PROJECT_ID } from '../utils/env'
+import { generateAdminToken } from '../utils/admin'
+import { makeGenerateAccessToken, makeTokenPayload } from '../utils/user'
+
+const NAME = 'api.test_oauth_client'
+const REDIRECT_URI = 'https://client.test/callback'
+const USER_ID = '5b8a7c6d-2e1f-4a3b-9c8d-7e6f5a4b3c2d'
+const SESSION_ID = '3c2b1a0f-9e8d-4c7b-a6a5-f4e3d2c1b0a9'
+
+let getAccessToken = makeGenerateAccessToken({
+	key: projectKeys.private_key,
+	passphrase: 'password',
+})
+
+async function cleanUp() {
+	await Db.query(`
+		delete from oauth_clients
+		 where project_id = $1
+		   and name = $2
+	`, [PROJECT_ID, NAME])
+
+	await Db.query(`
+		delete from users
+		 where id = $1
+	`, [USER_ID])
+}
+
+beforeEach(async () => {
+	await cleanUp()
+	await Db.query(`
+		insert into users(id, email, email_verified, display_name, project_id, provider_id)
+		values ($1, 'api.test+oauth@vulpo.dev', true, 'OAuth Test', $2, 'password')
+	`, [USER_ID, PROJECT_ID])
+
+	await Db.query(`
+		insert into sessions(id, public_key, user_id, expire_at, project_id)
+		values($1, '\\x00', $2, now() + interval '1 day', $3)
+	`, [SESSION_ID, USER_ID, PROJECT_ID])
+})
+
+afterAll(cleanUp)
+afterAll(() => Db.end())
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+function userOptions() {
+	let args = {
+		payload: makeTokenPayload(USER_ID, PROJECT_ID)()
+	}
+
+	return {
+		headers: {
+			'Authorization': `Bearer ${getAccessToken(args)}`,
+		}
+	}
+}
+
+async function createClient(confidential = true) {
+	let payload = {
+		project: PROJECT_ID,
+		name: NAME,
+		redirect_uris: [REDIRECT_URI],
+		confidential,
+	}
+
+	let { data } = await Http.post('/oauth/client/create', payload, adminOptions())
+	return data
+}
+
+function pkce() {
+	let verifier = crypto.randomBytes(32).toString('base64url')
+	let challenge = crypto.createHash('sha256').update(verifier).digest('base64url')
+	return { verifier, challenge }
+}
+
+async function authorize(clientId: string, challenge: string, scope = 'openid profile email') {
+	let params = new URLSearchParams({
+		response_type: 'code',
+		client_id: clientId,
+		redirect_uri: REDIRECT_URI,
+		scope,
+		state: 'state-1',
+		nonce: 'nonce-1',
+		code_challenge: challenge,
+		code_challenge_method: 'S256',
+	})
+
+	return Http
+		.get(`/oauth/authorize?${params}`, {
+			maxRedirects: 0,
+			validateStatus: () => true,
+		})
+}
+
+function requestId(res: { headers: Record<string, string> }) {
+	let location = new URL(res.headers.location)
+	return location.searchParams.get('request_id') ?? ''
+}
+
+function requestToken(body: Record<string, string>) {
+	return Http
+		.post('/oauth/token', new URLSearchParams(body).toString(), {
+			headers: { 'Content-Type': 'application/x-www-form-urlencoded' }
+		})
+		.catch(err => err.response)
+}
+
+async function signIn(clientId: string) {
+	let { verifier, challenge } = pkce()
+	let res = await authorize(clientId, challenge)
+
+	let confirm = await Http.post(
+		Url.OAuthAuthorizeConfirm,
+		{ id: requestId(res), session: SESSION_ID },
+		userOptions(),
+	)
+
+	let redirect = new URL(confirm.data.redirect_to)
+	return { verifier, redirect }
+}
+
+describe("Authorization Code", () => {
+	test("redirects to the consent page", async () => {
+		let { client_id } = await createClient()
+		let res = await authorize(client_id, pkce().challenge)
+
+		expect(res.status).toBe(303)
+		expect(res.headers.location).toContain('/oauth/authorize?request_id=')
+
+		let request = await Http.get(
+			Url.OAuthAuthorizeRequest.replace(':id', requestId(res)),
+			userOptions(),
+		)
+
+		expect(request.data.client.name).toBe(NAME)
+		expect(request.data.scopes).toEqual(['email', 'openid', 'profile'])
+		expect(request.data.consent).toBe(false)
+	})
+
+	test("rejects unknown redirect URIs without redirecting", async () => {
+		let { client_id } = await createClient()
+		let params = new URLSearchParams({
+			response_type: 'code',
+			client_id,
+			redirect_uri: 'https://evil.test/callback',
+			code_challenge: pkce().challenge,
+			code_challenge_method: 'S256',
+		})
+
+		let res = await Http
+			.get(`/oauth/authorize?${params}`, { maxRedirects: 0 })
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('oauth/invalid_redirect_uri')
+	})
+
+	test("exchanges the code for tokens", async () => {
+		let { client_id, client_secret } = await createClient()
+		let { verifier, redirect } = await signIn(client_id)
+
+		expect(redirect.origin + redirect.pathname).toBe(REDIRECT_URI)
+		expect(redirect.searchParams.get('state')).toBe('state-1')
+
+		let res = await requestToken({
+			grant_type: 'authorization_code',
+			code: redirect.searchParams.get('code') ?? '',
+			redirect_uri: REDIRECT_URI,
+			code_verifier: verifier,
+			client_id,
+			client_secret,
+		})
+
+		expect(res.status).toBe(200)
+		expect(res.data.scope).toBe('email openid profile')
+
+		let idToken = jwt.decode(res.data.id_token) as jwt.JwtPayload
+		expect(idToken.sub).toBe(USER_ID)
+		expect(idToken.aud).toBe(client_id)
+		expect(idToken.nonce).toBe('nonce-1')
+		expect(idToken.email).toBe('api.test+oauth@vulpo.dev')
+
+		let userinfo = await Http.get('/oauth/userinfo', {
+			headers: { 'Authorization': `Bearer ${res.data.access_token}` }
+		})
+
+		expect(userinfo.data.sub).toBe(USER_ID)
+		expect(userinfo.data.name).toBe('OAuth Test')
+
+		let claims = jwt.decode(res.data.access_token) as jwt.JwtPayload
+		expect(claims.client_id).toBe(client_id)
+		expect(claims.traits).toEqual([])
+		expect(claims.permissions).toBe(undefined)
+
+		// client tokens can't call the user API
+		let user = await Http.get(Url.UserGet, {
+			headers: {
+				'Authorization': `Bearer ${res.data.access_token}`,
+				'Vulpo-Project': PROJECT_ID,
+			}
+		}).catch(err => err.response)
+
+		expect(user.status).toBe(401)
+	})
+
+	test("codes can only be used once", async () => {
+		let { client_id } = await createClient(false)
+		let { verifier, redirect } = await signIn(client_id)
+
+		let body = {
+			grant_type: 'authorization_code',
+			code: redirect.searchParams.get('code') ?? '',
+			redirect_uri: REDIRECT_URI,
+			code_verifier: verifier,
+			client_id,
+		}
+
+		let first = await requestToken(body)
+		expect(first.status).toBe(200)
+
+		let second = await requestToken(body)
+		expect(second.status).toBe(400)
+		expect(second.data.error).toBe('invalid_grant')
+	})
+
+	test("fails with the wrong code verifier", async () => {
+		let { client_id } = await createClient(false)
+		let { redirect } = await signIn(client_id)
+
+		let res = await requestToken({
+			grant_type: 'authorization_code',
+			code: redirect.searchParams.get('code') ?? '',
+			redirect_uri: REDIRECT_URI,
+			code_verifier: pkce().verifier,
+			client_id,
+		})
+
+		expect(res.status).toBe(400)
+		expect(res.data.error).toBe('invalid_grant')
+	})
+
+	test("deny redirects with access_denied", async () => {
+		let { client_id } = await createClient()
+		let res = await authorize(client_id, pkce().challenge)
+
+		let deny = await Http.post(
+			Url.OAuthAuthorizeDeny,
+			{ id: requestId(res) },
+			userOptions(),
+		)
+
+		let redirect = new URL(deny.data.redirect_to)
+		expect(redirect.searchParams.get('error')).toBe('access_denied')
+		expect(redirect.searchParams.get('state')).toBe('state-1')
+	})
+})
@@ -0,0 +1,140 @@
+import { admin, projectKeys } from '@vulpo-dev/auth-seeds/data/projects'
+import { v4 as uuid } from 'uuid'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { PROJECT_ID } from '../utils/env'
+import { generateAdminToken } from '../utils/admin'
+import { makeGenerateAccessToken, makeTokenPayload } from '../utils/user'
+
+const NAME = 'api.test_introspection'
+const USER_ID = '8f7e6d5c-4b3a-4291-8e7d-6c5b4a392817'
+const SESSION_ID = '1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d'
+
+let getAccessToken = makeGenerateAccessToken({
+	key: projectKeys.private_key,
+	passphrase: 'password',
+})
+
+async function cleanUp() {
+	await Db.query(`
+		delete from service_accounts
+		 where project_id = $1
+		   and name = $2
+	`, [PROJECT_ID, NAME])
+
+	await Db.query(`
+		delete from users
+		 where id = $1
+	`, [USER_ID])
+}
+
+beforeEach(async () => {
+	await cleanUp()
+	await Db.query(`
+		insert into users(id, email, email_verified, project_id, provider_id, traits)
+		values ($1, 'api.test+introspection@vulpo.dev', true, $2, 'password', '{beta}')
+	`, [USER_ID, PROJECT_ID])
+
+	await Db.query(`
+		insert into sessions(id, public_key, user_id, expire_at, project_id)
+		values($1, '\\x00', $2, now() + interval '1 day', $3)
+	`, [SESSION_ID, USER_ID, PROJECT_ID])
+})
+
+afterAll(cleanUp)
+afterAll(() => Db.end())
+
+async function createServiceAccount() {
+	let payload = { project: PROJECT_ID, name: NAME, scopes: [] }
+	let { data } = await Http.post('/service_account/create', payload, {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	})
+
+	return data
+}
+
+function post(path: string, body: Record<string, string>) {
+	return Http
+		.post(path, new URLSearchParams(body).toString(), {
+			headers: { 'Content-Type': 'application/x-www-form-urlencoded' }
+		})
+		.catch(err => err.response)
+}
+
+describe("Introspection", () => {
+	test("returns the claims of an access token", async () => {
+		let credentials = await createServiceAccount()
+		let token = getAccessToken({
+			payload: makeTokenPayload(USER_ID, PROJECT_ID)()
+		})
+
+		let res = await post('/oauth/introspect', { token, ...credentials })
+
+		expect(res.status).toBe(200)
+		expect(res.data.active).toBe(true)
+		expect(res.data.token_type).toBe('access_token')
+		expect(res.data.sub).toBe(USER_ID)
+	})
+
+	test("returns the user of a session", async () => {
+		let credentials = await createServiceAccount()
+		let res = await post('/oauth/introspect', { token: SESSION_ID, ...credentials })
+
+		expect(res.data.active).toBe(true)
+		expect(res.data.token_type).toBe('session')
+		expect(res.data.sub).toBe(USER_ID)
+		expect(res.data.traits).toEqual(['beta'])
+	})
+
+	test("unknown tokens are inactive", async () => {
+		let credentials = await createServiceAccount()
+		let res = await post('/oauth/introspect', { token: uuid(), ...credentials })
+
+		expect(res.status).toBe(200)
+		expect(res.data).toEqual({ active: false })
+	})
+
+	test("requires service account credentials", async () => {
+		let { client_id } = await createServiceAccount()
+		let res = await post('/oauth/introspect', {
+			token: SESSION_ID,
+			client_id,
+			client_secret: 'invalid',
+		})
+
+		expect(res.status).toBe(401)
+		expect(res.data.error).toBe('invalid_client')
+	})
+})
+
+describe("Revocation", () => {
+	test("removes the session", async () => {
+		let credentials = await createServiceAccount()
+		let res = await post('/oauth/revoke', { token: SESSION_ID, ...credentials })
+		expect(res.status).toBe(200)
+
+		let { rowCount } = await Db.query(`
+			select id
+			  from sessions
+			 where id = $1
+		`, [SESSION_ID])
+
+		expect(rowCount).toBe(0)
+	})
+
+	test("access tokens can not be revoked", async () => {
+		let credentials = await createServiceAccount()
+		let token = getAccessToken({
+			payload: makeTokenPayload(USER_ID, PROJECT_ID)()
+		})
+
+		let res = await post('/oauth/revoke', { token, ...credentials })
+
+		expect(res.status).toBe(400)
+		expect(res.data.error).toBe('unsupported_token_type')
+	})
+})
@@ -0,0 +1,193 @@
+import { projectKeys } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, ErrorCode } from '@vulpo-dev/auth-sdk'
+import { v4 as uuid } from 'uuid'
+
+import Http from '../utils/http'
+import Db from '../utils/db'
+import { PROJECT_ID } from '../utils/env'
+import { makeGenerateAccessToken, makeTokenPayload } from '../utils/user'
+
+const OWNER_ID = '0d3a3f4e-8c1f-4b7a-9a55-7a3c1e6f2b10'
+const MEMBER_ID = '9e0c2b7d-1f6a-4c3e-8b2d-5a4f3e2d1c0b'
+const SESSION_ID = 'f1e2d3c4-b5a6-4978-8a9b-0c1d2e3f4a5b'
+
+let getAccessToken = makeGenerateAccessToken({
+	key: projectKeys.private_key,
+	passphrase: 'password',
+})
+
+async function cleanup() {
+	await Db.query(`
+		delete from users
+		where id = any($1)
+	`, [[OWNER_ID, MEMBER_ID]])
+}
+
+beforeEach(async () => {
+	await cleanup()
+	await Db.query(`
+		update projects
+		   set flags = array_append(flags, 'auth::organizations')
+		 where id = $1
+		   and not ('auth::organizations' = any(flags))
+	`, [PROJECT_ID])
+
+	await Db.query(`
+		insert into users(id, email, email_verified, project_id, provider_id)
+		values ($1, 'api.test+org-owner@vulpo.dev', true, $3, 'password')
+		     , ($2, 'api.test+org-member@vulpo.dev', true, $3, 'password')
+	`, [OWNER_ID, MEMBER_ID, PROJECT_ID])
+})
+
+afterAll(cleanup)
+afterAll(() => Db.end())
+
+describe("Organizations", () => {
+	test("creator becomes the owner", async () => {
+		let res = await Http
+			.post(Url.Organization, { name: 'Acme' }, options(OWNER_ID))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+		expect(res.data.name).toBe('Acme')
+		expect(res.data.role).toBe('owner')
+
+		let list = await Http.get(Url.OrganizationList, options(OWNER_ID))
+		expect(list.data.items.map((org: { id: string }) => org.id)).toEqual([res.data.id])
+	})
+
+	test("name can not be empty", async () => {
+		let res = await Http
+			.post(Url.Organization, { name: '  ' }, options(OWNER_ID))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+	})
+
+	test("members can not invite", async () => {
+		let id = await createOrganization()
+		await addMember(id, MEMBER_ID, 'member')
+
+		let payload = {
+			organization_id: id,
+			email: 'api.test+org-invite@vulpo.dev',
+			role: 'member',
+		}
+
+		let res = await Http
+			.post(Url.OrganizationInvite, payload, options(MEMBER_ID))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+
+	test("can not invite existing members", async () => {
+		let id = await createOrganization()
+		await addMember(id, MEMBER_ID, 'member')
+
+		let payload = {
+			organization_id: id,
+			email: 'api.test+org-member@vulpo.dev',
+			role: 'member',
+		}
+
+		let res = await Http
+			.post(Url.OrganizationInvite, payload, options(OWNER_ID))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe(ErrorCode.OrganizationMemberExists)
+	})
+
+	test("switch adds the organization claims", async () => {
+		let id = await createOrganization()
+		await addMember(id, MEMBER_ID, 'admin')
+		await Db.query(`
+			insert into sessions(id, public_key, user_id, expire_at, project_id)
+			values($1, '\\x00', $2, now() + interval '1 day', $3)
+		`, [SESSION_ID, MEMBER_ID, PROJECT_ID])
+
+		let res = await Http
+			.post(Url.OrganizationSwitch, { session: SESSION_ID, organization_id: id }, options(MEMBER_ID))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+
+		let claims = decode(res.data.access_token)
+		expect(claims.org_id).toBe(id)
+		expect(claims.org_role).toBe('admin')
+	})
+
+	test("switch requires a membership", async () => {
+		let id = await createOrganization()
+		await Db.query(`
+			insert into sessions(id, public_key, user_id, expire_at, project_id)
+			values($1, '\\x00', $2, now() + interval '1 day', $3)
+		`, [SESSION_ID, MEMBER_ID, PROJECT_ID])
+
+		let res = await Http
+			.post(Url.OrganizationSwitch, { session: SESSION_ID, organization_id: id }, options(MEMBER_ID))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+
+	test("last owner can not leave", async () => {
+		let id = await createOrganization()
+		await addMember(id, MEMBER_ID, 'member')
+
+		let res = await Http
+			.post(Url.OrganizationLeave, { organization_id: id }, options(OWNER_ID))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe(ErrorCode.OrganizationLastOwner)
+	})
+
+	test("last member removes the organization", async () => {
+		let id = await createOrganization()
+
+		let res = await Http
+			.post(Url.OrganizationLeave, { organization_id: id }, options(OWNER_ID))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+
+		let { rows } = await Db.query(`
+			select count(*)::int as count
+			  from organizations
+			 where id = $1
+		`, [id])
+
+		expect(rows[0].count).toBe(0)
+	})
+})
+
+async function createOrganization(): Promise<string> {
+	let res = await Http.post(Url.Organization, { name: `org-${uuid()}` }, options(OWNER_ID))
+	return res.data.id
+}
+
+async function addMember(organizationId: string, userId: string, role: string) {
+	await Db.query(`
+		insert into organization_members(organization_id, user_id, role)
+		values($1, $2, $3)
+	`, [organizationId, userId, role])
+}
+
+function decode(token: string) {
+	let [, payload] = token.split('.')
+	return JSON.parse(Buffer.from(payload, 'base64').toString())
+}
+
+function options(userId: string) {
+	let args = {
+		payload: makeTokenPayload(userId, PROJECT_ID)()
+	}
+
+	return {
+		headers: {
+			'Authorization': `Bearer ${getAccessToken(args)}`,
+		}
+	}
+}
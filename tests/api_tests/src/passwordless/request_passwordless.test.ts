@@ -31,9 +31,14 @@ describe("Request Passwordless", () => {
 
 		let body = PasswordlessResponseSchema.validate(res.data)
 		expect(body).toBeTruthy()
+		expect(res.data.mode).toBe('link')
 
 		let token = await getPasswordlessToken({ email, project: PROJECT_ID })
 		expect(token).toBeTruthy()
+
+		// links expire after 30 minutes, codes after 15
+		let expireIn = new Date(token.expire_at).getTime() - Date.now()
+		expect(Math.round(expireIn / 60_000)).toBe(30)
 	})
 
 
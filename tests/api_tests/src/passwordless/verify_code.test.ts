@@ -0,0 +1,126 @@
+import { Url, ErrorCode } from '@vulpo-dev/auth-sdk'
+import * as jwt from 'jsonwebtoken'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { createToken, createSession, CreatedSession } from '../utils/passwordless'
+import { createUser, ratPayload } from '../utils/user'
+import SessionResponseSchema from '../utils/schema/session-response'
+
+const VERIFY_CODE = '/passwordless/verify_code'
+
+afterAll(() => Db.end())
+
+function generateRat(session: CreatedSession) {
+	return jwt.sign(
+		JSON.stringify(ratPayload()),
+		session.keys.privateKey,
+		{ algorithm: 'ES384', header: { typ: 'JWT', alg: 'ES384' } }
+	)
+}
+
+function verifyPayload(id: string, code: string, session: CreatedSession) {
+	return {
+		id,
+		code,
+		session: session.id,
+		token: generateRat(session),
+		device_languages: ['de-AT', 'de'],
+	}
+}
+
+describe("Verify Passwordless Code", () => {
+	test("returns session data for a valid code", async () => {
+		let user = await createUser()
+		let session = await createSession({ user })
+		let token = await createToken({ user, session, token: '12345678', mode: 'code' })
+
+		let res = await Http
+			.post(VERIFY_CODE, verifyPayload(token.id, token.value, session))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+		expect(res.headers['content-type']).toBe('application/json')
+		expect(SessionResponseSchema.validate(res.data)).toBeTruthy()
+		expect(res.data.user_id).toBe(user.id)
+	})
+
+	test("fails with an invalid code and counts the attempt", async () => {
+		let user = await createUser()
+		let session = await createSession({ user })
+		let token = await createToken({ user, session, token: '12345678', mode: 'code' })
+
+		let res = await Http
+			.post(VERIFY_CODE, verifyPayload(token.id, '87654321', session))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe(ErrorCode.PasswordlessInvalidToken)
+
+		let { rows } = await Db.query(`
+			select attempts
+			  from passwordless
+			 where id = $1
+		`, [token.id])
+
+		expect(rows[0].attempts).toBe(1)
+	})
+
+	test("fails after too many attempts", async () => {
+		let user = await createUser()
+		let session = await createSession({ user })
+		let token = await createToken({ user, session, token: '12345678', mode: 'code' })
+
+		await Db.query(`
+			update passwordless
+			   set attempts = 5
+			 where id = $1
+		`, [token.id])
+
+		let res = await Http
+			.post(VERIFY_CODE, verifyPayload(token.id, token.value, session))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('passwordless/too_many_attempts')
+	})
+
+	test("fails for link tokens", async () => {
+		let user = await createUser()
+		let session = await createSession({ user })
+		let token = await createToken({ user, session })
+
+		let res = await Http
+			.post(VERIFY_CODE, verifyPayload(token.id, token.value, session))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe(ErrorCode.PasswordlessInvalidToken)
+	})
+
+	test("fails when the session does not match", async () => {
+		let user = await createUser()
+		let session = await createSession({ user })
+		let other = await createSession({ user })
+		let token = await createToken({ user, session, token: '12345678', mode: 'code' })
+
+		let res = await Http
+			.post(VERIFY_CODE, verifyPayload(token.id, token.value, other))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+
+	test("link confirm does not accept codes", async () => {
+		let user = await createUser()
+		let session = await createSession({ user })
+		let token = await createToken({ user, session, token: '12345678', mode: 'code' })
+
+		let res = await Http
+			.post(Url.PasswordlessConfim, { id: token.id, token: token.value })
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe(ErrorCode.PasswordlessInvalidToken)
+	})
+})
@@ -0,0 +1,205 @@
+import { v4 as uuid } from 'uuid'
+import * as http from 'http'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, ErrorCode, Flag, EmailPasswordPayload, PasswordResetPayload } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { generateAdminToken } from '../utils/admin'
+
+const SECRET = 'captcha-secret'
+const VALID_TOKEN = 'valid-token'
+
+let PROJECT: string
+
+// local stand-in for the provider's siteverify endpoint, the server has to
+// run with VULPO_CAPTCHA_VERIFY_URL=http://127.0.0.1:7001/siteverify
+let verifier = http.createServer((req, res) => {
+	let body = ''
+	req.on('data', chunk => body += chunk)
+	req.on('end', () => {
+		let form = new URLSearchParams(body)
+		let success = form.get('secret') === SECRET && form.get('response') === VALID_TOKEN
+
+		res.writeHead(200, { 'Content-Type': 'application/json' })
+		res.end(JSON.stringify({ success }))
+	})
+})
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+function setCaptcha(enabled: boolean) {
+	return Http.post('/settings/captcha', {
+		enabled,
+		provider: 'turnstile',
+		site_key: 'site-key',
+		secret: SECRET,
+	}, {
+		...adminOptions(),
+		params: { project_id: PROJECT },
+	}).catch(err => err.response)
+}
+
+beforeAll(async () => {
+	await new Promise<void>(resolve => verifier.listen(7001, '127.0.0.1', resolve))
+
+	let res = await Http.post('/admin/project/create', {
+		name: `captcha-${uuid()}`,
+		domain: 'http://localhost:3000',
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+
+	await Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, [Flag.SignIn, Flag.SignUp, Flag.EmailAndPassword, Flag.PasswordReset]])
+
+	await setCaptcha(true)
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	verifier.close()
+	return Db.end()
+})
+
+function signUp(captcha_token?: string, headers: Record<string, string> = {}) {
+	let { publicKey } = generateKeyPair()
+
+	let payload: EmailPasswordPayload = {
+		email: `api.test+${uuid()}@vulpo.dev`,
+		password: 'password',
+		public_key: Array.from(Buffer.from(publicKey)),
+		session: uuid(),
+		device_languages: ['en'],
+		captcha_token,
+	}
+
+	return Http
+		.post(Url.SignUp, payload, {
+			headers: { 'Vulpo-Project': PROJECT, ...headers }
+		})
+		.catch(err => err.response)
+}
+
+// unknown emails don't send anything
+function requestReset(captcha_token?: string) {
+	let payload: PasswordResetPayload = {
+		email: `${uuid()}@vulpo.dev`,
+		captcha_token,
+	}
+
+	return Http
+		.post(Url.RequestPasswordReset, payload, {
+			headers: { 'Vulpo-Project': PROJECT }
+		})
+		.catch(err => err.response)
+}
+
+describe("CAPTCHA", () => {
+	test("returns the site key to clients", async () => {
+		let res = await Http.get(`/project/captcha?project=${PROJECT}`)
+
+		expect(res.data).toEqual({
+			provider: 'turnstile',
+			site_key: 'site-key',
+		})
+	})
+
+	test("requires a token", async () => {
+		let res = await signUp()
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe(ErrorCode.CaptchaRequired)
+
+		res = await requestReset()
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe(ErrorCode.CaptchaRequired)
+	})
+
+	test("rejects invalid tokens", async () => {
+		let res = await signUp('invalid-token')
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.CaptchaInvalid)
+	})
+
+	test("accepts valid tokens", async () => {
+		let res = await signUp(VALID_TOKEN)
+		expect(res.status).toBe(200)
+
+		res = await signUp(undefined, { 'Vulpo-Captcha': VALID_TOKEN })
+		expect(res.status).toBe(200)
+
+		res = await requestReset(VALID_TOKEN)
+		expect(res.status).toBe(200)
+	})
+
+	test("disabled CAPTCHAs are not verified", async () => {
+		await setCaptcha(false)
+
+		let res = await signUp()
+		expect(res.status).toBe(200)
+
+		res = await Http.get(`/project/captcha?project=${PROJECT}`)
+		expect(res.data).toBe(null)
+
+		await setCaptcha(true)
+	})
+
+	test("doesn't return the secret", async () => {
+		let res = await Http.get('/settings/captcha', {
+			...adminOptions(),
+			params: { project_id: PROJECT },
+		})
+
+		expect(res.data).toEqual({
+			enabled: true,
+			provider: 'turnstile',
+			site_key: 'site-key',
+		})
+	})
+
+	test("keeps the secret when it's empty", async () => {
+		let res = await Http.post('/settings/captcha', {
+			enabled: true,
+			provider: 'turnstile',
+			site_key: 'site-key',
+			secret: '',
+		}, {
+			...adminOptions(),
+			params: { project_id: PROJECT },
+		})
+
+		expect(res.status).toBe(200)
+
+		res = await signUp(VALID_TOKEN)
+		expect(res.status).toBe(200)
+	})
+
+	test("rejects invalid settings", async () => {
+		let res = await Http.post('/settings/captcha', {
+			enabled: true,
+			provider: 'turnstile',
+			site_key: '',
+			secret: SECRET,
+		}, {
+			...adminOptions(),
+			params: { project_id: PROJECT },
+		}).catch(err => err.response)
+
+		expect(res.status).toBe(400)
+	})
+})
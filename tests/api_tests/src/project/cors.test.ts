@@ -0,0 +1,128 @@
+import { v4 as uuid } from 'uuid'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateAdminToken } from '../utils/admin'
+
+const DOMAIN = 'http://localhost:3000'
+
+let PROJECT: string
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `cors-${uuid()}`,
+		domain: DOMAIN,
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+
+	await Http.post('/settings/cors', {
+		origins: ['https://*.example.com', 'capacitor://localhost'],
+		methods: ['GET', 'POST', 'DELETE'],
+		headers: ['Content-Type', 'Vulpo-Project', 'Authorization'],
+		max_age: 600,
+	}, {
+		...adminOptions(),
+		params: { project_id: PROJECT },
+	})
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+function request(origin: string) {
+	return Http
+		.get(Url.Flags.replace(':projectId', PROJECT), {
+			headers: {
+				'Origin': origin,
+				'Vulpo-Project': PROJECT,
+			}
+		})
+		.catch(err => err.response)
+}
+
+describe("CORS", () => {
+	test("allows the project domain", async () => {
+		let res = await request(DOMAIN)
+
+		expect(res.headers['access-control-allow-origin']).toBe(DOMAIN)
+		expect(res.headers['vary']).toBe('Origin')
+	})
+
+	test("allows configured origins", async () => {
+		for (let origin of ['https://app.example.com', 'https://a.b.example.com', 'capacitor://localhost']) {
+			let res = await request(origin)
+			expect(res.headers['access-control-allow-origin']).toBe(origin)
+		}
+	})
+
+	test("rejects other origins", async () => {
+		for (let origin of ['https://example.com', 'http://app.example.com', 'https://evilexample.com']) {
+			let res = await request(origin)
+			expect(res.headers['access-control-allow-origin']).toBeUndefined()
+		}
+	})
+
+	test("uses the configured methods, headers and max age", async () => {
+		let res = await request('capacitor://localhost')
+
+		expect(res.headers['access-control-allow-methods']).toBe('GET, POST, DELETE')
+		expect(res.headers['access-control-allow-headers']).toBe('Content-Type, Vulpo-Project, Authorization')
+		expect(res.headers['access-control-max-age']).toBe('600')
+	})
+
+	test("answers preflight requests for allowed origins", async () => {
+		let res = await Http.options(Url.UserGet, {
+			headers: {
+				'Origin': 'https://app.example.com',
+				'Access-Control-Request-Method': 'GET',
+			}
+		})
+
+		expect(res.status).toBe(200)
+		expect(res.headers['access-control-allow-origin']).toBe('https://app.example.com')
+	})
+
+	test("doesn't answer preflight requests for unknown origins", async () => {
+		let res = await Http.options(Url.UserGet, {
+			headers: {
+				'Origin': 'https://unknown.example.org',
+				'Access-Control-Request-Method': 'GET',
+			}
+		})
+
+		expect(res.headers['access-control-allow-origin']).toBeUndefined()
+		expect(res.headers['access-control-allow-headers']).toBeUndefined()
+	})
+
+	test("rejects invalid origins", async () => {
+		let res = await Http.post('/settings/cors', {
+			origins: ['https://example.com/path'],
+			methods: ['GET'],
+			headers: [],
+			max_age: null,
+		}, {
+			...adminOptions(),
+			params: { project_id: PROJECT },
+		}).catch(err => err.response)
+
+		expect(res.status).toBe(400)
+	})
+})
@@ -0,0 +1,183 @@
+import { v4 as uuid } from 'uuid'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, ErrorCode, Flag, EmailPasswordPayload } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { generateAdminToken } from '../utils/admin'
+import { createUser } from '../utils/user'
+import { signInPayload } from '../utils/anonymous'
+
+let PROJECT: string
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+type EmailDomains = {
+	allow?: Array<string>;
+	deny?: Array<string>;
+	block_disposable?: boolean;
+}
+
+function setEmailDomains({ allow = [], deny = [], block_disposable = false }: EmailDomains) {
+	return Http.post('/settings/email_domains', { allow, deny, block_disposable }, {
+		...adminOptions(),
+		params: { project_id: PROJECT },
+	}).catch(err => err.response)
+}
+
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `email-domains-${uuid()}`,
+		domain: 'http://localhost:3000',
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+
+	await Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, [Flag.SignIn, Flag.SignUp, Flag.EmailAndPassword, Flag.Anonymous]])
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+function signUp(domain: string) {
+	let { publicKey } = generateKeyPair()
+
+	let payload: EmailPasswordPayload = {
+		email: `api.test+${uuid()}@${domain}`,
+		password: 'password',
+		public_key: Array.from(Buffer.from(publicKey)),
+		session: uuid(),
+		device_languages: ['en'],
+	}
+
+	return Http
+		.post(Url.SignUp, payload, {
+			headers: { 'Vulpo-Project': PROJECT }
+		})
+		.catch(err => err.response)
+}
+
+describe("Email Domains", () => {
+	test("returns the settings", async () => {
+		await setEmailDomains({ allow: ['customer.com'], deny: ['old.customer.com'] })
+
+		let res = await Http.get('/settings/email_domains', {
+			...adminOptions(),
+			params: { project_id: PROJECT },
+		})
+
+		expect(res.data).toEqual({
+			allow: ['customer.com'],
+			deny: ['old.customer.com'],
+			block_disposable: false,
+		})
+	})
+
+	test("only allows domains on the allow-list", async () => {
+		await setEmailDomains({ allow: ['customer.com', '*.customer.org'] })
+
+		for (let domain of ['customer.com', 'CUSTOMER.com', 'eu.customer.org']) {
+			let res = await signUp(domain)
+			expect(res.status).toBe(200)
+		}
+
+		for (let domain of ['vulpo.dev', 'customer.org', 'evilcustomer.com']) {
+			let res = await signUp(domain)
+			expect(res.status).toBe(403)
+			expect(res.data.code).toBe(ErrorCode.UserEmailDomainNotAllowed)
+		}
+	})
+
+	test("denied domains take precedence", async () => {
+		await setEmailDomains({ allow: ['*.customer.org'], deny: ['old.customer.org'] })
+
+		let res = await signUp('old.customer.org')
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.UserEmailDomainNotAllowed)
+	})
+
+	test("blocks disposable domains", async () => {
+		await setEmailDomains({})
+
+		let res = await signUp('mailinator.com')
+		expect(res.status).toBe(200)
+
+		await setEmailDomains({ block_disposable: true })
+
+		for (let domain of ['mailinator.com', 'sub.yopmail.com']) {
+			let res = await signUp(domain)
+			expect(res.status).toBe(403)
+			expect(res.data.code).toBe(ErrorCode.UserEmailDomainNotAllowed)
+		}
+	})
+
+	test("existing users are not affected", async () => {
+		await setEmailDomains({ allow: ['customer.com'] })
+		let user = await createUser({ project: PROJECT, password: 'password' })
+
+		let { publicKey } = generateKeyPair()
+		let payload: EmailPasswordPayload = {
+			email: user.email,
+			password: user.password,
+			public_key: Array.from(Buffer.from(publicKey)),
+			session: uuid(),
+		}
+
+		let res = await Http.post(Url.SignIn, payload, {
+			headers: { 'Vulpo-Project': PROJECT }
+		})
+
+		expect(res.status).toBe(200)
+	})
+
+	test("anonymous users can only link allowed domains", async () => {
+		await setEmailDomains({ allow: ['customer.com'] })
+
+		let options = { headers: { 'Vulpo-Project': PROJECT } }
+		let guest = await Http.post(Url.Anonymous, signInPayload(), options)
+
+		let link = (domain: string) => Http
+			.post(Url.AnonymousLinkPassword, {
+				email: `api.test+${uuid()}@${domain}`,
+				password: 'password',
+			}, {
+				headers: {
+					...options.headers,
+					'Authorization': `Bearer ${guest.data.access_token}`,
+				}
+			})
+			.catch(err => err.response)
+
+		let res = await link('vulpo.dev')
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.UserEmailDomainNotAllowed)
+
+		res = await link('customer.com')
+		expect(res.status).toBe(200)
+	})
+
+	test("rejects invalid domains", async () => {
+		for (let domain of ['*.*.example.com', 'user@example.com', 'https://example.com', '']) {
+			let res = await setEmailDomains({ allow: [domain] })
+			expect(res.status).toBe(400)
+		}
+	})
+})
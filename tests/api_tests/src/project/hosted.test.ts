@@ -0,0 +1,145 @@
+import Axios from 'axios'
+import { v4 as uuid } from 'uuid'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { SERVER_URL } from '../utils/env'
+import { generateAdminToken } from '../utils/admin'
+
+const DOMAIN = 'http://localhost:3000'
+
+const FLAGS = [
+	'auth::signin',
+	'auth::signup',
+	'method::email_password',
+	'hosted::pages',
+	'session::cookie',
+]
+
+let PROJECT: string
+
+let Hosted = Axios.create({
+	baseURL: `${SERVER_URL}/hosted`,
+	maxRedirects: 0,
+	validateStatus: () => true,
+})
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+function setFlags(flags: Array<string>) {
+	return Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, flags])
+}
+
+function csrfCookie(headers: Record<string, any>): string {
+	let cookie = (headers['set-cookie'] ?? [])
+		.find((cookie: string) => cookie.startsWith('vulpo_hosted_csrf='))
+
+	return cookie.split(';')[0].split('=')[1]
+}
+
+async function signUp(email: string, password: string) {
+	let page = await Hosted.get(`/${PROJECT}/sign_up`)
+	let csrf = csrfCookie(page.headers)
+
+	let form = new URLSearchParams({ email, password, csrf })
+	return Hosted.post(`/${PROJECT}/sign_up`, form.toString(), {
+		headers: {
+			'Content-Type': 'application/x-www-form-urlencoded',
+			'Cookie': `vulpo_hosted_csrf=${csrf}`,
+		}
+	})
+}
+
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `hosted-${uuid()}`,
+		domain: DOMAIN,
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+	await setFlags(FLAGS)
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+describe("Hosted Pages", () => {
+	test("renders the sign in page", async () => {
+		let res = await Hosted.get(`/${PROJECT}/sign_in`)
+
+		expect(res.status).toBe(200)
+		expect(res.headers['content-type']).toContain('text/html')
+		expect(res.data).toContain(`/hosted/${PROJECT}/sign_up`)
+	})
+
+	test("is not found without the flag", async () => {
+		await setFlags(FLAGS.filter(flag => flag !== 'hosted::pages'))
+		let res = await Hosted.get(`/${PROJECT}/sign_in`)
+		await setFlags(FLAGS)
+
+		expect(res.status).toBe(404)
+	})
+
+	test("rejects redirect URLs that are not allowed", async () => {
+		let res = await Hosted.get(`/${PROJECT}/sign_in`, {
+			params: { redirect_url: 'https://evil.com' }
+		})
+
+		expect(res.status).toBe(200)
+		expect(res.data).not.toContain('https://evil.com')
+	})
+
+	test("signs up and sets the session cookie", async () => {
+		let res = await signUp(`${uuid()}@vulpo.dev`, 'password')
+
+		expect(res.status).toBe(303)
+		expect(res.headers['location']).toBe(DOMAIN)
+		expect(res.headers['set-cookie'].join(';')).toContain('vulpo_session=')
+	})
+
+	test("rejects forms without a csrf token", async () => {
+		let form = new URLSearchParams({
+			email: `${uuid()}@vulpo.dev`,
+			password: 'password',
+			csrf: 'invalid',
+		})
+
+		let res = await Hosted.post(`/${PROJECT}/sign_up`, form.toString(), {
+			headers: { 'Content-Type': 'application/x-www-form-urlencoded' }
+		})
+
+		expect(res.status).toBe(200)
+		expect(res.headers['set-cookie']?.join(';') ?? '').not.toContain('vulpo_session=')
+	})
+
+	test("validates the branding", async () => {
+		let res = await Http.post('/settings/hosted_pages', {
+			logo_url: 'javascript:alert(1)',
+			primary_color: null,
+			background_color: null,
+		}, {
+			...adminOptions(),
+			params: { project_id: PROJECT },
+		}).catch(err => err.response)
+
+		expect(res.status).toBe(400)
+	})
+})
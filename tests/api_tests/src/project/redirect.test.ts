@@ -0,0 +1,113 @@
+import { v4 as uuid } from 'uuid'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, ErrorCode, PasswordResetPayload } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateAdminToken } from '../utils/admin'
+
+const DOMAIN = 'http://localhost:3000'
+
+let PROJECT: string
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+function setRedirectUrls(urls: Array<string>) {
+	return Http.post('/settings/redirect_urls', { urls }, {
+		...adminOptions(),
+		params: { project_id: PROJECT },
+	}).catch(err => err.response)
+}
+
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `redirect-${uuid()}`,
+		domain: DOMAIN,
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+
+	await setRedirectUrls([
+		'myapp://auth/*',
+		'https://*.example.com/auth',
+	])
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+// the URL is verified before the user is looked up, unknown emails don't
+// send anything
+function requestReset(redirect_url: string) {
+	let payload: PasswordResetPayload = {
+		email: `${uuid()}@vulpo.dev`,
+		redirect_url,
+	}
+
+	return Http
+		.post(Url.RequestPasswordReset, payload, {
+			headers: { 'Vulpo-Project': PROJECT }
+		})
+		.catch(err => err.response)
+}
+
+describe("Redirect URLs", () => {
+	test("returns the allow-list", async () => {
+		let res = await Http.get('/settings/redirect_urls', {
+			...adminOptions(),
+			params: { project_id: PROJECT },
+		})
+
+		expect(res.data.urls).toEqual(['myapp://auth/*', 'https://*.example.com/auth'])
+	})
+
+	test("allows the project domain and allowed URLs", async () => {
+		let urls = [
+			`${DOMAIN}/custom/reset`,
+			'myapp://auth/reset',
+			'https://shop.example.com/auth',
+			'https://shop.example.com/auth?lang=de',
+		]
+
+		for (let url of urls) {
+			let res = await requestReset(url)
+			expect(res.status).toBe(200)
+		}
+	})
+
+	test("rejects other URLs", async () => {
+		let urls = [
+			'https://evil.com/auth',
+			'https://example.com/auth',
+			'https://shop.example.com/auth/other',
+			'https://user@shop.example.com/auth',
+			'otherapp://auth/reset',
+		]
+
+		for (let url of urls) {
+			let res = await requestReset(url)
+			expect(res.status).toBe(400)
+			expect(res.data.code).toBe(ErrorCode.RedirectUrlNotAllowed)
+		}
+	})
+
+	test("rejects invalid allow-lists", async () => {
+		for (let url of ['https://example.com/auth?x=1', 'https://a*.example.com', 'example.com']) {
+			let res = await setRedirectUrls([url])
+			expect(res.status).toBe(400)
+		}
+	})
+})
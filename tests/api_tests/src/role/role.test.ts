@@ -0,0 +1,186 @@
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, RefreshAccessTokenPayload } from '@vulpo-dev/auth-sdk'
+import * as jwt from 'jsonwebtoken'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { PROJECT_ID } from '../utils/env'
+import { makeCreateSession, makeCreateUser, makeCleanUp } from '../utils/passwordless'
+import { generateAdminToken } from '../utils/admin'
+import { ratPayload } from '../utils/user'
+
+const EMAIL = 'api.test_roles@vulpo.dev'
+const USER_ID = '6b1f7c2e-3d4a-4e5f-9a8b-7c6d5e4f3a2b'
+const KEYS = generateKeyPair()
+
+let createSession = makeCreateSession(PROJECT_ID, USER_ID, KEYS.publicKey)
+let createUser = makeCreateUser(USER_ID, EMAIL, PROJECT_ID)
+let cleanUp = makeCleanUp(USER_ID)
+
+async function cleanUpRoles() {
+	await Db.query(`
+		delete from roles
+		 where project_id = $1
+		   and name like 'api.test%'
+	`, [PROJECT_ID])
+
+	await Db.query(`
+		delete from permissions
+		 where project_id = $1
+		   and name like 'api.test%'
+	`, [PROJECT_ID])
+}
+
+beforeEach(async () => {
+	await cleanUp()
+	await cleanUpRoles()
+	await createUser()
+})
+
+afterAll(() => Promise.all([cleanUp(), cleanUpRoles()]))
+afterAll(() => Db.end())
+
+function options() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+describe("Roles", () => {
+	test("roles can only contain known permissions", async () => {
+		let role = {
+			project: PROJECT_ID,
+			name: 'api.test_role',
+			description: null,
+			permissions: ['api.test:unknown'],
+		}
+
+		let res = await Http
+			.post('/role/create', role, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('permission/not_found')
+	})
+
+	test("role names are unique", async () => {
+		let role = {
+			project: PROJECT_ID,
+			name: 'api.test_role',
+			description: null,
+			permissions: [],
+		}
+
+		await Http.post('/role/create', role, options())
+
+		let res = await Http
+			.post('/role/create', role, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('role/exists')
+	})
+
+	test("access token contains the permissions of the user's roles", async () => {
+		let permission = {
+			project: PROJECT_ID,
+			name: 'api.test:write',
+			description: null,
+		}
+
+		await Http.post('/role/permission/create', permission, options())
+
+		let role = {
+			project: PROJECT_ID,
+			name: 'api.test_role',
+			description: null,
+			permissions: ['api.test:write'],
+		}
+
+		let { data } = await Http.post('/role/create', role, options())
+
+		let assign = {
+			user: USER_ID,
+			project: PROJECT_ID,
+			roles: [data.id],
+		}
+
+		let res = await Http
+			.post('/role/user/set', assign, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+
+		let sessionId = await createSession()
+
+		await Db.query(`
+			update sessions
+			   set expire_at = $2
+			 where id = $1
+		`, [sessionId, new Date(Date.now() + 30 * 60 * 1000)])
+
+		let url = Url.TokenRefresh.replace(':session', sessionId)
+		let refresh: RefreshAccessTokenPayload = {
+			value: jwt.sign(ratPayload(), KEYS.privateKey, { algorithm: 'ES384' }),
+		}
+
+		let token = await Http
+			.post(url, refresh)
+			.catch(err => err.response)
+
+		expect(token.status).toBe(200)
+
+		let [, payload] = token.data.access_token.split('.')
+		let claims = JSON.parse(Buffer.from(payload, 'base64').toString())
+		expect(claims.permissions).toEqual(['api.test:write'])
+	})
+
+	test("removed permissions are removed from roles", async () => {
+		let permission = {
+			project: PROJECT_ID,
+			name: 'api.test:read',
+			description: null,
+		}
+
+		await Http.post('/role/permission/create', permission, options())
+
+		let role = {
+			project: PROJECT_ID,
+			name: 'api.test_role',
+			description: null,
+			permissions: ['api.test:read'],
+		}
+
+		await Http.post('/role/create', role, options())
+
+		let payload = { project: PROJECT_ID, name: 'api.test:read' }
+		await Http.post('/role/permission/delete', payload, options())
+
+		let { rows } = await Db.query(`
+			select permissions
+			  from roles
+			 where project_id = $1
+			   and name = $2
+		`, [PROJECT_ID, 'api.test_role'])
+
+		expect(rows[0].permissions).toEqual([])
+	})
+
+	test("requires an admin", async () => {
+		let res = await Http
+			.get('/role/list', {
+				headers: {
+					'Authorization': `Bearer ${generateAdminToken(true)}`,
+					'Vulpo-Project': admin.id,
+				},
+				params: { project: PROJECT_ID },
+			})
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+})
@@ -0,0 +1,168 @@
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import * as jwt from 'jsonwebtoken'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { PROJECT_ID } from '../utils/env'
+import { generateAdminToken } from '../utils/admin'
+
+const NAME = 'api.test_service_account'
+
+async function cleanUp() {
+	await Db.query(`
+		delete from service_accounts
+		 where project_id = $1
+		   and name = $2
+	`, [PROJECT_ID, NAME])
+}
+
+beforeEach(cleanUp)
+afterAll(cleanUp)
+afterAll(() => Db.end())
+
+function options() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+async function createServiceAccount(scopes = ['invoices:read', 'invoices:write']) {
+	let payload = { project: PROJECT_ID, name: NAME, scopes }
+	let { data } = await Http.post('/service_account/create', payload, options())
+	return data
+}
+
+function requestToken(body: Record<string, string>) {
+	return Http
+		.post('/oauth/token', new URLSearchParams(body).toString(), {
+			headers: { 'Content-Type': 'application/x-www-form-urlencoded' }
+		})
+		.catch(err => err.response)
+}
+
+describe("Client Credentials", () => {
+	test("returns a token that can be verified with the project's public key", async () => {
+		let { client_id, client_secret } = await createServiceAccount()
+
+		let res = await requestToken({
+			grant_type: 'client_credentials',
+			client_id,
+			client_secret,
+		})
+
+		expect(res.status).toBe(200)
+		expect(res.data.token_type).toBe('Bearer')
+		expect(res.data.scope).toBe('invoices:read invoices:write')
+
+		let { rows } = await Db.query(`
+			select public_key
+			  from project_keys
+			 where project_id = $1
+			   and is_active = true
+		`, [PROJECT_ID])
+
+		let publicKey = rows[0].public_key.toString()
+		let claims = jwt.verify(res.data.access_token, publicKey, { algorithms: ['ES384'] }) as jwt.JwtPayload
+
+		expect(claims.sub).toBe(client_id)
+		expect(claims.client_id).toBe(client_id)
+		expect(claims.scope).toBe('invoices:read invoices:write')
+	})
+
+	test("accepts HTTP basic authentication and narrows the scope", async () => {
+		let { client_id, client_secret } = await createServiceAccount()
+		let basic = Buffer.from(`${client_id}:${client_secret}`).toString('base64')
+
+		let res = await Http
+			.post('/oauth/token', 'grant_type=client_credentials&scope=invoices:read', {
+				headers: {
+					'Content-Type': 'application/x-www-form-urlencoded',
+					'Authorization': `Basic ${basic}`,
+				}
+			})
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+		expect(res.data.scope).toBe('invoices:read')
+	})
+
+	test("rejects scopes that were not granted", async () => {
+		let { client_id, client_secret } = await createServiceAccount(['invoices:read'])
+
+		let res = await requestToken({
+			grant_type: 'client_credentials',
+			client_id,
+			client_secret,
+			scope: 'invoices:write',
+		})
+
+		expect(res.status).toBe(400)
+		expect(res.data.error).toBe('invalid_scope')
+	})
+
+	test("rejects an invalid secret", async () => {
+		let { client_id } = await createServiceAccount()
+
+		let res = await requestToken({
+			grant_type: 'client_credentials',
+			client_id,
+			client_secret: 'invalid',
+		})
+
+		expect(res.status).toBe(401)
+		expect(res.headers['www-authenticate']).toBe('Basic')
+		expect(res.data.error).toBe('invalid_client')
+	})
+
+	test("rejects unsupported grant types", async () => {
+		let { client_id, client_secret } = await createServiceAccount()
+
+		let res = await requestToken({
+			grant_type: 'password',
+			client_id,
+			client_secret,
+		})
+
+		expect(res.status).toBe(400)
+		expect(res.data.error).toBe('unsupported_grant_type')
+	})
+
+	test("rotating the secret invalidates the old one", async () => {
+		let { client_id, client_secret } = await createServiceAccount()
+
+		let payload = { project: PROJECT_ID, id: client_id }
+		let rotated = await Http.post('/service_account/rotate_secret', payload, options())
+
+		let res = await requestToken({
+			grant_type: 'client_credentials',
+			client_id,
+			client_secret,
+		})
+
+		expect(res.status).toBe(401)
+
+		res = await requestToken({
+			grant_type: 'client_credentials',
+			client_id,
+			client_secret: rotated.data.client_secret,
+		})
+
+		expect(res.status).toBe(200)
+	})
+
+	test("secrets are not listed", async () => {
+		await createServiceAccount()
+
+		let { data } = await Http.get('/service_account/list', {
+			...options(),
+			params: { project: PROJECT_ID },
+		})
+
+		let account = data.items.find((item: { name: string }) => item.name === NAME)
+		expect(account.secret).toBeUndefined()
+		expect(account.scopes).toEqual(['invoices:read', 'invoices:write'])
+	})
+})
@@ -0,0 +1,190 @@
+import { v4 as uuid } from 'uuid'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, EmailPasswordPayload, Flag, ErrorCode } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { generateAdminToken } from '../utils/admin'
+import { createUser } from '../utils/user'
+
+let PROJECT: string
+
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `cookie-${uuid()}`,
+		domain: 'http://localhost:3000',
+	}, {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	})
+
+	PROJECT = res.data[0]
+
+	await Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, [Flag.SignIn, Flag.EmailAndPassword, Flag.CookieSession]])
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+type Cookies = Record<string, { value: string, attributes: Array<string> }>
+
+function getCookies(headers: Record<string, any>): Cookies {
+	let cookies: Cookies = {}
+
+	for (let cookie of headers['set-cookie'] ?? []) {
+		let [pair, ...attributes] = cookie.split(';')
+		let index = pair.indexOf('=')
+		cookies[pair.slice(0, index)] = {
+			value: pair.slice(index + 1),
+			attributes: attributes.map((attribute: string) => attribute.trim().toLowerCase()),
+		}
+	}
+
+	return cookies
+}
+
+function toHeader(cookies: Cookies): string {
+	return Object.entries(cookies)
+		.map(([name, cookie]) => `${name}=${cookie.value}`)
+		.join('; ')
+}
+
+async function signIn() {
+	let user = await createUser({ project: PROJECT, password: 'password' })
+	let keys = generateKeyPair()
+
+	let payload: EmailPasswordPayload = {
+		email: user.email,
+		password: user.password,
+		public_key: Array.from(Buffer.from(keys.publicKey)),
+		session: uuid()
+	}
+
+	let res = await Http.post(Url.SignIn, payload, {
+		headers: { 'Vulpo-Project': PROJECT }
+	})
+
+	return { user, res, cookies: getCookies(res.headers) }
+}
+
+function refresh(cookies: Cookies, csrf?: string) {
+	let headers: Record<string, string> = {
+		'Vulpo-Project': PROJECT,
+		'Cookie': toHeader(cookies),
+	}
+
+	if (csrf) {
+		headers['X-CSRF-Token'] = csrf
+	}
+
+	return Http
+		.post(Url.CookieRefresh, null, { headers })
+		.catch(err => err.response)
+}
+
+describe("Cookie Sessions", () => {
+	test("sign in sets the session cookies", async () => {
+		let { res, cookies } = await signIn()
+
+		expect(cookies.vulpo_session.attributes).toEqual(
+			expect.arrayContaining(['httponly', 'secure', 'samesite=lax'])
+		)
+		expect(cookies.vulpo_access_token.value.split('.').length).toBe(3)
+		expect(cookies.vulpo_csrf.attributes).not.toContain('httponly')
+
+		// scripts can't read the access token from the response either
+		expect(res.data.access_token).toBeUndefined()
+		expect(res.data.session).toBeTruthy()
+	})
+
+	test("authenticates requests with the access token cookie", async () => {
+		let { user, cookies } = await signIn()
+
+		let res = await Http.get(Url.UserGet, {
+			headers: {
+				'Vulpo-Project': PROJECT,
+				'Cookie': toHeader(cookies),
+			}
+		})
+
+		expect(res.status).toBe(200)
+		expect(res.data.email).toBe(user.email)
+	})
+
+	test("refresh requires the CSRF token", async () => {
+		let { cookies } = await signIn()
+
+		let missing = await refresh(cookies)
+		expect(missing.status).toBe(403)
+		expect(missing.data.code).toBe(ErrorCode.SessionInvalidCsrfToken)
+
+		let invalid = await refresh(cookies, 'invalid')
+		expect(invalid.status).toBe(403)
+	})
+
+	test("refresh replaces the session cookie", async () => {
+		let { cookies } = await signIn()
+
+		let res = await refresh(cookies, cookies.vulpo_csrf.value)
+		expect(res.status).toBe(200)
+
+		let next = getCookies(res.headers)
+		expect(next.vulpo_session.value).not.toBe(cookies.vulpo_session.value)
+		expect(next.vulpo_access_token.value.split('.').length).toBe(3)
+		expect(res.data.access_token).toBeUndefined()
+
+		let reused = await refresh(cookies, cookies.vulpo_csrf.value)
+		expect(reused.status).toBe(403)
+	})
+
+	test("sign out removes the session", async () => {
+		let { cookies } = await signIn()
+		let [session] = cookies.vulpo_session.value.split('.')
+
+		let res = await Http.post(Url.CookieSignOut, null, {
+			headers: {
+				'Vulpo-Project': PROJECT,
+				'Cookie': toHeader(cookies),
+				'X-CSRF-Token': cookies.vulpo_csrf.value,
+			}
+		})
+
+		expect(res.status).toBe(200)
+		expect(getCookies(res.headers).vulpo_session.value).toBe('')
+
+		let { rows } = await Db.query(`
+			select count(*)::int as count
+			  from sessions
+			 where id = $1
+		`, [session])
+
+		expect(rows[0].count).toBe(0)
+	})
+
+	test("can't be combined with DPoP", async () => {
+		let res = await Http.post('/project/set_flags', {
+			project: PROJECT,
+			flags: [Flag.SignIn, Flag.EmailAndPassword, Flag.CookieSession, Flag.DPoP],
+		}, {
+			headers: {
+				'Authorization': `Bearer ${generateAdminToken()}`,
+				'Vulpo-Project': admin.id,
+			}
+		}).catch(err => err.response)
+
+		expect(res.status).toBe(400)
+	})
+})
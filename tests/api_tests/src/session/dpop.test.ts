@@ -0,0 +1,187 @@
+import { v4 as uuid } from 'uuid'
+import { createHash, createPublicKey, generateKeyPairSync } from 'crypto'
+import * as jwt from 'jsonwebtoken'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, EmailPasswordPayload, Flag } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { SERVER_URL } from '../utils/env'
+import { generateKeyPair } from '../utils/crypto'
+import { generateAdminToken } from '../utils/admin'
+import { createUser } from '../utils/user'
+
+const USER_URL = `${SERVER_URL}/api${Url.UserGet}`
+
+let PROJECT: string
+
+// tokens are only bound on a separate project, other tests use bearer tokens
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `dpop-${uuid()}`,
+		domain: 'http://localhost:3000',
+	}, {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	})
+
+	PROJECT = res.data[0]
+
+	await Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, [Flag.SignIn, Flag.EmailAndPassword, Flag.DPoP]])
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+function base64url(value: Buffer): string {
+	return value.toString('base64url')
+}
+
+function thumbprint(publicKey: string): string {
+	let { crv, x, y } = createPublicKey(publicKey).export({ format: 'jwk' })
+	let jwk = JSON.stringify({ crv, kty: 'EC', x, y })
+	return base64url(createHash('sha256').update(jwk).digest())
+}
+
+type ProofOptions = {
+	htm?: string;
+	htu?: string;
+	jti?: string;
+}
+
+function proof(keys: { publicKey: string, privateKey: string }, token: string, options: ProofOptions = {}) {
+	let jwk = createPublicKey(keys.publicKey).export({ format: 'jwk' })
+	let payload = {
+		jti: options.jti ?? uuid(),
+		htm: options.htm ?? 'GET',
+		htu: options.htu ?? USER_URL,
+		ath: base64url(createHash('sha256').update(token).digest()),
+	}
+
+	return jwt.sign(payload, keys.privateKey, {
+		algorithm: 'ES384',
+		header: { alg: 'ES384', typ: 'dpop+jwt', jwk } as jwt.JwtHeader,
+	})
+}
+
+async function signIn() {
+	let user = await createUser({ project: PROJECT, password: 'password' })
+	let keys = generateKeyPair()
+
+	let payload: EmailPasswordPayload = {
+		email: user.email,
+		password: user.password,
+		public_key: Array.from(Buffer.from(keys.publicKey)),
+		session: uuid()
+	}
+
+	let res = await Http.post(Url.SignIn, payload, {
+		headers: { 'Vulpo-Project': PROJECT }
+	})
+
+	return { user, keys, token: res.data.access_token as string }
+}
+
+function getUser(headers: Record<string, string>) {
+	return Http
+		.get(Url.UserGet, { headers: { ...headers, 'Vulpo-Project': PROJECT } })
+		.catch(err => err.response)
+}
+
+describe("DPoP", () => {
+	test("binds the access token to the session key", async () => {
+		let { keys, token } = await signIn()
+		let claims = jwt.decode(token) as jwt.JwtPayload
+
+		expect(claims.cnf.jkt).toBe(thumbprint(keys.publicKey))
+	})
+
+	test("accepts a valid proof", async () => {
+		let { user, keys, token } = await signIn()
+
+		let res = await getUser({
+			'Authorization': `DPoP ${token}`,
+			'DPoP': proof(keys, token),
+		})
+
+		expect(res.status).toBe(200)
+		expect(res.data.email).toBe(user.email)
+	})
+
+	test("rejects bound tokens without a proof", async () => {
+		let { token } = await signIn()
+
+		let bearer = await getUser({ 'Authorization': `Bearer ${token}` })
+		expect(bearer.status).toBe(401)
+
+		let missing = await getUser({ 'Authorization': `DPoP ${token}` })
+		expect(missing.status).toBe(401)
+	})
+
+	test("rejects a proof that was used before", async () => {
+		let { keys, token } = await signIn()
+		let headers = {
+			'Authorization': `DPoP ${token}`,
+			'DPoP': proof(keys, token),
+		}
+
+		expect((await getUser(headers)).status).toBe(200)
+		expect((await getUser(headers)).status).toBe(401)
+	})
+
+	test("rejects proofs for another request or key", async () => {
+		let { keys, token } = await signIn()
+		let other = generateKeyPair()
+
+		let cases = [
+			proof(keys, token, { htm: 'POST' }),
+			proof(keys, token, { htu: `${SERVER_URL}/api${Url.UserSessions}` }),
+			proof(other, token),
+		]
+
+		for (let dpop of cases) {
+			let res = await getUser({ 'Authorization': `DPoP ${token}`, 'DPoP': dpop })
+			expect(res.status).toBe(401)
+		}
+	})
+
+	test("rejects keys that can't be bound before creating the session", async () => {
+		let user = await createUser({ project: PROJECT, password: 'password' })
+		let publicKey = generateKeyPairSync('ed25519').publicKey
+			.export({ type: 'spki', format: 'pem' })
+
+		let session = uuid()
+		let payload: EmailPasswordPayload = {
+			email: user.email,
+			password: user.password,
+			public_key: Array.from(Buffer.from(publicKey)),
+			session,
+		}
+
+		let res = await Http.post(Url.SignIn, payload, {
+			headers: { 'Vulpo-Project': PROJECT }
+		}).catch(err => err.response)
+
+		expect(res.status).toBe(400)
+
+		let { rows } = await Db.query(`
+			select id
+			  from sessions
+			 where id = $1
+		`, [session])
+
+		expect(rows.length).toBe(0)
+	})
+})
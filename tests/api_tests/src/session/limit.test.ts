@@ -0,0 +1,124 @@
+import { v4 as uuid } from 'uuid'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, ErrorCode, EmailPasswordPayload, Flag } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { generateAdminToken } from '../utils/admin'
+import { CreatedUser, createUser } from '../utils/user'
+
+const PASSWORD = 'password'
+
+let PROJECT: string
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+// the limit is set on a separate project, other tests sign in concurrently
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `session-limit-${uuid()}`,
+		domain: 'http://localhost:3000',
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+
+	await Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, [Flag.SignIn, Flag.EmailAndPassword]])
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+async function setLimit(max_sessions: number | null, limit_mode: 'evict' | 'reject') {
+	await Http.post('/settings/sessions', { max_sessions, limit_mode }, {
+		...adminOptions(),
+		params: { project_id: PROJECT },
+	})
+}
+
+function signIn(user: CreatedUser) {
+	let { publicKey } = generateKeyPair()
+
+	let payload: EmailPasswordPayload = {
+		email: user.email,
+		password: user.password,
+		public_key: Array.from(Buffer.from(publicKey)),
+		session: uuid()
+	}
+
+	return Http
+		.post(Url.SignIn, payload, {
+			headers: { 'Vulpo-Project': PROJECT }
+		})
+		.catch(err => err.response)
+}
+
+async function sessions(user: CreatedUser): Promise<Array<string>> {
+	let { rows } = await Db.query(`
+		select id
+		  from sessions
+		 where user_id = $1
+	`, [user.id])
+
+	return rows.map(row => row.id)
+}
+
+describe("Session Limit", () => {
+	test("evicts the least recently used session", async () => {
+		await setLimit(2, 'evict')
+		let user = await createUser({ project: PROJECT, password: PASSWORD })
+
+		let first = await signIn(user)
+		let second = await signIn(user)
+		let third = await signIn(user)
+
+		expect(third.status).toBe(200)
+
+		let ids = await sessions(user)
+		expect(ids.sort()).toEqual([second.data.session, third.data.session].sort())
+		expect(ids).not.toContain(first.data.session)
+	})
+
+	test("rejects sign ins above the limit", async () => {
+		await setLimit(1, 'reject')
+		let user = await createUser({ project: PROJECT, password: PASSWORD })
+
+		let first = await signIn(user)
+		expect(first.status).toBe(200)
+
+		let second = await signIn(user)
+		expect(second.status).toBe(400)
+		expect(second.data.code).toBe(ErrorCode.SessionLimitReached)
+
+		expect(await sessions(user)).toEqual([first.data.session])
+	})
+
+	test("allows any number of sessions without a limit", async () => {
+		await setLimit(null, 'reject')
+		let user = await createUser({ project: PROJECT, password: PASSWORD })
+
+		for (let i = 0; i < 3; i++) {
+			let res = await signIn(user)
+			expect(res.status).toBe(200)
+		}
+
+		expect(await sessions(user)).toHaveLength(3)
+	})
+})
@@ -0,0 +1,285 @@
+import { v4 as uuid } from 'uuid'
+import * as jwt from 'jsonwebtoken'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import {
+	Url,
+	EmailPasswordPayload,
+	Flag,
+	ErrorCode,
+	ReauthenticatePayload,
+	Claims,
+} from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { generateAdminToken } from '../utils/admin'
+import { createUser, makeGenerateAccessToken, ratPayload, CreatedUser } from '../utils/user'
+import { createToken } from '../utils/passwordless'
+
+let PROJECT: string
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `reauthenticate-${uuid()}`,
+		domain: 'http://localhost:3000',
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+
+	await Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, [Flag.SignIn, Flag.EmailAndPassword]])
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+type SignedIn = {
+	user: CreatedUser;
+	session: string;
+	keys: ReturnType<typeof generateKeyPair>;
+	accessToken: string;
+}
+
+async function signIn(): Promise<SignedIn> {
+	let user = await createUser({ project: PROJECT, password: 'password' })
+	let keys = generateKeyPair()
+
+	let payload: EmailPasswordPayload = {
+		email: user.email,
+		password: user.password,
+		public_key: Array.from(Buffer.from(keys.publicKey)),
+		session: uuid()
+	}
+
+	let res = await Http.post(Url.SignIn, payload, {
+		headers: { 'Vulpo-Project': PROJECT }
+	})
+
+	return { user, keys, session: res.data.session, accessToken: res.data.access_token }
+}
+
+// the user signed in an hour ago
+function ageSession(session: string) {
+	return Db.query(`
+		update sessions
+		   set auth_time = now() - interval '1 hour'
+		 where id = $1
+	`, [session])
+}
+
+function rat({ keys }: SignedIn) {
+	return makeGenerateAccessToken(keys.privateKey)({ payload: ratPayload() })
+}
+
+function refresh(signedIn: SignedIn) {
+	let url = Url.TokenRefresh.replace(':session', signedIn.session)
+	return Http.post(url, { value: rat(signedIn) }, {
+		headers: { 'Vulpo-Project': PROJECT }
+	})
+}
+
+function generateApiKey(accessToken: string) {
+	return Http.post(Url.GenerateApiKey, { name: uuid() }, {
+		headers: {
+			'Authorization': `Bearer ${accessToken}`,
+			'Vulpo-Project': PROJECT,
+		}
+	}).catch(err => err.response)
+}
+
+function reauthenticate(signedIn: SignedIn, payload: ReauthenticatePayload) {
+	let url = Url.Reauthenticate.replace(':session', signedIn.session)
+	return Http.post(url, payload, {
+		headers: { 'Vulpo-Project': PROJECT }
+	}).catch(err => err.response)
+}
+
+describe("Reauthenticate", () => {
+	test("access tokens contain auth_time and amr", async () => {
+		let { accessToken } = await signIn()
+		let claims = jwt.decode(accessToken) as Claims
+
+		expect(claims.amr).toEqual(['pwd'])
+		expect(Math.abs(claims.auth_time! - Date.now() / 1000)).toBeLessThan(60)
+	})
+
+	test("sensitive operations require a recent authentication", async () => {
+		let signedIn = await signIn()
+
+		let res = await generateApiKey(signedIn.accessToken)
+		expect(res.status).toBe(200)
+
+		await ageSession(signedIn.session)
+		let { data } = await refresh(signedIn)
+
+		res = await generateApiKey(data.access_token)
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.SessionReauthenticationRequired)
+
+		res = await Http.post(Url.UpdateEmail, { new_email: `${uuid()}@vulpo.dev` }, {
+			headers: {
+				'Authorization': `Bearer ${data.access_token}`,
+				'Vulpo-Project': PROJECT,
+			}
+		}).catch(err => err.response)
+
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.SessionReauthenticationRequired)
+
+		let url = Url.UserDeleteAccount.replace(':session', signedIn.session)
+		res = await Http.post(url, { value: rat(signedIn) }, {
+			headers: { 'Vulpo-Project': PROJECT }
+		}).catch(err => err.response)
+
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.SessionReauthenticationRequired)
+	})
+
+	test("can re-authenticate with the password", async () => {
+		let signedIn = await signIn()
+		await ageSession(signedIn.session)
+
+		let res = await reauthenticate(signedIn, {
+			token: rat(signedIn),
+			method: 'password',
+			password: 'wrong-password',
+		})
+
+		expect(res.data.code).toBe(ErrorCode.InvalidEmailPassword)
+
+		res = await reauthenticate(signedIn, {
+			token: rat(signedIn),
+			method: 'password',
+			password: signedIn.user.password,
+		})
+
+		expect(res.status).toBe(200)
+
+		res = await generateApiKey(res.data.access_token)
+		expect(res.status).toBe(200)
+	})
+
+	test("limits the password attempts", async () => {
+		let signedIn = await signIn()
+
+		for (let i = 0; i < 5; i++) {
+			let res = await reauthenticate(signedIn, {
+				token: rat(signedIn),
+				method: 'password',
+				password: 'wrong-password',
+			})
+
+			expect(res.data.code).toBe(ErrorCode.InvalidEmailPassword)
+		}
+
+		let res = await reauthenticate(signedIn, {
+			token: rat(signedIn),
+			method: 'password',
+			password: signedIn.user.password,
+		})
+
+		expect(res.data.code).toBe(ErrorCode.SessionTooManyAttempts)
+	})
+
+	test("can re-authenticate with a code", async () => {
+		let signedIn = await signIn()
+		await ageSession(signedIn.session)
+
+		let code = await createToken({
+			user: signedIn.user,
+			session: { id: signedIn.session, keys: signedIn.keys },
+			token: '12345678',
+			mode: 'code',
+		})
+
+		let res = await reauthenticate(signedIn, {
+			token: rat(signedIn),
+			method: 'code',
+			id: code.id,
+			code: '00000000',
+		})
+
+		expect(res.data.code).toBe(ErrorCode.PasswordlessInvalidToken)
+
+		res = await reauthenticate(signedIn, {
+			token: rat(signedIn),
+			method: 'code',
+			id: code.id,
+			code: code.value,
+		})
+
+		expect(res.status).toBe(200)
+
+		let claims = jwt.decode(res.data.access_token) as Claims
+		expect(claims.amr).toEqual(['otp'])
+
+		// codes can only be used once
+		res = await reauthenticate(signedIn, {
+			token: rat(signedIn),
+			method: 'code',
+			id: code.id,
+			code: code.value,
+		})
+
+		expect(res.data.code).toBe(ErrorCode.PasswordlessInvalidToken)
+	})
+
+	test("rejects codes of other sessions", async () => {
+		let signedIn = await signIn()
+		let other = await signIn()
+
+		let code = await createToken({
+			user: other.user,
+			session: { id: other.session, keys: other.keys },
+			token: '12345678',
+			mode: 'code',
+		})
+
+		let res = await reauthenticate(signedIn, {
+			token: rat(signedIn),
+			method: 'code',
+			id: code.id,
+			code: code.value,
+		})
+
+		expect(res.status).toBe(403)
+	})
+
+	test("projects can disable the check", async () => {
+		let signedIn = await signIn()
+		await ageSession(signedIn.session)
+		let { data } = await refresh(signedIn)
+
+		await Http.post('/settings/reauthentication', { max_age: null }, {
+			...adminOptions(),
+			params: { project_id: PROJECT },
+		})
+
+		let res = await generateApiKey(data.access_token)
+		expect(res.status).toBe(200)
+
+		await Http.post('/settings/reauthentication', { max_age: 600 }, {
+			...adminOptions(),
+			params: { project_id: PROJECT },
+		})
+	})
+})
@@ -0,0 +1,176 @@
+import { v4 as uuid } from 'uuid'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { enableSms, getPhone, startSmsGateway, SmsGateway } from '../utils/sms'
+import { PROJECT_ID } from '../utils/env'
+
+let gateway: SmsGateway
+
+beforeAll(async () => {
+	gateway = await startSmsGateway()
+	await enableSms(gateway.url)
+})
+
+afterAll(async () => {
+	await gateway.close()
+	await Db.end()
+})
+
+function requestPayload(phone: string, device_languages: Array<string> = []) {
+	let { publicKey } = generateKeyPair()
+	return {
+		phone,
+		public_key: Array.from(Buffer.from(publicKey)),
+		session: uuid(),
+		device_languages,
+	}
+}
+
+describe("Request SMS Code", () => {
+	test("sends a numeric code through the gateway", async () => {
+		let phone = getPhone()
+
+		let res = await Http
+			.post('/sms/', requestPayload(phone))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+		expect(res.data.id).toBeTruthy()
+		expect(res.data.session).toBeTruthy()
+
+		let sms = gateway.messages.find(message => message.to === phone)
+		expect(sms).toBeTruthy()
+		expect(sms?.from).toBe('Vulpo')
+		expect(sms?.authorization).toBe('Bearer sms-test')
+		expect(sms?.message).toMatch(/^\d{6} /)
+
+		let { rows } = await Db.query(`
+			select phone
+			     , user_id
+			     , attempts
+			  from sms_codes
+			 where id = $1
+			   and project_id = $2
+		`, [res.data.id, PROJECT_ID])
+
+		expect(rows[0].phone).toBe(phone)
+		expect(rows[0].user_id).toBeNull()
+		expect(rows[0].attempts).toBe(0)
+	})
+
+
+	test("normalizes formatted phone numbers", async () => {
+		let phone = getPhone()
+		let formatted = `${phone.slice(0, 3)} (${phone.slice(3, 6)}) ${phone.slice(6, 9)}-${phone.slice(9)}`
+
+		let res = await Http
+			.post('/sms/', requestPayload(formatted))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+
+		let sms = gateway.messages.find(message => message.to === phone)
+		expect(sms).toBeTruthy()
+	})
+
+
+	test("limits the codes sent to a phone number", async () => {
+		let phone = getPhone()
+
+		let res = await Http
+			.post('/sms/', requestPayload(phone))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+
+		res = await Http
+			.post('/sms/', requestPayload(phone))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(429)
+		expect(res.data.code).toBe('sms/too_many_requests')
+
+		let sent = gateway.messages.filter(message => message.to === phone)
+		expect(sent.length).toBe(1)
+	})
+
+
+	test("limits parallel requests", async () => {
+		let phone = getPhone()
+
+		let responses = await Promise.all(Array.from({ length: 6 }, () => {
+			return Http
+				.post('/sms/', requestPayload(phone))
+				.catch(err => err.response)
+		}))
+
+		let sent = responses.filter(res => res.status === 200)
+		expect(sent.length).toBe(1)
+
+		let { rows } = await Db.query(`
+			select count(*)::int as count
+			  from sms_codes
+			 where phone = $1
+		`, [phone])
+
+		expect(rows[0].count).toBe(1)
+	})
+
+
+	test("uses the project's translations", async () => {
+		let { rows } = await Db.query(`
+			insert into templates(body, name, project_id)
+			values('', 'sms', $1)
+			on conflict (name, project_id)
+			  do update set name = excluded.name
+			returning id
+		`, [PROJECT_ID])
+
+		await Db.query(`
+			insert into template_translations(project_id, template_id, language, content)
+			values($1, $2, 'de', $3)
+			on conflict (template_id, language)
+			  do update set content = $3
+		`, [PROJECT_ID, rows[0].id, { message: 'Dein Code: {{props.code}}' }])
+
+		let phone = getPhone()
+
+		let res = await Http
+			.post('/sms/', requestPayload(phone, ['de']))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+
+		let sms = gateway.messages.find(message => message.to === phone)
+		expect(sms?.message).toMatch(/^Dein Code: \d{6}$/)
+	})
+
+
+	test("fails for invalid phone numbers", async () => {
+		let res = await Http
+			.post('/sms/', requestPayload('0660 123'))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('sms/invalid_phone')
+	})
+
+
+	test("fails when user is disabled", async () => {
+		let phone = getPhone()
+
+		await Db.query(`
+			insert into users(phone, phone_verified, project_id, provider_id, state)
+			values($1, true, $2, 'sms', 'disabled')
+		`, [phone, PROJECT_ID])
+
+		let res = await Http
+			.post('/sms/', requestPayload(phone))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('user/disabled')
+	})
+})
@@ -0,0 +1,214 @@
+import * as jwt from 'jsonwebtoken'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { CreatedSession } from '../utils/passwordless'
+import { ratPayload } from '../utils/user'
+import { createSmsCode, createSmsSession, enableSms, getPhone, startSmsGateway, SmsGateway } from '../utils/sms'
+import SessionResponseSchema from '../utils/schema/session-response'
+import { PROJECT_ID } from '../utils/env'
+
+let gateway: SmsGateway
+
+beforeAll(async () => {
+	gateway = await startSmsGateway()
+	await enableSms(gateway.url)
+})
+
+afterAll(async () => {
+	await gateway.close()
+	await Db.end()
+})
+
+function generateRat(session: CreatedSession) {
+	return jwt.sign(
+		JSON.stringify(ratPayload()),
+		session.keys.privateKey,
+		{ algorithm: 'ES384', header: { typ: 'JWT', alg: 'ES384' } }
+	)
+}
+
+function verifyPayload(id: string, code: string, session: CreatedSession) {
+	return {
+		id,
+		code,
+		session: session.id,
+		token: generateRat(session),
+		device_languages: ['de-AT', 'de'],
+	}
+}
+
+describe("Verify SMS Code", () => {
+	test("creates a user with a verified phone number", async () => {
+		let phone = getPhone()
+		let session = await createSmsSession()
+		let smsCode = await createSmsCode({ phone, session })
+
+		let res = await Http
+			.post('/sms/verify', verifyPayload(smsCode.id, smsCode.code, session))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+		expect(SessionResponseSchema.validate(res.data)).toBeTruthy()
+		expect(res.data.created).toBe(true)
+
+		let { rows } = await Db.query(`
+			select phone
+			     , phone_verified
+			     , email
+			     , provider_id
+			  from users
+			 where id = $1
+			   and project_id = $2
+		`, [res.data.user_id, PROJECT_ID])
+
+		expect(rows[0].phone).toBe(phone)
+		expect(rows[0].phone_verified).toBe(true)
+		expect(rows[0].email).toBeNull()
+		expect(rows[0].provider_id).toBe('sms')
+
+		let { rows: codes } = await Db.query(`
+			select id from sms_codes where phone = $1
+		`, [phone])
+
+		expect(codes.length).toBe(0)
+	})
+
+
+	test("device languages are optional", async () => {
+		let phone = getPhone()
+		let session = await createSmsSession()
+		let smsCode = await createSmsCode({ phone, session })
+
+		let { device_languages, ...payload } = verifyPayload(smsCode.id, smsCode.code, session)
+
+		let res = await Http
+			.post('/sms/verify', payload)
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+	})
+
+
+	test("signs in an existing user and verifies the phone number", async () => {
+		let phone = getPhone()
+
+		let { rows: users } = await Db.query(`
+			insert into users(phone, project_id, provider_id)
+			values($1, $2, 'sms')
+			returning id
+		`, [phone, PROJECT_ID])
+
+		let userId = users[0].id
+		let session = await createSmsSession()
+		let smsCode = await createSmsCode({ phone, session, userId })
+
+		let res = await Http
+			.post('/sms/verify', verifyPayload(smsCode.id, smsCode.code, session))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+		expect(res.data.created).toBe(false)
+		expect(res.data.user_id).toBe(userId)
+
+		let { rows } = await Db.query(`
+			select phone_verified from users where id = $1
+		`, [userId])
+
+		expect(rows[0].phone_verified).toBe(true)
+	})
+
+
+	test("counts failed attempts", async () => {
+		let phone = getPhone()
+		let session = await createSmsSession()
+		let smsCode = await createSmsCode({ phone, session })
+
+		let res = await Http
+			.post('/sms/verify', verifyPayload(smsCode.id, '000000', session))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('sms/invalid_code')
+
+		let { rows } = await Db.query(`
+			select attempts from sms_codes where id = $1
+		`, [smsCode.id])
+
+		expect(rows[0].attempts).toBe(1)
+	})
+
+
+	test("fails after too many attempts", async () => {
+		let phone = getPhone()
+		let session = await createSmsSession()
+		let smsCode = await createSmsCode({ phone, session })
+
+		await Db.query(`
+			update sms_codes set attempts = 5 where id = $1
+		`, [smsCode.id])
+
+		let res = await Http
+			.post('/sms/verify', verifyPayload(smsCode.id, smsCode.code, session))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('sms/too_many_attempts')
+	})
+
+
+	test("counts parallel attempts", async () => {
+		let phone = getPhone()
+		let session = await createSmsSession()
+		let smsCode = await createSmsCode({ phone, session })
+
+		let responses = await Promise.all(Array.from({ length: 10 }, () => {
+			return Http
+				.post('/sms/verify', verifyPayload(smsCode.id, '000000', session))
+				.catch(err => err.response)
+		}))
+
+		let compared = responses.filter(res => res.data.code === 'sms/invalid_code')
+		expect(compared.length).toBe(5)
+
+		let { rows } = await Db.query(`
+			select attempts from sms_codes where id = $1
+		`, [smsCode.id])
+
+		expect(rows[0].attempts).toBe(5)
+	})
+
+
+	test("fails when code is expired", async () => {
+		let phone = getPhone()
+		let session = await createSmsSession()
+		let smsCode = await createSmsCode({ phone, session })
+
+		await Db.query(`
+			update sms_codes
+			   set expire_at = now() - interval '1 minutes'
+			 where id = $1
+		`, [smsCode.id])
+
+		let res = await Http
+			.post('/sms/verify', verifyPayload(smsCode.id, smsCode.code, session))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe('sms/code_expired')
+	})
+
+
+	test("fails when the session does not match", async () => {
+		let phone = getPhone()
+		let session = await createSmsSession()
+		let otherSession = await createSmsSession()
+		let smsCode = await createSmsCode({ phone, session })
+
+		let res = await Http
+			.post('/sms/verify', verifyPayload(smsCode.id, smsCode.code, otherSession))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+})
@@ -0,0 +1,185 @@
+import { v4 as uuid } from 'uuid'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, EmailPasswordPayload, Flag, ErrorCode } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { generateAdminToken } from '../utils/admin'
+import { createUser, makeGenerateAccessToken, ratPayload, CreatedUser } from '../utils/user'
+
+let PROJECT: string
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+function setSettings(grace_days: number | null) {
+	return Http.post('/settings/account_deletion', { grace_days, send_email: false }, {
+		...adminOptions(),
+		params: { project_id: PROJECT },
+	}).catch(err => err.response)
+}
+
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `account-deletion-${uuid()}`,
+		domain: 'http://localhost:3000',
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+
+	await Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, [Flag.SignIn, Flag.EmailAndPassword]])
+
+	await setSettings(30)
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+function signIn(user: CreatedUser) {
+	let keys = generateKeyPair()
+
+	let payload: EmailPasswordPayload = {
+		email: user.email,
+		password: user.password,
+		public_key: Array.from(Buffer.from(keys.publicKey)),
+		session: uuid()
+	}
+
+	return Http.post(Url.SignIn, payload, {
+		headers: { 'Vulpo-Project': PROJECT }
+	})
+	.then(res => ({ ...res, keys }))
+	.catch(err => ({ ...err.response, keys }))
+}
+
+function getUser(id: string) {
+	return Db.query(`
+		select state
+		     , deleted_at
+		  from users
+		 where id = $1
+	`, [id]).then(res => res.rows[0])
+}
+
+function adminDelete(id: string) {
+	return Http.post(`/user/admin/delete_account/${id}`, null, adminOptions())
+		.catch(err => err.response)
+}
+
+function adminRestore(id: string) {
+	return Http.post(`/user/admin/restore_account/${id}`, null, adminOptions())
+		.catch(err => err.response)
+}
+
+describe("Account Deletion", () => {
+	test("keeps deleted users during the grace period", async () => {
+		let user = await createUser({ project: PROJECT, password: 'password' })
+		let { data, keys } = await signIn(user)
+
+		let url = Url.UserDeleteAccount.replace(':session', data.session)
+		let token = makeGenerateAccessToken(keys.privateKey)({ payload: ratPayload() })
+		let res = await Http.post(url, { value: token }, {
+			headers: { 'Vulpo-Project': PROJECT }
+		})
+
+		expect(res.status).toBe(200)
+
+		let deleted = await getUser(user.id)
+		expect(deleted.state).toBe('deleted')
+		expect(deleted.deleted_at).not.toBe(null)
+
+		let { rows } = await Db.query(`
+			select id
+			  from sessions
+			 where user_id = $1
+		`, [user.id])
+
+		expect(rows.length).toBe(0)
+
+		res = await signIn(user)
+		expect(res.data.code).toBe(ErrorCode.UserDisabled)
+	})
+
+	test("admins can restore deleted users", async () => {
+		let user = await createUser({ project: PROJECT, password: 'password' })
+
+		let res = await adminDelete(user.id)
+		expect(res.status).toBe(200)
+
+		res = await adminRestore(user.id)
+		expect(res.status).toBe(200)
+
+		let restored = await getUser(user.id)
+		expect(restored.state).toBe('active')
+		expect(restored.deleted_at).toBe(null)
+
+		res = await signIn(user)
+		expect(res.status).toBe(200)
+
+		res = await adminRestore(user.id)
+		expect(res.status).toBe(404)
+	})
+
+	test("restored users keep their state", async () => {
+		let user = await createUser({ project: PROJECT, password: 'password' })
+
+		await Db.query(`
+			update users
+			   set state = 'disabled'
+			 where id = $1
+		`, [user.id])
+
+		await adminDelete(user.id)
+		let res = await adminRestore(user.id)
+		expect(res.status).toBe(200)
+
+		let restored = await getUser(user.id)
+		expect(restored.state).toBe('disabled')
+	})
+
+	test("deleting a deleted user removes it", async () => {
+		let user = await createUser({ project: PROJECT, password: 'password' })
+
+		await adminDelete(user.id)
+		let res = await adminDelete(user.id)
+		expect(res.status).toBe(200)
+
+		let removed = await getUser(user.id)
+		expect(removed).toBe(undefined)
+	})
+
+	test("removes users right away without a grace period", async () => {
+		await setSettings(null)
+
+		let user = await createUser({ project: PROJECT, password: 'password' })
+		let res = await adminDelete(user.id)
+		expect(res.status).toBe(200)
+
+		let removed = await getUser(user.id)
+		expect(removed).toBe(undefined)
+
+		await setSettings(30)
+	})
+
+	test("rejects invalid settings", async () => {
+		let res = await setSettings(0)
+		expect(res.status).toBe(400)
+	})
+})
@@ -21,7 +21,7 @@ describe("Create User", () => {
 			email: EMAIL,
 			project_id: PROJECT_ID,
 			display_name: 'display_name',
-			data: {
+			user_metadata: {
 				fuu: 1,
 				bar: 'baz',
 			},
@@ -45,7 +45,7 @@ describe("Create User", () => {
 			select id
 			     , email
 			     , display_name
-			     , data
+			     , user_metadata
 			     , provider_id
 			     , state
 			  from users
@@ -63,7 +63,7 @@ describe("Create User", () => {
 			display_name: user.display_name,
 			provider_id: user.provider_id,
 			state: UserState.Active,
-			data: user.data,
+			user_metadata: user.user_metadata,
 		})
 	})
 
@@ -76,7 +76,7 @@ describe("Create User", () => {
 			password: 'password',
 			project_id: PROJECT_ID,
 			display_name: 'display_name',
-			data: {
+			user_metadata: {
 				fuu: 1,
 				bar: 'baz',
 			},
@@ -101,7 +101,7 @@ describe("Create User", () => {
 			     , users.email
 			     , passwords.hash as "password"
 			     , users.display_name
-			     , users.data
+			     , users.user_metadata
 			     , users.provider_id
 			     , users.state
 			  from users
@@ -119,7 +119,7 @@ describe("Create User", () => {
 			display_name: user.display_name,
 			provider_id: user.provider_id,
 			state: UserState.SetPassword,
-			data: user.data,
+			user_metadata: user.user_metadata,
 		})
 
 		let passwordSet = await argon2.verify(createdUser.password, user.password)
@@ -135,7 +135,7 @@ describe("Create User", () => {
 			password: '1234567',
 			project_id: PROJECT_ID,
 			display_name: 'display_name',
-			data: {
+			user_metadata: {
 				fuu: 1,
 				bar: 'baz',
 			},
@@ -163,7 +163,7 @@ describe("Create User", () => {
 			email: EMAIL,
 			project_id: PROJECT_ID,
 			display_name: 'display_name',
-			data: {
+			user_metadata: {
 				fuu: 1,
 				bar: 'baz',
 			},
@@ -192,7 +192,7 @@ describe("Create User", () => {
 			password: 'passwordpasswordpasswordpasswordpasswordpasswordpasswordpasswordpassword',
 			project_id: PROJECT_ID,
 			display_name: 'display_name',
-			data: {
+			user_metadata: {
 				fuu: 1,
 				bar: 'baz',
 			},
@@ -0,0 +1,181 @@
+import { v4 as uuid } from 'uuid'
+import * as jwt from 'jsonwebtoken'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, ErrorCode, EmailPasswordPayload, Flag } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { generateAdminToken } from '../utils/admin'
+import { CreatedUser, createUser } from '../utils/user'
+
+const PASSWORD = 'password'
+
+let PROJECT: string
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+// the policy is set on a separate project, other tests sign in concurrently
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `email-verification-${uuid()}`,
+		domain: 'http://localhost:3000',
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+
+	await Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, [Flag.SignIn, Flag.EmailAndPassword, Flag.VerifyEmail]])
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+async function setPolicy(policy: 'off' | 'block' | 'mark') {
+	await Http.post('/settings/email_verification', { policy }, {
+		...adminOptions(),
+		params: { project_id: PROJECT },
+	})
+}
+
+function signIn(user: CreatedUser) {
+	let { publicKey } = generateKeyPair()
+
+	let payload: EmailPasswordPayload = {
+		email: user.email,
+		password: user.password,
+		public_key: Array.from(Buffer.from(publicKey)),
+		session: uuid()
+	}
+
+	return Http
+		.post(Url.SignIn, payload, {
+			headers: { 'Vulpo-Project': PROJECT }
+		})
+		.catch(err => err.response)
+}
+
+function resend(email: string) {
+	return Http
+		.post(Url.UserResendEmailVerification, { email }, {
+			headers: { 'Vulpo-Project': PROJECT }
+		})
+		.catch(err => err.response)
+}
+
+describe("Email Verification", () => {
+	test("unverified users can sign in by default", async () => {
+		await setPolicy('off')
+		let user = await createUser({ project: PROJECT, password: PASSWORD })
+
+		let res = await signIn(user)
+		expect(res.status).toBe(200)
+
+		let claims = jwt.decode(res.data.access_token) as jwt.JwtPayload
+		expect(claims.email_verified).toBeUndefined()
+	})
+
+	test("blocks unverified users", async () => {
+		await setPolicy('block')
+		let user = await createUser({ project: PROJECT, password: PASSWORD })
+
+		let res = await signIn(user)
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.UserEmailNotVerified)
+
+		await Db.query(`
+			update users
+			   set email_verified = true
+			 where id = $1
+		`, [user.id])
+
+		res = await signIn(user)
+		expect(res.status).toBe(200)
+	})
+
+	test("marks the tokens of unverified users", async () => {
+		await setPolicy('mark')
+		let user = await createUser({ project: PROJECT, password: PASSWORD })
+
+		let res = await signIn(user)
+		expect(res.status).toBe(200)
+
+		let claims = jwt.decode(res.data.access_token) as jwt.JwtPayload
+		expect(claims.email_verified).toBe(false)
+	})
+
+	test("throttles verification emails", async () => {
+		let user = await createUser({ project: PROJECT, password: PASSWORD })
+
+		await Db.query(`
+			insert into verify_email (token, user_id, project_id, email)
+			values ('token', $1, $2, $3)
+		`, [user.id, PROJECT, user.email])
+
+		let res = await resend(user.email)
+		expect(res.status).toBe(200)
+
+		let { rows } = await Db.query(`
+			select count(*)::int as count
+			  from verify_email
+			 where user_id = $1
+		`, [user.id])
+
+		expect(rows[0].count).toBe(1)
+	})
+
+	test("links only verify the address they were sent to", async () => {
+		let user = await createUser({ project: PROJECT, password: PASSWORD })
+		let token = 'verification-token'
+
+		let { rows } = await Db.query(`
+			insert into verify_email (token, user_id, project_id, email)
+			values (crypt($1, gen_salt('bf')), $2, $3, $4)
+			returning id
+		`, [token, user.id, PROJECT, user.email])
+
+		await Db.query(`
+			update users
+			   set email = $2
+			 where id = $1
+		`, [user.id, `api.test+${uuid()}@vulpo.dev`])
+
+		let res = await Http
+			.post(Url.UserVerifyEmail, { id: rows[0].id, token }, {
+				headers: { 'Vulpo-Project': PROJECT }
+			})
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.TokenInvalid)
+
+		let user_row = await Db.query(`
+			select email_verified
+			  from users
+			 where id = $1
+		`, [user.id])
+
+		expect(user_row.rows[0].email_verified).toBe(false)
+	})
+
+	test("does not reveal unknown users", async () => {
+		let res = await resend(`${uuid()}@vulpo.dev`)
+		expect(res.status).toBe(200)
+	})
+})
@@ -61,7 +61,7 @@ describe("Get User", () => {
 			email_verified: true,
 			photo_url: 'random fuu',
 			traits: ['one', 'two'],
-			data: {
+			user_metadata: {
 				random: 'value',
 				fuu: 124,
 				bar: false
@@ -77,7 +77,7 @@ describe("Get User", () => {
 			     , email_verified = $4
 			     , photo_url = $5
 			     , traits = $6
-			     , data = $7
+			     , user_metadata = $7
 			     , provider_id = $8
 			     , state = $9
 			 where id = $1
@@ -88,7 +88,7 @@ describe("Get User", () => {
 			newUser.email_verified,
 			newUser.photo_url,
 			newUser.traits,
-			newUser.data,
+			newUser.user_metadata,
 			newUser.provider_id,
 			newUser.state,
 		])
@@ -0,0 +1,242 @@
+import { v4 as uuid } from 'uuid'
+import * as bcrypt from 'bcryptjs'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import {
+	Url,
+	ErrorCode,
+	Flag,
+	UserState,
+	AcceptUserInvitationPayload,
+	RequestPasswordlessPayload,
+} from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { generateAdminToken } from '../utils/admin'
+import { createUser, getEmail } from '../utils/user'
+
+const TOKEN = 'invitation-token'
+const SALT = bcrypt.genSaltSync(10)
+const HASHED_TOKEN = bcrypt.hashSync(TOKEN, SALT)
+
+let PROJECT: string
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+// invite-only project, sign up is disabled
+beforeAll(async () => {
+	let res = await Http.post('/admin/project/create', {
+		name: `invitation-${uuid()}`,
+		domain: 'http://localhost:3000',
+	}, adminOptions())
+
+	PROJECT = res.data[0]
+
+	await Db.query(`
+		update projects
+		   set flags = $2
+		 where id = $1
+	`, [PROJECT, [Flag.SignIn, Flag.EmailAndPassword, Flag.AuthenticationLink]])
+})
+
+afterAll(async () => {
+	await Db.query(`
+		delete from projects
+		 where id = $1
+	`, [PROJECT])
+
+	return Db.end()
+})
+
+type Invitation = {
+	id: string;
+	userId: string;
+}
+
+// invitations are created directly, sending emails is not part of these tests
+async function createInvitation(expireAt = new Date(Date.now() + 60_000)): Promise<Invitation> {
+	let user = await createUser({ project: PROJECT, state: UserState.SetPassword })
+
+	let { rows } = await Db.query(`
+		insert into user_invitations(user_id, project_id, token, expire_at)
+		values($1, $2, $3, $4)
+		returning id
+	`, [user.id, PROJECT, HASHED_TOKEN, expireAt])
+
+	return { id: rows[0].id, userId: user.id }
+}
+
+function accept(id: string, token = TOKEN, password?: string) {
+	let { publicKey } = generateKeyPair()
+
+	let payload: AcceptUserInvitationPayload = {
+		id,
+		token,
+		password,
+		public_key: Array.from(Buffer.from(publicKey)),
+		session: uuid(),
+		device_languages: ['en'],
+	}
+
+	return Http
+		.post(Url.UserAcceptInvitation, payload, {
+			headers: { 'Vulpo-Project': PROJECT }
+		})
+		.catch(err => err.response)
+}
+
+describe("User Invitations", () => {
+	test("accepting an invitation activates the user", async () => {
+		let invitation = await createInvitation()
+
+		let res = await accept(invitation.id, TOKEN, 'password')
+		expect(res.status).toBe(200)
+		expect(res.data.user_id).toBe(invitation.userId)
+		expect(res.data.created).toBe(true)
+
+		let { rows } = await Db.query(`
+			select state
+			     , email_verified
+			     , exists(select 1 from passwords where user_id = users.id) as has_password
+			  from users
+			 where id = $1
+		`, [invitation.userId])
+
+		expect(rows[0].state).toBe(UserState.Active)
+		expect(rows[0].email_verified).toBe(true)
+		expect(rows[0].has_password).toBe(true)
+	})
+
+	test("users can accept without a password", async () => {
+		let invitation = await createInvitation()
+
+		let res = await accept(invitation.id)
+		expect(res.status).toBe(200)
+
+		let { rows } = await Db.query(`
+			select provider_id
+			  from users
+			 where id = $1
+		`, [invitation.userId])
+
+		expect(rows[0].provider_id).toBe('link')
+	})
+
+	test("invitations can only be used once", async () => {
+		let invitation = await createInvitation()
+
+		let res = await accept(invitation.id, TOKEN, 'password')
+		expect(res.status).toBe(200)
+
+		res = await accept(invitation.id, TOKEN, 'password')
+		expect(res.data.code).toBe(ErrorCode.TokenNotFound)
+	})
+
+	test("keeps the invitation when the user can't be activated", async () => {
+		let invitation = await createInvitation()
+
+		await Db.query(`
+			update users
+			   set state = 'active'
+			 where id = $1
+		`, [invitation.userId])
+
+		let res = await accept(invitation.id, TOKEN, 'password')
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.TokenInvalid)
+
+		let { rows } = await Db.query(`
+			select id
+			  from user_invitations
+			 where id = $1
+		`, [invitation.id])
+
+		expect(rows.length).toBe(1)
+	})
+
+	test("rejects invalid tokens", async () => {
+		let invitation = await createInvitation()
+
+		let res = await accept(invitation.id, 'invalid-token', 'password')
+		expect(res.status).toBe(403)
+		expect(res.data.code).toBe(ErrorCode.TokenInvalid)
+	})
+
+	test("rejects expired invitations", async () => {
+		let invitation = await createInvitation(new Date(Date.now() - 60_000))
+
+		let res = await accept(invitation.id, TOKEN, 'password')
+		expect(res.data.code).toBe(ErrorCode.TokenExpired)
+	})
+
+	test("revoking removes the invited user", async () => {
+		let invitation = await createInvitation()
+
+		let res = await Http.post('/user/invite/revoke', {
+			id: invitation.id,
+			project_id: PROJECT,
+		}, adminOptions())
+
+		expect(res.status).toBe(200)
+
+		let { rows } = await Db.query(`
+			select id
+			  from users
+			 where id = $1
+		`, [invitation.userId])
+
+		expect(rows.length).toBe(0)
+	})
+
+	test("lists pending invitations", async () => {
+		let invitation = await createInvitation()
+
+		let res = await Http.get('/user/invitations', {
+			...adminOptions(),
+			params: { project: PROJECT },
+		})
+
+		expect(res.status).toBe(200)
+		expect(res.data.map((entry: { id: string }) => entry.id)).toContain(invitation.id)
+	})
+
+	test("active users can not be invited", async () => {
+		let user = await createUser({ project: PROJECT, password: 'password' })
+
+		let res = await Http
+			.post('/user/invite', {
+				project_id: PROJECT,
+				email: user.email,
+			}, adminOptions())
+			.catch(err => err.response)
+
+		expect(res.data.code).toBe(ErrorCode.UserExists)
+	})
+
+	test("passwordless does not create users without sign up", async () => {
+		let { publicKey } = generateKeyPair()
+
+		let payload: RequestPasswordlessPayload = {
+			email: getEmail(),
+			public_key: Array.from(Buffer.from(publicKey)),
+			session: uuid(),
+			device_languages: ['en'],
+		}
+
+		let res = await Http
+			.post(Url.Passwordless, payload, {
+				headers: { 'Vulpo-Project': PROJECT }
+			})
+			.catch(err => err.response)
+
+		expect(res.status).toBe(403)
+	})
+})
@@ -0,0 +1,126 @@
+import { projectKeys } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, ErrorCode } from '@vulpo-dev/auth-sdk'
+
+import Http from '../utils/http'
+import Db from '../utils/db'
+import { PROJECT_ID } from '../utils/env'
+import { makeGenerateAccessToken, makeTokenPayload } from '../utils/user'
+
+const USER_ID = '4b0c8c1c-53c5-4c4f-9f0e-0a7c6e1d1b2a'
+const EMAIL = 'api.test+link@vulpo.dev'
+
+let getAccessToken = makeGenerateAccessToken({
+	key: projectKeys.private_key,
+	passphrase: 'password',
+})
+
+let tokenPayload = makeTokenPayload(USER_ID, PROJECT_ID)
+
+async function removeUser() {
+	await Db.query(`
+		delete from users
+		where id = $1
+	`, [USER_ID])
+}
+
+beforeEach(async () => {
+	await removeUser()
+	await Db.query(`
+		insert into users(id, email, email_verified, project_id, provider_id)
+		values($1, $2, true, $3, 'link')
+	`, [USER_ID, EMAIL, PROJECT_ID])
+})
+
+afterAll(removeUser)
+afterAll(() => Db.end())
+
+describe("Link Providers", () => {
+	test("starts with the sign up provider", async () => {
+		let user = await getUser()
+		expect(user.providers).toEqual(['link'])
+	})
+
+	test("can link a password", async () => {
+		let res = await Http
+			.post(Url.UserLinkPassword, { password: 'password' }, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+
+		let user = await getUser()
+		expect(user.providers).toEqual(['link', 'password'])
+		expect(user.password).toBeTruthy()
+	})
+
+	test("fails when password is already linked", async () => {
+		await Http.post(Url.UserLinkPassword, { password: 'password' }, options())
+
+		let res = await Http
+			.post(Url.UserLinkPassword, { password: 'password' }, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe(ErrorCode.UserProviderLinked)
+	})
+
+	test("can unlink a password", async () => {
+		await Http.post(Url.UserLinkPassword, { password: 'password' }, options())
+
+		let res = await Http
+			.post(Url.UserUnlink, { provider: 'password' }, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(200)
+
+		let user = await getUser()
+		expect(user.providers).toEqual(['link'])
+		expect(user.password).toBeNull()
+	})
+
+	test("can not unlink the last provider", async () => {
+		await Db.query(`
+			update users
+			   set providers = '{google}'
+			 where id = $1
+		`, [USER_ID])
+
+		let res = await Http
+			.post(Url.UserUnlink, { provider: 'google' }, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(400)
+		expect(res.data.code).toBe(ErrorCode.UserLastProvider)
+	})
+
+	test("fails for providers that are not linked", async () => {
+		let res = await Http
+			.post(Url.UserUnlink, { provider: 'google' }, options())
+			.catch(err => err.response)
+
+		expect(res.status).toBe(404)
+	})
+})
+
+async function getUser() {
+	let { rows } = await Db.query(`
+		select users.providers
+		     , passwords.hash as "password"
+		  from users
+		  left join passwords on passwords.user_id = users.id
+		 where users.id = $1
+	`, [USER_ID])
+
+	return rows[0]
+}
+
+function options() {
+	let args = {
+		payload: tokenPayload()
+	}
+
+	return {
+		headers: {
+			'Authorization': `Bearer ${getAccessToken(args)}`,
+		}
+	}
+}
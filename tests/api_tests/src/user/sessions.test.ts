@@ -0,0 +1,142 @@
+import { v4 as uuid } from 'uuid'
+import { admin } from '@vulpo-dev/auth-seeds/data/projects'
+import { Url, ErrorCode, EmailPasswordPayload, UserSession, RevokeSessionPayload } from '@vulpo-dev/auth-sdk'
+
+import Db from '../utils/db'
+import Http from '../utils/http'
+import { generateKeyPair } from '../utils/crypto'
+import { PROJECT_ID } from '../utils/env'
+import { createSession } from '../utils/passwordless'
+import { createAccessToken, createUser } from '../utils/user'
+import { generateAdminToken } from '../utils/admin'
+
+const USER_AGENT = 'Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/118.0.0.0 Safari/537.36'
+
+afterAll(() => Db.end())
+
+function bearer(token: string) {
+	return {
+		headers: {
+			'Authorization': `Bearer ${token}`,
+		}
+	}
+}
+
+function adminOptions() {
+	return {
+		headers: {
+			'Authorization': `Bearer ${generateAdminToken()}`,
+			'Vulpo-Project': admin.id,
+		}
+	}
+}
+
+async function sessionExists(id: string): Promise<boolean> {
+	let { rows } = await Db.query(`
+		select id
+		  from sessions
+		 where id = $1
+	`, [id])
+
+	return rows.length > 0
+}
+
+describe("User Sessions", () => {
+	test("sign in records the device", async () => {
+		let user = await createUser({ password: 'password' })
+		let { publicKey } = generateKeyPair()
+
+		let payload: EmailPasswordPayload = {
+			email: user.email,
+			password: user.password,
+			public_key: Array.from(Buffer.from(publicKey)),
+			session: uuid()
+		}
+
+		await Http.post(Url.SignIn, payload, {
+			headers: { 'User-Agent': USER_AGENT }
+		})
+
+		let token = createAccessToken({ user })
+		let res = await Http.get<Array<UserSession>>(Url.UserSessions, bearer(token))
+
+		expect(res.status).toBe(200)
+		expect(res.data).toHaveLength(1)
+
+		let session = res.data[0]
+		expect(session.id).toBe(payload.session)
+		expect(session.device_name).toBe('Chrome on macOS')
+		expect(session.user_agent).toBe(USER_AGENT)
+		expect(session.ip).toBeTruthy()
+		expect(session.last_refreshed_at).toBeNull()
+	})
+
+	test("lists only the user's sessions", async () => {
+		let user = await createUser()
+		let other = await createUser()
+		let first = await createSession({ user })
+		let second = await createSession({ user })
+		await createSession({ user: other })
+
+		let token = createAccessToken({ user })
+		let res = await Http.get<Array<UserSession>>(Url.UserSessions, bearer(token))
+
+		expect(res.status).toBe(200)
+
+		let ids = res.data.map(session => session.id).sort()
+		expect(ids).toEqual([first.id, second.id].sort())
+	})
+
+	test("revokes a session", async () => {
+		let user = await createUser()
+		let session = await createSession({ user })
+		let token = createAccessToken({ user })
+
+		let payload: RevokeSessionPayload = { id: session.id }
+		let res = await Http.post(Url.RevokeUserSession, payload, bearer(token))
+
+		expect(res.status).toBe(200)
+		expect(await sessionExists(session.id)).toBeFalsy()
+	})
+
+	test("can not revoke the session of another user", async () => {
+		let user = await createUser()
+		let other = await createUser()
+		let session = await createSession({ user: other })
+		let token = createAccessToken({ user })
+
+		let payload: RevokeSessionPayload = { id: session.id }
+		let res = await Http
+			.post(Url.RevokeUserSession, payload, bearer(token))
+			.catch(err => err.response)
+
+		expect(res.status).toBe(404)
+		expect(res.data.code).toBe(ErrorCode.NotFound)
+		expect(await sessionExists(session.id)).toBeTruthy()
+	})
+
+	test("admin can list and revoke sessions", async () => {
+		let user = await createUser()
+		let session = await createSession({ user })
+
+		let list = await Http.get<Array<UserSession>>('/user/admin/sessions', {
+			...adminOptions(),
+			params: {
+				user: user.id,
+				project: PROJECT_ID,
+			}
+		})
+
+		expect(list.status).toBe(200)
+		expect(list.data.map(s => s.id)).toEqual([session.id])
+
+		let res = await Http.post('/user/admin/sessions/revoke', {
+			id: session.id,
+			user: user.id,
+			project: PROJECT_ID,
+		}, adminOptions())
+
+		expect(res.status).toBe(200)
+		expect(await sessionExists(session.id)).toBeFalsy()
+	})
+})
@@ -58,6 +58,9 @@ describe("Set Password", () => {
 		let user = await getUser()
 		expect(user.state).toEqual(UserState.Active)
 		expect(bcrypt.compare(password, user.password)).toBeTruthy()
+
+		// the access tokens issued until now are revoked
+		expect(user.tokens_valid_after).not.toEqual(null)
 	})
 
 	test("fails when user is in incorrect state", async () => {
@@ -142,6 +145,7 @@ async function getUser() {
 	let { rows } = await Db.query(`
 		select passwords.hash as "password"
 		     , state
+		     , tokens_valid_after
 		  from users
 		  left join passwords on passwords.user_id = users.id
 		 where id = $1 
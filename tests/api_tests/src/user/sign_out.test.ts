@@ -1,4 +1,5 @@
 import { v4 as uuid } from 'uuid'
+import { projectKeys } from '@vulpo-dev/auth-seeds/data/projects'
 import { Url, ErrorCode } from '@vulpo-dev/auth-sdk'
 
 import Db from '../utils/db'
@@ -13,6 +14,7 @@ import {
 import {
 	makeGenerateAccessToken,
 	makeGenerateInvalidAccessToken,
+	makeTokenPayload,
 	ratPayload
 } from '../utils/user'
 
@@ -132,6 +134,32 @@ describe("User Sign Out", () => {
 			expect(rows[0].count).toBe("0")
 		})
 
+		test("Revokes access tokens issued before the sign out", async () => {
+			let sessionId = await createSession()
+			let generateUserToken = makeGenerateAccessToken({
+				key: projectKeys.private_key,
+				passphrase: 'password',
+			})
+
+			let payload = makeTokenPayload(USER_ID, PROJECT_ID)()
+			let accessToken = generateUserToken({
+				payload: { ...payload, iat: payload.iat - 10 }
+			})
+
+			let url = Url.SignOutAll.replace(':session', sessionId)
+			await Http.post(url, {
+				value: generateAccessToken({ payload: ratPayload() })
+			})
+
+			let res = await Http
+				.get(Url.UserGet, {
+					headers: { 'Authorization': `Bearer ${accessToken}` }
+				})
+				.catch(err => err.response)
+
+			expect(res.status).toBe(401)
+		})
+
 		test("Fails for invalid token", async () => {
 			let sessionId = await createSession()
 			let token = generateInvalidAccessToken({
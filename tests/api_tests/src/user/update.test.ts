@@ -53,6 +53,12 @@ afterAll(() => {
 })
 afterAll(() => Db.end())
 
+type AdminUpdateUser = UpdateUserPayload & {
+	email: string;
+	traits: Array<string>;
+	app_metadata: Object;
+}
+
 describe("Update user", () => {
 	test("should update values", async () => {
 		let session = await createSession()
@@ -62,7 +68,7 @@ describe("Update user", () => {
 	           set display_name = $2
 	             , email = $3
 	             , traits = $4
-	             , data = $5
+	             , user_metadata = $5
 	             , email_verified = True
 	         where id = $1 
 		`, [USER_ID, 'abc', 'email', [], {}])
@@ -73,9 +79,7 @@ describe("Update user", () => {
 
 		let user: UpdateUserPayload = {
 			display_name: 'test',
-			email: EMAIL,
-			traits: ['one', 'two'],
-			data: {
+			user_metadata: {
 				prop1: 1,
 				prop2: 'bar'
 			},
@@ -94,30 +98,27 @@ describe("Update user", () => {
 			select display_name
 	             , email
 	             , traits
-	             , data
+	             , user_metadata
 	             , email_verified
 	          from users
 	         where id = $1 
 		`, [USER_ID])
 
-		let { email_verified, ...newUser} = rows[0]
+		let { email_verified, email, ...newUser} = rows[0]
 		expect(newUser).toMatchObject(user)
-		expect(email_verified).toBe(false)
+		expect(email).toBe('email')
+		expect(email_verified).toBe(true)
 	})
 
-	test("user.email_verified is true when email is stays the same", async () => {
+	test("rejects email changes", async () => {
 		let token = generateAccessToken({
 			payload: tokenPayload()
 		})
 
-		let user: UpdateUserPayload = {
+		let user = {
 			display_name: 'test',
-			email: EMAIL,
-			traits: ['one', 'two'],
-			data: {
-				prop1: 1,
-				prop2: 'bar'
-			},
+			email: DUPLICATE_EMAIL,
+			user_metadata: {},
 		}
 
 		let res = await Http.post(Url.UserUpdate, user, {
@@ -127,16 +128,15 @@ describe("Update user", () => {
 		})
 		.catch(err => err.response)
 
-		expect(res.status).toBe(200)
+		expect(res.status).toBe(422)
 
 		let { rows } = await Db.query(`
-			select email_verified
+			select email
 	          from users
 	         where id = $1 
 		`, [USER_ID])
 
-		let { email_verified } = rows[0]
-		expect(email_verified).toBe(true)
+		expect(rows[0].email).toBe(EMAIL)
 	})
 
 
@@ -147,9 +147,7 @@ describe("Update user", () => {
 
 		let user: UpdateUserPayload = {
 			display_name: 'test',
-			email: EMAIL,
-			traits: ['one', 'two'],
-			data: {
+			user_metadata: {
 				prop1: 1,
 				prop2: 'bar'
 			},
@@ -166,33 +164,35 @@ describe("Update user", () => {
 	})
 
 
-	test("fails for duplicate email", async () => {
-		let create = makeCreateUser(DUPLICATE_ID, DUPLICATE_EMAIL, PROJECT_ID)
-		await create()
-
+	test("rejects traits and app_metadata", async () => {
 		let token = generateAccessToken({
 			payload: tokenPayload()
 		})
 
-		let user: UpdateUserPayload = {
-			display_name: 'test',
-			email: DUPLICATE_EMAIL,
-			traits: ['one', 'two'],
-			data: {
-				prop1: 1,
-				prop2: 'bar'
-			},
+		let payloads = [
+			{ user_metadata: {}, traits: ['admin'] },
+			{ user_metadata: {}, app_metadata: { plan: 'pro' } },
+		]
+
+		for (let payload of payloads) {
+			let res = await Http.post(Url.UserUpdate, payload, {
+				headers: {
+					'Authorization': `Bearer ${token}`,
+				}
+			})
+			.catch(err => err.response)
+
+			expect(res.status).toBe(422)
 		}
 
-		let res = await Http.post(Url.UserUpdate, user, {
-			headers: {
-				'Authorization': `Bearer ${token}`,
-			}
-		})
-		.catch(err => err.response)
+		let { rows } = await Db.query(`
+			select traits
+			     , app_metadata
+			  from users
+			 where id = $1
+		`, [USER_ID])
 
-		expect(res.status).toBe(400)
-		expect(res.data.code).toBe(ErrorCode.UserExists)
+		expect(rows[0]).toMatchObject({ traits: [], app_metadata: {} })
 	})
 })
 
@@ -205,21 +205,25 @@ describe("Admin: Update user", () => {
 	           set display_name = $2
 	             , email = $3
 	             , traits = $4
-	             , data = $5
+	             , user_metadata = $5
+	             , app_metadata = $5
 	             , email_verified = True
 	         where id = $1 
 		`, [USER_ID, 'abc', 'email', [], {}])
 
 		let token = generateAdminToken()
 
-		let user: UpdateUserPayload = {
+		let user: AdminUpdateUser = {
 			display_name: 'test',
 			email: EMAIL,
 			traits: ['one', 'two'],
-			data: {
+			user_metadata: {
 				prop1: 1,
 				prop2: 'bar'
 			},
+			app_metadata: {
+				plan: 'pro'
+			},
 		}
 
 		let res = await Http.post('/user/admin/update', user, {
@@ -240,7 +244,8 @@ describe("Admin: Update user", () => {
 			select display_name
 	             , email
 	             , traits
-	             , data
+	             , user_metadata
+	             , app_metadata
 	             , email_verified
 	          from users
 	         where id = $1 
@@ -257,7 +262,8 @@ describe("Admin: Update user", () => {
 			select display_name
 	             , email
 	             , traits
-	             , data
+	             , user_metadata
+	             , app_metadata
 	             , email_verified
 	          from users
 	         where id = $1
@@ -269,14 +275,17 @@ describe("Admin: Update user", () => {
 			payload: tokenPayload()
 		})
 
-		let user: UpdateUserPayload = {
+		let user: AdminUpdateUser = {
 			display_name: 'test',
 			email: EMAIL,
 			traits: ['one', 'two'],
-			data: {
+			user_metadata: {
 				prop1: 1,
 				prop2: 'bar'
 			},
+			app_metadata: {
+				plan: 'pro'
+			},
 		}
 
 		let res = await Http.post('/user/admin/update', user, {
@@ -300,14 +309,17 @@ describe("Admin: Update user", () => {
 	test("user.email_verified is true when email is stays the same", async () => {
 		let token = generateAdminToken()
 
-		let user: UpdateUserPayload = {
+		let user: AdminUpdateUser = {
 			display_name: 'test',
 			email: EMAIL,
 			traits: ['one', 'two'],
-			data: {
+			user_metadata: {
 				prop1: 1,
 				prop2: 'bar'
 			},
+			app_metadata: {
+				plan: 'pro'
+			},
 		}
 
 		let res = await Http.post('/user/admin/update', user, {
@@ -340,14 +352,17 @@ describe("Admin: Update user", () => {
 			payload: tokenPayload()
 		})
 
-		let user: UpdateUserPayload = {
+		let user: AdminUpdateUser = {
 			display_name: 'test',
 			email: EMAIL,
 			traits: ['one', 'two'],
-			data: {
+			user_metadata: {
 				prop1: 1,
 				prop2: 'bar'
 			},
+			app_metadata: {
+				plan: 'pro'
+			},
 		}
 
 		let res = await Http.post('/user/admin/update', user, {
@@ -372,14 +387,17 @@ describe("Admin: Update user", () => {
 
 		let token = generateAdminToken()
 
-		let user: UpdateUserPayload = {
+		let user: AdminUpdateUser = {
 			display_name: 'test',
 			email: DUPLICATE_EMAIL,
 			traits: ['one', 'two'],
-			data: {
+			user_metadata: {
 				prop1: 1,
 				prop2: 'bar'
 			},
+			app_metadata: {
+				plan: 'pro'
+			},
 		}
 
 		let res = await Http.post('/user/admin/update', user, {
@@ -77,6 +77,26 @@ describe("Verify Email", () => {
 		expect(tokens.length).toBe(0)		
 	})
 
+	test("tokens can only be used once", async () => {
+		let payload: VerifyEmailPayload = {
+			id: TOKEN_ID,
+			token: TOKEN,
+		}
+
+		let first = await Http
+			.post(Url.UserVerifyEmail, payload)
+			.catch(err => err.response)
+
+		expect(first.status).toBe(200)
+
+		let second = await Http
+			.post(Url.UserVerifyEmail, payload)
+			.catch(err => err.response)
+
+		expect(second.status).toBe(401)
+		expect(second.data.code).toBe(ErrorCode.TokenNotFound)
+	})
+
 	test("returns token/invalid for invalid token", async () => {
 		let payload: VerifyEmailPayload = {
 			id: TOKEN_ID,
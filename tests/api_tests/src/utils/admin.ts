@@ -18,4 +18,23 @@ export function generateAdminToken(invalid = false) {
 			algorithm: 'ES384'
 		}
 	)
-}
\ No newline at end of file
+}
+
+// token for an admin without the "Admin" trait, access is granted
+// through the admin_roles of the user
+export function generateScopedAdminToken(userId: string) {
+
+	let payload: Claims = {
+		sub: userId,
+		traits: [],
+		exp: Math.round(new Date(Date.now() + 15 * 60 * 1000).getTime() / 1000),
+	}
+
+	return jwt.sign(
+		JSON.stringify(payload),
+		adminKeys.private_key,
+		{
+			algorithm: 'ES384'
+		}
+	)
+}
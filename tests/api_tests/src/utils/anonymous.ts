@@ -0,0 +1,37 @@
+import { v4 as uuid } from 'uuid'
+
+import Db from './db'
+import Http from './http'
+import { generateKeyPair } from './crypto'
+import { PROJECT_ID } from './env'
+
+export async function enableAnonymous(project = PROJECT_ID) {
+	await Db.query(`
+		update projects
+		   set flags = array_append(flags, 'auth::anonymous')
+		 where id = $1
+		   and not ('auth::anonymous' = any(flags))
+	`, [project])
+}
+
+export async function disableAnonymous(project = PROJECT_ID) {
+	await Db.query(`
+		update projects
+		   set flags = array_remove(flags, 'auth::anonymous')
+		 where id = $1
+	`, [project])
+}
+
+export function signInPayload() {
+	let { publicKey } = generateKeyPair()
+	return {
+		public_key: Array.from(Buffer.from(publicKey)),
+		session: uuid(),
+		device_languages: ['en'],
+	}
+}
+
+export async function signInAnonymously() {
+	let res = await Http.post('/anonymous/', signInPayload())
+	return res.data
+}
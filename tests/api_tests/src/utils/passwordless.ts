@@ -37,6 +37,7 @@ type CreateToken = {
 	session: CreatedSession;
 	token?: string;
 	id?: string;
+	mode?: 'link' | 'code';
 }
 
 export async function createToken({
@@ -44,6 +45,7 @@ export async function createToken({
 	session,
 	token = uuid(),
 	id = uuid(),
+	mode = 'link',
 }: CreateToken) {
 	let hashedToken = bcrypt.hashSync(token, SALT)
 
@@ -56,10 +58,11 @@ export async function createToken({
 			, project_id
 			, session_id
 			, is_valid
+			, mode
 			)
-		values ($1, $2, $3, $4, $5, $6, True)
+		values ($1, $2, $3, $4, $5, $6, True, $7)
 		returning id
-	`, [id, user.id, user.email, hashedToken, user.project, session.id])
+	`, [id, user.id, user.email, hashedToken, user.project, session.id, mode])
 
 	return { hashedToken, id, value: token }
 }
@@ -97,8 +100,9 @@ export function makeCreateSession(
 				, project_id
 				, user_id
 				, public_key
+				, auth_time
 				)
-			values($1, $2, $3, $4)
+			values($1, $2, $3, $4, now())
 			on conflict do nothing
 		`, [sessionId, projectId, userId, publicKey])
 
@@ -134,8 +138,9 @@ export async function createSession({
 			, project_id
 			, user_id
 			, public_key
+			, auth_time
 			)
-		values($1, $2, $3, $4)
+		values($1, $2, $3, $4, now())
 		on conflict do nothing
 	`, [id, user.project, user.id, keys.publicKey])
 
@@ -1,17 +1,23 @@
 import {JSONSchemaType} from "ajv"
-import { PasswordlessResponse } from '@vulpo-dev/auth-sdk'
+import { PasswordlessResponse as SdkPasswordlessResponse } from '@vulpo-dev/auth-sdk'
 
 import Ajv from '../ajv'
 
+type PasswordlessResponse = SdkPasswordlessResponse & {
+	mode: 'link' | 'code';
+}
+
 export let schema: JSONSchemaType<PasswordlessResponse> = {
 	type: 'object',
 	properties: {
 		id: { type: 'string', uuid: true },
 		session: { type: 'string', uuid: true },
+		mode: { type: 'string', enum: ['link', 'code'] },
 	},
 	required: [
 		'id',
 		'session',
+		'mode',
 	],
 	additionalProperties: false,
 }
@@ -8,19 +8,30 @@ let schema: JSONSchemaType<User> = {
   properties: {
     id: { type: "string", uuid: true },
     display_name: { type: "string", nullable: true },
-    email: { type: "string" },
+    email: { type: "string", nullable: true },
     email_verified: { type: "boolean"  },
+    phone: { type: "string", nullable: true },
+    phone_verified: { type: "boolean"  },
     photo_url: { type: "string", nullable: true },
     traits: {
     	type: "array",
     	items: { type: "string" },
     },
-    data: {
+    user_metadata: {
+    	type: "object",
+    	required: [],
+    	additionalProperties: true
+    },
+    app_metadata: {
     	type: "object",
     	required: [],
     	additionalProperties: true
     },
     provider_id: { type: "string"  },
+    providers: {
+      type: "array",
+      items: { type: "string" }
+    },
     created_at: { type: "string", dateTime: true },
     updated_at: { type: "string", dateTime: true },
     state: { type: "string", enum: Object.values(UserState)  },
@@ -0,0 +1,116 @@
+import { createServer, IncomingMessage } from 'http'
+import { AddressInfo } from 'net'
+import { v4 as uuid } from 'uuid'
+import * as bcrypt from 'bcryptjs'
+
+import Db from './db'
+import { CreatedSession } from './passwordless'
+import { generateKeyPair } from './crypto'
+import { PROJECT_ID } from './env'
+
+const SALT = bcrypt.genSaltSync(10);
+
+export type SentSms = {
+	to: string;
+	from: string | null;
+	message: string;
+	authorization?: string;
+}
+
+export type SmsGateway = {
+	url: string;
+	messages: Array<SentSms>;
+	close: () => Promise<void>;
+}
+
+function readBody(req: IncomingMessage): Promise<string> {
+	return new Promise((resolve, reject) => {
+		let body = ''
+		req.on('data', chunk => body += chunk)
+		req.on('end', () => resolve(body))
+		req.on('error', reject)
+	})
+}
+
+export async function startSmsGateway(): Promise<SmsGateway> {
+	let messages: Array<SentSms> = []
+
+	let server = createServer(async (req, res) => {
+		let body = JSON.parse(await readBody(req))
+		messages.push({ ...body, authorization: req.headers.authorization })
+		res.writeHead(200, { 'Content-Type': 'application/json' })
+		res.end('{}')
+	})
+
+	await new Promise<void>(resolve => server.listen(0, '127.0.0.1', resolve))
+	let { port } = server.address() as AddressInfo
+
+	return {
+		url: `http://127.0.0.1:${port}/send`,
+		messages,
+		close: () => new Promise(resolve => server.close(() => resolve())),
+	}
+}
+
+export async function enableSms(url: string, project = PROJECT_ID) {
+	await Db.query(`
+		update projects
+		   set flags = array_append(flags, 'method::sms')
+		 where id = $1
+		   and not ('method::sms' = any(flags))
+	`, [project])
+
+	await Db.query(`
+		insert into sms_settings(project_id, provider, url, auth_header, sender)
+		values($1, 'http', $2, 'Bearer sms-test', 'Vulpo')
+		on conflict (project_id)
+		  do update
+		        set provider = 'http'
+		          , url = $2
+		          , auth_header = 'Bearer sms-test'
+		          , sender = 'Vulpo'
+	`, [project, url])
+}
+
+export let getPhone = () => {
+	let digits = Array.from({ length: 9 }, () => Math.floor(Math.random() * 10)).join('')
+	return `+43${digits}`
+}
+
+export async function createSmsSession(project = PROJECT_ID): Promise<CreatedSession> {
+	let id = uuid()
+	let keys = generateKeyPair()
+
+	await Db.query(`
+		insert into sessions(id, project_id, public_key)
+		values($1, $2, $3)
+	`, [id, project, keys.publicKey])
+
+	return { id, keys }
+}
+
+type CreateSmsCode = {
+	phone: string;
+	session: CreatedSession;
+	userId?: string | null;
+	code?: string;
+	project?: string;
+}
+
+export async function createSmsCode({
+	phone,
+	session,
+	userId = null,
+	code = '123456',
+	project = PROJECT_ID,
+}: CreateSmsCode) {
+	let id = uuid()
+	let hashedCode = bcrypt.hashSync(code, SALT)
+
+	await Db.query(`
+		insert into sms_codes(id, user_id, phone, code, project_id, session_id)
+		values($1, $2, $3, $4, $5, $6)
+	`, [id, userId, phone, hashedCode, project, session.id])
+
+	return { id, code }
+}
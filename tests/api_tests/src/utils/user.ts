@@ -65,12 +65,15 @@ export function makeTokenPayload(
 ) {
 	return function tokenPayload(minutes = 5) {
 		let now = new Date()
+		let iat = Math.floor(now.getTime() / 1000)
 		let exp = Math.ceil(now.setMinutes(now.getMinutes() + minutes) / 1000)
 		return {
 			exp,
+			iat,
 			sub,
 			iss,
 			traits: [],
+			auth_time: iat,
 		}
 	}
 }
@@ -126,12 +129,15 @@ export async function createUser({
 
 export function createTokenPayload(sub: string, iss: string, minutes = 5) {
 	let now = new Date()
+	let iat = Math.floor(now.getTime() / 1000)
 	let exp = Math.ceil(now.setMinutes(now.getMinutes() + minutes) / 1000)
 	return {
 		exp,
+		iat,
 		sub,
 		iss,
 		traits: [],
+		auth_time: iat,
 	}
 }
 